@@ -0,0 +1,104 @@
+pub const AGENT_ENV_SEPARATOR: char = ';';
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgentEnvVar {
+    pub key: String,
+    pub value: String,
+}
+
+pub fn parse_agent_env_vars(raw: &str) -> Result<Vec<AgentEnvVar>, String> {
+    let mut parsed = Vec::new();
+    for segment in raw
+        .split([AGENT_ENV_SEPARATOR, '\n'])
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+    {
+        let Some((raw_key, raw_value)) = segment.split_once('=') else {
+            return Err(format!("'{segment}' must be KEY=VALUE"));
+        };
+        let key = raw_key.trim();
+        if !env_var_key_is_valid(key) {
+            return Err(format!("invalid env key '{key}'"));
+        }
+        let value = raw_value.trim();
+        if value.is_empty() {
+            return Err(format!("env '{key}' cannot have empty value"));
+        }
+        parsed.push(AgentEnvVar {
+            key: key.to_string(),
+            value: value.to_string(),
+        });
+    }
+    Ok(parsed)
+}
+
+pub fn parse_agent_env_vars_from_entries(entries: &[String]) -> Result<Vec<AgentEnvVar>, String> {
+    parse_agent_env_vars(entries.join("; ").as_str())
+}
+
+/// Parses configured agent env entries into the `(key, value)` pairs a
+/// launch request exports before starting the agent.
+pub fn agent_env_pairs(entries: &[String]) -> Result<Vec<(String, String)>, String> {
+    parse_agent_env_vars_from_entries(entries).map(|vars| {
+        vars.into_iter()
+            .map(|entry| (entry.key, entry.value))
+            .collect()
+    })
+}
+
+pub fn format_agent_env_vars(entries: &[String]) -> String {
+    entries
+        .iter()
+        .map(|entry| entry.trim())
+        .filter(|entry| !entry.is_empty())
+        .collect::<Vec<&str>>()
+        .join("; ")
+}
+
+pub fn encode_agent_env_vars(raw: &str) -> Result<Vec<String>, String> {
+    parse_agent_env_vars(raw).map(|vars| {
+        vars.into_iter()
+            .map(|entry| format!("{}={}", entry.key, entry.value))
+            .collect()
+    })
+}
+
+fn env_var_key_is_valid(key: &str) -> bool {
+    let mut chars = key.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    if !(first.is_ascii_alphabetic() || first == '_') {
+        return false;
+    }
+    chars.all(|character| character.is_ascii_alphanumeric() || character == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{agent_env_pairs, encode_agent_env_vars};
+
+    #[test]
+    fn agent_env_entries_parse_into_pairs() {
+        assert_eq!(
+            agent_env_pairs(&["A=1; B = two".to_string()]),
+            Ok(vec![
+                ("A".to_string(), "1".to_string()),
+                ("B".to_string(), "two".to_string()),
+            ])
+        );
+        assert!(agent_env_pairs(&["broken".to_string()]).is_err());
+    }
+
+    #[test]
+    fn agent_env_rejects_invalid_keys_and_empty_values() {
+        assert_eq!(
+            agent_env_pairs(&["1BAD=x".to_string()]),
+            Err("invalid env key '1BAD'".to_string())
+        );
+        assert_eq!(
+            encode_agent_env_vars("A=1\nEMPTY="),
+            Err("env 'EMPTY' cannot have empty value".to_string())
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use std::path::Path;
 
 use crate::domain::{AgentType, PermissionMode, Workspace};
-use crate::infrastructure::config::ThemeName;
+use crate::infrastructure::themes::ThemeSelection;
 
 use super::execution::kill_tmux_session_command;
 use super::sessions::{session_name_for_task, session_name_for_workspace_in_project};
@@ -13,7 +13,7 @@ use super::{
 pub fn launch_request_for_workspace(
     workspace: &Workspace,
     prompt: Option<String>,
-    theme_name: ThemeName,
+    theme: ThemeSelection,
     workspace_init_command: Option<String>,
     permission_mode: PermissionMode,
     agent_env: Vec<(String, String)>,
@@ -28,7 +28,7 @@ pub fn launch_request_for_workspace(
         workspace_name: workspace.name.clone(),
         workspace_path: workspace.path.clone(),
         agent: workspace.agent,
-        theme_name,
+        theme,
         prompt,
         workspace_init_command,
         permission_mode,
@@ -42,7 +42,7 @@ pub fn shell_launch_request_for_workspace(
     workspace: &Workspace,
     session_name: String,
     command: String,
-    theme_name: ThemeName,
+    theme: ThemeSelection,
     workspace_init_command: Option<String>,
     capture_cols: Option<u16>,
     capture_rows: Option<u16>,
@@ -51,7 +51,7 @@ pub fn shell_launch_request_for_workspace(
         session_name,
         workspace_path: workspace.path.clone(),
         command,
-        theme_name,
+        theme,
         workspace_init_command,
         capture_cols,
         capture_rows,
@@ -115,7 +115,7 @@ pub fn build_task_launch_plan(request: &super::TaskLaunchRequest) -> LaunchPlan
         workspace_name: request.task_slug.clone(),
         workspace_path: request.task_root.clone(),
         agent: request.agent,
-        theme_name: request.theme_name,
+        theme: request.theme.clone(),
         prompt: request.prompt.clone(),
         workspace_init_command: request.workspace_init_command.clone(),
         permission_mode: request.permission_mode,
@@ -147,7 +147,7 @@ pub fn build_shell_launch_plan(request: &ShellLaunchRequest) -> LaunchPlan {
         workspace_name: request.session_name.clone(),
         workspace_path: request.workspace_path.clone(),
         agent: AgentType::Codex,
-        theme_name: request.theme_name,
+        theme: request.theme.clone(),
         prompt: None,
         workspace_init_command: request.workspace_init_command.clone(),
         permission_mode: PermissionMode::Default,
@@ -207,10 +207,7 @@ fn tmux_launch_plan(
             "off".to_string(),
         ],
     ];
-    pre_launch_cmds.extend(tmux_theme_commands(
-        session_name.as_str(),
-        request.theme_name,
-    ));
+    pre_launch_cmds.extend(tmux_theme_commands(session_name.as_str(), &request.theme));
     if let Some(agent_env_cmd) = build_agent_env_command(&request.agent_env) {
         pre_launch_cmds.push(vec![
             "tmux".to_string(),
@@ -433,8 +430,11 @@ fn build_launcher_script(agent_cmd: &str, prompt: &str, launcher_path: &Path) ->
 mod tests {
     use std::path::PathBuf;
 
+    use crate::application::agent_runtime::agent_env::agent_env_pairs;
     use crate::application::agent_runtime::{LaunchRequest, TaskLaunchRequest};
     use crate::domain::{AgentType, PermissionMode};
+    use crate::infrastructure::config::ProjectDefaults;
+    use crate::infrastructure::shared_config::{SharedProjectConfig, layer_project_config};
 
     use super::super::capture::tmux_capture_error_indicates_missing_session;
     use super::{
@@ -448,7 +448,7 @@ mod tests {
             task_slug: "flohome-launch".to_string(),
             task_root: PathBuf::from("/tmp/.grove/tasks/flohome-launch"),
             agent: AgentType::Codex,
-            theme_name: crate::infrastructure::config::ThemeName::default(),
+            theme: crate::infrastructure::themes::ThemeSelection::default(),
             prompt: None,
             workspace_init_command: None,
             permission_mode: PermissionMode::Default,
@@ -491,7 +491,7 @@ mod tests {
             task_slug: "my-task".to_string(),
             task_root: PathBuf::from("/tmp/.grove/tasks/my-task"),
             agent: AgentType::Claude,
-            theme_name: crate::infrastructure::config::ThemeName::default(),
+            theme: crate::infrastructure::themes::ThemeSelection::default(),
             prompt: None,
             workspace_init_command: None,
             permission_mode: PermissionMode::Default,
@@ -522,7 +522,7 @@ mod tests {
             workspace_name: "auth-flow".to_string(),
             workspace_path: PathBuf::from("/repos/grove-auth-flow"),
             agent: AgentType::Claude,
-            theme_name: crate::infrastructure::config::ThemeName::default(),
+            theme: crate::infrastructure::themes::ThemeSelection::default(),
             prompt: None,
             workspace_init_command: None,
             permission_mode: PermissionMode::Default,
@@ -553,7 +553,7 @@ mod tests {
             workspace_name: "auth-flow".to_string(),
             workspace_path: PathBuf::from("/repos/grove-auth-flow"),
             agent: AgentType::Claude,
-            theme_name: crate::infrastructure::config::ThemeName::default(),
+            theme: crate::infrastructure::themes::ThemeSelection::default(),
             prompt: None,
             workspace_init_command: None,
             permission_mode: PermissionMode::Default,
@@ -655,7 +655,7 @@ mod tests {
             workspace_name: "auth-flow".to_string(),
             workspace_path: PathBuf::from("/repos/grove-auth-flow"),
             agent: AgentType::Claude,
-            theme_name: crate::infrastructure::config::ThemeName::default(),
+            theme: crate::infrastructure::themes::ThemeSelection::default(),
             prompt: None,
             workspace_init_command: None,
             permission_mode: PermissionMode::Unsafe,
@@ -690,7 +690,7 @@ mod tests {
             workspace_name: "auth-flow".to_string(),
             workspace_path: PathBuf::from("/repos/grove-auth-flow"),
             agent: AgentType::Claude,
-            theme_name: crate::infrastructure::config::ThemeName::default(),
+            theme: crate::infrastructure::themes::ThemeSelection::default(),
             prompt: None,
             workspace_init_command: Some("direnv allow".to_string()),
             permission_mode: PermissionMode::Default,
@@ -732,7 +732,7 @@ mod tests {
             workspace_name: "auth-flow".to_string(),
             workspace_path: PathBuf::from("/repos/grove-auth-flow"),
             agent: AgentType::Claude,
-            theme_name: crate::infrastructure::config::ThemeName::default(),
+            theme: crate::infrastructure::themes::ThemeSelection::default(),
             prompt: None,
             workspace_init_command: Some("echo init".to_string()),
             permission_mode: PermissionMode::Default,
@@ -758,7 +758,7 @@ mod tests {
             workspace_name: "auth-flow".to_string(),
             workspace_path: PathBuf::from("/repos/grove-auth-flow"),
             agent: AgentType::Claude,
-            theme_name: crate::infrastructure::config::ThemeName::CatppuccinMocha,
+            theme: crate::infrastructure::config::ThemeName::CatppuccinMocha.into(),
             prompt: None,
             workspace_init_command: None,
             permission_mode: PermissionMode::Unsafe,
@@ -793,7 +793,7 @@ mod tests {
             workspace_name: "auth-flow".to_string(),
             workspace_path: PathBuf::from("/repos/grove-auth-flow"),
             agent: AgentType::Claude,
-            theme_name: crate::infrastructure::config::ThemeName::CatppuccinMocha,
+            theme: crate::infrastructure::config::ThemeName::CatppuccinMocha.into(),
             prompt: None,
             workspace_init_command: None,
             permission_mode: PermissionMode::Default,
@@ -837,7 +837,7 @@ mod tests {
             workspace_name: "auth-flow".to_string(),
             workspace_path: PathBuf::from("/repos/grove-auth-flow"),
             agent: AgentType::Claude,
-            theme_name: crate::infrastructure::config::ThemeName::default(),
+            theme: crate::infrastructure::themes::ThemeSelection::default(),
             prompt: None,
             workspace_init_command: None,
             permission_mode: PermissionMode::Default,
@@ -871,6 +871,41 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn untrusted_shared_agent_env_never_reaches_the_launch_plan() {
+        let shared = toml::from_str::<SharedProjectConfig>(
+            "[agent_env]\nclaude = [\"ANTHROPIC_BASE_URL=https://attacker.example\"]\n",
+        )
+        .expect("shared config should parse");
+        let effective = layer_project_config(&ProjectDefaults::default(), &shared);
+        let request = LaunchRequest {
+            session_name: None,
+            task_slug: None,
+            project_name: None,
+            workspace_name: "auth-flow".to_string(),
+            workspace_path: PathBuf::from("/repos/grove-auth-flow"),
+            agent: AgentType::Claude,
+            theme: crate::infrastructure::themes::ThemeSelection::default(),
+            prompt: None,
+            workspace_init_command: None,
+            permission_mode: PermissionMode::Default,
+            agent_env: agent_env_pairs(effective.agent_env(AgentType::Claude))
+                .expect("env should parse"),
+            capture_cols: None,
+            capture_rows: None,
+        };
+
+        let plan = build_launch_plan(&request);
+
+        assert!(
+            plan.pre_launch_cmds
+                .iter()
+                .chain(std::iter::once(&plan.launch_cmd))
+                .flatten()
+                .all(|argument| !argument.contains("ANTHROPIC_BASE_URL"))
+        );
+    }
+
     #[test]
     fn launch_plan_with_prompt_writes_launcher_script() {
         let request = LaunchRequest {
@@ -880,7 +915,7 @@ mod tests {
             workspace_name: "db_migration".to_string(),
             workspace_path: PathBuf::from("/repos/grove-db_migration"),
             agent: AgentType::Codex,
-            theme_name: crate::infrastructure::config::ThemeName::default(),
+            theme: crate::infrastructure::themes::ThemeSelection::default(),
             prompt: Some("fix migration".to_string()),
             workspace_init_command: None,
             permission_mode: PermissionMode::Default,
@@ -935,7 +970,7 @@ mod tests {
             workspace_name: "auth-flow".to_string(),
             workspace_path: PathBuf::from("/repos/grove-auth-flow"),
             agent: AgentType::Claude,
-            theme_name: crate::infrastructure::config::ThemeName::default(),
+            theme: crate::infrastructure::themes::ThemeSelection::default(),
             prompt: None,
             workspace_init_command: None,
             permission_mode: PermissionMode::Default,
@@ -972,7 +1007,7 @@ mod tests {
             workspace_name: "auth-flow".to_string(),
             workspace_path: PathBuf::from("/repos/grove-auth-flow"),
             agent: AgentType::Claude,
-            theme_name: crate::infrastructure::config::ThemeName::default(),
+            theme: crate::infrastructure::themes::ThemeSelection::default(),
             prompt: None,
             workspace_init_command: Some("direnv allow".to_string()),
             permission_mode: PermissionMode::Unsafe,
@@ -0,0 +1,50 @@
+use crate::infrastructure::shared_config::EffectiveProjectConfig;
+
+/// Replaced with the task's notes wherever it appears in a launch prompt.
+pub const TASK_NOTES_PROMPT_PLACEHOLDER: &str = "{notes}";
+
+/// Expands `@name` into the project's prompt template, then fills `{notes}`
+/// with the task's notes so they reach the agent as context.
+pub fn expand_launch_prompt(
+    prompt: String,
+    project: Option<&EffectiveProjectConfig>,
+    task_notes: &str,
+) -> String {
+    let expanded = project
+        .and_then(|project| project.expand_prompt(prompt.as_str()))
+        .unwrap_or(prompt);
+    expanded.replace(TASK_NOTES_PROMPT_PLACEHOLDER, task_notes.trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expand_launch_prompt;
+    use crate::infrastructure::config::ProjectDefaults;
+    use crate::infrastructure::shared_config::{SharedProjectConfig, layer_project_config};
+
+    #[test]
+    fn templates_expand_before_task_notes_fill_in() {
+        let shared = toml::from_str::<SharedProjectConfig>(
+            "[prompt_templates]\nreview = \"Review this.\\n{notes}\"\n",
+        )
+        .expect("shared config should parse");
+        let project = layer_project_config(&ProjectDefaults::default(), &shared);
+
+        assert_eq!(
+            expand_launch_prompt(
+                "@review".to_string(),
+                Some(&project),
+                "\nKeep the API stable.\n"
+            ),
+            "Review this.\nKeep the API stable."
+        );
+        assert_eq!(
+            expand_launch_prompt("@missing {notes}".to_string(), Some(&project), ""),
+            "@missing "
+        );
+        assert_eq!(
+            expand_launch_prompt("fix it".to_string(), None, "notes"),
+            "fix it"
+        );
+    }
+}
@@ -2,17 +2,20 @@ use std::path::PathBuf;
 use std::time::Duration;
 
 use crate::domain::{AgentType, PermissionMode, Workspace};
-use crate::infrastructure::config::ThemeName;
+use crate::infrastructure::themes::ThemeSelection;
 
+pub mod agent_env;
 mod agents;
 pub mod capture;
 pub mod execution;
 pub mod launch_plan;
+pub mod launch_prompt;
 pub mod polling;
 pub mod reconciliation;
 pub mod restart;
 mod sessions;
 pub mod status;
+mod tmux_input;
 mod tmux_theme;
 
 pub(crate) use capture::evaluate_capture_change;
@@ -44,17 +47,19 @@ pub use restart::{
     infer_workspace_permission_mode, restart_workspace_in_pane_with_io,
 };
 pub use sessions::{
-    git_preview_session_if_ready, git_session_name_for_workspace, live_preview_agent_session,
-    live_preview_capture_target_for_tab, live_preview_session_for_tab, session_name_for_task,
-    session_name_for_task_worktree, session_name_for_workspace_in_project,
-    session_name_for_workspace_ref, shell_session_name_for_workspace,
-    workspace_can_enter_interactive, workspace_can_start_agent, workspace_can_stop_agent,
-    workspace_session_for_preview_tab,
+    git_preview_session_if_ready, git_session_name_for_workspace, is_grove_session_name,
+    live_preview_agent_session, live_preview_capture_target_for_tab, live_preview_session_for_tab,
+    session_name_for_task, session_name_for_task_worktree, session_name_for_workspace_in_project,
+    session_name_for_workspace_ref, shell_session_name_for_workspace, task_session_prefix,
+    task_worktree_session_prefix, workspace_can_enter_interactive, workspace_can_start_agent,
+    workspace_can_stop_agent, workspace_session_for_preview_tab, workspace_session_prefix,
 };
 pub(crate) use status::{detect_status_with_session_override, latest_assistant_attention_marker};
+pub use tmux_input::{
+    paste_and_submit_to_session, paste_into_session, submit_session_input_command,
+};
 pub use tmux_theme::{grove_managed_tmux_sessions, tmux_theme_commands};
 
-pub const TMUX_SESSION_PREFIX: &str = "grove-ws-";
 const GROVE_LAUNCHER_SCRIPT_PATH: &str = ".grove/start.sh";
 const WAITING_PATTERNS: [&str; 9] = [
     "[y/n]",
@@ -96,7 +101,7 @@ pub struct LaunchRequest {
     pub workspace_name: String,
     pub workspace_path: PathBuf,
     pub agent: AgentType,
-    pub theme_name: ThemeName,
+    pub theme: ThemeSelection,
     pub prompt: Option<String>,
     pub workspace_init_command: Option<String>,
     pub permission_mode: PermissionMode,
@@ -110,7 +115,7 @@ pub struct TaskLaunchRequest {
     pub task_slug: String,
     pub task_root: PathBuf,
     pub agent: AgentType,
-    pub theme_name: ThemeName,
+    pub theme: ThemeSelection,
     pub prompt: Option<String>,
     pub workspace_init_command: Option<String>,
     pub permission_mode: PermissionMode,
@@ -150,7 +155,7 @@ pub struct ShellLaunchRequest {
     pub session_name: String,
     pub workspace_path: PathBuf,
     pub command: String,
-    pub theme_name: ThemeName,
+    pub theme: ThemeSelection,
     pub workspace_init_command: Option<String>,
     pub capture_cols: Option<u16>,
     pub capture_rows: Option<u16>,
@@ -1,11 +1,29 @@
 use std::collections::HashSet;
 
 use crate::domain::{Workspace, WorkspaceStatus};
+use crate::infrastructure::profile::tmux_session_root;
 
-use super::{LivePreviewTarget, TMUX_SESSION_PREFIX};
+use super::LivePreviewTarget;
 
-const TASK_SESSION_PREFIX: &str = "grove-task-";
-const TASK_WORKTREE_SESSION_PREFIX: &str = "grove-wt-";
+/// Session prefixes start with the active profile's tmux prefix (`grove` by
+/// default), so two profiles never share session names.
+pub fn workspace_session_prefix() -> String {
+    format!("{}-ws-", tmux_session_root())
+}
+
+pub fn task_session_prefix() -> String {
+    format!("{}-task-", tmux_session_root())
+}
+
+pub fn task_worktree_session_prefix() -> String {
+    format!("{}-wt-", tmux_session_root())
+}
+
+pub fn is_grove_session_name(session_name: &str) -> bool {
+    session_name.starts_with(task_session_prefix().as_str())
+        || session_name.starts_with(task_worktree_session_prefix().as_str())
+        || session_name.starts_with(workspace_session_prefix().as_str())
+}
 
 pub(crate) fn sanitize_workspace_name(name: &str) -> String {
     let mut out = String::new();
@@ -59,14 +77,16 @@ pub fn session_name_for_workspace_ref(workspace: &Workspace) -> String {
 
 pub fn session_name_for_task(task_slug: &str) -> String {
     format!(
-        "{TASK_SESSION_PREFIX}{}",
+        "{}{}",
+        task_session_prefix(),
         sanitize_workspace_name(task_slug)
     )
 }
 
 pub fn session_name_for_task_worktree(task_slug: &str, repository_name: &str) -> String {
     format!(
-        "{TASK_WORKTREE_SESSION_PREFIX}{}-{}",
+        "{}{}-{}",
+        task_worktree_session_prefix(),
         sanitize_workspace_name(task_slug),
         sanitize_workspace_name(repository_name)
     )
@@ -87,13 +107,15 @@ pub fn session_name_for_workspace_in_project(
     if let Some(project_name) = project_name {
         let project = sanitize_workspace_name(project_name);
         return format!(
-            "{TMUX_SESSION_PREFIX}{project}-{}",
+            "{}{project}-{}",
+            workspace_session_prefix(),
             sanitize_workspace_name(workspace_name)
         );
     }
 
     format!(
-        "{TMUX_SESSION_PREFIX}{}",
+        "{}{}",
+        workspace_session_prefix(),
         sanitize_workspace_name(workspace_name)
     )
 }
@@ -318,7 +340,7 @@ mod tests {
         let request = launch_request_for_workspace(
             &workspace,
             Some("run checks".to_string()),
-            crate::infrastructure::config::ThemeName::default(),
+            crate::infrastructure::themes::ThemeSelection::default(),
             Some("direnv allow".to_string()),
             PermissionMode::Unsafe,
             vec![(
@@ -359,7 +381,7 @@ mod tests {
             &workspace,
             "grove-ws-feature-git".to_string(),
             "lazygit".to_string(),
-            crate::infrastructure::config::ThemeName::default(),
+            crate::infrastructure::themes::ThemeSelection::default(),
             Some("direnv allow".to_string()),
             Some(120),
             Some(40),
@@ -385,7 +407,7 @@ mod tests {
             &fixture_workspace("feature", false),
             "grove-ws-feature-shell".to_string(),
             String::new(),
-            crate::infrastructure::config::ThemeName::default(),
+            crate::infrastructure::themes::ThemeSelection::default(),
             None,
             Some(120),
             Some(40),
@@ -401,7 +423,7 @@ mod tests {
             &fixture_workspace("feature", false),
             "grove-ws-feature-shell".to_string(),
             String::new(),
-            crate::infrastructure::config::ThemeName::default(),
+            crate::infrastructure::themes::ThemeSelection::default(),
             Some("direnv allow".to_string()),
             Some(120),
             Some(40),
@@ -427,7 +449,7 @@ mod tests {
             &fixture_workspace("feature", false),
             "grove-ws-feature-shell".to_string(),
             String::new(),
-            crate::infrastructure::config::ThemeName::default(),
+            crate::infrastructure::themes::ThemeSelection::default(),
             Some("echo init".to_string()),
             Some(120),
             Some(40),
@@ -455,7 +477,7 @@ mod tests {
             &fixture_workspace("feature", false),
             "grove-ws-feature-shell".to_string(),
             "yarn test".to_string(),
-            crate::infrastructure::config::ThemeName::default(),
+            crate::infrastructure::themes::ThemeSelection::default(),
             Some("direnv allow".to_string()),
             Some(120),
             Some(40),
@@ -480,7 +502,7 @@ mod tests {
             &fixture_workspace("feature", false),
             "grove-ws-feature-shell".to_string(),
             "bash".to_string(),
-            crate::infrastructure::config::ThemeName::default(),
+            crate::infrastructure::themes::ThemeSelection::default(),
             None,
             Some(120),
             Some(40),
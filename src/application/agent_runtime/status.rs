@@ -267,7 +267,7 @@ pub(crate) fn exit_code_file_path(session_name: &str) -> std::path::PathBuf {
     std::path::PathBuf::from(format!("/tmp/grove-exit-{session_name}"))
 }
 
-fn read_session_exit_code(session_name: &str) -> Option<i32> {
+pub(crate) fn read_session_exit_code(session_name: &str) -> Option<i32> {
     let path = exit_code_file_path(session_name);
     let content = std::fs::read_to_string(path).ok()?;
     content.trim().parse::<i32>().ok()
@@ -0,0 +1,49 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use crate::infrastructure::process::{execute_command, stderr_or_status};
+
+/// Pastes `text` into the session through a tmux buffer, so multi-line text
+/// arrives as one paste instead of a stream of key presses.
+pub fn paste_into_session(target_session: &str, text: &str) -> std::io::Result<()> {
+    let mut load_child = Command::new("tmux")
+        .args(["load-buffer", "-"])
+        .stdin(Stdio::piped())
+        .spawn()?;
+    if let Some(stdin) = load_child.stdin.as_mut() {
+        stdin.write_all(text.as_bytes())?;
+    }
+    let load_status = load_child.wait()?;
+    if !load_status.success() {
+        return Err(std::io::Error::other(format!(
+            "tmux load-buffer failed for '{target_session}': exit status {load_status}"
+        )));
+    }
+
+    let paste_output = Command::new("tmux")
+        .args(["paste-buffer", "-t", target_session])
+        .output()?;
+    if paste_output.status.success() {
+        return Ok(());
+    }
+
+    Err(std::io::Error::other(format!(
+        "tmux paste-buffer failed: {}",
+        stderr_or_status(&paste_output),
+    )))
+}
+
+pub fn submit_session_input_command(target_session: &str) -> Vec<String> {
+    vec![
+        "tmux".to_string(),
+        "send-keys".to_string(),
+        "-t".to_string(),
+        target_session.to_string(),
+        "Enter".to_string(),
+    ]
+}
+
+pub fn paste_and_submit_to_session(target_session: &str, text: &str) -> std::io::Result<()> {
+    paste_into_session(target_session, text)?;
+    execute_command(&submit_session_input_command(target_session))
+}
@@ -1,43 +1,62 @@
 use std::collections::HashSet;
 
-use ftui::Color;
+use ftui::{Color, ResolvedTheme};
 
-use crate::infrastructure::config::ThemeName;
+use crate::infrastructure::themes::{ThemeColor, ThemeSelection, TmuxPalette};
 
-pub fn tmux_theme_commands(session_name: &str, theme_name: ThemeName) -> Vec<Vec<String>> {
-    let theme = crate::ui::tui::ui_theme_for(theme_name);
+use super::sessions::is_grove_session_name;
+
+pub fn tmux_theme_commands(session_name: &str, theme: &ThemeSelection) -> Vec<Vec<String>> {
+    let overrides = theme
+        .custom
+        .as_ref()
+        .map(|custom| custom.tmux)
+        .unwrap_or_default();
+    themed_tmux_commands(session_name, crate::ui::tui::ui_theme_for(theme), overrides)
+}
+
+fn themed_tmux_commands(
+    session_name: &str,
+    theme: ResolvedTheme,
+    overrides: TmuxPalette,
+) -> Vec<Vec<String>> {
+    let pick = |color: Option<ThemeColor>, fallback: Color| {
+        color.map_or(fallback, |color| Color::rgb(color.r, color.g, color.b))
+    };
+    let border = pick(overrides.border, theme.border);
+    let active_border = pick(overrides.active_border, theme.primary);
 
     vec![
-        tmux_style_option(session_name, "pane-border-style", Some(theme.border), None),
+        tmux_style_option(session_name, "pane-border-style", Some(border), None),
         tmux_style_option(
             session_name,
             "pane-active-border-style",
-            Some(theme.primary),
+            Some(active_border),
             None,
         ),
         tmux_style_option(
             session_name,
             "status-style",
-            Some(theme.text),
-            Some(theme.surface),
+            Some(pick(overrides.status_fg, theme.text)),
+            Some(pick(overrides.status_bg, theme.surface)),
         ),
         tmux_style_option(
             session_name,
             "message-style",
-            Some(theme.text),
-            Some(theme.surface),
+            Some(pick(overrides.message_fg, theme.text)),
+            Some(pick(overrides.message_bg, theme.surface)),
         ),
         tmux_style_option(
             session_name,
             "mode-style",
-            Some(theme.selection_fg),
-            Some(theme.selection_bg),
+            Some(pick(overrides.mode_fg, theme.selection_fg)),
+            Some(pick(overrides.mode_bg, theme.selection_bg)),
         ),
-        tmux_option(session_name, "display-panes-colour", tmux_hex(theme.border)),
+        tmux_option(session_name, "display-panes-colour", tmux_hex(border)),
         tmux_option(
             session_name,
             "display-panes-active-colour",
-            tmux_hex(theme.primary),
+            tmux_hex(active_border),
         ),
     ]
 }
@@ -48,11 +67,7 @@ pub fn grove_managed_tmux_sessions(metadata_rows: &str) -> Vec<String> {
         .lines()
         .filter_map(|row| row.split('\t').next())
         .map(str::trim)
-        .filter(|session| {
-            session.starts_with("grove-ws-")
-                || session.starts_with("grove-wt-")
-                || session.starts_with("grove-task-")
-        })
+        .filter(|session| is_grove_session_name(session))
         .filter(|session| seen.insert((*session).to_string()))
         .map(ToOwned::to_owned)
         .collect()
@@ -109,9 +124,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn themed_tmux_commands_prefer_custom_tmux_colors() {
+        let overrides = TmuxPalette {
+            status_bg: Some(ThemeColor { r: 0, g: 0, b: 0 }),
+            active_border: Some(ThemeColor {
+                r: 0xff,
+                g: 0xff,
+                b: 0x00,
+            }),
+            ..TmuxPalette::default()
+        };
+        let commands = themed_tmux_commands(
+            "grove-ws-main",
+            crate::ui::tui::ui_theme_for(&ThemeName::CatppuccinMocha.into()),
+            overrides,
+        );
+
+        assert!(
+            commands.iter().any(
+                |command| command[4] == "status-style" && command[5] == "bg=#000000,fg=#cdd6f4"
+            )
+        );
+        assert!(commands.iter().any(|command| {
+            command[4] == "pane-active-border-style" && command[5] == "fg=#ffff00"
+        }));
+        assert!(
+            commands
+                .iter()
+                .any(|command| command[4] == "pane-border-style" && command[5] == "fg=#6c7086")
+        );
+    }
+
     #[test]
     fn tmux_theme_commands_do_not_override_window_default_colors() {
-        let commands = tmux_theme_commands("grove-ws-main", ThemeName::CatppuccinMocha);
+        let commands = tmux_theme_commands("grove-ws-main", &ThemeName::CatppuccinMocha.into());
 
         assert!(!commands.iter().any(|command| {
             command.len() == 6
@@ -0,0 +1,194 @@
+use crate::domain::{Workspace, WorkspaceStatus};
+
+/// Polls without new agent output before an idle workspace counts as stalled.
+pub const STALLED_IDLE_POLLS: u32 = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AttentionReason {
+    BlockedOnQuestion,
+    PermissionWall,
+    SessionEnded,
+    Finished,
+    Stalled,
+    FallingBehind,
+}
+
+impl AttentionReason {
+    pub const fn rank(self) -> u8 {
+        match self {
+            Self::BlockedOnQuestion => 0,
+            Self::PermissionWall => 1,
+            Self::SessionEnded => 2,
+            Self::Finished => 3,
+            Self::Stalled => 4,
+            Self::FallingBehind => 5,
+        }
+    }
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::BlockedOnQuestion => "blocked_on_question",
+            Self::PermissionWall => "permission_wall",
+            Self::SessionEnded => "session_ended",
+            Self::Finished => "finished",
+            Self::Stalled => "stalled",
+            Self::FallingBehind => "falling_behind",
+        }
+    }
+
+    pub const fn summary(self) -> &'static str {
+        match self {
+            Self::BlockedOnQuestion => "blocked on question",
+            Self::PermissionWall => "permission wall",
+            Self::SessionEnded => "session ended unexpectedly",
+            Self::Finished => "finished, awaiting review",
+            Self::Stalled => "stalled, no output",
+            Self::FallingBehind => "falling behind base",
+        }
+    }
+}
+
+/// What the poller observed about a workspace's agent since the last poll.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttentionSignals<'a> {
+    pub status: WorkspaceStatus,
+    pub waiting_prompt: Option<&'a str>,
+    pub idle_polls: u32,
+    pub agent_running: bool,
+}
+
+/// Classifies why a workspace needs the user, if it does. Falling behind the
+/// base branch is not an agent signal and is left to the caller.
+pub fn classify_attention(
+    workspace: &Workspace,
+    signals: AttentionSignals<'_>,
+) -> Option<AttentionReason> {
+    if workspace.is_orphaned && !workspace.is_main {
+        return Some(AttentionReason::SessionEnded);
+    }
+    if !workspace.supported_agent || !signals.agent_running {
+        return None;
+    }
+    match signals.status {
+        WorkspaceStatus::Waiting => Some(
+            if signals.waiting_prompt.is_some_and(permission_wall_prompt) {
+                AttentionReason::PermissionWall
+            } else {
+                AttentionReason::BlockedOnQuestion
+            },
+        ),
+        WorkspaceStatus::Done => Some(AttentionReason::Finished),
+        WorkspaceStatus::Idle if signals.idle_polls >= STALLED_IDLE_POLLS => {
+            Some(AttentionReason::Stalled)
+        }
+        _ => None,
+    }
+}
+
+fn permission_wall_prompt(prompt: &str) -> bool {
+    let lower = prompt.to_ascii_lowercase();
+    ["approve", "allow", "confirm", "do you want"]
+        .iter()
+        .any(|pattern| lower.contains(pattern))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AttentionReason, AttentionSignals, STALLED_IDLE_POLLS, classify_attention};
+    use crate::domain::{AgentType, Workspace, WorkspaceStatus};
+    use std::path::PathBuf;
+
+    fn workspace() -> Workspace {
+        Workspace::try_new(
+            "feature-a".to_string(),
+            PathBuf::from("/tasks/feature-a/grove"),
+            "feature-a".to_string(),
+            Some(1_700_000_000),
+            AgentType::Claude,
+            WorkspaceStatus::Idle,
+            false,
+        )
+        .expect("workspace should be valid")
+    }
+
+    fn signals(status: WorkspaceStatus) -> AttentionSignals<'static> {
+        AttentionSignals {
+            status,
+            waiting_prompt: None,
+            idle_polls: 0,
+            agent_running: true,
+        }
+    }
+
+    #[test]
+    fn waiting_prompts_split_into_questions_and_permission_walls() {
+        let workspace = workspace();
+        assert_eq!(
+            classify_attention(&workspace, signals(WorkspaceStatus::Waiting)),
+            Some(AttentionReason::BlockedOnQuestion)
+        );
+        assert_eq!(
+            classify_attention(
+                &workspace,
+                AttentionSignals {
+                    waiting_prompt: Some("Do you want to allow this command?"),
+                    ..signals(WorkspaceStatus::Waiting)
+                }
+            ),
+            Some(AttentionReason::PermissionWall)
+        );
+    }
+
+    #[test]
+    fn idle_workspaces_stall_only_after_enough_quiet_polls() {
+        let workspace = workspace();
+        assert_eq!(
+            classify_attention(
+                &workspace,
+                AttentionSignals {
+                    idle_polls: STALLED_IDLE_POLLS - 1,
+                    ..signals(WorkspaceStatus::Idle)
+                }
+            ),
+            None
+        );
+        assert_eq!(
+            classify_attention(
+                &workspace,
+                AttentionSignals {
+                    idle_polls: STALLED_IDLE_POLLS,
+                    ..signals(WorkspaceStatus::Idle)
+                }
+            ),
+            Some(AttentionReason::Stalled)
+        );
+    }
+
+    #[test]
+    fn orphaned_sessions_need_attention_even_without_a_running_agent() {
+        let mut workspace = workspace();
+        workspace.is_orphaned = true;
+        assert_eq!(
+            classify_attention(
+                &workspace,
+                AttentionSignals {
+                    agent_running: false,
+                    ..signals(WorkspaceStatus::Done)
+                }
+            ),
+            Some(AttentionReason::SessionEnded)
+        );
+
+        workspace.is_orphaned = false;
+        assert_eq!(
+            classify_attention(
+                &workspace,
+                AttentionSignals {
+                    agent_running: false,
+                    ..signals(WorkspaceStatus::Done)
+                }
+            ),
+            None
+        );
+    }
+}
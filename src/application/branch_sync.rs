@@ -0,0 +1,473 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use crate::infrastructure::config::ProjectDefaults;
+
+pub const DEFAULT_FETCH_INTERVAL_SECS: u64 = 300;
+const MAX_FETCH_BACKOFF_SECS: u64 = 60 * 60;
+const MAX_FETCH_BACKOFF_DOUBLINGS: u32 = 6;
+const BATCH_MODE_SSH_COMMAND: &str = "ssh -o BatchMode=yes";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct BranchDivergence {
+    pub ahead: usize,
+    pub behind: usize,
+}
+
+impl BranchDivergence {
+    pub const fn is_even(self) -> bool {
+        self.ahead == 0 && self.behind == 0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct WorktreeSyncStatus {
+    pub base_ref: Option<String>,
+    pub base: Option<BranchDivergence>,
+    pub upstream: Option<BranchDivergence>,
+}
+
+impl WorktreeSyncStatus {
+    pub fn behind_base(&self) -> usize {
+        self.base.map_or(0, |divergence| divergence.behind)
+    }
+}
+
+pub fn fetch_interval_for(defaults: &ProjectDefaults) -> Option<Duration> {
+    match defaults
+        .fetch_interval_secs
+        .unwrap_or(DEFAULT_FETCH_INTERVAL_SECS)
+    {
+        0 => None,
+        secs => Some(Duration::from_secs(secs)),
+    }
+}
+
+pub fn worktree_sync_status(worktree_path: &Path, base_branch: Option<&str>) -> WorktreeSyncStatus {
+    if !worktree_path.exists() {
+        return WorktreeSyncStatus::default();
+    }
+
+    let base_ref =
+        base_branch.and_then(|base_branch| base_comparison_ref(worktree_path, base_branch));
+    let base = base_ref
+        .as_deref()
+        .and_then(|reference| divergence_against(worktree_path, reference));
+    let upstream = divergence_against(worktree_path, "@{upstream}");
+    WorktreeSyncStatus {
+        base_ref,
+        base,
+        upstream,
+    }
+}
+
+// Background fetches only move remote-tracking refs, so prefer them over the
+// local base branch, which stays put until someone pulls.
+pub(crate) fn base_comparison_ref(worktree_path: &Path, base_branch: &str) -> Option<String> {
+    let base_branch = base_branch.trim();
+    if base_branch.is_empty() {
+        return None;
+    }
+    [
+        format!("refs/remotes/origin/{base_branch}"),
+        format!("refs/heads/{base_branch}"),
+    ]
+    .into_iter()
+    .find(|reference| {
+        git_succeeds(
+            worktree_path,
+            &["rev-parse", "--verify", "--quiet", reference.as_str()],
+        )
+    })
+}
+
+fn divergence_against(worktree_path: &Path, reference: &str) -> Option<BranchDivergence> {
+    let range = format!("HEAD...{reference}");
+    let output = Command::new("git")
+        .current_dir(worktree_path)
+        .args(["rev-list", "--left-right", "--count", range.as_str()])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_left_right_count(String::from_utf8_lossy(&output.stdout).as_ref())
+}
+
+pub(crate) fn parse_left_right_count(output: &str) -> Option<BranchDivergence> {
+    let mut counts = output.split_whitespace();
+    let ahead = counts.next()?.parse::<usize>().ok()?;
+    let behind = counts.next()?.parse::<usize>().ok()?;
+    if counts.next().is_some() {
+        return None;
+    }
+    Some(BranchDivergence { ahead, behind })
+}
+
+pub fn fetch_project_remotes(repository_path: &Path) -> Result<bool, String> {
+    let remotes = Command::new("git")
+        .current_dir(repository_path)
+        .args(["remote"])
+        .output()
+        .map_err(|error| format!("git remote failed: {error}"))?;
+    if !remotes.status.success() {
+        return Err(format!(
+            "git remote failed: {}",
+            String::from_utf8_lossy(&remotes.stderr).trim()
+        ));
+    }
+    if String::from_utf8_lossy(&remotes.stdout).trim().is_empty() {
+        return Ok(false);
+    }
+
+    let mut fetch = Command::new("git");
+    fetch
+        .current_dir(repository_path)
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .args(["fetch", "--all", "--prune", "--quiet"]);
+    if let Some(ssh_command) = batch_mode_ssh_command(repository_path) {
+        fetch.env("GIT_SSH_COMMAND", ssh_command);
+    }
+    let output = fetch
+        .output()
+        .map_err(|error| format!("git fetch failed: {error}"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        if stderr.is_empty() {
+            return Err(format!("git fetch exited with {}", output.status));
+        }
+        return Err(stderr);
+    }
+    Ok(true)
+}
+
+/// Background fetches must fail instead of waiting on an ssh passphrase or
+/// host key prompt, unless the user already chose how git runs ssh.
+fn batch_mode_ssh_command(repository_path: &Path) -> Option<&'static str> {
+    if std::env::var_os("GIT_SSH_COMMAND").is_some() || std::env::var_os("GIT_SSH").is_some() {
+        return None;
+    }
+    let configured = Command::new("git")
+        .current_dir(repository_path)
+        .args(["config", "--get", "core.sshCommand"])
+        .output()
+        .is_ok_and(|output| output.status.success());
+    (!configured).then_some(BATCH_MODE_SSH_COMMAND)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ProjectFetchState {
+    interval: Duration,
+    next_due_at: Instant,
+    consecutive_failures: u32,
+    in_flight: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FetchScheduler {
+    projects: HashMap<PathBuf, ProjectFetchState>,
+}
+
+impl FetchScheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns projects whose fetch is due and marks them in flight.
+    pub fn take_due(&mut self, now: Instant, projects: &[(PathBuf, Duration)]) -> Vec<PathBuf> {
+        self.projects.retain(|path, _| {
+            projects
+                .iter()
+                .any(|(project_path, _)| project_path == path)
+        });
+
+        let mut due = Vec::new();
+        for (project_path, interval) in projects {
+            let state = self
+                .projects
+                .entry(project_path.clone())
+                .or_insert_with(|| ProjectFetchState {
+                    interval: *interval,
+                    next_due_at: now,
+                    consecutive_failures: 0,
+                    in_flight: false,
+                });
+            if state.interval != *interval {
+                state.interval = *interval;
+                state.next_due_at = state.next_due_at.min(now + *interval);
+            }
+            if state.in_flight || now < state.next_due_at {
+                continue;
+            }
+            state.in_flight = true;
+            due.push(project_path.clone());
+        }
+        due
+    }
+
+    /// Failed fetches back off exponentially so an offline machine does not
+    /// keep spawning git processes every interval.
+    pub fn record_result(&mut self, project_path: &Path, now: Instant, succeeded: bool) {
+        let Some(state) = self.projects.get_mut(project_path) else {
+            return;
+        };
+        state.in_flight = false;
+        if succeeded {
+            state.consecutive_failures = 0;
+            state.next_due_at = now + state.interval;
+            return;
+        }
+
+        state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+        let doublings = state.consecutive_failures.min(MAX_FETCH_BACKOFF_DOUBLINGS);
+        let backoff = state
+            .interval
+            .saturating_mul(1u32 << doublings)
+            .min(Duration::from_secs(MAX_FETCH_BACKOFF_SECS).max(state.interval));
+        state.next_due_at = now + backoff;
+    }
+
+    pub fn consecutive_failures(&self, project_path: &Path) -> u32 {
+        self.projects
+            .get(project_path)
+            .map_or(0, |state| state.consecutive_failures)
+    }
+}
+
+fn git_succeeds(repository_path: &Path, args: &[&str]) -> bool {
+    Command::new("git")
+        .current_dir(repository_path)
+        .args(args)
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BranchDivergence, DEFAULT_FETCH_INTERVAL_SECS, FetchScheduler, batch_mode_ssh_command,
+        fetch_interval_for, parse_left_right_count, worktree_sync_status,
+    };
+    use crate::infrastructure::config::ProjectDefaults;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+    use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+    #[derive(Debug)]
+    struct TestDir {
+        path: PathBuf,
+    }
+
+    impl TestDir {
+        fn new(label: &str) -> Self {
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or(Duration::from_secs(0))
+                .as_nanos();
+            let path = std::env::temp_dir().join(format!(
+                "grove-branch-sync-{label}-{}-{timestamp}",
+                std::process::id()
+            ));
+            fs::create_dir_all(&path).expect("test dir should exist");
+            Self { path }
+        }
+    }
+
+    impl Drop for TestDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+
+    fn git(repo: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .current_dir(repo)
+            .args(args)
+            .output()
+            .expect("git should run");
+        assert!(
+            output.status.success(),
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn parse_left_right_count_reads_ahead_and_behind() {
+        assert_eq!(
+            parse_left_right_count("3\t12\n"),
+            Some(BranchDivergence {
+                ahead: 3,
+                behind: 12
+            })
+        );
+        assert_eq!(parse_left_right_count(""), None);
+        assert_eq!(parse_left_right_count("3"), None);
+        assert_eq!(parse_left_right_count("a\tb"), None);
+    }
+
+    #[test]
+    fn fetch_interval_defaults_and_zero_disables() {
+        let mut defaults = ProjectDefaults::default();
+        assert_eq!(
+            fetch_interval_for(&defaults),
+            Some(Duration::from_secs(DEFAULT_FETCH_INTERVAL_SECS))
+        );
+        defaults.fetch_interval_secs = Some(60);
+        assert_eq!(fetch_interval_for(&defaults), Some(Duration::from_secs(60)));
+        defaults.fetch_interval_secs = Some(0);
+        assert_eq!(fetch_interval_for(&defaults), None);
+    }
+
+    #[test]
+    fn scheduler_fetches_new_projects_immediately_and_skips_in_flight() {
+        let now = Instant::now();
+        let project = PathBuf::from("/repos/flohome");
+        let projects = vec![(project.clone(), Duration::from_secs(60))];
+        let mut scheduler = FetchScheduler::new();
+
+        assert_eq!(scheduler.take_due(now, &projects), vec![project.clone()]);
+        assert!(scheduler.take_due(now, &projects).is_empty());
+
+        scheduler.record_result(project.as_path(), now, true);
+        assert!(
+            scheduler
+                .take_due(now + Duration::from_secs(59), &projects)
+                .is_empty()
+        );
+        assert_eq!(
+            scheduler.take_due(now + Duration::from_secs(60), &projects),
+            vec![project]
+        );
+    }
+
+    #[test]
+    fn scheduler_backs_off_after_failures_and_resets_on_success() {
+        let now = Instant::now();
+        let project = PathBuf::from("/repos/flohome");
+        let projects = vec![(project.clone(), Duration::from_secs(60))];
+        let mut scheduler = FetchScheduler::new();
+
+        let _ = scheduler.take_due(now, &projects);
+        scheduler.record_result(project.as_path(), now, false);
+        assert_eq!(scheduler.consecutive_failures(project.as_path()), 1);
+        assert!(
+            scheduler
+                .take_due(now + Duration::from_secs(60), &projects)
+                .is_empty()
+        );
+        let retry_at = now + Duration::from_secs(120);
+        assert_eq!(
+            scheduler.take_due(retry_at, &projects),
+            vec![project.clone()]
+        );
+
+        scheduler.record_result(project.as_path(), retry_at, false);
+        assert!(
+            scheduler
+                .take_due(retry_at + Duration::from_secs(239), &projects)
+                .is_empty()
+        );
+
+        for _ in 0..10 {
+            let _ = scheduler.take_due(retry_at + Duration::from_secs(60 * 60 * 24), &projects);
+            scheduler.record_result(project.as_path(), retry_at, false);
+        }
+        assert_eq!(
+            scheduler.take_due(retry_at + Duration::from_secs(60 * 60), &projects),
+            vec![project.clone()]
+        );
+
+        scheduler.record_result(project.as_path(), retry_at, true);
+        assert_eq!(scheduler.consecutive_failures(project.as_path()), 0);
+    }
+
+    #[test]
+    fn scheduler_forgets_removed_projects() {
+        let now = Instant::now();
+        let project = PathBuf::from("/repos/flohome");
+        let mut scheduler = FetchScheduler::new();
+
+        let _ = scheduler.take_due(now, &[(project.clone(), Duration::from_secs(60))]);
+        scheduler.record_result(project.as_path(), now, false);
+        assert!(scheduler.take_due(now, &[]).is_empty());
+        assert_eq!(scheduler.consecutive_failures(project.as_path()), 0);
+    }
+
+    #[test]
+    fn fetch_leaves_a_configured_ssh_command_alone() {
+        let temp = TestDir::new("ssh-command");
+        git(&temp.path, &["init", "-q", "-b", "main"]);
+        git(
+            &temp.path,
+            &["config", "core.sshCommand", "ssh -i ~/.ssh/work"],
+        );
+
+        assert_eq!(batch_mode_ssh_command(&temp.path), None);
+    }
+
+    #[test]
+    fn worktree_sync_status_counts_commits_against_base_and_upstream() {
+        let temp = TestDir::new("status");
+        let origin = temp.path.join("origin");
+        fs::create_dir_all(&origin).expect("origin dir should exist");
+        git(&origin, &["init", "-q", "-b", "main"]);
+        git(&origin, &["config", "user.email", "grove@example.com"]);
+        git(&origin, &["config", "user.name", "grove"]);
+        git(&origin, &["commit", "-q", "--allow-empty", "-m", "root"]);
+
+        let clone = temp.path.join("clone");
+        git(
+            &temp.path,
+            &[
+                "clone",
+                "-q",
+                origin.to_string_lossy().as_ref(),
+                clone.to_string_lossy().as_ref(),
+            ],
+        );
+        git(&clone, &["config", "user.email", "grove@example.com"]);
+        git(&clone, &["config", "user.name", "grove"]);
+        git(&clone, &["checkout", "-q", "-b", "feature"]);
+        git(&clone, &["commit", "-q", "--allow-empty", "-m", "one"]);
+        git(&clone, &["commit", "-q", "--allow-empty", "-m", "two"]);
+        git(&clone, &["push", "-q", "-u", "origin", "feature"]);
+        git(&clone, &["commit", "-q", "--allow-empty", "-m", "three"]);
+
+        git(
+            &origin,
+            &["commit", "-q", "--allow-empty", "-m", "upstream one"],
+        );
+        git(
+            &origin,
+            &["commit", "-q", "--allow-empty", "-m", "upstream two"],
+        );
+        git(&clone, &["fetch", "-q", "origin"]);
+
+        let status = worktree_sync_status(&clone, Some("main"));
+        assert_eq!(status.base_ref.as_deref(), Some("refs/remotes/origin/main"));
+        assert_eq!(
+            status.base,
+            Some(BranchDivergence {
+                ahead: 3,
+                behind: 2
+            })
+        );
+        assert_eq!(
+            status.upstream,
+            Some(BranchDivergence {
+                ahead: 1,
+                behind: 0
+            })
+        );
+        assert_eq!(status.behind_base(), 2);
+
+        let missing_base = worktree_sync_status(&clone, Some("does-not-exist"));
+        assert_eq!(missing_base.base, None);
+    }
+}
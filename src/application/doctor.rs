@@ -3,6 +3,7 @@ use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use crate::application::agent_runtime::workspace_session_prefix;
 use crate::application::session_cleanup::SessionRecord;
 use crate::application::session_cleanup::{
     SessionCleanupReason, cleanup_reason_for_tasks, list_tmux_sessions, now_unix_secs,
@@ -201,7 +202,7 @@ fn repair_plan_from_findings(findings: &[DoctorFinding]) -> Vec<DoctorRepairStep
     deduped.into_values().collect()
 }
 
-fn repair_step_for_finding(finding: &DoctorFinding) -> Option<DoctorRepairStep> {
+pub(super) fn repair_step_for_finding(finding: &DoctorFinding) -> Option<DoctorRepairStep> {
     let targets = finding.subject.targets();
     let (priority, action, goal, reason, preconditions, steps, verification) = match finding.kind {
         DoctorFindingKind::InvalidTaskManifest => (
@@ -430,6 +431,20 @@ impl DoctorRepairAction {
             DoctorRepairAction::InspectRepositoryMapping => "inspect_repository_mapping",
         }
     }
+
+    pub(crate) fn from_label(label: &str) -> Option<Self> {
+        [
+            DoctorRepairAction::InspectOrRewriteManifest,
+            DoctorRepairAction::RemoveDuplicateManifestOwner,
+            DoctorRepairAction::RestoreOrRemoveMissingWorktree,
+            DoctorRepairAction::WriteBaseMarker,
+            DoctorRepairAction::MaterializeBaseTaskManifest,
+            DoctorRepairAction::KillOrAdoptSession,
+            DoctorRepairAction::InspectRepositoryMapping,
+        ]
+        .into_iter()
+        .find(|action| action.label() == label)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -658,7 +673,10 @@ fn collect_tmux_findings(
                 .map(|loaded| loaded.task.clone())
                 .collect::<Vec<Task>>();
             for session in sessions {
-                if session.name.starts_with("grove-ws-") {
+                if session
+                    .name
+                    .starts_with(workspace_session_prefix().as_str())
+                {
                     findings.push(DoctorFinding {
                         severity: DoctorSeverity::Warn,
                         kind: DoctorFindingKind::LegacyGroveSessionMissingMetadata,
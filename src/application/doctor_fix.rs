@@ -0,0 +1,441 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::application::doctor::{
+    DoctorFinding, DoctorFindingKind, DoctorRepairAction, DoctorRepairStep, DoctorReport,
+    repair_step_for_finding,
+};
+use crate::application::session_cleanup::session_missing_error;
+use crate::application::task_discovery::bootstrap_task_data_for_root;
+use crate::application::task_lifecycle::{
+    materialize_base_task_manifest_for_project_in_root, task_lifecycle_error_message,
+    write_task_manifest,
+};
+use crate::application::workspace_lifecycle::{
+    workspace_lifecycle_error_message, write_workspace_base_marker,
+};
+use crate::infrastructure::config::ProjectConfig;
+use crate::infrastructure::paths::{refer_to_same_location, tasks_root};
+use crate::infrastructure::process::execute_command;
+use crate::infrastructure::task_manifest::load_task_manifest;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DoctorFixOperation {
+    WriteBaseMarker {
+        worktree_path: PathBuf,
+        base_branch: String,
+    },
+    MaterializeBaseTaskManifest {
+        repository_path: PathBuf,
+    },
+    KillSession {
+        session_name: String,
+    },
+    RemoveWorktreeEntry {
+        manifest_path: PathBuf,
+        worktree_path: PathBuf,
+    },
+}
+
+/// One repair-plan step paired with what `doctor --fix` can do about it.
+/// `operation` is `Err` with the reason when the step needs a human.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoctorFix {
+    pub step: DoctorRepairStep,
+    pub operation: Result<DoctorFixOperation, String>,
+}
+
+impl DoctorFixOperation {
+    pub fn is_destructive(&self) -> bool {
+        matches!(
+            self,
+            Self::KillSession { .. } | Self::RemoveWorktreeEntry { .. }
+        )
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Self::WriteBaseMarker {
+                worktree_path,
+                base_branch,
+            } => format!(
+                "write base marker `{base_branch}` into {}",
+                worktree_path.display()
+            ),
+            Self::MaterializeBaseTaskManifest { repository_path } => format!(
+                "create the base task manifest for {}",
+                repository_path.display()
+            ),
+            Self::KillSession { session_name } => format!("kill tmux session {session_name}"),
+            Self::RemoveWorktreeEntry {
+                manifest_path,
+                worktree_path,
+            } => format!(
+                "remove worktree {} from {}",
+                worktree_path.display(),
+                manifest_path.display()
+            ),
+        }
+    }
+}
+
+pub fn plan_doctor_fixes(report: &DoctorReport) -> Vec<DoctorFix> {
+    let mut deduped: BTreeMap<(u8, DoctorRepairAction, Vec<String>), DoctorFix> = BTreeMap::new();
+
+    for finding in &report.findings {
+        let Some(step) = repair_step_for_finding(finding) else {
+            continue;
+        };
+        deduped
+            .entry((step.priority, step.action, step.targets.clone()))
+            .or_insert_with(|| DoctorFix {
+                operation: fix_operation_for_finding(finding),
+                step,
+            });
+    }
+
+    deduped.into_values().collect()
+}
+
+fn fix_operation_for_finding(finding: &DoctorFinding) -> Result<DoctorFixOperation, String> {
+    let subject = &finding.subject;
+    match finding.kind {
+        DoctorFindingKind::MissingBaseMarker => {
+            let (Some(manifest_path), Some(worktree_path)) = (
+                subject.manifest_path.as_deref(),
+                subject.worktree_path.as_deref(),
+            ) else {
+                return Err("finding does not name a manifest and worktree".to_string());
+            };
+            let base_branch =
+                manifest_base_branch(Path::new(manifest_path), Path::new(worktree_path))?;
+            Ok(DoctorFixOperation::WriteBaseMarker {
+                worktree_path: PathBuf::from(worktree_path),
+                base_branch,
+            })
+        }
+        DoctorFindingKind::ConfiguredRepoMissingBaseTaskManifest => subject
+            .repository_path
+            .as_deref()
+            .map(|path| DoctorFixOperation::MaterializeBaseTaskManifest {
+                repository_path: PathBuf::from(path),
+            })
+            .ok_or_else(|| "finding does not name a repository".to_string()),
+        DoctorFindingKind::OrphanedGroveSession
+        | DoctorFindingKind::StaleAuxiliarySession
+        | DoctorFindingKind::LegacyGroveSessionMissingMetadata => subject
+            .session_name
+            .clone()
+            .map(|session_name| DoctorFixOperation::KillSession { session_name })
+            .ok_or_else(|| "finding does not name a session".to_string()),
+        DoctorFindingKind::MissingWorktreePath => {
+            let (Some(manifest_path), Some(worktree_path)) = (
+                subject.manifest_path.as_deref(),
+                subject.worktree_path.as_deref(),
+            ) else {
+                return Err("finding does not name a manifest and worktree".to_string());
+            };
+            Ok(DoctorFixOperation::RemoveWorktreeEntry {
+                manifest_path: PathBuf::from(manifest_path),
+                worktree_path: PathBuf::from(worktree_path),
+            })
+        }
+        DoctorFindingKind::InvalidTaskManifest
+        | DoctorFindingKind::DuplicateTaskSlug
+        | DoctorFindingKind::ManifestRepositoryMismatch
+        | DoctorFindingKind::SessionCheckSkipped => {
+            Err("needs a manual decision, follow the repair plan".to_string())
+        }
+    }
+}
+
+fn manifest_base_branch(manifest_path: &Path, worktree_path: &Path) -> Result<String, String> {
+    let task = load_task_manifest(manifest_path)?;
+    task.worktrees
+        .iter()
+        .find(|worktree| worktree.path == worktree_path)
+        .and_then(|worktree| worktree.base_branch.clone())
+        .filter(|branch| !branch.trim().is_empty())
+        .ok_or_else(|| "task manifest does not record a base branch for this worktree".to_string())
+}
+
+pub fn apply_doctor_fix(operation: &DoctorFixOperation) -> Result<String, String> {
+    let loaded_config = crate::infrastructure::config::load()?;
+    let tasks_root = tasks_root().ok_or_else(|| "task root unavailable".to_string())?;
+    apply_doctor_fix_in_root(
+        operation,
+        tasks_root.as_path(),
+        loaded_config.config.projects.as_slice(),
+    )
+}
+
+pub(crate) fn apply_doctor_fix_in_root(
+    operation: &DoctorFixOperation,
+    tasks_root: &Path,
+    projects: &[ProjectConfig],
+) -> Result<String, String> {
+    match operation {
+        DoctorFixOperation::WriteBaseMarker {
+            worktree_path,
+            base_branch,
+        } => {
+            write_workspace_base_marker(worktree_path, base_branch).map_err(|error| {
+                format!(
+                    "base marker write failed: {}",
+                    workspace_lifecycle_error_message(&error)
+                )
+            })?;
+            Ok(format!("wrote base marker `{base_branch}`"))
+        }
+        DoctorFixOperation::MaterializeBaseTaskManifest { repository_path } => {
+            let project = projects
+                .iter()
+                .find(|project| {
+                    refer_to_same_location(project.path.as_path(), repository_path.as_path())
+                })
+                .ok_or_else(|| "repository is no longer configured".to_string())?;
+            let existing_tasks = bootstrap_task_data_for_root(tasks_root).tasks;
+            let created = materialize_base_task_manifest_for_project_in_root(
+                tasks_root,
+                project,
+                &existing_tasks,
+            )
+            .map_err(|error| task_lifecycle_error_message(&error))?;
+            Ok(match created {
+                Some(result) => format!("created base task `{}`", result.task.slug),
+                None => "base task already exists".to_string(),
+            })
+        }
+        DoctorFixOperation::KillSession { session_name } => {
+            let command = vec![
+                "tmux".to_string(),
+                "kill-session".to_string(),
+                "-t".to_string(),
+                session_name.clone(),
+            ];
+            match execute_command(command.as_slice()) {
+                Ok(()) => Ok(format!("killed {session_name}")),
+                Err(error) if session_missing_error(error.to_string().as_str()) => {
+                    Ok(format!("{session_name} was already gone"))
+                }
+                Err(error) => Err(error.to_string()),
+            }
+        }
+        DoctorFixOperation::RemoveWorktreeEntry {
+            manifest_path,
+            worktree_path,
+        } => {
+            let task_root = manifest_path
+                .parent()
+                .and_then(Path::parent)
+                .ok_or_else(|| "task manifest path has no task root".to_string())?;
+            let mut task = load_task_manifest(manifest_path)?;
+            let before = task.worktrees.len();
+            task.worktrees
+                .retain(|worktree| worktree.path != *worktree_path);
+            if task.worktrees.len() == before {
+                return Ok("worktree entry already removed".to_string());
+            }
+            if task.worktrees.is_empty() {
+                return Err("it is the task's only worktree, delete the task instead".to_string());
+            }
+            write_task_manifest(task_root, &task)
+                .map_err(|error| task_lifecycle_error_message(&error))?;
+            Ok(format!("removed worktree entry from task `{}`", task.slug))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DoctorFixOperation, apply_doctor_fix_in_root, plan_doctor_fixes};
+    use crate::application::doctor::{
+        DoctorFinding, DoctorFindingKind, DoctorRepairAction, DoctorReport, DoctorSeverity,
+        DoctorSubject,
+    };
+    use crate::application::workspace_lifecycle::GROVE_BASE_MARKER_FILE;
+    use crate::domain::{AgentType, Task, WorkspaceStatus, Worktree};
+    use crate::infrastructure::task_manifest::{decode_task_manifest, encode_task_manifest};
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    struct TestDir {
+        path: PathBuf,
+    }
+
+    impl TestDir {
+        fn new(label: &str) -> Self {
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or(Duration::from_secs(0))
+                .as_nanos();
+            let path = std::env::temp_dir().join(format!(
+                "grove-doctor-fix-{label}-{}-{timestamp}",
+                std::process::id()
+            ));
+            fs::create_dir_all(&path).expect("test dir should exist");
+            Self { path }
+        }
+    }
+
+    impl Drop for TestDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+
+    fn worktree(root: &Path, repository: &str) -> Worktree {
+        Worktree::try_new(
+            repository.to_string(),
+            root.join("repos").join(repository),
+            root.join("tasks/feature-a").join(repository),
+            "feature-a".to_string(),
+            AgentType::Codex,
+            WorkspaceStatus::Idle,
+        )
+        .expect("worktree should be valid")
+        .with_base_branch(Some("main".to_string()))
+    }
+
+    fn write_task(root: &Path, repositories: &[&str]) -> PathBuf {
+        let task = Task::try_new(
+            "feature-a".to_string(),
+            "feature-a".to_string(),
+            root.join("tasks/feature-a"),
+            "feature-a".to_string(),
+            repositories
+                .iter()
+                .map(|repository| worktree(root, repository))
+                .collect(),
+        )
+        .expect("task should be valid");
+        let manifest_dir = root.join("tasks/feature-a/.grove");
+        fs::create_dir_all(&manifest_dir).expect("manifest dir should exist");
+        let manifest_path = manifest_dir.join("task.toml");
+        fs::write(
+            &manifest_path,
+            encode_task_manifest(&task).expect("manifest should encode"),
+        )
+        .expect("manifest should write");
+        manifest_path
+    }
+
+    fn finding(kind: DoctorFindingKind, subject: DoctorSubject) -> DoctorFinding {
+        DoctorFinding {
+            severity: DoctorSeverity::Warn,
+            kind,
+            subject,
+            evidence: String::new(),
+            recommended_action: String::new(),
+        }
+    }
+
+    fn subject(
+        manifest: Option<&Path>,
+        worktree: Option<&Path>,
+        session: Option<&str>,
+    ) -> DoctorSubject {
+        DoctorSubject {
+            task_slug: None,
+            manifest_path: manifest.map(|path| path.to_string_lossy().into_owned()),
+            repository_path: None,
+            worktree_path: worktree.map(|path| path.to_string_lossy().into_owned()),
+            session_name: session.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn plan_maps_findings_to_operations_and_flags_destructive_ones() {
+        let dir = TestDir::new("plan");
+        let manifest_path = write_task(&dir.path, &["api"]);
+        let worktree_path = dir.path.join("tasks/feature-a/api");
+        let report = DoctorReport::from_findings(vec![
+            finding(
+                DoctorFindingKind::MissingBaseMarker,
+                subject(Some(&manifest_path), Some(&worktree_path), None),
+            ),
+            finding(
+                DoctorFindingKind::OrphanedGroveSession,
+                subject(None, None, Some("grove-task-gone")),
+            ),
+            finding(
+                DoctorFindingKind::DuplicateTaskSlug,
+                subject(Some(&manifest_path), None, None),
+            ),
+        ]);
+
+        let fixes = plan_doctor_fixes(&report);
+
+        assert_eq!(fixes.len(), report.repair_plan.len());
+        assert_eq!(
+            fixes[0].step.action,
+            DoctorRepairAction::RemoveDuplicateManifestOwner
+        );
+        assert!(fixes[0].operation.is_err());
+        assert_eq!(
+            fixes[1].operation,
+            Ok(DoctorFixOperation::WriteBaseMarker {
+                worktree_path: worktree_path.clone(),
+                base_branch: "main".to_string(),
+            })
+        );
+        let kill = fixes[2]
+            .operation
+            .as_ref()
+            .expect("session fix should exist");
+        assert!(kill.is_destructive());
+        assert_eq!(kill.describe(), "kill tmux session grove-task-gone");
+    }
+
+    #[test]
+    fn write_base_marker_creates_marker_file() {
+        let dir = TestDir::new("marker");
+        let worktree_path = dir.path.join("worktree");
+        fs::create_dir_all(&worktree_path).expect("worktree should exist");
+
+        let message = apply_doctor_fix_in_root(
+            &DoctorFixOperation::WriteBaseMarker {
+                worktree_path: worktree_path.clone(),
+                base_branch: "develop".to_string(),
+            },
+            dir.path.as_path(),
+            &[],
+        )
+        .expect("marker should be written");
+
+        assert_eq!(message, "wrote base marker `develop`");
+        assert_eq!(
+            fs::read_to_string(worktree_path.join(GROVE_BASE_MARKER_FILE))
+                .expect("marker should exist"),
+            "develop\n"
+        );
+    }
+
+    #[test]
+    fn remove_worktree_entry_rewrites_manifest_but_keeps_last_worktree() {
+        let dir = TestDir::new("remove");
+        let manifest_path = write_task(&dir.path, &["api", "web"]);
+        let remove_web = DoctorFixOperation::RemoveWorktreeEntry {
+            manifest_path: manifest_path.clone(),
+            worktree_path: dir.path.join("tasks/feature-a/web"),
+        };
+
+        apply_doctor_fix_in_root(&remove_web, dir.path.as_path(), &[])
+            .expect("worktree entry should be removed");
+        let task = decode_task_manifest(
+            fs::read_to_string(&manifest_path)
+                .expect("manifest should read")
+                .as_str(),
+        )
+        .expect("manifest should decode");
+        assert_eq!(task.worktrees.len(), 1);
+        assert_eq!(task.worktrees[0].repository_name, "api");
+
+        let remove_api = DoctorFixOperation::RemoveWorktreeEntry {
+            manifest_path,
+            worktree_path: dir.path.join("tasks/feature-a/api"),
+        };
+        assert!(apply_doctor_fix_in_root(&remove_api, dir.path.as_path(), &[]).is_err());
+    }
+}
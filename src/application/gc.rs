@@ -0,0 +1,873 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::application::session_cleanup::{list_tmux_sessions, now_unix_secs};
+use crate::application::task_discovery::bootstrap_task_data_for_root_with_sessions;
+use crate::application::task_lifecycle::{DeleteTaskRequest, delete_task};
+use crate::application::workspace_lifecycle::{CommandGitRunner, GitCommandRunner};
+use crate::domain::{PullRequestStatus, Task, Worktree};
+use crate::infrastructure::paths::{refer_to_same_location, tasks_root};
+
+pub const DEFAULT_GC_IDLE_DAYS: u64 = 14;
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GcOptions {
+    pub idle_days: Option<u64>,
+}
+
+impl Default for GcOptions {
+    fn default() -> Self {
+        Self {
+            idle_days: Some(DEFAULT_GC_IDLE_DAYS),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GcReason {
+    BranchMerged,
+    PullRequestMerged,
+    Idle,
+    UnmanagedWorktree,
+}
+
+impl GcReason {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::BranchMerged => "merged",
+            Self::PullRequestMerged => "pr-merged",
+            Self::Idle => "idle",
+            Self::UnmanagedWorktree => "unmanaged",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GcTarget {
+    Task(Task),
+    UnmanagedWorktree { repository_path: PathBuf },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GcEntry {
+    pub name: String,
+    pub path: PathBuf,
+    pub reason: GcReason,
+    pub idle_secs: Option<u64>,
+    pub branches: Vec<String>,
+    #[serde(skip)]
+    pub target: GcTarget,
+}
+
+impl GcEntry {
+    /// Idle tasks keep their local branch; merged ones have nothing left on it.
+    pub fn delete_task_request(&self) -> Option<DeleteTaskRequest> {
+        let GcTarget::Task(task) = &self.target else {
+            return None;
+        };
+        Some(DeleteTaskRequest {
+            task: task.clone(),
+            delete_local_branch: self.reason != GcReason::Idle,
+            kill_tmux_sessions: true,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GcPlan {
+    pub candidates: Vec<GcEntry>,
+    pub skipped_running: Vec<GcEntry>,
+    pub skipped_dirty: Vec<GcEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GcApplyResult {
+    pub removed: Vec<String>,
+    pub warnings: Vec<String>,
+    pub failures: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct GcWorktreeFacts {
+    pub(crate) branch_merged: bool,
+    pub(crate) dirty: bool,
+    pub(crate) last_commit_unix_secs: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct GcTaskFacts {
+    pub(crate) task: Task,
+    pub(crate) worktrees: Vec<GcWorktreeFacts>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct GcUnmanagedWorktree {
+    pub(crate) repository_path: PathBuf,
+    pub(crate) path: PathBuf,
+    pub(crate) branch: Option<String>,
+    pub(crate) dirty: bool,
+}
+
+pub fn plan_gc(options: GcOptions) -> Result<GcPlan, String> {
+    let task_root = tasks_root().ok_or_else(|| "task root unavailable".to_string())?;
+    let loaded_config = crate::infrastructure::config::load()?;
+    let running_sessions = list_tmux_sessions()
+        .map(|sessions| {
+            sessions
+                .into_iter()
+                .map(|session| session.name)
+                .collect::<HashSet<String>>()
+        })
+        .unwrap_or_default();
+    let bootstrap =
+        bootstrap_task_data_for_root_with_sessions(task_root.as_path(), &running_sessions);
+    let repository_paths = loaded_config
+        .config
+        .projects
+        .iter()
+        .map(|project| project.path.clone())
+        .collect::<Vec<PathBuf>>();
+
+    Ok(plan_gc_for_tasks(
+        task_root.as_path(),
+        bootstrap.tasks.as_slice(),
+        repository_paths.as_slice(),
+        options,
+    ))
+}
+
+pub fn plan_gc_for_tasks(
+    tasks_root: &Path,
+    tasks: &[Task],
+    repository_paths: &[PathBuf],
+    options: GcOptions,
+) -> GcPlan {
+    let task_facts = tasks
+        .iter()
+        .filter(|task| !task.has_base_worktree())
+        .map(|task| GcTaskFacts {
+            task: task.clone(),
+            worktrees: task.worktrees.iter().map(worktree_facts).collect(),
+        })
+        .collect::<Vec<GcTaskFacts>>();
+    let unmanaged = discover_unmanaged_worktrees(tasks_root, tasks, repository_paths);
+
+    plan_gc_from_inputs(
+        task_facts.as_slice(),
+        unmanaged.as_slice(),
+        options,
+        now_unix_secs(),
+    )
+}
+
+pub(crate) fn plan_gc_from_inputs(
+    tasks: &[GcTaskFacts],
+    unmanaged: &[GcUnmanagedWorktree],
+    options: GcOptions,
+    now_unix_secs: u64,
+) -> GcPlan {
+    let mut candidates = Vec::new();
+    let mut skipped_running = Vec::new();
+    let mut skipped_dirty = Vec::new();
+
+    for facts in tasks {
+        let task = &facts.task;
+        let idle_secs = task_idle_secs(facts, now_unix_secs);
+        let Some(reason) = task_gc_reason(facts, idle_secs, options) else {
+            continue;
+        };
+        let entry = GcEntry {
+            name: task.slug.clone(),
+            path: task.root_path.clone(),
+            reason,
+            idle_secs,
+            branches: task
+                .worktrees
+                .iter()
+                .map(|worktree| worktree.branch.clone())
+                .collect(),
+            target: GcTarget::Task(task.clone()),
+        };
+
+        if task
+            .worktrees
+            .iter()
+            .any(|worktree| worktree.status.is_running())
+        {
+            skipped_running.push(entry);
+        } else if facts.worktrees.iter().any(|worktree| worktree.dirty) {
+            skipped_dirty.push(entry);
+        } else {
+            candidates.push(entry);
+        }
+    }
+
+    for worktree in unmanaged {
+        let entry = GcEntry {
+            name: worktree
+                .path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| worktree.path.to_string_lossy().into_owned()),
+            path: worktree.path.clone(),
+            reason: GcReason::UnmanagedWorktree,
+            idle_secs: None,
+            branches: worktree.branch.iter().cloned().collect(),
+            target: GcTarget::UnmanagedWorktree {
+                repository_path: worktree.repository_path.clone(),
+            },
+        };
+        if worktree.dirty {
+            skipped_dirty.push(entry);
+        } else {
+            candidates.push(entry);
+        }
+    }
+
+    candidates.sort_by(|left, right| left.path.cmp(&right.path));
+    skipped_running.sort_by(|left, right| left.path.cmp(&right.path));
+    skipped_dirty.sort_by(|left, right| left.path.cmp(&right.path));
+    GcPlan {
+        candidates,
+        skipped_running,
+        skipped_dirty,
+    }
+}
+
+fn task_gc_reason(
+    facts: &GcTaskFacts,
+    idle_secs: Option<u64>,
+    options: GcOptions,
+) -> Option<GcReason> {
+    if facts.task.worktrees.is_empty() {
+        return None;
+    }
+    let worktrees = facts.task.worktrees.iter().zip(facts.worktrees.iter());
+    let mut merged_by_branch = true;
+    let mut merged = true;
+    for (worktree, worktree_facts) in worktrees {
+        if worktree_facts.branch_merged {
+            continue;
+        }
+        merged_by_branch = false;
+        if !worktree_has_merged_pull_request(worktree) {
+            merged = false;
+            break;
+        }
+    }
+
+    if merged {
+        return Some(if merged_by_branch {
+            GcReason::BranchMerged
+        } else {
+            GcReason::PullRequestMerged
+        });
+    }
+
+    let idle_days = options.idle_days?;
+    let idle_secs = idle_secs?;
+    if idle_secs >= idle_days.saturating_mul(SECS_PER_DAY) {
+        return Some(GcReason::Idle);
+    }
+
+    None
+}
+
+fn worktree_has_merged_pull_request(worktree: &Worktree) -> bool {
+    worktree
+        .pull_requests
+        .iter()
+        .any(|pull_request| pull_request.status == PullRequestStatus::Merged)
+}
+
+fn task_idle_secs(facts: &GcTaskFacts, now_unix_secs: u64) -> Option<u64> {
+    let last_activity = facts
+        .task
+        .worktrees
+        .iter()
+        .zip(facts.worktrees.iter())
+        .flat_map(|(worktree, worktree_facts)| {
+            [
+                worktree.last_activity_unix_secs,
+                worktree_facts.last_commit_unix_secs,
+            ]
+        })
+        .flatten()
+        .max()?;
+    let last_activity = u64::try_from(last_activity).ok()?;
+    Some(now_unix_secs.saturating_sub(last_activity))
+}
+
+fn worktree_facts(worktree: &Worktree) -> GcWorktreeFacts {
+    GcWorktreeFacts {
+        branch_merged: branch_merged_into_base(worktree),
+        dirty: worktree_dirty(worktree.path.as_path()),
+        last_commit_unix_secs: git_stdout(
+            worktree.repository_path.as_path(),
+            &[
+                "log",
+                "-1",
+                "--format=%ct",
+                format!("refs/heads/{}", worktree.branch).as_str(),
+            ],
+        )
+        .and_then(|value| value.parse::<i64>().ok()),
+    }
+}
+
+fn branch_merged_into_base(worktree: &Worktree) -> bool {
+    let Some(base_branch) = worktree.base_branch.as_deref() else {
+        return false;
+    };
+    if base_branch == worktree.branch {
+        return false;
+    }
+    let repository_path = worktree.repository_path.as_path();
+    let Some(branch_tip) = rev_parse(
+        repository_path,
+        format!("refs/heads/{}", worktree.branch).as_str(),
+    ) else {
+        return false;
+    };
+
+    for base_ref in [
+        format!("refs/heads/{base_branch}"),
+        format!("refs/remotes/origin/{base_branch}"),
+    ] {
+        let Some(base_tip) = rev_parse(repository_path, base_ref.as_str()) else {
+            continue;
+        };
+        // A branch sitting exactly on its base has no work yet, it is not merged.
+        if base_tip == branch_tip {
+            continue;
+        }
+        if git_succeeds(
+            repository_path,
+            &[
+                "merge-base",
+                "--is-ancestor",
+                branch_tip.as_str(),
+                base_tip.as_str(),
+            ],
+        ) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn worktree_dirty(worktree_path: &Path) -> bool {
+    if !worktree_path.exists() {
+        return false;
+    }
+    let Ok(output) = Command::new("git")
+        .current_dir(worktree_path)
+        .args(["status", "--porcelain"])
+        .output()
+    else {
+        return true;
+    };
+    if !output.status.success() {
+        return true;
+    }
+    !String::from_utf8_lossy(&output.stdout).trim().is_empty()
+}
+
+fn discover_unmanaged_worktrees(
+    tasks_root: &Path,
+    tasks: &[Task],
+    repository_paths: &[PathBuf],
+) -> Vec<GcUnmanagedWorktree> {
+    let mut repositories = repository_paths.to_vec();
+    for task in tasks {
+        for worktree in &task.worktrees {
+            if !repositories
+                .iter()
+                .any(|path| refer_to_same_location(path, worktree.repository_path.as_path()))
+            {
+                repositories.push(worktree.repository_path.clone());
+            }
+        }
+    }
+    let managed_paths = tasks
+        .iter()
+        .flat_map(|task| task.worktrees.iter().map(|worktree| worktree.path.clone()))
+        .collect::<Vec<PathBuf>>();
+
+    let mut unmanaged = Vec::new();
+    for repository_path in repositories {
+        let Some(porcelain) = git_stdout(
+            repository_path.as_path(),
+            &["worktree", "list", "--porcelain"],
+        ) else {
+            continue;
+        };
+        for (path, branch) in
+            unmanaged_worktree_entries(porcelain.as_str(), tasks_root, managed_paths.as_slice())
+        {
+            if unmanaged.iter().any(|existing: &GcUnmanagedWorktree| {
+                refer_to_same_location(existing.path.as_path(), path.as_path())
+            }) {
+                continue;
+            }
+            unmanaged.push(GcUnmanagedWorktree {
+                repository_path: repository_path.clone(),
+                dirty: worktree_dirty(path.as_path()),
+                path,
+                branch,
+            });
+        }
+    }
+
+    unmanaged
+}
+
+pub(crate) fn unmanaged_worktree_entries(
+    porcelain: &str,
+    tasks_root: &Path,
+    managed_paths: &[PathBuf],
+) -> Vec<(PathBuf, Option<String>)> {
+    let mut entries: Vec<(PathBuf, Option<String>)> = Vec::new();
+    for line in porcelain.lines() {
+        if let Some(path) = line.strip_prefix("worktree ") {
+            entries.push((PathBuf::from(path), None));
+            continue;
+        }
+        if let Some(branch_ref) = line.strip_prefix("branch ")
+            && let Some((_, branch)) = entries.last_mut()
+        {
+            *branch = Some(
+                branch_ref
+                    .strip_prefix("refs/heads/")
+                    .unwrap_or(branch_ref)
+                    .to_string(),
+            );
+        }
+    }
+
+    entries
+        .into_iter()
+        .filter(|(path, _)| path.starts_with(tasks_root) && path != tasks_root)
+        .filter(|(path, _)| {
+            !managed_paths
+                .iter()
+                .any(|managed| refer_to_same_location(managed, path))
+        })
+        .collect()
+}
+
+pub fn apply_gc(plan: &GcPlan) -> GcApplyResult {
+    let mut result = GcApplyResult::default();
+
+    for entry in &plan.candidates {
+        match &entry.target {
+            GcTarget::Task(_) => {
+                let Some(request) = entry.delete_task_request() else {
+                    continue;
+                };
+                let (outcome, warnings) = delete_task(request);
+                result.warnings.extend(
+                    warnings
+                        .into_iter()
+                        .map(|warning| format!("{}: {warning}", entry.name)),
+                );
+                match outcome {
+                    Ok(()) => result.removed.push(entry.name.clone()),
+                    Err(error) => result.failures.push((entry.name.clone(), error)),
+                }
+            }
+            GcTarget::UnmanagedWorktree { repository_path } => {
+                match remove_unmanaged_worktree(repository_path.as_path(), entry.path.as_path()) {
+                    Ok(()) => result.removed.push(entry.name.clone()),
+                    Err(error) => result.failures.push((entry.name.clone(), error)),
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Removes a worktree git knows about but no task manifest claims.
+pub fn remove_unmanaged_worktree(
+    repository_path: &Path,
+    worktree_path: &Path,
+) -> Result<(), String> {
+    let git_runner = CommandGitRunner;
+    if !worktree_path.exists() {
+        return git_runner
+            .run(
+                repository_path,
+                &["worktree".to_string(), "prune".to_string()],
+            )
+            .map_err(|error| format!("git worktree prune failed: {error}"));
+    }
+
+    git_runner
+        .run(
+            repository_path,
+            &[
+                "worktree".to_string(),
+                "remove".to_string(),
+                worktree_path.to_string_lossy().to_string(),
+            ],
+        )
+        .map_err(|error| format!("git worktree remove failed: {error}"))?;
+
+    if let Some(task_root) = worktree_path.parent() {
+        let _ = fs::remove_dir(task_root);
+    }
+    Ok(())
+}
+
+fn rev_parse(repository_path: &Path, reference: &str) -> Option<String> {
+    git_stdout(
+        repository_path,
+        &["rev-parse", "--verify", "--quiet", reference],
+    )
+}
+
+fn git_stdout(repository_path: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .current_dir(repository_path)
+        .args(args)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let trimmed = stdout.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+    Some(trimmed.to_string())
+}
+
+fn git_succeeds(repository_path: &Path, args: &[&str]) -> bool {
+    Command::new("git")
+        .current_dir(repository_path)
+        .args(args)
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        GcOptions, GcReason, GcTaskFacts, GcUnmanagedWorktree, GcWorktreeFacts,
+        branch_merged_into_base, plan_gc_from_inputs, unmanaged_worktree_entries,
+    };
+    use crate::domain::{
+        AgentType, PullRequest, PullRequestStatus, Task, WorkspaceStatus, Worktree,
+    };
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    const NOW: u64 = 1_700_000_000;
+    const DAY: u64 = 24 * 60 * 60;
+
+    #[derive(Debug)]
+    struct TestDir {
+        path: PathBuf,
+    }
+
+    impl TestDir {
+        fn new(label: &str) -> Self {
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or(Duration::from_secs(0))
+                .as_nanos();
+            let path = std::env::temp_dir().join(format!(
+                "grove-gc-{label}-{}-{timestamp}",
+                std::process::id()
+            ));
+            fs::create_dir_all(&path).expect("test dir should exist");
+            Self { path }
+        }
+    }
+
+    impl Drop for TestDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+
+    fn fixture_task(slug: &str, status: WorkspaceStatus) -> Task {
+        let worktree = Worktree::try_new(
+            "flohome".to_string(),
+            PathBuf::from("/repos/flohome"),
+            PathBuf::from(format!("/tmp/.grove/tasks/{slug}/flohome")),
+            slug.to_string(),
+            AgentType::Codex,
+            status,
+        )
+        .expect("worktree should be valid")
+        .with_base_branch(Some("main".to_string()));
+
+        Task::try_new(
+            slug.to_string(),
+            slug.to_string(),
+            PathBuf::from(format!("/tmp/.grove/tasks/{slug}")),
+            slug.to_string(),
+            vec![worktree],
+        )
+        .expect("task should be valid")
+    }
+
+    fn facts(task: Task, branch_merged: bool, dirty: bool, last_commit: u64) -> GcTaskFacts {
+        let worktrees = task
+            .worktrees
+            .iter()
+            .map(|_| GcWorktreeFacts {
+                branch_merged,
+                dirty,
+                last_commit_unix_secs: Some(last_commit as i64),
+            })
+            .collect();
+        GcTaskFacts { task, worktrees }
+    }
+
+    fn git(repo: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .current_dir(repo)
+            .args(args)
+            .output()
+            .expect("git should run");
+        assert!(
+            output.status.success(),
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn plan_marks_merged_branches_and_merged_pull_requests() {
+        let merged = facts(
+            fixture_task("merged", WorkspaceStatus::Idle),
+            true,
+            false,
+            NOW,
+        );
+        let mut pr_task = fixture_task("pr-merged", WorkspaceStatus::Idle);
+        pr_task.worktrees[0].pull_requests = vec![PullRequest {
+            number: 42,
+            url: "https://github.com/acme/flohome/pull/42".to_string(),
+            status: PullRequestStatus::Merged,
+        }];
+        let pr_merged = facts(pr_task, false, false, NOW);
+        let active = facts(
+            fixture_task("active", WorkspaceStatus::Idle),
+            false,
+            false,
+            NOW,
+        );
+
+        let plan =
+            plan_gc_from_inputs(&[merged, pr_merged, active], &[], GcOptions::default(), NOW);
+
+        let reasons = plan
+            .candidates
+            .iter()
+            .map(|entry| (entry.name.as_str(), entry.reason))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            reasons,
+            vec![
+                ("merged", GcReason::BranchMerged),
+                ("pr-merged", GcReason::PullRequestMerged),
+            ]
+        );
+    }
+
+    #[test]
+    fn plan_marks_idle_tasks_only_past_threshold() {
+        let stale = facts(
+            fixture_task("stale", WorkspaceStatus::Idle),
+            false,
+            false,
+            NOW - 20 * DAY,
+        );
+        let recent = facts(
+            fixture_task("recent", WorkspaceStatus::Idle),
+            false,
+            false,
+            NOW - 3 * DAY,
+        );
+
+        let plan = plan_gc_from_inputs(
+            &[stale.clone(), recent.clone()],
+            &[],
+            GcOptions {
+                idle_days: Some(14),
+            },
+            NOW,
+        );
+        assert_eq!(plan.candidates.len(), 1);
+        assert_eq!(plan.candidates[0].name, "stale");
+        assert_eq!(plan.candidates[0].reason, GcReason::Idle);
+        assert_eq!(plan.candidates[0].idle_secs, Some(20 * DAY));
+
+        let without_idle =
+            plan_gc_from_inputs(&[stale, recent], &[], GcOptions { idle_days: None }, NOW);
+        assert!(without_idle.candidates.is_empty());
+    }
+
+    #[test]
+    fn plan_skips_running_and_dirty_tasks() {
+        let running = facts(
+            fixture_task("running", WorkspaceStatus::Active),
+            true,
+            false,
+            NOW,
+        );
+        let dirty = facts(
+            fixture_task("dirty", WorkspaceStatus::Idle),
+            true,
+            true,
+            NOW,
+        );
+
+        let plan = plan_gc_from_inputs(&[running, dirty], &[], GcOptions::default(), NOW);
+
+        assert!(plan.candidates.is_empty());
+        assert_eq!(plan.skipped_running.len(), 1);
+        assert_eq!(plan.skipped_running[0].name, "running");
+        assert_eq!(plan.skipped_dirty.len(), 1);
+        assert_eq!(plan.skipped_dirty[0].name, "dirty");
+    }
+
+    #[test]
+    fn plan_ignores_tasks_without_worktrees() {
+        let mut empty = fixture_task("empty", WorkspaceStatus::Idle);
+        empty.worktrees.clear();
+
+        let plan = plan_gc_from_inputs(
+            &[facts(empty, true, false, NOW)],
+            &[],
+            GcOptions::default(),
+            NOW,
+        );
+
+        assert!(plan.candidates.is_empty());
+    }
+
+    #[test]
+    fn plan_includes_unmanaged_worktrees() {
+        let plan = plan_gc_from_inputs(
+            &[],
+            &[
+                GcUnmanagedWorktree {
+                    repository_path: PathBuf::from("/repos/flohome"),
+                    path: PathBuf::from("/tmp/.grove/tasks/lost/flohome"),
+                    branch: Some("lost".to_string()),
+                    dirty: false,
+                },
+                GcUnmanagedWorktree {
+                    repository_path: PathBuf::from("/repos/flohome"),
+                    path: PathBuf::from("/tmp/.grove/tasks/wip/flohome"),
+                    branch: Some("wip".to_string()),
+                    dirty: true,
+                },
+            ],
+            GcOptions::default(),
+            NOW,
+        );
+
+        assert_eq!(plan.candidates.len(), 1);
+        assert_eq!(plan.candidates[0].reason, GcReason::UnmanagedWorktree);
+        assert_eq!(
+            plan.candidates[0].path,
+            PathBuf::from("/tmp/.grove/tasks/lost/flohome")
+        );
+        assert_eq!(plan.skipped_dirty.len(), 1);
+    }
+
+    #[test]
+    fn unmanaged_entries_only_include_paths_under_tasks_root_without_manifest() {
+        let porcelain = [
+            "worktree /repos/flohome",
+            "HEAD 1111111111111111111111111111111111111111",
+            "branch refs/heads/main",
+            "",
+            "worktree /tmp/.grove/tasks/known/flohome",
+            "HEAD 2222222222222222222222222222222222222222",
+            "branch refs/heads/known",
+            "",
+            "worktree /tmp/.grove/tasks/lost/flohome",
+            "HEAD 3333333333333333333333333333333333333333",
+            "branch refs/heads/lost",
+            "",
+            "worktree /tmp/.grove/tasks/detached/flohome",
+            "HEAD 4444444444444444444444444444444444444444",
+            "detached",
+        ]
+        .join("\n");
+
+        let entries = unmanaged_worktree_entries(
+            porcelain.as_str(),
+            Path::new("/tmp/.grove/tasks"),
+            &[PathBuf::from("/tmp/.grove/tasks/known/flohome")],
+        );
+
+        assert_eq!(
+            entries,
+            vec![
+                (
+                    PathBuf::from("/tmp/.grove/tasks/lost/flohome"),
+                    Some("lost".to_string())
+                ),
+                (PathBuf::from("/tmp/.grove/tasks/detached/flohome"), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn branch_merged_requires_commits_reachable_from_base() {
+        let temp = TestDir::new("merged");
+        let repo = temp.path.join("repo");
+        fs::create_dir_all(&repo).expect("repo dir should exist");
+        git(&repo, &["init", "-q", "-b", "main"]);
+        git(&repo, &["config", "user.email", "grove@example.com"]);
+        git(&repo, &["config", "user.name", "grove"]);
+        git(&repo, &["commit", "-q", "--allow-empty", "-m", "root"]);
+        git(&repo, &["branch", "fresh"]);
+        git(&repo, &["checkout", "-q", "-b", "feature"]);
+        git(
+            &repo,
+            &["commit", "-q", "--allow-empty", "-m", "feature work"],
+        );
+        git(&repo, &["checkout", "-q", "main"]);
+
+        let worktree = |branch: &str| {
+            Worktree::try_new(
+                "repo".to_string(),
+                repo.clone(),
+                temp.path.join(branch),
+                branch.to_string(),
+                AgentType::Codex,
+                WorkspaceStatus::Idle,
+            )
+            .expect("worktree should be valid")
+            .with_base_branch(Some("main".to_string()))
+        };
+
+        assert!(!branch_merged_into_base(&worktree("feature")));
+        assert!(!branch_merged_into_base(&worktree("fresh")));
+
+        git(
+            &repo,
+            &["merge", "-q", "--no-ff", "-m", "merge feature", "feature"],
+        );
+        assert!(branch_merged_into_base(&worktree("feature")));
+    }
+}
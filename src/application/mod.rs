@@ -1,10 +1,18 @@
 pub mod agent_runtime;
+pub mod attention;
+pub mod branch_sync;
 pub mod doctor;
+pub mod doctor_fix;
+pub mod gc;
 pub mod hardening;
 pub mod interactive;
 pub mod preview;
+pub mod review_comments;
 pub mod scale_benchmark;
 pub mod session_cleanup;
 pub mod task_discovery;
 pub mod task_lifecycle;
+pub mod task_spec;
+pub mod task_transfer;
 pub mod workspace_lifecycle;
+pub mod worktree_changes;
@@ -0,0 +1,351 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const REVIEW_COMMENTS_DIR: &str = ".grove/review_comments";
+const REVIEW_CONTEXT_LINES: usize = 2;
+
+/// A local review comment anchored to a line of a worktree file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewComment {
+    pub id: u64,
+    pub path: String,
+    /// 1-based line number in the worktree file.
+    pub line: usize,
+    /// Text of the anchored line when the comment was written.
+    pub anchor: String,
+    /// First line number of `context`.
+    pub context_start: usize,
+    pub context: Vec<String>,
+    pub body: String,
+    #[serde(default)]
+    pub resolved: bool,
+}
+
+/// Comments for a worktree live in its task's `.grove/`, one file per
+/// worktree, so they never show up in the repository itself.
+pub fn review_comments_path(task_root: &Path, worktree_path: &Path) -> PathBuf {
+    let worktree_name = worktree_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "workspace".to_string());
+    task_root
+        .join(REVIEW_COMMENTS_DIR)
+        .join(format!("{worktree_name}.json"))
+}
+
+pub fn load_review_comments(comments_path: &Path) -> Result<Vec<ReviewComment>, String> {
+    let raw = match fs::read_to_string(comments_path) {
+        Ok(raw) => raw,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(format!("read review comments failed: {error}")),
+    };
+    serde_json::from_str(&raw).map_err(|error| format!("invalid review comments: {error}"))
+}
+
+pub fn save_review_comments(
+    comments_path: &Path,
+    comments: &[ReviewComment],
+) -> Result<(), String> {
+    if let Some(parent) = comments_path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|error| format!("save review comments failed: {error}"))?;
+    }
+    let encoded = serde_json::to_string_pretty(comments)
+        .map_err(|error| format!("encode review comments failed: {error}"))?;
+    fs::write(comments_path, format!("{encoded}\n"))
+        .map_err(|error| format!("save review comments failed: {error}"))
+}
+
+/// Appends a comment on `file_path:line`, snapshotting the surrounding code
+/// so the comment can be quoted and later detected as resolved.
+pub fn add_review_comment(
+    comments_path: &Path,
+    worktree_path: &Path,
+    file_path: &str,
+    line: usize,
+    body: &str,
+) -> Result<ReviewComment, String> {
+    let body = body.trim();
+    if body.is_empty() {
+        return Err("comment is empty".to_string());
+    }
+    let content = fs::read_to_string(worktree_path.join(file_path))
+        .map_err(|error| format!("read '{file_path}' failed: {error}"))?;
+    let lines = content.lines().collect::<Vec<&str>>();
+    if line == 0 || line > lines.len() {
+        return Err(format!(
+            "line {line} is outside '{file_path}' ({} lines)",
+            lines.len()
+        ));
+    }
+
+    let context_start = line.saturating_sub(REVIEW_CONTEXT_LINES).max(1);
+    let context_end = line.saturating_add(REVIEW_CONTEXT_LINES).min(lines.len());
+    let mut comments = load_review_comments(comments_path)?;
+    let comment = ReviewComment {
+        id: comments
+            .iter()
+            .map(|comment| comment.id)
+            .max()
+            .unwrap_or(0)
+            .saturating_add(1),
+        path: file_path.to_string(),
+        line,
+        anchor: lines[line - 1].to_string(),
+        context_start,
+        context: lines[context_start - 1..context_end]
+            .iter()
+            .map(|line| (*line).to_string())
+            .collect(),
+        body: body.to_string(),
+        resolved: false,
+    };
+    comments.push(comment.clone());
+    save_review_comments(comments_path, &comments)?;
+    Ok(comment)
+}
+
+/// Follows each pending comment to where its anchored line now sits and
+/// marks it resolved once that line is gone. Only reads the worktree; the
+/// caller persists the result with [`store_review_comment_resolution`].
+pub fn resolve_review_comments(
+    worktree_path: &Path,
+    mut comments: Vec<ReviewComment>,
+) -> Vec<ReviewComment> {
+    for comment in comments.iter_mut().filter(|comment| !comment.resolved) {
+        let content = fs::read_to_string(worktree_path.join(&comment.path)).unwrap_or_default();
+        let lines = content.lines().collect::<Vec<&str>>();
+        match reanchored_line(comment, &lines) {
+            Some(line) => {
+                let shift = line as isize - comment.line as isize;
+                comment.line = line;
+                comment.context_start = comment.context_start.saturating_add_signed(shift).max(1);
+            }
+            None => comment.resolved = true,
+        }
+    }
+    comments
+}
+
+/// Picks the line matching the anchor text, preferring the candidate whose
+/// surrounding lines still match the saved context, then the nearest one.
+fn reanchored_line(comment: &ReviewComment, lines: &[&str]) -> Option<usize> {
+    let context_offset = comment.line as isize - comment.context_start as isize;
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(_, text)| **text == comment.anchor)
+        .map(|(index, _)| index + 1)
+        .max_by_key(|candidate| {
+            let context_matches = comment
+                .context
+                .iter()
+                .enumerate()
+                .filter(|(offset, text)| {
+                    let number = *candidate as isize - context_offset + *offset as isize;
+                    number >= 1
+                        && lines
+                            .get(number as usize - 1)
+                            .is_some_and(|line| line == text)
+                })
+                .count();
+            (
+                context_matches,
+                std::cmp::Reverse(candidate.abs_diff(comment.line)),
+            )
+        })
+}
+
+/// Writes resolved positions back by id, keeping comments added since the
+/// resolution started, and returns the stored list.
+pub fn store_review_comment_resolution(
+    comments_path: &Path,
+    resolved: &[ReviewComment],
+) -> Result<Vec<ReviewComment>, String> {
+    let mut comments = load_review_comments(comments_path)?;
+    let mut changed = false;
+    for comment in &mut comments {
+        let Some(update) = resolved.iter().find(|update| update.id == comment.id) else {
+            continue;
+        };
+        if (comment.line, comment.context_start, comment.resolved)
+            != (update.line, update.context_start, update.resolved)
+        {
+            comment.line = update.line;
+            comment.context_start = update.context_start;
+            comment.resolved = update.resolved;
+            changed = true;
+        }
+    }
+    if changed {
+        save_review_comments(comments_path, &comments)?;
+    }
+    Ok(comments)
+}
+
+/// Formats unresolved comments into a single prompt for the agent.
+pub fn review_prompt(comments: &[ReviewComment]) -> Option<String> {
+    let pending = comments
+        .iter()
+        .filter(|comment| !comment.resolved)
+        .collect::<Vec<&ReviewComment>>();
+    if pending.is_empty() {
+        return None;
+    }
+
+    let mut prompt = String::from(
+        "Please address these review comments on your changes. \
+Each one quotes the code it refers to; the marked line is the one commented on.\n",
+    );
+    for (index, comment) in pending.iter().enumerate() {
+        prompt.push_str(&format!(
+            "\n{}. {}:{}\n```\n",
+            index + 1,
+            comment.path,
+            comment.line
+        ));
+        for (offset, code) in comment.context.iter().enumerate() {
+            let number = comment.context_start + offset;
+            let marker = if number == comment.line { '>' } else { ' ' };
+            prompt.push_str(&format!("{marker}{number:>5} | {code}\n"));
+        }
+        prompt.push_str(&format!("```\n{}\n", comment.body));
+    }
+    Some(prompt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        add_review_comment, load_review_comments, resolve_review_comments, review_comments_path,
+        review_prompt, store_review_comment_resolution,
+    };
+    use std::fs;
+    use std::path::PathBuf;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    #[derive(Debug)]
+    struct TestDir {
+        path: PathBuf,
+    }
+
+    impl TestDir {
+        fn new(label: &str) -> Self {
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or(Duration::from_secs(0))
+                .as_nanos();
+            let path = std::env::temp_dir().join(format!(
+                "grove-review-comments-{label}-{}-{timestamp}",
+                std::process::id()
+            ));
+            fs::create_dir_all(&path).expect("test dir should exist");
+            Self { path }
+        }
+    }
+
+    impl Drop for TestDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+
+    #[test]
+    fn comments_persist_in_task_dir_and_format_into_prompt() {
+        let temp = TestDir::new("prompt");
+        let worktree = temp.path.join("grove");
+        let comments_path = review_comments_path(&temp.path, &worktree);
+        assert_eq!(
+            comments_path,
+            temp.path.join(".grove/review_comments/grove.json")
+        );
+        fs::create_dir_all(worktree.join("src")).expect("src dir should exist");
+        fs::write(
+            worktree.join("src/lib.rs"),
+            "fn a() {}\nfn b() {}\nfn c() {}\nfn d() {}\n",
+        )
+        .expect("source should be writable");
+
+        let first = add_review_comment(&comments_path, &worktree, "src/lib.rs", 2, "rename b\n")
+            .expect("comment should be added");
+        let second = add_review_comment(&comments_path, &worktree, "src/lib.rs", 4, "drop d")
+            .expect("comment should be added");
+        assert_eq!((first.id, second.id), (1, 2));
+        assert_eq!(first.context_start, 1);
+        assert_eq!(
+            first.context,
+            vec!["fn a() {}", "fn b() {}", "fn c() {}", "fn d() {}"]
+        );
+        assert!(add_review_comment(&comments_path, &worktree, "src/lib.rs", 9, "nope").is_err());
+        assert!(add_review_comment(&comments_path, &worktree, "src/lib.rs", 1, "  ").is_err());
+        assert!(!worktree.join(".grove").exists());
+
+        let comments = load_review_comments(&comments_path).expect("comments should load");
+        assert_eq!(comments.len(), 2);
+        let prompt = review_prompt(&comments).expect("prompt should exist");
+        assert!(prompt.contains("1. src/lib.rs:2\n```\n     1 | fn a() {}\n>    2 | fn b() {}\n"));
+        assert!(prompt.contains("```\nrename b\n"));
+        assert!(prompt.contains("2. src/lib.rs:4"));
+    }
+
+    #[test]
+    fn comments_resolve_when_their_line_changes() {
+        let temp = TestDir::new("resolve");
+        let comments_path = temp.path.join("comments.json");
+        fs::write(temp.path.join("notes.txt"), "one\ntwo\nthree\n")
+            .expect("notes should be writable");
+        add_review_comment(&comments_path, &temp.path, "notes.txt", 1, "keep")
+            .expect("comment should be added");
+        add_review_comment(&comments_path, &temp.path, "notes.txt", 2, "fix")
+            .expect("comment should be added");
+
+        fs::write(temp.path.join("notes.txt"), "one\n2\nthree\n").expect("notes should update");
+        let loaded = load_review_comments(&comments_path).expect("comments should load");
+        let comments = resolve_review_comments(&temp.path, loaded);
+        assert!(!comments[0].resolved);
+        assert!(comments[1].resolved);
+        assert!(
+            !load_review_comments(&comments_path).expect("comments should load")[1].resolved,
+            "resolving should not write"
+        );
+        let stored = store_review_comment_resolution(&comments_path, &comments)
+            .expect("resolution should be stored");
+        assert!(stored[1].resolved);
+
+        let prompt = review_prompt(&comments).expect("one comment is still pending");
+        assert!(prompt.contains("notes.txt:1"));
+        assert!(!prompt.contains("notes.txt:2"));
+        fs::remove_file(temp.path.join("notes.txt")).expect("notes should be removable");
+        let comments = resolve_review_comments(&temp.path, stored);
+        assert!(review_prompt(&comments).is_none());
+    }
+
+    #[test]
+    fn comments_follow_their_line_when_code_moves() {
+        let temp = TestDir::new("reanchor");
+        let comments_path = temp.path.join("comments.json");
+        fs::write(temp.path.join("lib.rs"), "fn a() {\n}\nfn b() {\n}\n")
+            .expect("source should be writable");
+        add_review_comment(&comments_path, &temp.path, "lib.rs", 4, "close b")
+            .expect("comment should be added");
+
+        fs::write(
+            temp.path.join("lib.rs"),
+            "use x;\n\nfn a() {\n}\nfn b() {\n}\n",
+        )
+        .expect("source should update");
+        let loaded = load_review_comments(&comments_path).expect("comments should load");
+        let comments = resolve_review_comments(&temp.path, loaded);
+        assert!(!comments[0].resolved);
+        assert_eq!((comments[0].line, comments[0].context_start), (6, 4));
+
+        let added = add_review_comment(&comments_path, &temp.path, "lib.rs", 1, "drop x")
+            .expect("comment should be added during the poll");
+        let stored = store_review_comment_resolution(&comments_path, &comments)
+            .expect("resolution should be stored");
+        assert_eq!(stored.len(), 2, "comments added meanwhile are kept");
+        assert_eq!(stored[0].line, 6);
+        assert_eq!(stored[1], added);
+    }
+}
@@ -1,7 +1,7 @@
 use std::process::Command;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use crate::application::agent_runtime::task_session_names_for_cleanup;
+use crate::application::agent_runtime::{is_grove_session_name, task_session_names_for_cleanup};
 use crate::application::task_discovery::bootstrap_task_data_for_root;
 use crate::domain::Task;
 use crate::infrastructure::paths::tasks_root;
@@ -246,9 +246,7 @@ pub(crate) fn cleanup_reason_for_tasks(
 }
 
 pub(crate) fn is_grove_managed_session(session_name: &str) -> bool {
-    session_name.starts_with("grove-task-")
-        || session_name.starts_with("grove-wt-")
-        || session_name.starts_with("grove-ws-")
+    is_grove_session_name(session_name)
 }
 
 pub(crate) fn stale_auxiliary_session(session: &SessionRecord, now_unix_secs: u64) -> bool {
@@ -275,7 +273,7 @@ pub(crate) fn is_auxiliary_session(session_name: &str) -> bool {
     !ordinal.is_empty() && ordinal.chars().all(|character| character.is_ascii_digit())
 }
 
-fn session_missing_error(message: &str) -> bool {
+pub(crate) fn session_missing_error(message: &str) -> bool {
     message.contains("can't find session")
 }
 
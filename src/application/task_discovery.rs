@@ -4,7 +4,7 @@ use std::path::{Path, PathBuf};
 
 use crate::application::agent_runtime::session_name_for_task_worktree;
 use crate::domain::Task;
-use crate::infrastructure::task_manifest::decode_task_manifest;
+use crate::infrastructure::task_manifest::load_task_manifest;
 
 const TASK_MANIFEST_FILE: &str = ".grove/task.toml";
 
@@ -66,15 +66,9 @@ fn load_tasks_from_root(tasks_root: &Path) -> Result<Vec<Task>, String> {
             continue;
         }
 
-        let raw = fs::read_to_string(&manifest_path).map_err(|error| {
+        let task = load_task_manifest(&manifest_path).map_err(|error| {
             format!(
-                "task manifest read failed for {}: {error}",
-                manifest_path.display()
-            )
-        })?;
-        let task = decode_task_manifest(raw.as_str()).map_err(|error| {
-            format!(
-                "task manifest decode failed for {}: {error}",
+                "task manifest load failed for {}: {error}",
                 manifest_path.display()
             )
         })?;
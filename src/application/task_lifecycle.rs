@@ -133,8 +133,7 @@ pub fn create_task(
     setup_script_runner: &impl SetupScriptRunner,
     setup_command_runner: &impl SetupCommandRunner,
 ) -> Result<CreateTaskResult, TaskLifecycleError> {
-    let home_directory = dirs::home_dir().ok_or(TaskLifecycleError::HomeDirectoryUnavailable)?;
-    let tasks_root = home_directory.join(".grove").join("tasks");
+    let tasks_root = tasks_root().ok_or(TaskLifecycleError::HomeDirectoryUnavailable)?;
     create_task_in_root(
         tasks_root.as_path(),
         request,
@@ -163,8 +162,7 @@ pub fn create_task_in_root(
 pub fn create_base_task(
     request: &CreateBaseTaskRequest,
 ) -> Result<CreateTaskResult, TaskLifecycleError> {
-    let home_directory = dirs::home_dir().ok_or(TaskLifecycleError::HomeDirectoryUnavailable)?;
-    let tasks_root = home_directory.join(".grove").join("tasks");
+    let tasks_root = tasks_root().ok_or(TaskLifecycleError::HomeDirectoryUnavailable)?;
     create_base_task_in_root(tasks_root.as_path(), request)
 }
 
@@ -175,6 +173,26 @@ pub fn create_base_task_in_root(
     create::create_base_task_in_root(tasks_root, request)
 }
 
+pub(crate) fn create_task_worktree_for_branch(
+    task_root: &Path,
+    branch: &str,
+    repository: &RepositoryConfig,
+    agent: AgentType,
+    git_runner: &impl GitCommandRunner,
+    setup_script_runner: &impl SetupScriptRunner,
+    setup_command_runner: &impl SetupCommandRunner,
+) -> Result<(Worktree, Vec<String>), TaskLifecycleError> {
+    create::create_task_worktree(
+        task_root,
+        branch,
+        repository,
+        agent,
+        git_runner,
+        setup_script_runner,
+        setup_command_runner,
+    )
+}
+
 pub fn materialize_base_task_manifest_for_project_in_root(
     tasks_root: &Path,
     project: &RepositoryConfig,
@@ -197,6 +215,33 @@ pub fn materialize_base_task_manifest_for_project_in_root(
     create_base_task_in_root(tasks_root, &request).map(Some)
 }
 
+/// Creates the task unless one with the same slug already exists, so a
+/// declarative task list can be re-applied without duplicating work.
+pub fn materialize_task_in_root(
+    tasks_root: &Path,
+    request: &CreateTaskRequest,
+    existing_tasks: &[Task],
+    git_runner: &impl GitCommandRunner,
+    setup_script_runner: &impl SetupScriptRunner,
+    setup_command_runner: &impl SetupCommandRunner,
+) -> Result<Option<CreateTaskResult>, TaskLifecycleError> {
+    if existing_tasks
+        .iter()
+        .any(|task| task.slug == request.task_name)
+    {
+        return Ok(None);
+    }
+
+    create_task_in_root(
+        tasks_root,
+        request,
+        git_runner,
+        setup_script_runner,
+        setup_command_runner,
+    )
+    .map(Some)
+}
+
 pub fn add_worktree_to_task(
     request: &AddWorktreeToTaskRequest,
     git_runner: &impl GitCommandRunner,
@@ -229,6 +274,27 @@ pub fn add_worktree_to_task_in_root(
     )
 }
 
+/// Rewrites the task manifest with the task's current tags and notes.
+pub fn update_task_metadata(task: &Task) -> Result<(), TaskLifecycleError> {
+    let manifest_tasks_root = tasks_root();
+    update_task_metadata_in_manifest_root(task, manifest_tasks_root.as_deref())
+}
+
+pub fn update_task_metadata_in_root(
+    tasks_root: &Path,
+    task: &Task,
+) -> Result<(), TaskLifecycleError> {
+    update_task_metadata_in_manifest_root(task, Some(tasks_root))
+}
+
+fn update_task_metadata_in_manifest_root(
+    task: &Task,
+    manifest_tasks_root: Option<&Path>,
+) -> Result<(), TaskLifecycleError> {
+    let manifest_root = manifest_write_root(manifest_tasks_root, task);
+    write_task_manifest(manifest_root.as_path(), task)
+}
+
 pub fn delete_task(request: DeleteTaskRequest) -> (Result<(), String>, Vec<String>) {
     let git_runner = CommandGitRunner;
     delete_task_with_runner(request, &git_runner)
@@ -250,7 +316,7 @@ fn delete_task_with_runner_in_manifest_root(
     delete::delete_task_with_runner(request, git_runner, stop_task_sessions, manifest_tasks_root)
 }
 
-fn task_name_is_valid(name: &str) -> bool {
+pub(crate) fn task_name_is_valid(name: &str) -> bool {
     name.chars()
         .all(|character| character.is_ascii_alphanumeric() || character == '-' || character == '_')
 }
@@ -271,7 +337,7 @@ fn manifest_write_root(manifest_tasks_root: Option<&Path>, task: &Task) -> PathB
     manifest_task_root
 }
 
-fn write_task_manifest(task_root: &Path, task: &Task) -> Result<(), TaskLifecycleError> {
+pub(crate) fn write_task_manifest(task_root: &Path, task: &Task) -> Result<(), TaskLifecycleError> {
     let manifest_path = task_manifest_path(task_root);
     let Some(manifest_parent) = manifest_path.parent() else {
         return Err(TaskLifecycleError::TaskManifest(
@@ -445,7 +511,9 @@ fn add_worktree_to_task_in_manifest_root(
         request.task.branch.as_str(),
         request.task.root_path.as_path(),
         worktrees,
-    )?;
+    )?
+    .with_tags(request.task.tags.clone())
+    .with_notes(request.task.notes.clone());
     let manifest_root = manifest_write_root(manifest_tasks_root, &task);
     write_task_manifest(manifest_root.as_path(), &task)?;
 
@@ -470,15 +538,17 @@ mod tests {
         TaskBranchSource, add_worktree_to_task_in_root, create_base_task_in_root,
         create_task_in_root, delete_task_with_runner_in_manifest_root,
         detect_repository_base_branch, materialize_base_task_manifest_for_project_in_root,
-        repo_directory_name, task_manifest_path,
+        repo_directory_name, task_manifest_path, update_task_metadata_in_root,
     };
     use crate::application::workspace_lifecycle::{
         GitCommandRunner, SetupCommandContext, SetupCommandRunner, SetupScriptContext,
         SetupScriptRunner,
     };
     use crate::domain::AgentType;
-    use crate::infrastructure::config::{ProjectDefaults, RepositoryConfig};
+    use crate::infrastructure::config::{GlobalSettings, ProjectDefaults, RepositoryConfig};
+    use crate::infrastructure::paths::tasks_root_for;
     use crate::infrastructure::process::stderr_trimmed;
+    use crate::infrastructure::profile::resolve_profile;
     use crate::infrastructure::task_manifest::decode_task_manifest;
     use std::cell::RefCell;
     use std::fs;
@@ -574,6 +644,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn update_task_metadata_persists_tags_and_notes_across_added_worktrees() {
+        let temp = TestDir::new("metadata");
+        let tasks_root = temp.path.join("tasks");
+        let flohome = temp.path.join("repos").join("flohome");
+        let fastly = temp.path.join("repos").join("terraform-fastly");
+        fs::create_dir_all(&flohome).expect("flohome repo should exist");
+        fs::create_dir_all(&fastly).expect("fastly repo should exist");
+        let request = CreateTaskRequest {
+            task_name: "flohome-launch".to_string(),
+            repositories: vec![repository(flohome)],
+            agent: AgentType::Codex,
+            branch_source: TaskBranchSource::BaseBranch,
+        };
+        let git = StubGitRunner::default();
+        let setup = StubSetupRunner;
+        let setup_command = StubSetupCommandRunner;
+        let created =
+            create_task_in_root(tasks_root.as_path(), &request, &git, &setup, &setup_command)
+                .expect("task should create");
+
+        let task = created
+            .task
+            .with_tags(vec!["launch".to_string()])
+            .with_notes("Keep the CDN config in sync.".to_string());
+        update_task_metadata_in_root(tasks_root.as_path(), &task).expect("metadata should update");
+        let added = add_worktree_to_task_in_root(
+            tasks_root.as_path(),
+            &AddWorktreeToTaskRequest {
+                task,
+                repository: repository(fastly),
+                agent: AgentType::Codex,
+            },
+            &git,
+            &setup,
+            &setup_command,
+        )
+        .expect("worktree should add");
+
+        let manifest = fs::read_to_string(task_manifest_path(&added.task_root))
+            .expect("manifest should exist");
+        let decoded = decode_task_manifest(&manifest).expect("manifest should decode");
+        assert_eq!(decoded.worktrees.len(), 2);
+        assert_eq!(decoded.tags, vec!["launch".to_string()]);
+        assert_eq!(decoded.notes, "Keep the CDN config in sync.");
+    }
+
     #[test]
     fn create_task_builds_one_worktree_per_repository_under_task_root() {
         let temp = TestDir::new("create");
@@ -657,6 +774,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn create_task_uses_resolved_profile_tasks_root() {
+        let temp = TestDir::new("create-profile-root");
+        let home = temp.path.join("home");
+        let flohome = temp.path.join("repos").join("flohome");
+        fs::create_dir_all(&flohome).expect("flohome repo should exist");
+        let profile = resolve_profile(
+            "work",
+            home.join(".config/grove/config.toml").as_path(),
+            &GlobalSettings::default(),
+            Some(home.as_path()),
+        )
+        .expect("work profile should resolve");
+        let tasks_root = tasks_root_for(Some(&profile), Some(home.as_path()))
+            .expect("tasks root should resolve");
+        assert_ne!(
+            Some(tasks_root.clone()),
+            tasks_root_for(None, Some(home.as_path()))
+        );
+
+        let request = CreateTaskRequest {
+            task_name: "flohome-launch".to_string(),
+            repositories: vec![repository(flohome)],
+            agent: AgentType::Codex,
+            branch_source: TaskBranchSource::BaseBranch,
+        };
+        let git = StubGitRunner::default();
+        let setup = StubSetupRunner;
+        let setup_command = StubSetupCommandRunner;
+
+        let result =
+            create_task_in_root(tasks_root.as_path(), &request, &git, &setup, &setup_command)
+                .expect("task should create");
+
+        assert_eq!(
+            result.task_root,
+            home.join(".grove/profiles/work/tasks/flohome-launch")
+        );
+        assert!(task_manifest_path(&result.task_root).exists());
+        assert!(!home.join(".grove/tasks").exists());
+    }
+
     #[test]
     fn create_task_resolves_base_branch_per_repository() {
         let temp = TestDir::new("create-per-project-base");
@@ -11,19 +11,25 @@ use super::{
 };
 use crate::application::workspace_lifecycle::{
     GitCommandRunner, SetupCommandContext, SetupCommandRunner, SetupScriptContext,
-    SetupScriptRunner, copy_env_files, ensure_grove_git_exclude_entries,
+    SetupScriptRunner, copy_env_files, copy_shared_files, ensure_grove_git_exclude_entries,
     write_workspace_base_marker,
 };
+use crate::infrastructure::config::RepositoryConfig;
+use crate::infrastructure::shared_config::{
+    EffectiveProjectConfig, SHARED_CONFIG_FILE, effective_project_config,
+};
 
 pub(super) fn create_task_worktree(
     task_root: &Path,
     task_branch: &str,
-    repository: &crate::infrastructure::config::RepositoryConfig,
+    repository: &RepositoryConfig,
     agent: crate::domain::AgentType,
     git_runner: &impl GitCommandRunner,
     setup_script_runner: &impl SetupScriptRunner,
     setup_command_runner: &impl SetupCommandRunner,
 ) -> Result<(Worktree, Vec<String>), TaskLifecycleError> {
+    let (repository, shared) = layered_repository(repository);
+    let repository = &repository;
     let base_branch = resolve_repository_base_branch(repository)?;
     let repository_dir = repo_directory_name(repository)?;
     let worktree_path = task_root.join(repository_dir);
@@ -56,8 +62,14 @@ pub(super) fn create_task_worktree(
         .map_err(|error| TaskLifecycleError::Io(format!("{error:?}")))?;
     copy_env_files(repository.path.as_path(), &worktree_path)
         .map_err(|error| TaskLifecycleError::Io(format!("{error:?}")))?;
+    copy_shared_files(
+        repository.path.as_path(),
+        &worktree_path,
+        shared.copy_files.as_slice(),
+    )
+    .map_err(|error| TaskLifecycleError::Io(format!("{error:?}")))?;
 
-    let mut warnings = Vec::new();
+    let mut warnings = shared_config_warnings(repository.name.as_str(), &shared);
     let setup_script_path = repository.path.join(GROVE_SETUP_SCRIPT_FILE);
     if setup_script_path.exists() {
         let context = SetupScriptContext {
@@ -161,6 +173,9 @@ fn create_task_in_dir(
                 number,
                 branch_name,
             } => {
+                let (repository, shared) = layered_repository(repository);
+                let repository = &repository;
+                warnings.extend(shared_config_warnings(repository.name.as_str(), &shared));
                 let base_branch = resolve_repository_base_branch(repository)?;
                 let repository_dir = repo_directory_name(repository)?;
                 let worktree_path = task_root.join(repository_dir);
@@ -213,6 +228,12 @@ fn create_task_in_dir(
                     .map_err(|error| TaskLifecycleError::Io(format!("{error:?}")))?;
                 copy_env_files(repository.path.as_path(), &worktree_path)
                     .map_err(|error| TaskLifecycleError::Io(format!("{error:?}")))?;
+                copy_shared_files(
+                    repository.path.as_path(),
+                    &worktree_path,
+                    shared.copy_files.as_slice(),
+                )
+                .map_err(|error| TaskLifecycleError::Io(format!("{error:?}")))?;
 
                 let setup_script_path = repository.path.join(GROVE_SETUP_SCRIPT_FILE);
                 if setup_script_path.exists() {
@@ -282,6 +303,39 @@ fn remove_dir_if_empty(path: &Path) -> std::io::Result<()> {
     Ok(())
 }
 
+fn shared_config_warnings(repository_name: &str, shared: &EffectiveProjectConfig) -> Vec<String> {
+    let mut warnings = Vec::new();
+    if let Some(error) = shared.shared_error.as_ref() {
+        warnings.push(format!("{repository_name}: {error}"));
+    }
+    if let Some(command) = shared.untrusted_init_command.as_ref() {
+        warnings.push(format!(
+            "{repository_name}: skipped {SHARED_CONFIG_FILE} workspace_init_command `{command}`, set trust_shared_commands = true for the project to run it"
+        ));
+    }
+    for (agent, entries) in [
+        ("claude", &shared.untrusted_agent_env.claude),
+        ("codex", &shared.untrusted_agent_env.codex),
+    ] {
+        if !entries.is_empty() {
+            warnings.push(format!(
+                "{repository_name}: skipped {SHARED_CONFIG_FILE} {agent} agent_env `{}`, set trust_shared_commands = true for the project to apply it",
+                entries.join(" ")
+            ));
+        }
+    }
+    warnings
+}
+
+fn layered_repository(repository: &RepositoryConfig) -> (RepositoryConfig, EffectiveProjectConfig) {
+    let effective = effective_project_config(repository);
+    let layered = RepositoryConfig {
+        defaults: effective.defaults.clone(),
+        ..repository.clone()
+    };
+    (layered, effective)
+}
+
 pub(super) fn create_base_task_in_root(
     tasks_root: &Path,
     request: &CreateBaseTaskRequest,
@@ -0,0 +1,426 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::application::agent_runtime::launch_prompt::expand_launch_prompt;
+use crate::application::task_lifecycle::{
+    CreateTaskRequest, TaskBranchSource, materialize_task_in_root, task_lifecycle_error_message,
+};
+use crate::application::workspace_lifecycle::{
+    GitCommandRunner, SetupCommandRunner, SetupScriptRunner, write_workspace_init_command,
+    write_workspace_launch_prompt,
+};
+use crate::domain::{AgentType, PermissionMode, Task};
+use crate::infrastructure::config::RepositoryConfig;
+use crate::infrastructure::paths::refer_to_same_location;
+use crate::infrastructure::shared_config::effective_project_config;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TaskSpecFile {
+    #[serde(default, rename = "task")]
+    tasks: Vec<RawTaskSpec>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawTaskSpec {
+    name: String,
+    repositories: Vec<String>,
+    agent: Option<String>,
+    permission: Option<String>,
+    prompt: Option<String>,
+    init_command: Option<String>,
+    pull_request: Option<RawPullRequestSpec>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawPullRequestSpec {
+    number: u64,
+    branch: String,
+}
+
+/// One `[[task]]` entry of a `grove apply` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskSpec {
+    pub name: String,
+    pub repositories: Vec<String>,
+    pub branch_source: TaskBranchSource,
+    pub agent: AgentType,
+    pub permission_mode: Option<PermissionMode>,
+    pub prompt: Option<String>,
+    pub init_command: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaskSpecOutcome {
+    Created { task: Task, warnings: Vec<String> },
+    Existing(Task),
+}
+
+impl TaskSpecOutcome {
+    pub fn task(&self) -> &Task {
+        match self {
+            Self::Created { task, .. } | Self::Existing(task) => task,
+        }
+    }
+}
+
+pub fn load_task_specs(path: &Path) -> Result<Vec<TaskSpec>, String> {
+    let raw = fs::read_to_string(path)
+        .map_err(|error| format!("task spec read failed for {}: {error}", path.display()))?;
+    parse_task_specs(raw.as_str())
+}
+
+pub fn parse_task_specs(raw: &str) -> Result<Vec<TaskSpec>, String> {
+    let file = toml::from_str::<TaskSpecFile>(raw)
+        .map_err(|error| format!("task spec parse failed: {error}"))?;
+    if file.tasks.is_empty() {
+        return Err("task spec lists no [[task]] entries".to_string());
+    }
+
+    let mut seen = HashSet::new();
+    let mut specs = Vec::with_capacity(file.tasks.len());
+    for raw in file.tasks {
+        if !seen.insert(raw.name.clone()) {
+            return Err(format!("task `{}` is listed more than once", raw.name));
+        }
+        specs.push(task_spec_from_raw(raw)?);
+    }
+    Ok(specs)
+}
+
+fn task_spec_from_raw(raw: RawTaskSpec) -> Result<TaskSpec, String> {
+    let agent = match raw.agent.as_deref() {
+        Some(value) => AgentType::from_marker(value)
+            .ok_or_else(|| format!("task `{}`: unsupported agent `{value}`", raw.name))?,
+        None => AgentType::Codex,
+    };
+    let permission_mode = raw
+        .permission
+        .as_deref()
+        .map(|value| {
+            PermissionMode::from_marker(value)
+                .ok_or_else(|| format!("task `{}`: unsupported permission `{value}`", raw.name))
+        })
+        .transpose()?;
+    let branch_source = match raw.pull_request {
+        Some(pull_request) => TaskBranchSource::PullRequest {
+            number: pull_request.number,
+            branch_name: pull_request.branch,
+        },
+        None => TaskBranchSource::BaseBranch,
+    };
+
+    Ok(TaskSpec {
+        name: raw.name,
+        repositories: raw.repositories,
+        branch_source,
+        agent,
+        permission_mode,
+        prompt: raw.prompt.filter(|prompt| !prompt.trim().is_empty()),
+        init_command: raw
+            .init_command
+            .filter(|command| !command.trim().is_empty()),
+    })
+}
+
+/// Creates the spec's task when it is missing and seeds each new worktree
+/// with the expanded launch prompt and init command the agent start path reads.
+/// Tasks that already exist are returned untouched.
+pub fn apply_task_spec_in_root(
+    tasks_root: &Path,
+    spec: &TaskSpec,
+    projects: &[RepositoryConfig],
+    existing_tasks: &[Task],
+    git_runner: &impl GitCommandRunner,
+    setup_script_runner: &impl SetupScriptRunner,
+    setup_command_runner: &impl SetupCommandRunner,
+) -> Result<TaskSpecOutcome, String> {
+    if let Some(task) = existing_tasks.iter().find(|task| task.slug == spec.name) {
+        return Ok(TaskSpecOutcome::Existing(task.clone()));
+    }
+
+    let repositories = spec
+        .repositories
+        .iter()
+        .map(|value| resolve_spec_repository(projects, value))
+        .collect::<Result<Vec<RepositoryConfig>, String>>()?;
+    let request = CreateTaskRequest {
+        task_name: spec.name.clone(),
+        repositories,
+        agent: spec.agent,
+        branch_source: spec.branch_source.clone(),
+    };
+    request
+        .validate()
+        .map_err(|error| task_lifecycle_error_message(&error))?;
+    let Some(result) = materialize_task_in_root(
+        tasks_root,
+        &request,
+        existing_tasks,
+        git_runner,
+        setup_script_runner,
+        setup_command_runner,
+    )
+    .map_err(|error| task_lifecycle_error_message(&error))?
+    else {
+        return Err(format!("task `{}` already exists", spec.name));
+    };
+
+    for worktree in &result.task.worktrees {
+        let project = request
+            .repositories
+            .iter()
+            .find(|repository| refer_to_same_location(&repository.path, &worktree.repository_path))
+            .map(effective_project_config);
+        let prompt = spec.prompt.clone().map(|prompt| {
+            expand_launch_prompt(prompt, project.as_ref(), result.task.notes.as_str())
+        });
+        write_workspace_launch_prompt(&worktree.path, prompt.as_deref())
+            .and_then(|()| {
+                write_workspace_init_command(&worktree.path, spec.init_command.as_deref())
+            })
+            .map_err(|error| format!("workspace marker write failed: {error}"))?;
+    }
+
+    Ok(TaskSpecOutcome::Created {
+        task: result.task,
+        warnings: result.warnings,
+    })
+}
+
+fn resolve_spec_repository(
+    projects: &[RepositoryConfig],
+    value: &str,
+) -> Result<RepositoryConfig, String> {
+    projects
+        .iter()
+        .find(|project| project.name == value)
+        .or_else(|| {
+            projects
+                .iter()
+                .find(|project| refer_to_same_location(project.path.as_path(), Path::new(value)))
+        })
+        .cloned()
+        .ok_or_else(|| format!("repository `{value}` is not configured"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TaskSpecOutcome, apply_task_spec_in_root, parse_task_specs};
+    use crate::application::task_lifecycle::TaskBranchSource;
+    use crate::application::workspace_lifecycle::{
+        GitCommandRunner, SetupCommandContext, SetupCommandRunner, SetupScriptContext,
+        SetupScriptRunner,
+    };
+    use crate::domain::{AgentType, PermissionMode};
+    use crate::infrastructure::config::{ProjectConfig, ProjectDefaults};
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    struct TestDir {
+        path: PathBuf,
+    }
+
+    impl TestDir {
+        fn new(label: &str) -> Self {
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or(Duration::from_secs(0))
+                .as_nanos();
+            let path = std::env::temp_dir().join(format!(
+                "grove-task-spec-{label}-{}-{timestamp}",
+                std::process::id()
+            ));
+            fs::create_dir_all(&path).expect("test dir should be created");
+            Self { path }
+        }
+    }
+
+    impl Drop for TestDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+
+    struct StubGitRunner;
+
+    impl GitCommandRunner for StubGitRunner {
+        fn run(&self, _repo_root: &Path, _args: &[String]) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    struct StubSetupRunner;
+
+    impl SetupScriptRunner for StubSetupRunner {
+        fn run(&self, _context: &SetupScriptContext) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    impl SetupCommandRunner for StubSetupRunner {
+        fn run(&self, _context: &SetupCommandContext, _command: &str) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    const SPEC: &str = r#"
+[[task]]
+name = "auth-refactor"
+repositories = ["api"]
+agent = "claude"
+permission = "auto"
+prompt = "Split the auth middleware"
+init_command = "make setup"
+
+[[task]]
+name = "pr-42"
+repositories = ["api"]
+
+[task.pull_request]
+number = 42
+branch = "fix-login"
+"#;
+
+    #[test]
+    fn parse_task_specs_reads_tasks_and_rejects_bad_entries() {
+        let specs = parse_task_specs(SPEC).expect("spec should parse");
+
+        assert_eq!(specs.len(), 2);
+        assert_eq!(specs[0].agent, AgentType::Claude);
+        assert_eq!(specs[0].permission_mode, Some(PermissionMode::Auto));
+        assert_eq!(specs[0].branch_source, TaskBranchSource::BaseBranch);
+        assert_eq!(specs[1].agent, AgentType::Codex);
+        assert_eq!(
+            specs[1].branch_source,
+            TaskBranchSource::PullRequest {
+                number: 42,
+                branch_name: "fix-login".to_string(),
+            }
+        );
+
+        for invalid in [
+            "",
+            "[[task]]\nname = \"a\"\nrepositories = [\"api\"]\nagent = \"gpt\"\n",
+            "[[task]]\nname = \"a\"\nrepositories = [\"api\"]\npermission = \"yolo\"\n",
+            "[[task]]\nname = \"a\"\nrepositories = [\"api\"]\n[[task]]\nname = \"a\"\nrepositories = [\"api\"]\n",
+            "[[task]]\nname = \"a\"\nrepositories = [\"api\"]\nbranch = \"main\"\n",
+        ] {
+            assert!(parse_task_specs(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn apply_task_spec_creates_missing_task_once_and_seeds_markers() {
+        let temp = TestDir::new("apply");
+        let tasks_root = temp.path.join("tasks");
+        let api = temp.path.join("repos").join("api");
+        fs::create_dir_all(&api).expect("repo should exist");
+        let projects = vec![ProjectConfig {
+            name: "api".to_string(),
+            path: api,
+            defaults: ProjectDefaults {
+                base_branch: "main".to_string(),
+                ..ProjectDefaults::default()
+            },
+        }];
+        let spec = parse_task_specs(SPEC).expect("spec should parse").remove(0);
+
+        let created = apply_task_spec_in_root(
+            &tasks_root,
+            &spec,
+            &projects,
+            &[],
+            &StubGitRunner,
+            &StubSetupRunner,
+            &StubSetupRunner,
+        )
+        .expect("task should be created");
+        let TaskSpecOutcome::Created { task, .. } = &created else {
+            panic!("first apply should create the task");
+        };
+        let worktree_path = task.worktrees[0].path.clone();
+        assert_eq!(
+            fs::read_to_string(worktree_path.join(".grove/prompt")).expect("prompt should exist"),
+            "Split the auth middleware\n"
+        );
+        assert_eq!(
+            fs::read_to_string(worktree_path.join(".grove/init_command"))
+                .expect("init command should exist"),
+            "make setup\n"
+        );
+
+        let again = apply_task_spec_in_root(
+            &tasks_root,
+            &spec,
+            &projects,
+            std::slice::from_ref(task),
+            &StubGitRunner,
+            &StubSetupRunner,
+            &StubSetupRunner,
+        )
+        .expect("re-apply should succeed");
+        assert_eq!(again, TaskSpecOutcome::Existing(task.clone()));
+
+        let mut unknown = spec.clone();
+        unknown.name = "other".to_string();
+        unknown.repositories = vec!["web".to_string()];
+        assert_eq!(
+            apply_task_spec_in_root(
+                &tasks_root,
+                &unknown,
+                &projects,
+                &[],
+                &StubGitRunner,
+                &StubSetupRunner,
+                &StubSetupRunner,
+            ),
+            Err("repository `web` is not configured".to_string())
+        );
+    }
+
+    #[test]
+    fn apply_task_spec_expands_prompt_templates_into_the_prompt_marker() {
+        let temp = TestDir::new("apply-template");
+        let api = temp.path.join("repos").join("api");
+        fs::create_dir_all(&api).expect("repo should exist");
+        fs::write(
+            api.join(".grove.toml"),
+            "[prompt_templates]\nsplit = \"Split the auth middleware{notes}\"\n",
+        )
+        .expect("shared config should write");
+        let projects = vec![ProjectConfig {
+            name: "api".to_string(),
+            path: api,
+            defaults: ProjectDefaults {
+                base_branch: "main".to_string(),
+                ..ProjectDefaults::default()
+            },
+        }];
+        let mut spec = parse_task_specs(SPEC).expect("spec should parse").remove(0);
+        spec.prompt = Some("@split".to_string());
+
+        let created = apply_task_spec_in_root(
+            &temp.path.join("tasks"),
+            &spec,
+            &projects,
+            &[],
+            &StubGitRunner,
+            &StubSetupRunner,
+            &StubSetupRunner,
+        )
+        .expect("task should be created");
+
+        let worktree_path = created.task().worktrees[0].path.clone();
+        assert_eq!(
+            fs::read_to_string(worktree_path.join(".grove/prompt")).expect("prompt should exist"),
+            "Split the auth middleware\n"
+        );
+    }
+}
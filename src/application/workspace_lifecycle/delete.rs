@@ -48,13 +48,11 @@ fn remove_worktree_from_manifest(
     task_root: &std::path::Path,
     deleted_workspace_path: &std::path::Path,
 ) -> Result<(), String> {
-    use crate::infrastructure::task_manifest::{decode_task_manifest, encode_task_manifest};
+    use crate::infrastructure::task_manifest::{encode_task_manifest, load_task_manifest};
 
     let manifest_path = task_root.join(".grove/task.toml");
-    let raw = std::fs::read_to_string(&manifest_path)
-        .map_err(|error| format!("read manifest: {error}"))?;
     let mut task =
-        decode_task_manifest(&raw).map_err(|error| format!("decode manifest: {error}"))?;
+        load_task_manifest(&manifest_path).map_err(|error| format!("load manifest: {error}"))?;
 
     let original_count = task.worktrees.len();
     task.worktrees
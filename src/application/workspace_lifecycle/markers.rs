@@ -33,6 +33,41 @@ pub(super) fn write_workspace_base_marker(
         .map_err(|error| WorkspaceLifecycleError::Io(error.to_string()))
 }
 
+pub(super) fn read_text_marker(workspace_path: &Path, marker: &str) -> Option<String> {
+    let raw = fs::read_to_string(workspace_path.join(marker)).ok()?;
+    let trimmed = raw.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
+
+/// Writes a trimmed single-value marker, removing it when the value is empty
+/// so readers fall back to project defaults.
+pub(super) fn write_text_marker(
+    workspace_path: &Path,
+    marker: &str,
+    value: Option<&str>,
+) -> Result<(), String> {
+    let marker_path = workspace_path.join(marker);
+    let Some(parent) = marker_path.parent() else {
+        return Err(format!(
+            "workspace marker has no parent: {}",
+            marker_path.display()
+        ));
+    };
+    fs::create_dir_all(parent)
+        .map_err(|error| format!("create marker directory failed: {error}"))?;
+    let trimmed = value.map(str::trim).unwrap_or_default();
+    if trimmed.is_empty() {
+        if marker_path.exists() {
+            fs::remove_file(&marker_path)
+                .map_err(|error| format!("remove marker failed: {error}"))?;
+        }
+        return Ok(());
+    }
+
+    fs::write(&marker_path, format!("{trimmed}\n"))
+        .map_err(|error| format!("write marker failed: {error}"))
+}
+
 fn ensure_workspace_grove_dir(workspace_path: &Path) -> Result<(), WorkspaceLifecycleError> {
     fs::create_dir_all(workspace_path.join(super::GROVE_DIR))
         .map_err(|error| WorkspaceLifecycleError::Io(error.to_string()))
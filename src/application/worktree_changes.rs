@@ -0,0 +1,1035 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::application::branch_sync::base_comparison_ref;
+
+const COMMIT_DRAFT_MAX_STAT_LINES: usize = 60;
+const DISCARDED_HUNKS_DIR: &str = ".grove/discarded";
+const MAX_DIFF_COMMITS: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeSection {
+    Staged,
+    Unstaged,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangedFile {
+    pub path: String,
+    pub section: ChangeSection,
+    pub status: char,
+}
+
+impl ChangedFile {
+    pub fn status_label(&self) -> &'static str {
+        match self.status {
+            'A' => "added",
+            'D' => "deleted",
+            'R' => "renamed",
+            'C' => "copied",
+            'U' => "conflict",
+            'T' => "type",
+            '?' => "untracked",
+            _ => "modified",
+        }
+    }
+}
+
+/// Lists changed files from `git status`, staged entries first. A file that
+/// has both staged and unstaged edits appears once in each section.
+pub fn list_changed_files(worktree_path: &Path) -> Result<Vec<ChangedFile>, String> {
+    let output = git_output(
+        worktree_path,
+        &["status", "--porcelain=v1", "-z", "--untracked-files=all"],
+    )?;
+    Ok(parse_porcelain_status(output.as_str()))
+}
+
+pub(crate) fn parse_porcelain_status(output: &str) -> Vec<ChangedFile> {
+    let mut staged = Vec::new();
+    let mut unstaged = Vec::new();
+    let mut entries = output.split('\0');
+    while let Some(entry) = entries.next() {
+        if entry.len() < 4 {
+            continue;
+        }
+        let mut codes = entry.chars();
+        let index_status = codes.next().unwrap_or(' ');
+        let worktree_status = codes.next().unwrap_or(' ');
+        let path = entry[3..].to_string();
+        if matches!(index_status, 'R' | 'C') {
+            // Renames carry the original path as a separate NUL field.
+            let _ = entries.next();
+        }
+
+        if index_status == '?' {
+            unstaged.push(ChangedFile {
+                path,
+                section: ChangeSection::Unstaged,
+                status: '?',
+            });
+            continue;
+        }
+        if index_status == '!' {
+            continue;
+        }
+        if index_status == 'U' || worktree_status == 'U' {
+            unstaged.push(ChangedFile {
+                path,
+                section: ChangeSection::Unstaged,
+                status: 'U',
+            });
+            continue;
+        }
+        if index_status != ' ' {
+            staged.push(ChangedFile {
+                path: path.clone(),
+                section: ChangeSection::Staged,
+                status: index_status,
+            });
+        }
+        if worktree_status != ' ' {
+            unstaged.push(ChangedFile {
+                path,
+                section: ChangeSection::Unstaged,
+                status: worktree_status,
+            });
+        }
+    }
+    staged.extend(unstaged);
+    staged
+}
+
+pub fn stage_file(worktree_path: &Path, file_path: &str) -> Result<(), String> {
+    git_output(worktree_path, &["add", "--all", "--", file_path]).map(|_| ())
+}
+
+pub fn stage_all(worktree_path: &Path) -> Result<(), String> {
+    git_output(worktree_path, &["add", "--all"]).map(|_| ())
+}
+
+pub fn unstage_file(worktree_path: &Path, file_path: &str) -> Result<(), String> {
+    if git_output(worktree_path, &["rev-parse", "--verify", "--quiet", "HEAD"]).is_ok() {
+        return git_output(
+            worktree_path,
+            &["reset", "--quiet", "HEAD", "--", file_path],
+        )
+        .map(|_| ());
+    }
+    git_output(
+        worktree_path,
+        &["rm", "--cached", "--quiet", "--", file_path],
+    )
+    .map(|_| ())
+}
+
+/// Commits the index with `message` and returns the new short commit id.
+pub fn commit_staged(worktree_path: &Path, message: &str) -> Result<String, String> {
+    let message = message.trim();
+    if message.is_empty() {
+        return Err("commit message is empty".to_string());
+    }
+    if staged_diff_summary(worktree_path)?.is_empty() {
+        return Err("nothing staged".to_string());
+    }
+
+    git_output_with_input(worktree_path, &["commit", "--quiet", "--file=-"], message)?;
+    git_output(worktree_path, &["rev-parse", "--short", "HEAD"])
+        .map(|commit| commit.trim().to_string())
+}
+
+pub fn staged_diff_summary(worktree_path: &Path) -> Result<String, String> {
+    git_output(worktree_path, &["diff", "--cached", "--stat", "--no-color"])
+        .map(|summary| summary.trim_end().to_string())
+}
+
+pub fn commit_draft_prompt(staged_summary: &str) -> String {
+    let lines = staged_summary.lines().collect::<Vec<&str>>();
+    let mut summary = lines
+        .iter()
+        .take(COMMIT_DRAFT_MAX_STAT_LINES)
+        .copied()
+        .collect::<Vec<&str>>()
+        .join("\n");
+    if lines.len() > COMMIT_DRAFT_MAX_STAT_LINES {
+        summary.push_str(&format!(
+            "\n... {} more lines",
+            lines.len() - COMMIT_DRAFT_MAX_STAT_LINES
+        ));
+    }
+    format!(
+        "Draft a git commit message for the currently staged changes (see `git diff --cached`). \
+Use a short imperative subject line, a blank line, then a brief body. \
+Reply with the message only, do not commit.\n\nStaged changes:\n{summary}\n"
+    )
+}
+
+/// What the Diff tab compares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DiffMode {
+    /// Staged and unstaged changes against `HEAD`.
+    #[default]
+    WorkingTree,
+    /// Committed branch changes since the merge-base with the base branch.
+    Branch,
+    /// The last N commits on the branch.
+    LastCommits(usize),
+}
+
+impl DiffMode {
+    pub fn cycle(self) -> Self {
+        match self {
+            Self::WorkingTree => Self::Branch,
+            Self::Branch => Self::LastCommits(1),
+            Self::LastCommits(_) => Self::WorkingTree,
+        }
+    }
+
+    pub fn with_commit_delta(self, delta: isize) -> Self {
+        match self {
+            Self::LastCommits(count) => Self::LastCommits(
+                count
+                    .saturating_add_signed(delta)
+                    .clamp(1, MAX_DIFF_COMMITS),
+            ),
+            other => other,
+        }
+    }
+
+    pub fn label(self) -> String {
+        match self {
+            Self::WorkingTree => "working tree vs HEAD".to_string(),
+            Self::Branch => "branch vs merge-base".to_string(),
+            Self::LastCommits(1) => "last commit".to_string(),
+            Self::LastCommits(count) => format!("last {count} commits"),
+        }
+    }
+}
+
+/// Resolves the revision range for `mode`, or `None` for the working tree.
+pub fn diff_mode_range(
+    worktree_path: &Path,
+    mode: DiffMode,
+    base_branch: Option<&str>,
+) -> Result<Option<String>, String> {
+    match mode {
+        DiffMode::WorkingTree => Ok(None),
+        DiffMode::Branch => {
+            let base_branch =
+                base_branch.ok_or_else(|| "workspace has no base branch".to_string())?;
+            let base_ref = base_comparison_ref(worktree_path, base_branch)
+                .ok_or_else(|| format!("base branch '{base_branch}' not found"))?;
+            let base_ref = base_ref
+                .strip_prefix("refs/remotes/")
+                .or_else(|| base_ref.strip_prefix("refs/heads/"))
+                .unwrap_or(base_ref.as_str());
+            Ok(Some(format!("{base_ref}...HEAD")))
+        }
+        DiffMode::LastCommits(count) => Ok(Some(format!("HEAD~{count}..HEAD"))),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileDiffStat {
+    pub path: String,
+    pub insertions: usize,
+    pub deletions: usize,
+    pub binary: bool,
+}
+
+/// Per-file counts for `range`, or for the working tree against `HEAD`.
+pub fn diff_file_stats(
+    worktree_path: &Path,
+    range: Option<&str>,
+) -> Result<Vec<FileDiffStat>, String> {
+    let output = git_output(
+        worktree_path,
+        &["diff", "--numstat", "-z", range.unwrap_or("HEAD")],
+    )?;
+    Ok(parse_numstat(output.as_str()))
+}
+
+pub(crate) fn parse_numstat(output: &str) -> Vec<FileDiffStat> {
+    let mut stats = Vec::new();
+    let mut fields = output.split('\0');
+    while let Some(field) = fields.next() {
+        let mut parts = field.splitn(3, '\t');
+        let (Some(insertions), Some(deletions), Some(path)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+        let path = if path.is_empty() {
+            // Renames list the old and new paths as separate NUL fields.
+            let _ = fields.next();
+            fields.next().unwrap_or_default()
+        } else {
+            path
+        };
+        let binary = insertions == "-" && deletions == "-";
+        stats.push(FileDiffStat {
+            path: path.to_string(),
+            insertions: insertions.parse().unwrap_or(0),
+            deletions: deletions.parse().unwrap_or(0),
+            binary,
+        });
+    }
+    stats
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileTreeRow {
+    pub depth: usize,
+    pub name: String,
+    /// Full path for file rows, `None` for directories.
+    pub path: Option<String>,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+/// Lays changed files out as a directory tree, sorted by path. Directory rows
+/// carry the summed counts of everything below them.
+pub fn file_tree_rows(stats: &[FileDiffStat]) -> Vec<FileTreeRow> {
+    let mut sorted = stats.iter().collect::<Vec<&FileDiffStat>>();
+    sorted.sort_by(|left, right| left.path.cmp(&right.path));
+
+    let mut rows: Vec<FileTreeRow> = Vec::new();
+    let mut open_dirs: Vec<(String, usize)> = Vec::new();
+    for stat in sorted {
+        let components = stat.path.split('/').collect::<Vec<&str>>();
+        let (file_name, dirs) = components
+            .split_last()
+            .map_or((stat.path.as_str(), &[][..]), |(name, dirs)| (*name, dirs));
+        let shared = open_dirs
+            .iter()
+            .zip(dirs)
+            .take_while(|((open, _), dir)| open == *dir)
+            .count();
+        open_dirs.truncate(shared);
+        for dir in &dirs[shared..] {
+            open_dirs.push(((*dir).to_string(), rows.len()));
+            rows.push(FileTreeRow {
+                depth: open_dirs.len() - 1,
+                name: format!("{dir}/"),
+                path: None,
+                insertions: 0,
+                deletions: 0,
+            });
+        }
+        for (_, row_index) in &open_dirs {
+            rows[*row_index].insertions += stat.insertions;
+            rows[*row_index].deletions += stat.deletions;
+        }
+        rows.push(FileTreeRow {
+            depth: dirs.len(),
+            name: file_name.to_string(),
+            path: Some(stat.path.clone()),
+            insertions: stat.insertions,
+            deletions: stat.deletions,
+        });
+    }
+    rows
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffFile {
+    /// Index of the `diff --git` line within the parsed input.
+    pub start_line: usize,
+    pub path: String,
+    pub header: Vec<String>,
+    pub hunks: Vec<DiffHunk>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffHunk {
+    /// Index of the `@@` line within the parsed input.
+    pub start_line: usize,
+    pub lines: Vec<String>,
+}
+
+impl DiffHunk {
+    pub fn header(&self) -> &str {
+        self.lines.first().map(String::as_str).unwrap_or_default()
+    }
+
+    pub fn end_line(&self) -> usize {
+        self.start_line + self.lines.len()
+    }
+
+    /// New-side line number for each entry of `lines`, or `None` for the
+    /// header, removed lines, and `\` markers.
+    pub fn new_line_numbers(&self) -> Vec<Option<usize>> {
+        let mut next = hunk_new_start(self.header()).unwrap_or(0);
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| {
+                if index == 0 || line.starts_with('-') || line.starts_with('\\') {
+                    return None;
+                }
+                next += 1;
+                Some(next - 1)
+            })
+            .collect()
+    }
+
+    /// New-side line of the first added or removed line in the hunk.
+    pub fn first_changed_line(&self) -> Option<usize> {
+        let mut next = hunk_new_start(self.header())?;
+        for line in self.lines.iter().skip(1) {
+            match line.chars().next() {
+                Some('+') | Some('-') => return Some(next.max(1)),
+                Some('\\') => {}
+                _ => next += 1,
+            }
+        }
+        None
+    }
+}
+
+impl DiffFile {
+    /// Builds a patch containing only `hunk`, suitable for `git apply`.
+    pub fn hunk_patch(&self, hunk: &DiffHunk) -> String {
+        let mut patch = self.header.join("\n");
+        patch.push('\n');
+        patch.push_str(hunk.lines.join("\n").as_str());
+        patch.push('\n');
+        patch
+    }
+
+    fn find_hunk(&self, hunk_header: &str) -> Option<&DiffHunk> {
+        let range = hunk_range(hunk_header)?;
+        self.hunks
+            .iter()
+            .find(|hunk| hunk_range(hunk.header()) == Some(range))
+    }
+}
+
+/// Splits unified diff output into files and hunks. Hunk bodies are bounded
+/// by the line counts in their `@@` headers, so trailing text after a diff
+/// is not swallowed into its last hunk.
+pub fn parse_diff_files<S: AsRef<str>>(lines: &[S]) -> Vec<DiffFile> {
+    let mut files: Vec<DiffFile> = Vec::new();
+    let mut old_remaining = 0usize;
+    let mut new_remaining = 0usize;
+    let mut in_hunk = false;
+
+    for (index, line) in lines.iter().enumerate() {
+        let line = line.as_ref();
+        if let Some(paths) = line.strip_prefix("diff --git ") {
+            let path = paths
+                .rsplit_once(" b/")
+                .map_or(paths, |(_, path)| path)
+                .to_string();
+            files.push(DiffFile {
+                start_line: index,
+                path,
+                header: vec![line.to_string()],
+                hunks: Vec::new(),
+            });
+            in_hunk = false;
+            continue;
+        }
+        let Some(file) = files.last_mut() else {
+            continue;
+        };
+
+        if line.starts_with("@@ ") {
+            let Some((old_count, new_count)) = hunk_line_counts(line) else {
+                in_hunk = false;
+                continue;
+            };
+            old_remaining = old_count;
+            new_remaining = new_count;
+            in_hunk = true;
+            file.hunks.push(DiffHunk {
+                start_line: index,
+                lines: vec![line.to_string()],
+            });
+            continue;
+        }
+
+        if in_hunk {
+            let Some(hunk) = file.hunks.last_mut() else {
+                continue;
+            };
+            if line.starts_with('\\') {
+                hunk.lines.push(line.to_string());
+                continue;
+            }
+            if old_remaining == 0 && new_remaining == 0 {
+                in_hunk = false;
+                continue;
+            }
+            match line.chars().next() {
+                Some('+') => new_remaining = new_remaining.saturating_sub(1),
+                Some('-') => old_remaining = old_remaining.saturating_sub(1),
+                // Blank context lines may arrive with their leading space trimmed.
+                Some(' ') | None => {
+                    old_remaining = old_remaining.saturating_sub(1);
+                    new_remaining = new_remaining.saturating_sub(1);
+                }
+                Some(_) => {
+                    in_hunk = false;
+                    continue;
+                }
+            }
+            hunk.lines.push(line.to_string());
+            continue;
+        }
+
+        if file.hunks.is_empty() {
+            if let Some(path) = line.strip_prefix("+++ b/") {
+                file.path = path.to_string();
+            }
+            file.header.push(line.to_string());
+        }
+    }
+    files
+}
+
+/// Returns the `@@ -a,b +c,d @@` part of a hunk header, without the trailing
+/// function context.
+pub fn hunk_range(hunk_header: &str) -> Option<&str> {
+    let rest = hunk_header.strip_prefix("@@ ")?;
+    let end = rest.find(" @@")?;
+    Some(&hunk_header[..end + 6])
+}
+
+fn hunk_new_start(hunk_header: &str) -> Option<usize> {
+    let range = hunk_range(hunk_header)?;
+    let new_part = range.split_whitespace().nth(2)?.strip_prefix('+')?;
+    new_part.split(',').next()?.parse().ok()
+}
+
+fn hunk_line_counts(hunk_header: &str) -> Option<(usize, usize)> {
+    let range = hunk_range(hunk_header)?;
+    let mut parts = range
+        .trim_start_matches("@@ ")
+        .trim_end_matches(" @@")
+        .split_whitespace();
+    let count = |part: &str, prefix: char| -> Option<usize> {
+        let part = part.strip_prefix(prefix)?;
+        match part.split_once(',') {
+            Some((_, count)) => count.parse().ok(),
+            None => part.parse::<usize>().ok().map(|_| 1),
+        }
+    };
+    Some((count(parts.next()?, '-')?, count(parts.next()?, '+')?))
+}
+
+pub fn stage_hunk(worktree_path: &Path, file_path: &str, hunk_header: &str) -> Result<(), String> {
+    let patch = current_hunk_patch(
+        worktree_path,
+        ChangeSection::Unstaged,
+        file_path,
+        hunk_header,
+    )?;
+    git_output_with_input(worktree_path, &["apply", "--cached", "-"], patch.as_str()).map(|_| ())
+}
+
+pub fn unstage_hunk(
+    worktree_path: &Path,
+    file_path: &str,
+    hunk_header: &str,
+) -> Result<(), String> {
+    let patch = current_hunk_patch(worktree_path, ChangeSection::Staged, file_path, hunk_header)?;
+    git_output_with_input(
+        worktree_path,
+        &["apply", "--cached", "--reverse", "-"],
+        patch.as_str(),
+    )
+    .map(|_| ())
+}
+
+/// Reverts an unstaged hunk in the working tree. The hunk is first written to
+/// `.grove/discarded/` so it can be restored with `git apply <patch>`; the
+/// saved patch path is returned.
+pub fn discard_hunk(
+    worktree_path: &Path,
+    file_path: &str,
+    hunk_header: &str,
+) -> Result<PathBuf, String> {
+    let patch = current_hunk_patch(
+        worktree_path,
+        ChangeSection::Unstaged,
+        file_path,
+        hunk_header,
+    )?;
+    let saved_path = save_discarded_hunk(worktree_path, file_path, patch.as_str())?;
+    if let Err(error) =
+        git_output_with_input(worktree_path, &["apply", "--reverse", "-"], patch.as_str())
+    {
+        let _ = fs::remove_file(&saved_path);
+        return Err(error);
+    }
+    Ok(saved_path)
+}
+
+fn current_hunk_patch(
+    worktree_path: &Path,
+    section: ChangeSection,
+    file_path: &str,
+    hunk_header: &str,
+) -> Result<String, String> {
+    let mut args = vec!["diff", "--no-color", "--no-ext-diff"];
+    if section == ChangeSection::Staged {
+        args.push("--cached");
+    }
+    args.extend(["--", file_path]);
+    let output = git_output(worktree_path, &args)?;
+    let lines = output.lines().collect::<Vec<&str>>();
+    parse_diff_files(&lines)
+        .iter()
+        .find(|file| file.path == file_path)
+        .and_then(|file| {
+            file.find_hunk(hunk_header)
+                .map(|hunk| file.hunk_patch(hunk))
+        })
+        .ok_or_else(|| "hunk no longer matches the diff, refresh and retry".to_string())
+}
+
+fn save_discarded_hunk(
+    worktree_path: &Path,
+    file_path: &str,
+    patch: &str,
+) -> Result<PathBuf, String> {
+    let directory = worktree_path.join(DISCARDED_HUNKS_DIR);
+    fs::create_dir_all(&directory)
+        .map_err(|error| format!("save discarded hunk failed: {error}"))?;
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+        .as_millis();
+    let file_label = file_path
+        .chars()
+        .map(|character| {
+            if character.is_ascii_alphanumeric() || matches!(character, '.' | '-' | '_') {
+                character
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    let mut saved_path = directory.join(format!("{timestamp}-{file_label}.patch"));
+    let mut suffix = 1;
+    while saved_path.exists() {
+        saved_path = directory.join(format!("{timestamp}-{file_label}-{suffix}.patch"));
+        suffix += 1;
+    }
+    fs::write(&saved_path, patch)
+        .map_err(|error| format!("save discarded hunk failed: {error}"))?;
+    Ok(saved_path)
+}
+
+fn git_output_with_input(
+    worktree_path: &Path,
+    args: &[&str],
+    input: &str,
+) -> Result<String, String> {
+    let label = format!("git {}", args.first().copied().unwrap_or_default());
+    let mut child = Command::new("git")
+        .current_dir(worktree_path)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| format!("{label} failed: {error}"))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(input.as_bytes())
+            .map_err(|error| format!("{label} failed: {error}"))?;
+    }
+    let output = child
+        .wait_with_output()
+        .map_err(|error| format!("{label} failed: {error}"))?;
+    if !output.status.success() {
+        return Err(command_error(label.as_str(), &output));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+fn git_output(worktree_path: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .current_dir(worktree_path)
+        .args(args)
+        .output()
+        .map_err(|error| format!("git {} failed: {error}", args.join(" ")))?;
+    if !output.status.success() {
+        return Err(command_error(
+            format!("git {}", args.first().copied().unwrap_or_default()).as_str(),
+            &output,
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+fn command_error(label: &str, output: &std::process::Output) -> String {
+    let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+    if !stderr.is_empty() {
+        return format!("{label} failed: {stderr}");
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if !stdout.is_empty() {
+        return format!("{label} failed: {stdout}");
+    }
+    format!("{label} failed: exit status {}", output.status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ChangeSection, ChangedFile, DiffMode, FileDiffStat, commit_draft_prompt, commit_staged,
+        diff_file_stats, diff_mode_range, discard_hunk, file_tree_rows, hunk_range,
+        list_changed_files, parse_diff_files, parse_numstat, parse_porcelain_status, stage_file,
+        stage_hunk, unstage_file, unstage_hunk,
+    };
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    #[derive(Debug)]
+    struct TestDir {
+        path: PathBuf,
+    }
+
+    impl TestDir {
+        fn new(label: &str) -> Self {
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or(Duration::from_secs(0))
+                .as_nanos();
+            let path = std::env::temp_dir().join(format!(
+                "grove-worktree-changes-{label}-{}-{timestamp}",
+                std::process::id()
+            ));
+            fs::create_dir_all(&path).expect("test dir should exist");
+            Self { path }
+        }
+    }
+
+    impl Drop for TestDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+
+    fn git(repo: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .current_dir(repo)
+            .args(args)
+            .output()
+            .expect("git should run");
+        assert!(
+            output.status.success(),
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8_lossy(&output.stdout).to_string()
+    }
+
+    fn init_repo(repo: &Path) {
+        git(repo, &["init", "--quiet", "--initial-branch=main"]);
+        git(repo, &["config", "user.name", "Grove Tests"]);
+        git(repo, &["config", "user.email", "grove-tests@example.com"]);
+        fs::write(repo.join("README.md"), "hello\n").expect("write should succeed");
+        git(repo, &["add", "README.md"]);
+        git(repo, &["commit", "--quiet", "-m", "init"]);
+    }
+
+    fn changed(path: &str, section: ChangeSection, status: char) -> ChangedFile {
+        ChangedFile {
+            path: path.to_string(),
+            section,
+            status,
+        }
+    }
+
+    #[test]
+    fn parse_porcelain_status_splits_staged_and_unstaged_entries() {
+        let output = "MM src/lib.rs\0A  new.rs\0 D gone.rs\0?? notes.txt\0R  b.rs\0a.rs\0UU c.rs\0";
+        assert_eq!(
+            parse_porcelain_status(output),
+            vec![
+                changed("src/lib.rs", ChangeSection::Staged, 'M'),
+                changed("new.rs", ChangeSection::Staged, 'A'),
+                changed("b.rs", ChangeSection::Staged, 'R'),
+                changed("src/lib.rs", ChangeSection::Unstaged, 'M'),
+                changed("gone.rs", ChangeSection::Unstaged, 'D'),
+                changed("notes.txt", ChangeSection::Unstaged, '?'),
+                changed("c.rs", ChangeSection::Unstaged, 'U'),
+            ]
+        );
+        assert!(parse_porcelain_status("").is_empty());
+    }
+
+    #[test]
+    fn stage_unstage_and_commit_round_trip() {
+        let dir = TestDir::new("round-trip");
+        init_repo(dir.path.as_path());
+        fs::write(dir.path.join("README.md"), "hello\nworld\n").expect("write should succeed");
+        fs::write(dir.path.join("notes.txt"), "draft\n").expect("write should succeed");
+
+        assert_eq!(
+            list_changed_files(dir.path.as_path()),
+            Ok(vec![
+                changed("README.md", ChangeSection::Unstaged, 'M'),
+                changed("notes.txt", ChangeSection::Unstaged, '?'),
+            ])
+        );
+
+        stage_file(dir.path.as_path(), "README.md").expect("stage should succeed");
+        stage_file(dir.path.as_path(), "notes.txt").expect("stage should succeed");
+        unstage_file(dir.path.as_path(), "notes.txt").expect("unstage should succeed");
+        assert_eq!(
+            list_changed_files(dir.path.as_path()),
+            Ok(vec![
+                changed("README.md", ChangeSection::Staged, 'M'),
+                changed("notes.txt", ChangeSection::Unstaged, '?'),
+            ])
+        );
+
+        assert_eq!(
+            commit_staged(dir.path.as_path(), "   "),
+            Err("commit message is empty".to_string())
+        );
+        let commit = commit_staged(dir.path.as_path(), "Update readme\n\nAdd a second line.\n")
+            .expect("commit should succeed");
+        assert!(!commit.is_empty());
+        assert_eq!(
+            git(dir.path.as_path(), &["log", "-1", "--format=%B"]).trim(),
+            "Update readme\n\nAdd a second line."
+        );
+        assert_eq!(
+            list_changed_files(dir.path.as_path()),
+            Ok(vec![changed("notes.txt", ChangeSection::Unstaged, '?')])
+        );
+        assert_eq!(
+            commit_staged(dir.path.as_path(), "Nothing here"),
+            Err("nothing staged".to_string())
+        );
+    }
+
+    #[test]
+    fn commit_draft_prompt_includes_summary_and_truncates() {
+        let prompt = commit_draft_prompt(" src/lib.rs | 2 +-\n 1 file changed");
+        assert!(prompt.contains("Reply with the message only"));
+        assert!(prompt.contains(" src/lib.rs | 2 +-\n 1 file changed"));
+
+        let long_summary = (0..70)
+            .map(|index| format!(" file{index}.rs | 1 +"))
+            .collect::<Vec<String>>()
+            .join("\n");
+        let prompt = commit_draft_prompt(long_summary.as_str());
+        assert!(prompt.contains("file59.rs"));
+        assert!(!prompt.contains("file60.rs"));
+        assert!(prompt.contains("... 10 more lines"));
+    }
+
+    #[test]
+    fn parse_diff_files_splits_files_and_bounded_hunks() {
+        let lines = [
+            "3 files changed, +2 -1",
+            "diff --git a/src/lib.rs b/src/lib.rs",
+            "index 1111111..2222222 100644",
+            "--- a/src/lib.rs",
+            "+++ b/src/lib.rs",
+            "@@ -1,2 +1,2 @@ fn main() {",
+            "-old",
+            "+new",
+            "",
+            "@@ -10 +10,2 @@",
+            " keep",
+            "+added",
+            "\\ No newline at end of file",
+            "diff --git a/gone.rs b/gone.rs",
+            "deleted file mode 100644",
+            "--- a/gone.rs",
+            "+++ /dev/null",
+            "@@ -1 +0,0 @@",
+            "-bye",
+            "",
+            "── unstaged ──",
+        ];
+        let files = parse_diff_files(&lines);
+
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].path, "src/lib.rs");
+        assert_eq!(files[0].start_line, 1);
+        assert_eq!(files[0].header.len(), 4);
+        assert_eq!(files[0].hunks.len(), 2);
+        assert_eq!(files[0].hunks[0].start_line, 5);
+        assert_eq!(files[0].hunks[0].end_line(), 9);
+        assert_eq!(files[0].hunks[1].header(), "@@ -10 +10,2 @@");
+        assert_eq!(files[0].hunks[1].lines.len(), 4);
+        assert_eq!(
+            files[0].hunks[0].new_line_numbers(),
+            vec![None, None, Some(1), Some(2)]
+        );
+        assert_eq!(files[0].hunks[1].first_changed_line(), Some(11));
+        assert_eq!(files[1].path, "gone.rs");
+        assert_eq!(files[1].hunks[0].end_line(), 19);
+        assert_eq!(
+            files[0].hunk_patch(&files[0].hunks[0]),
+            "diff --git a/src/lib.rs b/src/lib.rs\nindex 1111111..2222222 100644\n--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1,2 +1,2 @@ fn main() {\n-old\n+new\n\n"
+        );
+        assert_eq!(
+            hunk_range("@@ -1,2 +1,2 @@ fn main() {"),
+            Some("@@ -1,2 +1,2 @@")
+        );
+    }
+
+    #[test]
+    fn stage_unstage_and_discard_individual_hunks() {
+        let dir = TestDir::new("hunks");
+        let repo = dir.path.as_path();
+        init_repo(repo);
+        let original = (1..=30)
+            .map(|index| format!("line {index}\n"))
+            .collect::<String>();
+        fs::write(repo.join("README.md"), &original).expect("write should succeed");
+        git(repo, &["commit", "--quiet", "-am", "lines"]);
+        let edited = original
+            .replace("line 2\n", "line two\n")
+            .replace("line 25\n", "line twenty-five\n");
+        fs::write(repo.join("README.md"), &edited).expect("write should succeed");
+
+        let unstaged = git(repo, &["diff", "--no-color"]);
+        let unstaged_lines = unstaged.lines().collect::<Vec<&str>>();
+        let files = parse_diff_files(&unstaged_lines);
+        assert_eq!(files[0].hunks.len(), 2);
+        let first = files[0].hunks[0].header().to_string();
+        let second = files[0].hunks[1].header().to_string();
+
+        stage_hunk(repo, "README.md", first.as_str()).expect("stage hunk should succeed");
+        let staged = git(repo, &["diff", "--cached", "--no-color"]);
+        assert!(staged.contains("+line two"));
+        assert!(!staged.contains("+line twenty-five"));
+
+        unstage_hunk(repo, "README.md", first.as_str()).expect("unstage hunk should succeed");
+        assert!(git(repo, &["diff", "--cached"]).is_empty());
+        assert_eq!(
+            stage_hunk(repo, "README.md", "@@ -90,3 +90,3 @@"),
+            Err("hunk no longer matches the diff, refresh and retry".to_string())
+        );
+
+        let saved =
+            discard_hunk(repo, "README.md", second.as_str()).expect("discard hunk should succeed");
+        assert!(saved.starts_with(repo.join(".grove/discarded")));
+        let content = fs::read_to_string(repo.join("README.md")).expect("read should succeed");
+        assert!(content.contains("line two\n"));
+        assert!(content.contains("line 25\n"));
+
+        let saved_arg = saved.to_string_lossy().to_string();
+        git(repo, &["apply", saved_arg.as_str()]);
+        let restored = fs::read_to_string(repo.join("README.md")).expect("read should succeed");
+        assert_eq!(restored, edited);
+    }
+
+    #[test]
+    fn parse_numstat_reads_counts_binaries_and_renames() {
+        let output = [
+            "3\t1\tsrc/lib.rs",
+            "-\t-\tlogo.png",
+            "0\t0\t",
+            "old.rs",
+            "new.rs",
+            "",
+        ]
+        .join("\0");
+        let stat = |path: &str, insertions, deletions, binary| FileDiffStat {
+            path: path.to_string(),
+            insertions,
+            deletions,
+            binary,
+        };
+        assert_eq!(
+            parse_numstat(output.as_str()),
+            vec![
+                stat("src/lib.rs", 3, 1, false),
+                stat("logo.png", 0, 0, true),
+                stat("new.rs", 0, 0, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn file_tree_rows_nest_directories_and_sum_counts() {
+        let stat = |path: &str, insertions| FileDiffStat {
+            path: path.to_string(),
+            insertions,
+            deletions: 1,
+            binary: false,
+        };
+        let rows = file_tree_rows(&[
+            stat("src/ui/view.rs", 4),
+            stat("README.md", 1),
+            stat("src/lib.rs", 2),
+            stat("src/ui/model.rs", 3),
+        ]);
+        let summary = rows
+            .iter()
+            .map(|row| (row.depth, row.name.as_str(), row.insertions, row.deletions))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                (0, "README.md", 1, 1),
+                (0, "src/", 9, 3),
+                (1, "lib.rs", 2, 1),
+                (1, "ui/", 7, 2),
+                (2, "model.rs", 3, 1),
+                (2, "view.rs", 4, 1),
+            ]
+        );
+        assert_eq!(rows[4].path.as_deref(), Some("src/ui/model.rs"));
+        assert_eq!(rows[3].path, None);
+    }
+
+    #[test]
+    fn diff_modes_resolve_branch_and_commit_ranges() {
+        let dir = TestDir::new("diff-modes");
+        let repo = dir.path.as_path();
+        init_repo(repo);
+        git(repo, &["checkout", "--quiet", "-b", "feature"]);
+        fs::write(repo.join("feature.rs"), "one\ntwo\n").expect("write should succeed");
+        git(repo, &["add", "feature.rs"]);
+        git(repo, &["commit", "--quiet", "-m", "feature"]);
+
+        assert_eq!(
+            diff_mode_range(repo, DiffMode::WorkingTree, Some("main")),
+            Ok(None)
+        );
+        let range = diff_mode_range(repo, DiffMode::Branch, Some("main"))
+            .expect("branch range should resolve")
+            .expect("branch mode should have a range");
+        assert_eq!(range, "main...HEAD");
+        let stats = diff_file_stats(repo, Some(range.as_str())).expect("numstat should run");
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].path, "feature.rs");
+        assert_eq!(stats[0].insertions, 2);
+        assert_eq!(
+            diff_mode_range(repo, DiffMode::Branch, Some("missing")),
+            Err("base branch 'missing' not found".to_string())
+        );
+        assert_eq!(
+            diff_mode_range(repo, DiffMode::LastCommits(2), None),
+            Ok(Some("HEAD~2..HEAD".to_string()))
+        );
+
+        assert_eq!(DiffMode::WorkingTree.cycle(), DiffMode::Branch);
+        assert_eq!(
+            DiffMode::LastCommits(1).with_commit_delta(-1),
+            DiffMode::LastCommits(1)
+        );
+        assert_eq!(DiffMode::LastCommits(3).label(), "last 3 commits");
+    }
+}
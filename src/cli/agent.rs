@@ -0,0 +1,736 @@
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::process::Command;
+
+use crate::application::agent_runtime::agent_env::agent_env_pairs;
+use crate::application::agent_runtime::launch_prompt::expand_launch_prompt;
+use crate::application::agent_runtime::status::detect_waiting_prompt;
+use crate::application::agent_runtime::{
+    CommandExecutionMode, SessionActivity, detect_status_with_session_override,
+    execute_commands_for_mode, execute_launch_request_with_result_for_mode,
+    infer_workspace_permission_mode, launch_request_for_workspace, paste_and_submit_to_session,
+    session_name_for_workspace_ref, stop_plan, tmux_launch_error_indicates_duplicate_session,
+};
+use crate::application::session_cleanup::list_tmux_sessions;
+use crate::application::workspace_lifecycle::{
+    read_workspace_init_command, read_workspace_permission_mode, write_workspace_permission_mode,
+};
+use crate::domain::{AgentType, PermissionMode, Task, Workspace, WorkspaceStatus, Worktree};
+use crate::infrastructure::config::{GroveConfig, ProjectConfig};
+use crate::infrastructure::paths::refer_to_same_location;
+use crate::infrastructure::process::stderr_or_status;
+use crate::infrastructure::shared_config::effective_project_config;
+
+use super::task::{
+    find_task, invalid_input, load_tasks, not_found, print_json, task_worktrees, workspace_name,
+};
+
+const STATUS_CAPTURE_LINES: usize = 200;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum AgentCommand {
+    Start {
+        task: String,
+        repository: Option<String>,
+        agent: Option<AgentType>,
+        prompt: Option<String>,
+        permission_mode: Option<PermissionMode>,
+    },
+    Send {
+        task: String,
+        repository: Option<String>,
+        text: String,
+    },
+    Stop {
+        task: String,
+        repository: Option<String>,
+    },
+    Status {
+        task: Option<String>,
+        repository: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct AgentArgs {
+    pub(crate) command: AgentCommand,
+    pub(crate) json_output: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct AgentSessionReport {
+    task: String,
+    repository: String,
+    path: PathBuf,
+    session: String,
+    agent: &'static str,
+    action: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct AgentStatusEntry {
+    task: String,
+    repository: String,
+    path: PathBuf,
+    session: String,
+    agent: &'static str,
+    running: bool,
+    status: &'static str,
+    waiting: Option<String>,
+}
+
+pub(crate) fn parse_agent_args(
+    args: &mut impl Iterator<Item = String>,
+) -> std::io::Result<AgentArgs> {
+    let Some(action) = args.next() else {
+        return Err(invalid_input(
+            "agent requires an action (`start`, `send`, `stop`, `status`)",
+        ));
+    };
+    if !matches!(action.as_str(), "start" | "send" | "stop" | "status") {
+        return Err(invalid_input(format!(
+            "unsupported agent action `{action}`"
+        )));
+    }
+
+    let mut json_output = false;
+    let mut positionals = Vec::new();
+    let mut repository = None;
+    let mut agent = None;
+    let mut prompt = None;
+    let mut permission_mode = None;
+    while let Some(argument) = args.next() {
+        match argument.as_str() {
+            "--json" => {
+                json_output = true;
+            }
+            "--repo" => {
+                let Some(value) = args.next() else {
+                    return Err(invalid_input("--repo requires a repository name"));
+                };
+                repository = Some(value);
+            }
+            "--agent" => {
+                let Some(value) = args.next() else {
+                    return Err(invalid_input("--agent requires `claude` or `codex`"));
+                };
+                let Some(parsed) = AgentType::from_marker(value.as_str()) else {
+                    return Err(invalid_input(format!("unsupported agent `{value}`")));
+                };
+                agent = Some(parsed);
+            }
+            "--prompt" => {
+                let Some(value) = args.next() else {
+                    return Err(invalid_input("--prompt requires text"));
+                };
+                prompt = Some(value);
+            }
+            "--permission" => {
+                let Some(value) = args.next() else {
+                    return Err(invalid_input(
+                        "--permission requires `default`, `auto` or `unsafe`",
+                    ));
+                };
+                let Some(parsed) = PermissionMode::from_marker(value.as_str()) else {
+                    return Err(invalid_input(format!(
+                        "unsupported permission mode `{value}`"
+                    )));
+                };
+                permission_mode = Some(parsed);
+            }
+            flag if flag.starts_with("--") => {
+                return Err(invalid_input(format!("unknown agent flag `{flag}`")));
+            }
+            _ => positionals.push(argument),
+        }
+    }
+
+    let start_only = agent.is_some() || prompt.is_some() || permission_mode.is_some();
+    if start_only && action != "start" {
+        return Err(invalid_input(format!(
+            "--agent, --prompt and --permission are not supported by agent {action}"
+        )));
+    }
+    let mut positionals = positionals.into_iter();
+    let task = positionals.next();
+    if action == "status" {
+        if let Some(extra) = positionals.next() {
+            return Err(invalid_input(format!(
+                "unexpected argument `{extra}` for agent status"
+            )));
+        }
+        if task.is_none() && repository.is_some() {
+            return Err(invalid_input("--repo requires a task for agent status"));
+        }
+        return Ok(AgentArgs {
+            command: AgentCommand::Status { task, repository },
+            json_output,
+        });
+    }
+    let Some(task) = task else {
+        return Err(invalid_input(format!(
+            "agent {action} requires a task name or slug"
+        )));
+    };
+
+    let rest = positionals.collect::<Vec<String>>();
+    if action != "send" && !rest.is_empty() {
+        return Err(invalid_input(format!(
+            "unexpected arguments for agent {action}"
+        )));
+    }
+
+    let command = match action.as_str() {
+        "send" => {
+            let text = rest.join(" ");
+            if text.trim().is_empty() {
+                return Err(invalid_input("agent send requires text"));
+            }
+            AgentCommand::Send {
+                task,
+                repository,
+                text,
+            }
+        }
+        "start" => AgentCommand::Start {
+            task,
+            repository,
+            agent,
+            prompt,
+            permission_mode,
+        },
+        _ => AgentCommand::Stop { task, repository },
+    };
+
+    Ok(AgentArgs {
+        command,
+        json_output,
+    })
+}
+
+fn single_worktree<'a>(task: &'a Task, repository: Option<&str>) -> std::io::Result<&'a Worktree> {
+    let worktrees = task_worktrees(task, repository)?;
+    if worktrees.len() > 1 {
+        return Err(invalid_input(format!(
+            "task `{}` has {} worktrees, pass --repo",
+            task.slug,
+            worktrees.len()
+        )));
+    }
+    Ok(worktrees[0])
+}
+
+pub(super) fn task_workspace(task: &Task, worktree: &Worktree) -> Workspace {
+    let is_main = worktree.is_main_checkout();
+    Workspace {
+        name: workspace_name(task, worktree),
+        task_slug: Some(task.slug.clone()),
+        path: worktree.path.clone(),
+        project_name: Some(worktree.repository_name.clone()),
+        project_path: Some(worktree.repository_path.clone()),
+        branch: worktree.branch.clone(),
+        base_branch: worktree.base_branch.clone(),
+        last_activity_unix_secs: worktree.last_activity_unix_secs,
+        agent: worktree.agent,
+        status: if is_main {
+            WorkspaceStatus::Main
+        } else {
+            worktree.status
+        },
+        is_main,
+        is_orphaned: worktree.is_orphaned,
+        supported_agent: worktree.supported_agent,
+        pull_requests: worktree.pull_requests.clone(),
+    }
+}
+
+fn workspace_project<'a>(
+    config: &'a GroveConfig,
+    worktree: &Worktree,
+) -> Option<&'a ProjectConfig> {
+    config
+        .projects
+        .iter()
+        .find(|project| refer_to_same_location(&project.path, &worktree.repository_path))
+}
+
+pub(super) fn live_session_names() -> std::io::Result<HashSet<String>> {
+    Ok(list_tmux_sessions()
+        .map_err(std::io::Error::other)?
+        .into_iter()
+        .map(|session| session.name)
+        .collect())
+}
+
+pub(super) fn capture_session_output(session_name: &str) -> std::io::Result<String> {
+    let output = Command::new("tmux")
+        .args([
+            "capture-pane",
+            "-p",
+            "-N",
+            "-t",
+            session_name,
+            "-S",
+            format!("-{STATUS_CAPTURE_LINES}").as_str(),
+        ])
+        .output()?;
+    if !output.status.success() {
+        return Err(std::io::Error::other(format!(
+            "tmux capture-pane failed for '{session_name}': {}",
+            stderr_or_status(&output)
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn print_session_report(args: &AgentArgs, report: &AgentSessionReport) -> std::io::Result<()> {
+    if args.json_output {
+        return print_json(report);
+    }
+    println!(
+        "{} {} agent in {}/{} (session {})",
+        report.action, report.agent, report.task, report.repository, report.session
+    );
+    Ok(())
+}
+
+fn run_agent_start(
+    args: &AgentArgs,
+    name: &str,
+    repository: Option<&str>,
+    agent: Option<AgentType>,
+    prompt: Option<String>,
+    permission_mode: Option<PermissionMode>,
+) -> std::io::Result<()> {
+    let loaded_config = crate::infrastructure::config::load().map_err(std::io::Error::other)?;
+    let tasks = load_tasks()?;
+    let task = find_task(tasks.as_slice(), name)?;
+    let worktree = single_worktree(task, repository)?;
+    let (workspace, session_name) = start_agent_session(
+        &loaded_config.config,
+        task,
+        worktree,
+        agent,
+        prompt,
+        permission_mode,
+    )?;
+
+    print_session_report(
+        args,
+        &AgentSessionReport {
+            task: task.slug.clone(),
+            repository: worktree.repository_name.clone(),
+            path: workspace.path.clone(),
+            session: session_name,
+            agent: workspace.agent.marker(),
+            action: "started",
+        },
+    )
+}
+
+/// Launches the agent for one task worktree the same way the TUI does,
+/// returning the workspace it launched in and the tmux session name.
+pub(super) fn start_agent_session(
+    config: &GroveConfig,
+    task: &Task,
+    worktree: &Worktree,
+    agent: Option<AgentType>,
+    prompt: Option<String>,
+    permission_mode: Option<PermissionMode>,
+) -> std::io::Result<(Workspace, String)> {
+    let mut workspace = task_workspace(task, worktree);
+    if let Some(agent) = agent {
+        workspace.agent = agent;
+    }
+    if !workspace.supported_agent {
+        return Err(invalid_input(format!(
+            "workspace `{}` does not support agents",
+            workspace.name
+        )));
+    }
+
+    let project = workspace_project(config, worktree).map(effective_project_config);
+    let prompt =
+        prompt.map(|prompt| expand_launch_prompt(prompt, project.as_ref(), task.notes.as_str()));
+    let agent_env = project
+        .as_ref()
+        .map(|project| project.agent_env(workspace.agent))
+        .map(agent_env_pairs)
+        .transpose()
+        .map_err(|error| invalid_input(format!("invalid project agent env: {error}")))?
+        .unwrap_or_default();
+    let workspace_init_command = read_workspace_init_command(&workspace.path).or_else(|| {
+        project
+            .as_ref()
+            .and_then(|project| project.workspace_init_command())
+    });
+    let permission_mode = match permission_mode {
+        Some(permission_mode) => {
+            write_workspace_permission_mode(&workspace.path, permission_mode)
+                .map_err(std::io::Error::other)?;
+            permission_mode
+        }
+        None => read_workspace_permission_mode(&workspace.path)
+            .or_else(|| infer_workspace_permission_mode(workspace.agent, &workspace.path))
+            .unwrap_or_else(|| {
+                project
+                    .as_ref()
+                    .map_or(config.launch_permission_mode, |project| {
+                        project.launch_permission_mode_for(config.launch_permission_mode)
+                    })
+            }),
+    };
+
+    let request = launch_request_for_workspace(
+        &workspace,
+        prompt,
+        config.custom_themes.select(&config.theme),
+        workspace_init_command,
+        permission_mode,
+        agent_env,
+        None,
+    );
+    let completion =
+        execute_launch_request_with_result_for_mode(&request, CommandExecutionMode::Process);
+    if let Err(error) = completion.result {
+        if tmux_launch_error_indicates_duplicate_session(&error) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::AlreadyExists,
+                format!(
+                    "agent already running in session {}",
+                    completion.session_name
+                ),
+            ));
+        }
+        return Err(std::io::Error::other(format!(
+            "agent start failed: {error}"
+        )));
+    }
+
+    Ok((workspace, completion.session_name))
+}
+
+fn running_session(task: &Task, worktree: &Worktree) -> std::io::Result<String> {
+    let session_name = session_name_for_workspace_ref(&task_workspace(task, worktree));
+    if !live_session_names()?.contains(&session_name) {
+        return Err(not_found(format!(
+            "no running agent for {}/{}",
+            task.slug, worktree.repository_name
+        )));
+    }
+    Ok(session_name)
+}
+
+fn run_agent_send(
+    args: &AgentArgs,
+    name: &str,
+    repository: Option<&str>,
+    text: &str,
+) -> std::io::Result<()> {
+    let tasks = load_tasks()?;
+    let task = find_task(tasks.as_slice(), name)?;
+    let worktree = single_worktree(task, repository)?;
+    let session_name = running_session(task, worktree)?;
+    paste_and_submit_to_session(session_name.as_str(), text)?;
+
+    print_session_report(
+        args,
+        &AgentSessionReport {
+            task: task.slug.clone(),
+            repository: worktree.repository_name.clone(),
+            path: worktree.path.clone(),
+            session: session_name,
+            agent: worktree.agent.marker(),
+            action: "sent to",
+        },
+    )
+}
+
+fn run_agent_stop(args: &AgentArgs, name: &str, repository: Option<&str>) -> std::io::Result<()> {
+    let tasks = load_tasks()?;
+    let task = find_task(tasks.as_slice(), name)?;
+    let worktree = single_worktree(task, repository)?;
+    let session_name = running_session(task, worktree)?;
+    execute_commands_for_mode(&stop_plan(&session_name), CommandExecutionMode::Process)
+        .map_err(|error| std::io::Error::other(format!("agent stop failed: {error}")))?;
+
+    print_session_report(
+        args,
+        &AgentSessionReport {
+            task: task.slug.clone(),
+            repository: worktree.repository_name.clone(),
+            path: worktree.path.clone(),
+            session: session_name,
+            agent: worktree.agent.marker(),
+            action: "stopped",
+        },
+    )
+}
+
+fn agent_status_entry(
+    task: &Task,
+    worktree: &Worktree,
+    live_sessions: &HashSet<String>,
+) -> AgentStatusEntry {
+    let workspace = task_workspace(task, worktree);
+    let session_name = session_name_for_workspace_ref(&workspace);
+    let running = live_sessions.contains(&session_name);
+    let output = if running {
+        capture_session_output(session_name.as_str()).unwrap_or_default()
+    } else {
+        String::new()
+    };
+    let status = detect_status_with_session_override(
+        output.as_str(),
+        SessionActivity::Idle,
+        workspace.is_main,
+        running,
+        workspace.supported_agent,
+        workspace.agent,
+        workspace.path.as_path(),
+        session_name.as_str(),
+    );
+
+    AgentStatusEntry {
+        task: task.slug.clone(),
+        repository: worktree.repository_name.clone(),
+        path: worktree.path.clone(),
+        session: session_name,
+        agent: workspace.agent.marker(),
+        running,
+        status: status.label(),
+        waiting: if status == WorkspaceStatus::Waiting {
+            detect_waiting_prompt(output.as_str())
+        } else {
+            None
+        },
+    }
+}
+
+fn run_agent_status(
+    args: &AgentArgs,
+    name: Option<&str>,
+    repository: Option<&str>,
+) -> std::io::Result<()> {
+    let tasks = load_tasks()?;
+    let selected = match name {
+        Some(name) => {
+            let task = find_task(tasks.as_slice(), name)?;
+            task_worktrees(task, repository)?
+                .into_iter()
+                .map(|worktree| (task, worktree))
+                .collect::<Vec<(&Task, &Worktree)>>()
+        }
+        None => tasks
+            .iter()
+            .flat_map(|task| task.worktrees.iter().map(move |worktree| (task, worktree)))
+            .collect(),
+    };
+    let live_sessions = live_session_names()?;
+    let entries = selected
+        .into_iter()
+        .map(|(task, worktree)| agent_status_entry(task, worktree, &live_sessions))
+        .collect::<Vec<AgentStatusEntry>>();
+
+    if args.json_output {
+        return print_json(&entries);
+    }
+    if entries.is_empty() {
+        println!("agent status: no tasks");
+        return Ok(());
+    }
+    for entry in &entries {
+        println!(
+            "{}/{} {} agent={} session={}",
+            entry.task,
+            entry.repository,
+            entry.status,
+            entry.agent,
+            if entry.running {
+                entry.session.as_str()
+            } else {
+                "-"
+            }
+        );
+        if let Some(waiting) = entry.waiting.as_ref() {
+            println!("  waiting: {waiting}");
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn run_agent(args: &AgentArgs) -> std::io::Result<()> {
+    match &args.command {
+        AgentCommand::Start {
+            task,
+            repository,
+            agent,
+            prompt,
+            permission_mode,
+        } => run_agent_start(
+            args,
+            task,
+            repository.as_deref(),
+            *agent,
+            prompt.clone(),
+            *permission_mode,
+        ),
+        AgentCommand::Send {
+            task,
+            repository,
+            text,
+        } => run_agent_send(args, task, repository.as_deref(), text),
+        AgentCommand::Stop { task, repository } => {
+            run_agent_stop(args, task, repository.as_deref())
+        }
+        AgentCommand::Status { task, repository } => {
+            run_agent_status(args, task.as_deref(), repository.as_deref())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AgentArgs, AgentCommand, agent_status_entry, parse_agent_args, single_worktree};
+    use crate::domain::{AgentType, PermissionMode, Task, WorkspaceStatus, Worktree};
+    use std::collections::HashSet;
+    use std::path::PathBuf;
+
+    fn parse(args: &[&str]) -> std::io::Result<AgentArgs> {
+        let mut args = args.iter().map(|argument| (*argument).to_string());
+        parse_agent_args(&mut args)
+    }
+
+    fn fixture_task(repositories: &[&str]) -> Task {
+        Task::try_new(
+            "feature-a".to_string(),
+            "feature-a".to_string(),
+            PathBuf::from("/tasks/feature-a"),
+            "feature-a".to_string(),
+            repositories
+                .iter()
+                .map(|repository| {
+                    Worktree::try_new(
+                        (*repository).to_string(),
+                        PathBuf::from(format!("/repos/{repository}")),
+                        PathBuf::from(format!("/tasks/feature-a/{repository}")),
+                        "feature-a".to_string(),
+                        AgentType::Codex,
+                        WorkspaceStatus::Idle,
+                    )
+                    .expect("worktree should be valid")
+                })
+                .collect(),
+        )
+        .expect("task should be valid")
+    }
+
+    #[test]
+    fn agent_parser_reads_each_action() {
+        assert_eq!(
+            parse(&[
+                "start",
+                "feature-a",
+                "--repo",
+                "api",
+                "--agent",
+                "claude",
+                "--prompt",
+                "fix the tests",
+                "--permission",
+                "auto",
+            ])
+            .expect("start should parse")
+            .command,
+            AgentCommand::Start {
+                task: "feature-a".to_string(),
+                repository: Some("api".to_string()),
+                agent: Some(AgentType::Claude),
+                prompt: Some("fix the tests".to_string()),
+                permission_mode: Some(PermissionMode::Auto),
+            }
+        );
+        assert_eq!(
+            parse(&["send", "feature-a", "run", "cargo", "test"])
+                .expect("send should parse")
+                .command,
+            AgentCommand::Send {
+                task: "feature-a".to_string(),
+                repository: None,
+                text: "run cargo test".to_string(),
+            }
+        );
+        assert_eq!(
+            parse(&["stop", "feature-a"])
+                .expect("stop should parse")
+                .command,
+            AgentCommand::Stop {
+                task: "feature-a".to_string(),
+                repository: None,
+            }
+        );
+        assert_eq!(
+            parse(&["status", "--json"]).expect("status should parse"),
+            AgentArgs {
+                command: AgentCommand::Status {
+                    task: None,
+                    repository: None,
+                },
+                json_output: true,
+            }
+        );
+    }
+
+    #[test]
+    fn agent_parser_rejects_invalid_arguments() {
+        for args in [
+            &[][..],
+            &["restart", "feature-a"][..],
+            &["start"][..],
+            &["start", "feature-a", "--permission", "yolo"][..],
+            &["send", "feature-a"][..],
+            &["stop", "feature-a", "--prompt", "hi"][..],
+            &["stop", "feature-a", "extra"][..],
+            &["status", "--repo", "api"][..],
+        ] {
+            let error = parse(args).expect_err("arguments should be rejected");
+            assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput, "{args:?}");
+        }
+    }
+
+    #[test]
+    fn single_worktree_requires_repo_for_multi_repository_tasks() {
+        let single = fixture_task(&["api"]);
+        assert_eq!(
+            single_worktree(&single, None)
+                .expect("single worktree should resolve")
+                .repository_name,
+            "api"
+        );
+
+        let multi = fixture_task(&["api", "web"]);
+        assert_eq!(
+            single_worktree(&multi, None)
+                .expect_err("ambiguous worktree should fail")
+                .kind(),
+            std::io::ErrorKind::InvalidInput
+        );
+        assert_eq!(
+            single_worktree(&multi, Some("web"))
+                .expect("repo should select worktree")
+                .path,
+            PathBuf::from("/tasks/feature-a/web")
+        );
+    }
+
+    #[test]
+    fn status_entry_reports_idle_without_live_session() {
+        let task = fixture_task(&["api", "web"]);
+        let entry = agent_status_entry(&task, &task.worktrees[1], &HashSet::new());
+        assert!(!entry.running);
+        assert_eq!(entry.status, "idle");
+        assert_eq!(entry.session, "grove-wt-feature-a-web");
+        assert_eq!(entry.waiting, None);
+    }
+}
@@ -0,0 +1,255 @@
+use serde::Serialize;
+use std::path::PathBuf;
+
+use crate::application::agent_runtime::session_name_for_workspace_ref;
+use crate::application::task_spec::{
+    TaskSpec, TaskSpecOutcome, apply_task_spec_in_root, load_task_specs,
+};
+use crate::application::workspace_lifecycle::{
+    CommandGitRunner, CommandSetupCommandRunner, CommandSetupScriptRunner,
+};
+use crate::domain::Task;
+use crate::infrastructure::config::GroveConfig;
+use crate::infrastructure::paths::tasks_root;
+
+use super::agent::{live_session_names, start_agent_session, task_workspace};
+use super::task::{invalid_input, load_tasks, print_json, print_warnings};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ApplyArgs {
+    pub(crate) spec_path: PathBuf,
+    pub(crate) start_agents: bool,
+    pub(crate) json_output: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct ApplyTaskReport {
+    task: String,
+    action: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    agents: Vec<ApplyAgentReport>,
+    warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct ApplyAgentReport {
+    repository: String,
+    session: String,
+    agent: &'static str,
+    action: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+pub(crate) fn parse_apply_args(
+    args: &mut impl Iterator<Item = String>,
+) -> std::io::Result<ApplyArgs> {
+    let mut spec_path = None;
+    let mut start_agents = true;
+    let mut json_output = false;
+    for argument in args {
+        match argument.as_str() {
+            "--no-start" => {
+                start_agents = false;
+            }
+            "--json" => {
+                json_output = true;
+            }
+            flag if flag.starts_with('-') => {
+                return Err(invalid_input(format!("unknown apply flag `{flag}`")));
+            }
+            _ if spec_path.is_none() => {
+                spec_path = Some(PathBuf::from(argument));
+            }
+            _ => {
+                return Err(invalid_input(format!(
+                    "unexpected argument `{argument}` for apply"
+                )));
+            }
+        }
+    }
+    let Some(spec_path) = spec_path else {
+        return Err(invalid_input("apply requires a task spec file"));
+    };
+    Ok(ApplyArgs {
+        spec_path,
+        start_agents,
+        json_output,
+    })
+}
+
+pub(crate) fn run_apply(args: &ApplyArgs) -> std::io::Result<()> {
+    let specs = load_task_specs(args.spec_path.as_path()).map_err(invalid_input)?;
+    let loaded_config = crate::infrastructure::config::load().map_err(std::io::Error::other)?;
+    let tasks_root = tasks_root().ok_or_else(|| std::io::Error::other("task root unavailable"))?;
+    let existing_tasks = load_tasks()?;
+    let live_sessions = if args.start_agents {
+        live_session_names().unwrap_or_default()
+    } else {
+        Default::default()
+    };
+
+    let mut reports = Vec::with_capacity(specs.len());
+    for spec in &specs {
+        let outcome = apply_task_spec_in_root(
+            tasks_root.as_path(),
+            spec,
+            loaded_config.config.projects.as_slice(),
+            existing_tasks.as_slice(),
+            &CommandGitRunner,
+            &CommandSetupScriptRunner,
+            &CommandSetupCommandRunner,
+        );
+        let mut report = match &outcome {
+            Ok(TaskSpecOutcome::Created { warnings, .. }) => ApplyTaskReport {
+                task: spec.name.clone(),
+                action: "created",
+                error: None,
+                agents: Vec::new(),
+                warnings: warnings.clone(),
+            },
+            Ok(TaskSpecOutcome::Existing(_)) => ApplyTaskReport {
+                task: spec.name.clone(),
+                action: "existing",
+                error: None,
+                agents: Vec::new(),
+                warnings: Vec::new(),
+            },
+            Err(error) => ApplyTaskReport {
+                task: spec.name.clone(),
+                action: "failed",
+                error: Some(error.clone()),
+                agents: Vec::new(),
+                warnings: Vec::new(),
+            },
+        };
+        if let Ok(outcome) = &outcome
+            && args.start_agents
+        {
+            let prompt = matches!(outcome, TaskSpecOutcome::Created { .. })
+                .then(|| spec.prompt.clone())
+                .flatten();
+            report.agents = start_task_agents(
+                &loaded_config.config,
+                outcome.task(),
+                spec,
+                prompt,
+                &live_sessions,
+            );
+        }
+        reports.push(report);
+    }
+
+    let failures = reports
+        .iter()
+        .filter(|report| {
+            report.error.is_some() || report.agents.iter().any(|agent| agent.error.is_some())
+        })
+        .count();
+    if args.json_output {
+        print_json(&reports)?;
+    } else {
+        print_apply_reports(reports.as_slice());
+    }
+    if failures == 0 {
+        return Ok(());
+    }
+
+    Err(std::io::Error::other(format!(
+        "apply failed for {failures} task(s)"
+    )))
+}
+
+fn start_task_agents(
+    config: &GroveConfig,
+    task: &Task,
+    spec: &TaskSpec,
+    prompt: Option<String>,
+    live_sessions: &std::collections::HashSet<String>,
+) -> Vec<ApplyAgentReport> {
+    task.worktrees
+        .iter()
+        .map(|worktree| {
+            let session = session_name_for_workspace_ref(&task_workspace(task, worktree));
+            if live_sessions.contains(&session) {
+                return ApplyAgentReport {
+                    repository: worktree.repository_name.clone(),
+                    session,
+                    agent: worktree.agent.marker(),
+                    action: "running",
+                    error: None,
+                };
+            }
+            match start_agent_session(
+                config,
+                task,
+                worktree,
+                Some(spec.agent),
+                prompt.clone(),
+                spec.permission_mode,
+            ) {
+                Ok((workspace, session)) => ApplyAgentReport {
+                    repository: worktree.repository_name.clone(),
+                    session,
+                    agent: workspace.agent.marker(),
+                    action: "started",
+                    error: None,
+                },
+                Err(error) => ApplyAgentReport {
+                    repository: worktree.repository_name.clone(),
+                    session,
+                    agent: spec.agent.marker(),
+                    action: "failed",
+                    error: Some(error.to_string()),
+                },
+            }
+        })
+        .collect()
+}
+
+fn print_apply_reports(reports: &[ApplyTaskReport]) {
+    for report in reports {
+        match report.error.as_deref() {
+            Some(error) => println!("{} {}: {error}", report.action, report.task),
+            None => println!("{} {}", report.action, report.task),
+        }
+        for agent in &report.agents {
+            match agent.error.as_deref() {
+                Some(error) => println!("  - {} {} agent: {error}", agent.repository, agent.action),
+                None => println!(
+                    "  - {} {} {} agent (session {})",
+                    agent.repository, agent.action, agent.agent, agent.session
+                ),
+            }
+        }
+        print_warnings(report.warnings.as_slice());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ApplyArgs, parse_apply_args};
+    use std::path::PathBuf;
+
+    #[test]
+    fn parse_apply_args_reads_spec_path_and_flags() {
+        let mut args = vec![
+            "sprint.toml".to_string(),
+            "--no-start".to_string(),
+            "--json".to_string(),
+        ]
+        .into_iter();
+        assert_eq!(
+            parse_apply_args(&mut args).expect("apply should parse"),
+            ApplyArgs {
+                spec_path: PathBuf::from("sprint.toml"),
+                start_agents: false,
+                json_output: true,
+            }
+        );
+
+        assert!(parse_apply_args(&mut Vec::<String>::new().into_iter()).is_err());
+        assert!(parse_apply_args(&mut vec!["--force".to_string()].into_iter()).is_err());
+    }
+}
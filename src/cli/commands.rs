@@ -0,0 +1,413 @@
+pub(super) struct FlagSpec {
+    pub(super) name: &'static str,
+    pub(super) value: Option<&'static str>,
+    pub(super) help: &'static str,
+}
+
+pub(super) struct CommandSpec {
+    pub(super) name: &'static str,
+    pub(super) summary: &'static str,
+    pub(super) usage: &'static str,
+    pub(super) actions: &'static [(&'static str, &'static str)],
+    pub(super) flags: &'static [FlagSpec],
+}
+
+const fn flag(name: &'static str, help: &'static str) -> FlagSpec {
+    FlagSpec {
+        name,
+        value: None,
+        help,
+    }
+}
+
+const fn valued(name: &'static str, value: &'static str, help: &'static str) -> FlagSpec {
+    FlagSpec {
+        name,
+        value: Some(value),
+        help,
+    }
+}
+
+pub(super) const ROOT_FLAGS: &[FlagSpec] = &[
+    valued(
+        "--profile",
+        "<name>",
+        "Use a config profile from [profiles.<name>] (also GROVE_PROFILE)",
+    ),
+    valued(
+        "--event-log",
+        "<path>",
+        "Write the TUI event log to a file (relative paths go under .grove/)",
+    ),
+    flag(
+        "--debug-record",
+        "Write a continuous debug record to .grove/debug-record-*.jsonl",
+    ),
+    flag("--print-hello", "Print a sanity check line and exit"),
+    flag("--version", "Print the grove version"),
+    flag("--help", "Show this help"),
+];
+
+pub(super) const COMMANDS: &[CommandSpec] = &[
+    CommandSpec {
+        name: "tui",
+        summary: "Launch the interactive TUI (the default with no command)",
+        usage: "grove tui [--event-log <path>] [--debug-record]",
+        actions: &[],
+        flags: &[
+            valued("--event-log", "<path>", "Write the TUI event log to a file"),
+            flag("--debug-record", "Write a continuous debug record"),
+        ],
+    },
+    CommandSpec {
+        name: "task",
+        summary: "List, create, delete, merge and update tasks",
+        usage: "grove task <action> [task] [options]",
+        actions: &[
+            ("list", "List tasks and their worktrees"),
+            ("create", "Create a task with one worktree per repository"),
+            ("delete", "Delete a task and its worktrees"),
+            ("merge", "Merge a task worktree into its base branch"),
+            ("update", "Pull the base branch into a task worktree"),
+        ],
+        flags: &[
+            flag("--json", "Emit machine-readable JSON"),
+            valued(
+                "--repo",
+                "<project>",
+                "Limit to a repository (repeatable for create)",
+            ),
+            valued(
+                "--agent",
+                "<claude|codex>",
+                "Agent for new worktrees (create)",
+            ),
+            flag("--delete-branch", "Also delete local branches (delete)"),
+            flag("--keep-worktree", "Keep the worktree after merging (merge)"),
+            flag("--keep-branch", "Keep the branch after merging (merge)"),
+        ],
+    },
+    CommandSpec {
+        name: "agent",
+        summary: "Start, message, stop and inspect agents in task worktrees",
+        usage: "grove agent <action> [task] [text] [options]",
+        actions: &[
+            ("start", "Start an agent session in a task worktree"),
+            ("send", "Send text to a running agent"),
+            ("stop", "Stop a running agent"),
+            ("status", "Show agent status for one or all tasks"),
+        ],
+        flags: &[
+            flag("--json", "Emit machine-readable JSON"),
+            valued(
+                "--repo",
+                "<repository>",
+                "Pick the worktree in a multi-repo task",
+            ),
+            valued("--agent", "<claude|codex>", "Agent to start (start)"),
+            valued("--prompt", "<text>", "Initial prompt (start)"),
+            valued(
+                "--permission",
+                "<default|auto|unsafe>",
+                "Permission mode for the agent (start)",
+            ),
+        ],
+    },
+    CommandSpec {
+        name: "watch",
+        summary: "Stream workspace status transitions and attention events",
+        usage: "grove watch [--json]",
+        actions: &[],
+        flags: &[flag("--json", "Emit one JSON object per line")],
+    },
+    CommandSpec {
+        name: "status",
+        summary: "Print a one-line agent summary for status bars and prompts",
+        usage: "grove status [--format <template>] [--json]",
+        actions: &[],
+        flags: &[
+            valued(
+                "--format",
+                "<template>",
+                "Template with {running} {waiting} {error} {done} {total} {oldest_waiting} {oldest_waiting_age}",
+            ),
+            flag("--json", "Emit the summary as JSON"),
+        ],
+    },
+    CommandSpec {
+        name: "doctor",
+        summary: "Diagnose task, config and tmux drift and print a repair plan",
+        usage: "grove doctor [--json] | grove doctor --fix [--yes] [--only <action>]",
+        actions: &[],
+        flags: &[
+            flag("--json", "Emit machine-readable diagnosis output"),
+            flag(
+                "--fix",
+                "Apply the repair plan, then re-run diagnosis to verify",
+            ),
+            flag("--yes", "Apply destructive repairs without asking (--fix)"),
+            valued(
+                "--only",
+                "<action>",
+                "Only apply one repair action, e.g. write_base_marker (--fix)",
+            ),
+        ],
+    },
+    CommandSpec {
+        name: "gc",
+        summary: "List or remove merged, idle and unmanaged task worktrees",
+        usage: "grove gc [--apply] [--idle-days <N>] [--json]",
+        actions: &[],
+        flags: &[
+            flag("--apply", "Remove listed worktrees instead of a dry run"),
+            valued(
+                "--idle-days",
+                "<N>",
+                "Idle threshold in days (default 14, 0 disables)",
+            ),
+            flag("--json", "Emit the cleanup plan as JSON"),
+        ],
+    },
+    CommandSpec {
+        name: "cleanup",
+        summary: "Find and remove orphaned Grove tmux sessions",
+        usage: "grove cleanup sessions [options]",
+        actions: &[("sessions", "Clean up tmux sessions without a workspace")],
+        flags: &[
+            flag("--apply", "Kill listed sessions instead of a dry run"),
+            flag("--include-stale", "Also include stale sessions"),
+            flag("--include-attached", "Also include attached sessions"),
+        ],
+    },
+    CommandSpec {
+        name: "export",
+        summary: "Bundle a task, its branches and uncommitted work into a tar file",
+        usage: "grove export <task> [-o <path>]",
+        actions: &[],
+        flags: &[valued(
+            "--output",
+            "<path>",
+            "Bundle path, also -o (default <task>.tar)",
+        )],
+    },
+    CommandSpec {
+        name: "import",
+        summary: "Recreate an exported task against local clones of its repositories",
+        usage: "grove import <bundle.tar>",
+        actions: &[],
+        flags: &[],
+    },
+    CommandSpec {
+        name: "apply",
+        summary: "Create the tasks listed in a spec file and launch their agents",
+        usage: "grove apply <tasks.toml> [--no-start] [--json]",
+        actions: &[],
+        flags: &[
+            flag(
+                "--no-start",
+                "Create missing tasks without launching agents",
+            ),
+            flag("--json", "Emit per-task results as JSON"),
+        ],
+    },
+    CommandSpec {
+        name: "config",
+        summary: "Migrate config files and task manifests to the current schema",
+        usage: "grove config migrate [--dry-run]",
+        actions: &[(
+            "migrate",
+            "Back up and upgrade outdated files, logging to migrations.log",
+        )],
+        flags: &[flag("--dry-run", "List pending migrations without writing")],
+    },
+    CommandSpec {
+        name: "replay",
+        summary: "Replay a debug-record trace headlessly",
+        usage: "grove replay <trace-path> [options]",
+        actions: &[],
+        flags: &[
+            valued("--snapshot", "<path>", "Write the replay snapshot JSON"),
+            valued(
+                "--emit-test",
+                "<name>",
+                "Copy the trace into tests/fixtures/replay/<name>.jsonl",
+            ),
+            flag("--invariant-only", "Check state and invariants only"),
+        ],
+    },
+    CommandSpec {
+        name: "benchmark-scale",
+        summary: "Run the workspace scale benchmark harness",
+        usage: "grove benchmark-scale [options]",
+        actions: &[],
+        flags: &[
+            flag("--json", "Emit machine-readable benchmark output"),
+            valued("--baseline", "<path>", "Compare against a baseline JSON"),
+            valued("--write-baseline", "<path>", "Write a baseline JSON"),
+            valued(
+                "--warn-regression-pct",
+                "<N>",
+                "Severe regression threshold percent (default 35)",
+            ),
+        ],
+    },
+    CommandSpec {
+        name: "completions",
+        summary: "Print a shell completion script",
+        usage: "grove completions <bash|zsh|fish>",
+        actions: &[
+            ("bash", "Bash completion script"),
+            ("zsh", "Zsh completion script"),
+            ("fish", "Fish completion script"),
+        ],
+        flags: &[],
+    },
+    CommandSpec {
+        name: "help",
+        summary: "Show help for grove or one of its commands",
+        usage: "grove help [command]",
+        actions: &[],
+        flags: &[],
+    },
+];
+
+pub(super) fn command_spec(name: &str) -> Option<&'static CommandSpec> {
+    COMMANDS.iter().find(|spec| spec.name == name)
+}
+
+/// Whether `argument` is one of `command`'s flags that consumes the next
+/// argument as its value.
+pub(super) fn flag_takes_value(command: &str, argument: &str) -> bool {
+    let argument = match (command, argument) {
+        ("export", "-o") => "--output",
+        _ => argument,
+    };
+    command_spec(command).is_some_and(|spec| {
+        spec.flags
+            .iter()
+            .any(|flag| flag.name == argument && flag.value.is_some())
+    })
+}
+
+pub(super) fn root_help() -> String {
+    let mut help = format!(
+        "grove {}\n\nUsage: grove [options]\n       grove [--profile <name>] <command> [args]\n\nCommands:\n",
+        env!("CARGO_PKG_VERSION")
+    );
+    push_rows(
+        &mut help,
+        COMMANDS
+            .iter()
+            .map(|spec| (spec.name.to_string(), spec.summary)),
+    );
+    help.push_str("\nOptions:\n");
+    push_rows(&mut help, ROOT_FLAGS.iter().map(flag_row));
+    help.push_str("\nRun `grove help <command>` for command details.");
+    help
+}
+
+pub(super) fn command_help(name: &str) -> Option<String> {
+    let spec = command_spec(name)?;
+    let mut help = format!("{}\n\nUsage: {}\n", spec.summary, spec.usage);
+    if !spec.actions.is_empty() {
+        help.push_str("\nActions:\n");
+        push_rows(
+            &mut help,
+            spec.actions
+                .iter()
+                .map(|(action, summary)| ((*action).to_string(), *summary)),
+        );
+    }
+    help.push_str("\nOptions:\n");
+    push_rows(
+        &mut help,
+        spec.flags
+            .iter()
+            .map(flag_row)
+            .chain(std::iter::once(("--help".to_string(), "Show this help"))),
+    );
+    Some(help.trim_end().to_string())
+}
+
+/// Suggests the closest command name for a likely typo, so a misspelled
+/// subcommand fails with a hint instead of silently doing something else.
+pub(super) fn suggest_command(input: &str) -> Option<&'static str> {
+    COMMANDS
+        .iter()
+        .map(|spec| (edit_distance(input, spec.name), spec.name))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, name)| name)
+}
+
+fn flag_row(flag: &FlagSpec) -> (String, &'static str) {
+    let label = match flag.value {
+        Some(value) => format!("{} {value}", flag.name),
+        None => flag.name.to_string(),
+    };
+    (label, flag.help)
+}
+
+fn push_rows(help: &mut String, rows: impl Iterator<Item = (String, &'static str)>) {
+    let rows = rows.collect::<Vec<(String, &'static str)>>();
+    let width = rows.iter().map(|(label, _)| label.len()).max().unwrap_or(0);
+    for (label, summary) in rows {
+        help.push_str(&format!("  {label:<width$}  {summary}\n"));
+    }
+}
+
+fn edit_distance(left: &str, right: &str) -> usize {
+    let right = right.chars().collect::<Vec<char>>();
+    let mut previous = (0..=right.len()).collect::<Vec<usize>>();
+    for (row, left_char) in left.chars().enumerate() {
+        let mut current = vec![row + 1];
+        for (column, right_char) in right.iter().enumerate() {
+            let substitution = previous[column] + usize::from(left_char != *right_char);
+            current.push(
+                substitution
+                    .min(previous[column + 1] + 1)
+                    .min(current[column] + 1),
+            );
+        }
+        previous = current;
+    }
+    previous[right.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{COMMANDS, command_help, root_help, suggest_command};
+
+    #[test]
+    fn every_command_has_help_listing_usage_and_flags() {
+        for spec in COMMANDS {
+            let help = command_help(spec.name).expect("command should have help");
+            assert!(help.contains(spec.usage), "{} help lists usage", spec.name);
+            for flag in spec.flags {
+                assert!(
+                    help.contains(flag.name),
+                    "{} help lists {}",
+                    spec.name,
+                    flag.name
+                );
+            }
+        }
+        assert_eq!(command_help("missing"), None);
+    }
+
+    #[test]
+    fn root_help_lists_commands_and_version() {
+        let help = root_help();
+        assert!(help.contains(env!("CARGO_PKG_VERSION")));
+        for spec in COMMANDS {
+            assert!(help.contains(spec.name));
+        }
+    }
+
+    #[test]
+    fn suggest_command_catches_small_typos() {
+        assert_eq!(suggest_command("doctr"), Some("doctor"));
+        assert_eq!(suggest_command("tsak"), Some("task"));
+        assert_eq!(suggest_command("frobnicate"), None);
+    }
+}
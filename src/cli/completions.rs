@@ -0,0 +1,247 @@
+use super::commands::{COMMANDS, CommandSpec, FlagSpec, ROOT_FLAGS};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CompletionShell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl CompletionShell {
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        match value {
+            "bash" => Some(Self::Bash),
+            "zsh" => Some(Self::Zsh),
+            "fish" => Some(Self::Fish),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn completion_script(shell: CompletionShell) -> String {
+    match shell {
+        CompletionShell::Bash => bash_script(),
+        CompletionShell::Zsh => zsh_script(),
+        CompletionShell::Fish => fish_script(),
+    }
+}
+
+fn flag_names(flags: &[FlagSpec]) -> String {
+    flags
+        .iter()
+        .map(|flag| flag.name)
+        .chain(std::iter::once("--help"))
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+fn action_names(spec: &CommandSpec) -> String {
+    spec.actions
+        .iter()
+        .map(|(action, _)| *action)
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+fn bash_script() -> String {
+    let commands = COMMANDS
+        .iter()
+        .map(|spec| spec.name)
+        .collect::<Vec<&str>>()
+        .join(" ");
+    let root_flags = ROOT_FLAGS
+        .iter()
+        .map(|flag| flag.name)
+        .collect::<Vec<&str>>()
+        .join(" ");
+
+    let mut script = String::from(
+        "_grove() {\n    local cur=\"${COMP_WORDS[COMP_CWORD]}\"\n    local opts\n\n    if [[ ${COMP_CWORD} -eq 1 ]]; then\n",
+    );
+    script.push_str(&format!(
+        "        COMPREPLY=($(compgen -W \"{commands} {root_flags}\" -- \"$cur\"))\n        return\n    fi\n\n    case \"${{COMP_WORDS[1]}}\" in\n"
+    ));
+    for spec in COMMANDS {
+        if spec.name == "help" {
+            script.push_str(&format!("        help) opts=\"{commands}\" ;;\n"));
+            continue;
+        }
+        let flags = flag_names(spec.flags);
+        if spec.actions.is_empty() {
+            script.push_str(&format!("        {}) opts=\"{flags}\" ;;\n", spec.name));
+        } else {
+            script.push_str(&format!(
+                "        {})\n            if [[ ${{COMP_CWORD}} -eq 2 ]]; then\n                opts=\"{}\"\n            else\n                opts=\"{flags}\"\n            fi\n            ;;\n",
+                spec.name,
+                action_names(spec)
+            ));
+        }
+    }
+    script.push_str(
+        "        *) opts=\"\" ;;\n    esac\n    COMPREPLY=($(compgen -W \"$opts\" -- \"$cur\"))\n}\n\ncomplete -o default -F _grove grove\n",
+    );
+    script
+}
+
+fn zsh_entry(name: &str, summary: &str) -> String {
+    format!(
+        "'{name}:{}'",
+        summary.replace('\'', "'\\''").replace(':', "\\:")
+    )
+}
+
+fn zsh_entries(entries: impl Iterator<Item = String>) -> String {
+    entries
+        .map(|entry| format!("            {entry}"))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn zsh_flag_entries(flags: &[FlagSpec]) -> String {
+    zsh_entries(
+        flags
+            .iter()
+            .map(|flag| zsh_entry(flag.name, flag.help))
+            .chain(std::iter::once(zsh_entry("--help", "Show help"))),
+    )
+}
+
+fn zsh_script() -> String {
+    let commands = zsh_entries(
+        COMMANDS
+            .iter()
+            .map(|spec| zsh_entry(spec.name, spec.summary)),
+    );
+    let root_flags = zsh_entries(
+        ROOT_FLAGS
+            .iter()
+            .map(|flag| zsh_entry(flag.name, flag.help)),
+    );
+
+    let mut script = format!(
+        "#compdef grove\n\n_grove() {{\n    local -a entries\n\n    if (( CURRENT == 2 )); then\n        entries=(\n{commands}\n        )\n        _describe -t commands 'grove command' entries\n        entries=(\n{root_flags}\n        )\n        _describe -t flags 'grove option' entries\n        return\n    fi\n\n    case \"$words[2]\" in\n"
+    );
+    for spec in COMMANDS {
+        if spec.name == "help" {
+            script.push_str(&format!(
+                "        help)\n            entries=(\n{commands}\n            )\n            _describe -t commands 'grove command' entries\n            ;;\n"
+            ));
+            continue;
+        }
+        script.push_str(&format!("        {})\n", spec.name));
+        if !spec.actions.is_empty() {
+            let actions = zsh_entries(
+                spec.actions
+                    .iter()
+                    .map(|(action, summary)| zsh_entry(action, summary)),
+            );
+            script.push_str(&format!(
+                "            if (( CURRENT == 3 )); then\n                entries=(\n{actions}\n                )\n                _describe -t actions '{} action' entries\n                return\n            fi\n",
+                spec.name
+            ));
+        }
+        script.push_str(&format!(
+            "            entries=(\n{}\n            )\n            _describe -t flags '{} option' entries\n            _files\n            ;;\n",
+            zsh_flag_entries(spec.flags),
+            spec.name
+        ));
+    }
+    script.push_str("    esac\n}\n\n_grove \"$@\"\n");
+    script
+}
+
+fn fish_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+fn fish_flag_line(condition: &str, flag: &FlagSpec) -> String {
+    format!(
+        "complete -c grove -n {} -l {}{} -d {}\n",
+        fish_quote(condition),
+        flag.name.trim_start_matches("--"),
+        if flag.value.is_some() { " -r" } else { "" },
+        fish_quote(flag.help)
+    )
+}
+
+fn fish_script() -> String {
+    let mut script = String::from("complete -c grove -f\n");
+    for flag in ROOT_FLAGS {
+        script.push_str(&fish_flag_line("__fish_use_subcommand", flag));
+    }
+    for spec in COMMANDS {
+        script.push_str(&format!(
+            "complete -c grove -n __fish_use_subcommand -a {} -d {}\n",
+            spec.name,
+            fish_quote(spec.summary)
+        ));
+    }
+    for spec in COMMANDS {
+        let condition = format!("__fish_seen_subcommand_from {}", spec.name);
+        if spec.name == "help" {
+            for command in COMMANDS {
+                script.push_str(&format!(
+                    "complete -c grove -n {} -a {} -d {}\n",
+                    fish_quote(condition.as_str()),
+                    command.name,
+                    fish_quote(command.summary)
+                ));
+            }
+            continue;
+        }
+        for (action, summary) in spec.actions {
+            script.push_str(&format!(
+                "complete -c grove -n {} -a {action} -d {}\n",
+                fish_quote(condition.as_str()),
+                fish_quote(summary)
+            ));
+        }
+        for flag in spec.flags {
+            script.push_str(&fish_flag_line(condition.as_str(), flag));
+        }
+        if spec.flags.iter().any(|flag| flag.value.is_some()) {
+            script.push_str(&format!(
+                "complete -c grove -n {} -F\n",
+                fish_quote(condition.as_str())
+            ));
+        }
+    }
+    script
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CompletionShell, completion_script};
+
+    #[test]
+    fn completion_shell_parses_supported_shells() {
+        assert_eq!(CompletionShell::parse("bash"), Some(CompletionShell::Bash));
+        assert_eq!(CompletionShell::parse("zsh"), Some(CompletionShell::Zsh));
+        assert_eq!(CompletionShell::parse("fish"), Some(CompletionShell::Fish));
+        assert_eq!(CompletionShell::parse("powershell"), None);
+    }
+
+    #[test]
+    fn bash_completion_offers_commands_and_subcommand_flags() {
+        let script = completion_script(CompletionShell::Bash);
+        assert!(script.contains("complete -o default -F _grove grove"));
+        assert!(script.contains("tui task agent watch status doctor"));
+        assert!(script.contains("opts=\"list create delete merge update\""));
+        assert!(script.contains("--idle-days"));
+    }
+
+    #[test]
+    fn zsh_and_fish_completions_describe_commands() {
+        let zsh = completion_script(CompletionShell::Zsh);
+        assert!(zsh.starts_with("#compdef grove\n"));
+        assert!(
+            zsh.contains("'doctor:Diagnose task, config and tmux drift and print a repair plan'")
+        );
+
+        let fish = completion_script(CompletionShell::Fish);
+        assert!(fish.contains("complete -c grove -n __fish_use_subcommand -a gc"));
+        assert!(
+            fish.contains("complete -c grove -n '__fish_seen_subcommand_from gc' -l idle-days -r")
+        );
+    }
+}
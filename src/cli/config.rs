@@ -0,0 +1,198 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::infrastructure::config::{config_path, projects_path_for};
+use crate::infrastructure::migrations::{SchemaDocument, migrate_document, write_migrated_file};
+use crate::infrastructure::paths::tasks_root;
+
+use super::task::invalid_input;
+
+const TASK_MANIFEST_PATH: &str = ".grove/task.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ConfigArgs {
+    pub(crate) dry_run: bool,
+}
+
+pub(crate) fn parse_config_args(
+    args: &mut impl Iterator<Item = String>,
+) -> std::io::Result<ConfigArgs> {
+    match args.next().as_deref() {
+        Some("migrate") => {}
+        Some(action) => {
+            return Err(invalid_input(format!("unknown config action `{action}`")));
+        }
+        None => return Err(invalid_input("config requires an action: migrate")),
+    }
+    let mut dry_run = false;
+    for argument in args {
+        match argument.as_str() {
+            "--dry-run" => dry_run = true,
+            flag if flag.starts_with('-') => {
+                return Err(invalid_input(format!("unknown config flag `{flag}`")));
+            }
+            _ => {
+                return Err(invalid_input(format!(
+                    "unexpected argument `{argument}` for config migrate"
+                )));
+            }
+        }
+    }
+    Ok(ConfigArgs { dry_run })
+}
+
+fn migration_targets() -> Vec<(SchemaDocument, PathBuf)> {
+    let mut targets = Vec::new();
+    if let Some(config_path) = config_path() {
+        targets.push((
+            SchemaDocument::ProjectsState,
+            projects_path_for(&config_path),
+        ));
+        targets.push((SchemaDocument::GlobalConfig, config_path));
+    }
+    if let Some(root) = tasks_root()
+        && let Ok(entries) = fs::read_dir(root)
+    {
+        let mut manifests = entries
+            .flatten()
+            .map(|entry| entry.path().join(TASK_MANIFEST_PATH))
+            .filter(|path| path.is_file())
+            .collect::<Vec<PathBuf>>();
+        manifests.sort();
+        targets.extend(
+            manifests
+                .into_iter()
+                .map(|path| (SchemaDocument::TaskManifest, path)),
+        );
+    }
+    targets
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct MigrationRun {
+    pending: usize,
+    failures: usize,
+}
+
+fn migrate_targets(targets: Vec<(SchemaDocument, PathBuf)>, dry_run: bool) -> MigrationRun {
+    let mut run = MigrationRun::default();
+    for (document, path) in targets {
+        let Ok(raw) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let outcome = match migrate_document(document, raw.as_str()) {
+            Ok(outcome) => outcome,
+            Err(error) => {
+                eprintln!("{}: {error}", path.display());
+                run.failures += 1;
+                continue;
+            }
+        };
+        if !outcome.outdated() {
+            continue;
+        }
+        run.pending += 1;
+        println!(
+            "{} {}: v{} -> v{}",
+            document.label(),
+            path.display(),
+            outcome.from_version,
+            outcome.to_version
+        );
+        for summary in &outcome.applied {
+            println!("  {summary}");
+        }
+        if dry_run {
+            continue;
+        }
+        match write_migrated_file(&path, &outcome) {
+            Ok(backup) => println!("  backup {}", backup.display()),
+            Err(error) => {
+                eprintln!("{}: {error}", path.display());
+                run.failures += 1;
+            }
+        }
+    }
+
+    run
+}
+
+pub(crate) fn run_config(args: &ConfigArgs) -> std::io::Result<()> {
+    let MigrationRun { pending, failures } = migrate_targets(migration_targets(), args.dry_run);
+    match (pending, args.dry_run) {
+        (0, _) => println!("all config files are at the current schema"),
+        (count, true) => println!("{count} file(s) would be migrated"),
+        (count, false) => println!("{count} file(s) migrated"),
+    }
+    if failures > 0 {
+        return Err(std::io::Error::other(format!(
+            "{failures} file(s) could not be migrated"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ConfigArgs, MigrationRun, migrate_targets, parse_config_args};
+    use crate::infrastructure::migrations::SchemaDocument;
+    use crate::test_support::unique_test_dir;
+    use std::fs;
+
+    fn args(values: &[&str]) -> std::io::Result<ConfigArgs> {
+        parse_config_args(&mut values.iter().map(|value| value.to_string()))
+    }
+
+    #[test]
+    fn parse_config_args_requires_migrate_and_reads_dry_run() {
+        assert_eq!(
+            args(&["migrate"]).expect("migrate should parse"),
+            ConfigArgs { dry_run: false }
+        );
+        assert_eq!(
+            args(&["migrate", "--dry-run"]).expect("dry run should parse"),
+            ConfigArgs { dry_run: true }
+        );
+
+        assert!(args(&[]).is_err());
+        assert!(args(&["upgrade"]).is_err());
+        assert!(args(&["migrate", "--force"]).is_err());
+        assert!(args(&["migrate", "config.toml"]).is_err());
+    }
+
+    #[test]
+    fn dry_run_reports_pending_migrations_without_writing() {
+        let directory = unique_test_dir("grove-cli-config");
+        let path = directory.join("config.toml");
+        let legacy = "launch_skip_permissions = true\n";
+        fs::write(&path, legacy).expect("config should write");
+        let targets = vec![(SchemaDocument::GlobalConfig, path.clone())];
+
+        assert_eq!(
+            migrate_targets(targets.clone(), true),
+            MigrationRun {
+                pending: 1,
+                failures: 0,
+            }
+        );
+        assert_eq!(
+            fs::read_to_string(&path).expect("config should read"),
+            legacy
+        );
+        assert!(!directory.join("config.toml.v0.bak").exists());
+
+        assert_eq!(
+            migrate_targets(targets.clone(), false),
+            MigrationRun {
+                pending: 1,
+                failures: 0,
+            }
+        );
+        let migrated = fs::read_to_string(&path).expect("config should read");
+        assert!(migrated.contains("launch_permission_mode = \"unsafe\""));
+        assert!(directory.join("config.toml.v0.bak").exists());
+        assert_eq!(migrate_targets(targets, false), MigrationRun::default());
+
+        let _ = fs::remove_dir_all(directory);
+    }
+}
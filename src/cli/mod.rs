@@ -1,17 +1,45 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use crate::application::doctor::DoctorReport;
+use crate::application::doctor::{DoctorRepairAction, DoctorReport};
+use crate::application::doctor_fix::{DoctorFix, apply_doctor_fix, plan_doctor_fixes};
+use crate::application::gc::{GcEntry, GcOptions, apply_gc, plan_gc};
 use crate::application::session_cleanup::{
     SessionCleanupEntry, SessionCleanupOptions, SessionCleanupReason, apply_session_cleanup,
     plan_session_cleanup,
 };
 use crate::infrastructure::event_log::now_millis;
 
+mod agent;
+mod apply;
+mod commands;
+mod completions;
+mod config;
+mod status;
+mod task;
+mod transfer;
+mod watch;
+
+use agent::{AgentArgs, parse_agent_args, run_agent};
+use apply::{ApplyArgs, parse_apply_args, run_apply};
+use commands::{command_help, flag_takes_value, root_help, suggest_command};
+use completions::{CompletionShell, completion_script};
+use config::{ConfigArgs, parse_config_args, run_config};
+use status::{StatusArgs, parse_status_args, run_status};
+use task::{TaskArgs, invalid_input, parse_task_args, run_task};
+use transfer::{
+    ExportArgs, ImportArgs, parse_export_args, parse_import_args, run_export, run_import,
+};
+use watch::{WatchArgs, parse_watch_args, run_watch};
+
 const DEBUG_RECORD_DIR: &str = ".grove";
+const EXIT_FAILURE: i32 = 1;
+const EXIT_USAGE: i32 = 2;
+const EXIT_NOT_FOUND: i32 = 3;
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub(crate) struct CliArgs {
+    pub(crate) profile: Option<String>,
     pub(crate) print_hello: bool,
     pub(crate) event_log_path: Option<PathBuf>,
     pub(crate) debug_record: bool,
@@ -26,228 +54,414 @@ pub(crate) struct CliArgs {
     pub(crate) benchmark_warn_regression_pct: Option<u64>,
     pub(crate) doctor: bool,
     pub(crate) doctor_json_output: bool,
+    pub(crate) doctor_fix: bool,
+    pub(crate) doctor_fix_yes: bool,
+    pub(crate) doctor_fix_only: Option<DoctorRepairAction>,
     pub(crate) cleanup_sessions: bool,
     pub(crate) cleanup_sessions_apply: bool,
     pub(crate) cleanup_sessions_include_stale: bool,
     pub(crate) cleanup_sessions_include_attached: bool,
+    pub(crate) gc: bool,
+    pub(crate) gc_apply: bool,
+    pub(crate) gc_json_output: bool,
+    pub(crate) gc_idle_days: Option<u64>,
+    pub(crate) task: Option<TaskArgs>,
+    pub(crate) agent: Option<AgentArgs>,
+    pub(crate) watch: Option<WatchArgs>,
+    pub(crate) status: Option<StatusArgs>,
+    pub(crate) export: Option<ExportArgs>,
+    pub(crate) import: Option<ImportArgs>,
+    pub(crate) apply: Option<ApplyArgs>,
+    pub(crate) config: Option<ConfigArgs>,
+    pub(crate) help: Option<String>,
+    pub(crate) version: bool,
+    pub(crate) completions: Option<CompletionShell>,
 }
 
 pub(crate) fn parse_cli_args(args: impl IntoIterator<Item = String>) -> std::io::Result<CliArgs> {
     let mut cli = CliArgs::default();
     let mut args = args.into_iter();
-    let mut saw_json_output = false;
 
     while let Some(argument) = args.next() {
         match argument.as_str() {
+            "-h" | "--help" => {
+                cli.help = Some(root_help());
+                return Ok(cli);
+            }
+            "-V" | "--version" => {
+                cli.version = true;
+                return Ok(cli);
+            }
             "--print-hello" => {
                 cli.print_hello = true;
             }
+            "--profile" => {
+                let Some(name) = args.next() else {
+                    return Err(invalid_input("--profile requires a profile name"));
+                };
+                cli.profile = Some(name);
+            }
             "--event-log" => {
                 let Some(path) = args.next() else {
-                    return Err(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        "--event-log requires a file path",
-                    ));
+                    return Err(invalid_input("--event-log requires a file path"));
                 };
                 cli.event_log_path = Some(PathBuf::from(path));
             }
             "--debug-record" => {
                 cli.debug_record = true;
             }
-            "replay" => {
-                if cli.benchmark_scale {
-                    return Err(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        "replay cannot be combined with benchmark-scale",
-                    ));
-                }
-                let Some(path) = args.next() else {
-                    return Err(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        "replay requires a trace path",
-                    ));
-                };
-                cli.replay_trace_path = Some(PathBuf::from(path));
+            flag if flag.starts_with('-') => {
+                return Err(invalid_input(format!(
+                    "unknown flag `{flag}` (see `grove --help`)"
+                )));
             }
-            "benchmark-scale" => {
-                if cli.replay_trace_path.is_some() {
-                    return Err(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        "benchmark-scale cannot be combined with replay",
-                    ));
+            command => {
+                parse_command(command, &mut args, &mut cli)?;
+                if cli.help.is_none()
+                    && command != "tui"
+                    && (cli.debug_record || cli.event_log_path.is_some() || cli.print_hello)
+                {
+                    return Err(invalid_input(format!(
+                        "{command} cannot be combined with other command modes"
+                    )));
                 }
-                cli.benchmark_scale = true;
+                return Ok(cli);
             }
-            "doctor" => {
-                cli.doctor = true;
+        }
+    }
+
+    Ok(cli)
+}
+
+fn parse_command(
+    command: &str,
+    args: &mut impl Iterator<Item = String>,
+    cli: &mut CliArgs,
+) -> std::io::Result<()> {
+    match command {
+        "task" | "agent" | "watch" | "status" | "export" | "import" | "apply" | "config" => {
+            let rest = args.collect::<Vec<String>>();
+            if help_flag_requested(command, rest.as_slice()) {
+                cli.help = command_help(command);
+                return Ok(());
             }
-            "cleanup" => {
-                let Some(target) = args.next() else {
-                    return Err(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        "cleanup requires a target (`sessions`)",
-                    ));
-                };
-                if target != "sessions" {
-                    return Err(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        format!("unsupported cleanup target `{target}`"),
-                    ));
-                }
-                cli.cleanup_sessions = true;
+            let mut rest = rest.into_iter();
+            match command {
+                "task" => cli.task = Some(parse_task_args(&mut rest)?),
+                "agent" => cli.agent = Some(parse_agent_args(&mut rest)?),
+                "watch" => cli.watch = Some(parse_watch_args(&mut rest)?),
+                "export" => cli.export = Some(parse_export_args(&mut rest)?),
+                "import" => cli.import = Some(parse_import_args(&mut rest)?),
+                "apply" => cli.apply = Some(parse_apply_args(&mut rest)?),
+                "config" => cli.config = Some(parse_config_args(&mut rest)?),
+                _ => cli.status = Some(parse_status_args(&mut rest)?),
+            }
+            Ok(())
+        }
+        "tui" => parse_tui_args(args, cli),
+        "replay" => parse_replay_args(args, cli),
+        "benchmark-scale" => parse_benchmark_args(args, cli),
+        "doctor" => parse_doctor_args(args, cli),
+        "gc" => parse_gc_args(args, cli),
+        "cleanup" => parse_cleanup_args(args, cli),
+        "completions" => parse_completions_args(args, cli),
+        "help" => {
+            let topic = args.next();
+            if let Some(extra) = args.next() {
+                return Err(unexpected_argument("help", extra.as_str()));
+            }
+            cli.help = match topic {
+                Some(topic) if is_help_flag(topic.as_str()) => command_help("help"),
+                Some(topic) => Some(
+                    command_help(topic.as_str())
+                        .ok_or_else(|| invalid_input(unknown_command_message(topic.as_str())))?,
+                ),
+                None => Some(root_help()),
+            };
+            Ok(())
+        }
+        _ => Err(invalid_input(unknown_command_message(command))),
+    }
+}
+
+fn is_help_flag(argument: &str) -> bool {
+    matches!(argument, "-h" | "--help")
+}
+
+/// Looks for `-h`/`--help` where a flag is expected, skipping flag values so
+/// `--prompt --help` still passes `--help` as the prompt.
+fn help_flag_requested(command: &str, arguments: &[String]) -> bool {
+    let mut arguments = arguments.iter();
+    while let Some(argument) = arguments.next() {
+        if is_help_flag(argument) {
+            return true;
+        }
+        if flag_takes_value(command, argument) {
+            arguments.next();
+        }
+    }
+    false
+}
+
+fn unknown_command_message(command: &str) -> String {
+    match suggest_command(command) {
+        Some(suggestion) => {
+            format!(
+                "unknown command `{command}`, did you mean `{suggestion}`? (see `grove --help`)"
+            )
+        }
+        None => format!("unknown command `{command}` (see `grove --help`)"),
+    }
+}
+
+fn unexpected_argument(command: &str, argument: &str) -> std::io::Error {
+    if argument.starts_with('-') {
+        return invalid_input(format!(
+            "unknown {command} flag `{argument}` (see `grove {command} --help`)"
+        ));
+    }
+    invalid_input(format!(
+        "unexpected argument `{argument}` for {command} (see `grove {command} --help`)"
+    ))
+}
+
+fn required_value(
+    args: &mut impl Iterator<Item = String>,
+    message: &str,
+) -> std::io::Result<String> {
+    args.next().ok_or_else(|| invalid_input(message))
+}
+
+fn parse_positive_integer(value: &str, flag: &str) -> std::io::Result<u64> {
+    value
+        .parse::<u64>()
+        .map_err(|error| invalid_input(format!("{flag} must be an integer: {error}")))
+}
+
+fn parse_tui_args(
+    args: &mut impl Iterator<Item = String>,
+    cli: &mut CliArgs,
+) -> std::io::Result<()> {
+    while let Some(argument) = args.next() {
+        match argument.as_str() {
+            help if is_help_flag(help) => {
+                cli.help = command_help("tui");
+                return Ok(());
+            }
+            "--event-log" => {
+                let path = required_value(args, "--event-log requires a file path")?;
+                cli.event_log_path = Some(PathBuf::from(path));
+            }
+            "--debug-record" => {
+                cli.debug_record = true;
+            }
+            other => return Err(unexpected_argument("tui", other)),
+        }
+    }
+    Ok(())
+}
+
+fn parse_replay_args(
+    args: &mut impl Iterator<Item = String>,
+    cli: &mut CliArgs,
+) -> std::io::Result<()> {
+    while let Some(argument) = args.next() {
+        match argument.as_str() {
+            help if is_help_flag(help) => {
+                cli.help = command_help("replay");
+                return Ok(());
             }
             "--snapshot" => {
-                let Some(path) = args.next() else {
-                    return Err(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        "--snapshot requires a file path",
-                    ));
-                };
+                let path = required_value(args, "--snapshot requires a file path")?;
                 cli.replay_snapshot_path = Some(PathBuf::from(path));
             }
             "--emit-test" => {
-                let Some(name) = args.next() else {
-                    return Err(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        "--emit-test requires a fixture name",
-                    ));
-                };
-                cli.replay_emit_test_name = Some(name);
+                cli.replay_emit_test_name =
+                    Some(required_value(args, "--emit-test requires a fixture name")?);
             }
             "--invariant-only" => {
                 cli.replay_invariant_only = true;
             }
+            path if !path.starts_with('-') && cli.replay_trace_path.is_none() => {
+                cli.replay_trace_path = Some(PathBuf::from(path));
+            }
+            other => return Err(unexpected_argument("replay", other)),
+        }
+    }
+
+    if cli.replay_trace_path.is_none() {
+        return Err(invalid_input("replay requires a trace path"));
+    }
+    Ok(())
+}
+
+fn parse_benchmark_args(
+    args: &mut impl Iterator<Item = String>,
+    cli: &mut CliArgs,
+) -> std::io::Result<()> {
+    cli.benchmark_scale = true;
+    while let Some(argument) = args.next() {
+        match argument.as_str() {
+            help if is_help_flag(help) => {
+                cli.help = command_help("benchmark-scale");
+                return Ok(());
+            }
             "--json" => {
-                saw_json_output = true;
+                cli.benchmark_json_output = true;
             }
             "--baseline" => {
-                let Some(path) = args.next() else {
-                    return Err(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        "--baseline requires a file path",
-                    ));
-                };
+                let path = required_value(args, "--baseline requires a file path")?;
                 cli.benchmark_baseline_path = Some(PathBuf::from(path));
             }
             "--write-baseline" => {
-                let Some(path) = args.next() else {
-                    return Err(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        "--write-baseline requires a file path",
-                    ));
-                };
+                let path = required_value(args, "--write-baseline requires a file path")?;
                 cli.benchmark_write_baseline_path = Some(PathBuf::from(path));
             }
             "--warn-regression-pct" => {
-                let Some(value) = args.next() else {
-                    return Err(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        "--warn-regression-pct requires a positive integer",
-                    ));
-                };
-                let parsed = value.parse::<u64>().map_err(|error| {
-                    std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        format!("--warn-regression-pct must be an integer: {error}"),
-                    )
-                })?;
+                let value =
+                    required_value(args, "--warn-regression-pct requires a positive integer")?;
+                let parsed = parse_positive_integer(value.as_str(), "--warn-regression-pct")?;
                 if parsed == 0 {
-                    return Err(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
+                    return Err(invalid_input(
                         "--warn-regression-pct must be greater than zero",
                     ));
                 }
                 cli.benchmark_warn_regression_pct = Some(parsed);
             }
-            "--apply" => {
-                cli.cleanup_sessions_apply = true;
+            other => return Err(unexpected_argument("benchmark-scale", other)),
+        }
+    }
+    Ok(())
+}
+
+fn parse_doctor_args(
+    args: &mut impl Iterator<Item = String>,
+    cli: &mut CliArgs,
+) -> std::io::Result<()> {
+    cli.doctor = true;
+    while let Some(argument) = args.next() {
+        match argument.as_str() {
+            help if is_help_flag(help) => {
+                cli.help = command_help("doctor");
+                return Ok(());
             }
-            "--include-stale" => {
-                cli.cleanup_sessions_include_stale = true;
+            "--json" => {
+                cli.doctor_json_output = true;
             }
-            "--include-attached" => {
-                cli.cleanup_sessions_include_attached = true;
+            "--fix" => {
+                cli.doctor_fix = true;
+            }
+            "--yes" => {
+                cli.doctor_fix_yes = true;
+            }
+            "--only" => {
+                let value = required_value(args, "--only requires a repair action")?;
+                let Some(action) = DoctorRepairAction::from_label(value.as_str()) else {
+                    return Err(invalid_input(format!(
+                        "unknown repair action `{value}` for --only"
+                    )));
+                };
+                cli.doctor_fix_only = Some(action);
             }
-            _ => {}
+            other => return Err(unexpected_argument("doctor", other)),
         }
     }
+    if !cli.doctor_fix && (cli.doctor_fix_yes || cli.doctor_fix_only.is_some()) {
+        return Err(invalid_input("--yes and --only require --fix"));
+    }
+    if cli.doctor_fix && cli.doctor_json_output {
+        return Err(invalid_input("--fix cannot be combined with --json"));
+    }
+    Ok(())
+}
 
-    if saw_json_output {
-        if cli.benchmark_scale {
-            cli.benchmark_json_output = true;
-        } else if cli.doctor {
-            cli.doctor_json_output = true;
-        } else {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                "json output requires `benchmark-scale` or `doctor`",
-            ));
+fn parse_gc_args(
+    args: &mut impl Iterator<Item = String>,
+    cli: &mut CliArgs,
+) -> std::io::Result<()> {
+    cli.gc = true;
+    while let Some(argument) = args.next() {
+        match argument.as_str() {
+            help if is_help_flag(help) => {
+                cli.help = command_help("gc");
+                return Ok(());
+            }
+            "--apply" => {
+                cli.gc_apply = true;
+            }
+            "--json" => {
+                cli.gc_json_output = true;
+            }
+            "--idle-days" => {
+                let value = required_value(args, "--idle-days requires a number of days")?;
+                cli.gc_idle_days = Some(parse_positive_integer(value.as_str(), "--idle-days")?);
+            }
+            other => return Err(unexpected_argument("gc", other)),
         }
     }
+    Ok(())
+}
 
-    if cli.replay_trace_path.is_none()
-        && (cli.replay_snapshot_path.is_some()
-            || cli.replay_emit_test_name.is_some()
-            || cli.replay_invariant_only)
-    {
-        return Err(std::io::Error::new(
-            std::io::ErrorKind::InvalidInput,
-            "replay flags require `replay <trace-path>`",
-        ));
+fn parse_cleanup_args(
+    args: &mut impl Iterator<Item = String>,
+    cli: &mut CliArgs,
+) -> std::io::Result<()> {
+    let Some(target) = args.next() else {
+        return Err(invalid_input("cleanup requires a target (`sessions`)"));
+    };
+    if is_help_flag(target.as_str()) {
+        cli.help = command_help("cleanup");
+        return Ok(());
     }
-
-    if !cli.benchmark_scale
-        && (cli.benchmark_json_output
-            || cli.benchmark_baseline_path.is_some()
-            || cli.benchmark_write_baseline_path.is_some()
-            || cli.benchmark_warn_regression_pct.is_some())
-    {
-        return Err(std::io::Error::new(
-            std::io::ErrorKind::InvalidInput,
-            "benchmark flags require `benchmark-scale`",
-        ));
+    if target != "sessions" {
+        return Err(invalid_input(format!(
+            "unsupported cleanup target `{target}`"
+        )));
     }
 
-    if !cli.cleanup_sessions
-        && (cli.cleanup_sessions_apply
-            || cli.cleanup_sessions_include_stale
-            || cli.cleanup_sessions_include_attached)
-    {
-        return Err(std::io::Error::new(
-            std::io::ErrorKind::InvalidInput,
-            "cleanup flags require `cleanup sessions`",
-        ));
+    cli.cleanup_sessions = true;
+    for argument in args {
+        match argument.as_str() {
+            help if is_help_flag(help) => {
+                cli.help = command_help("cleanup");
+                return Ok(());
+            }
+            "--apply" => {
+                cli.cleanup_sessions_apply = true;
+            }
+            "--include-stale" => {
+                cli.cleanup_sessions_include_stale = true;
+            }
+            "--include-attached" => {
+                cli.cleanup_sessions_include_attached = true;
+            }
+            other => return Err(unexpected_argument("cleanup", other)),
+        }
     }
+    Ok(())
+}
 
-    if cli.cleanup_sessions
-        && (cli.replay_trace_path.is_some()
-            || cli.benchmark_scale
-            || cli.doctor
-            || cli.debug_record
-            || cli.event_log_path.is_some()
-            || cli.print_hello)
-    {
-        return Err(std::io::Error::new(
-            std::io::ErrorKind::InvalidInput,
-            "cleanup sessions cannot be combined with other command modes",
+fn parse_completions_args(
+    args: &mut impl Iterator<Item = String>,
+    cli: &mut CliArgs,
+) -> std::io::Result<()> {
+    let Some(shell) = args.next() else {
+        return Err(invalid_input(
+            "completions requires a shell (`bash`, `zsh`, `fish`)",
         ));
+    };
+    if is_help_flag(shell.as_str()) {
+        cli.help = command_help("completions");
+        return Ok(());
     }
-
-    if cli.doctor
-        && (cli.replay_trace_path.is_some()
-            || cli.benchmark_scale
-            || cli.cleanup_sessions
-            || cli.debug_record
-            || cli.event_log_path.is_some()
-            || cli.print_hello)
-    {
-        return Err(std::io::Error::new(
-            std::io::ErrorKind::InvalidInput,
-            "doctor cannot be combined with other command modes",
-        ));
+    let Some(parsed) = CompletionShell::parse(shell.as_str()) else {
+        return Err(invalid_input(format!("unsupported shell `{shell}`")));
+    };
+    if let Some(extra) = args.next() {
+        return Err(unexpected_argument("completions", extra.as_str()));
     }
-
-    Ok(cli)
+    cli.completions = Some(parsed);
+    Ok(())
 }
 
 pub(crate) fn debug_record_path(app_start_ts: u64) -> std::io::Result<PathBuf> {
@@ -379,6 +593,90 @@ fn run_cleanup_sessions(cli: &CliArgs) -> std::io::Result<()> {
     )))
 }
 
+fn print_gc_entries(prefix: &str, entries: &[GcEntry]) {
+    for entry in entries {
+        let branches = if entry.branches.is_empty() {
+            "-".to_string()
+        } else {
+            entry.branches.join(",")
+        };
+        println!(
+            "{prefix} {} [{}] idle={} branch={} path={}",
+            entry.name,
+            entry.reason.label(),
+            format_age(entry.idle_secs),
+            branches,
+            entry.path.display()
+        );
+    }
+}
+
+fn run_gc(cli: &CliArgs) -> std::io::Result<()> {
+    let options = match cli.gc_idle_days {
+        Some(0) => GcOptions { idle_days: None },
+        Some(days) => GcOptions {
+            idle_days: Some(days),
+        },
+        None => GcOptions::default(),
+    };
+    let plan = plan_gc(options).map_err(std::io::Error::other)?;
+    if cli.gc_json_output && !cli.gc_apply {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&plan).map_err(std::io::Error::other)?
+        );
+        return Ok(());
+    }
+
+    if plan.candidates.is_empty() {
+        println!("gc: no candidates");
+    } else {
+        println!("gc: {} candidate(s)", plan.candidates.len());
+        print_gc_entries("-", plan.candidates.as_slice());
+    }
+    if !plan.skipped_running.is_empty() {
+        println!(
+            "gc: {} task(s) with running agents skipped",
+            plan.skipped_running.len()
+        );
+        print_gc_entries("~", plan.skipped_running.as_slice());
+    }
+    if !plan.skipped_dirty.is_empty() {
+        println!(
+            "gc: {} worktree(s) with uncommitted changes skipped",
+            plan.skipped_dirty.len()
+        );
+        print_gc_entries("~", plan.skipped_dirty.as_slice());
+    }
+
+    if !cli.gc_apply {
+        if !plan.candidates.is_empty() {
+            println!("dry run only, rerun with `gc --apply` to remove candidates");
+        }
+        return Ok(());
+    }
+
+    let applied = apply_gc(&plan);
+    for name in &applied.removed {
+        println!("removed {name}");
+    }
+    for warning in &applied.warnings {
+        eprintln!("warning {warning}");
+    }
+    for (name, error) in &applied.failures {
+        eprintln!("failed {name}: {error}");
+    }
+
+    if applied.failures.is_empty() {
+        return Ok(());
+    }
+
+    Err(std::io::Error::other(format!(
+        "gc failed for {} candidate(s)",
+        applied.failures.len()
+    )))
+}
+
 fn format_doctor_summary(report: &DoctorReport) -> String {
     if report.summary.total == 0 {
         return "doctor: clean".to_string();
@@ -490,6 +788,71 @@ fn run_doctor(cli: &CliArgs) -> std::io::Result<()> {
     Err(std::io::Error::other("doctor found actionable issues"))
 }
 
+fn run_doctor_fix(cli: &CliArgs) -> std::io::Result<()> {
+    let report = crate::application::doctor::diagnose().map_err(std::io::Error::other)?;
+    let fixes = plan_doctor_fixes(&report)
+        .into_iter()
+        .filter(|fix| {
+            cli.doctor_fix_only
+                .is_none_or(|action| fix.step.action == action)
+        })
+        .collect::<Vec<DoctorFix>>();
+    println!("{}", format_doctor_summary(&report));
+    if fixes.is_empty() {
+        println!("nothing to fix");
+    }
+
+    for (index, fix) in fixes.iter().enumerate() {
+        let targets = fix.step.targets.join(", ");
+        println!("{}. {} [{}]", index + 1, fix.step.action.label(), targets);
+        let operation = match fix.operation.as_ref() {
+            Ok(operation) => operation,
+            Err(reason) => {
+                println!("   skipped: {reason}");
+                continue;
+            }
+        };
+        if operation.is_destructive()
+            && !cli.doctor_fix_yes
+            && !confirm_doctor_fix(operation.describe().as_str())?
+        {
+            println!("   skipped: not confirmed");
+            continue;
+        }
+        match apply_doctor_fix(operation) {
+            Ok(message) => println!("   fixed: {message}"),
+            Err(error) => println!("   failed: {error}"),
+        }
+    }
+
+    println!();
+    let verified = crate::application::doctor::diagnose().map_err(std::io::Error::other)?;
+    println!("after fix {}", format_doctor_summary(&verified));
+    for finding in &verified.findings {
+        println!(
+            "- {} [{}]",
+            finding.kind.label(),
+            finding.subject.targets().join(", ")
+        );
+    }
+
+    if doctor_exit_code(&verified) == 0 {
+        return Ok(());
+    }
+
+    Err(std::io::Error::other("doctor found actionable issues"))
+}
+
+fn confirm_doctor_fix(description: &str) -> std::io::Result<bool> {
+    use std::io::Write;
+
+    print!("   {description}? [y/N] ");
+    std::io::stdout().flush()?;
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+}
+
 fn doctor_exit_code(report: &DoctorReport) -> i32 {
     if report.summary.warn > 0 || report.summary.error > 0 {
         1
@@ -498,9 +861,73 @@ fn doctor_exit_code(report: &DoctorReport) -> i32 {
     }
 }
 
+/// Maps a failed command to the process exit code: 2 for usage errors, 3 when
+/// a named task or project does not exist, 1 for everything else.
+pub fn exit_code(error: &std::io::Error) -> i32 {
+    match error.kind() {
+        std::io::ErrorKind::InvalidInput => EXIT_USAGE,
+        std::io::ErrorKind::NotFound => EXIT_NOT_FOUND,
+        _ => EXIT_FAILURE,
+    }
+}
+
 pub fn run(args: impl IntoIterator<Item = String>) -> std::io::Result<()> {
     let cli = parse_cli_args(args)?;
 
+    if let Some(help) = cli.help.as_deref() {
+        println!("{help}");
+        return Ok(());
+    }
+
+    if cli.version {
+        println!("grove {}", env!("CARGO_PKG_VERSION"));
+        return Ok(());
+    }
+
+    if let Some(shell) = cli.completions {
+        print!("{}", completion_script(shell));
+        return Ok(());
+    }
+
+    crate::infrastructure::profile::activate_profile(cli.profile.as_deref())
+        .map_err(invalid_input)?;
+
+    if let Some(task) = cli.task.as_ref() {
+        return run_task(task);
+    }
+
+    if let Some(agent) = cli.agent.as_ref() {
+        return run_agent(agent);
+    }
+
+    if let Some(watch) = cli.watch.as_ref() {
+        return run_watch(watch);
+    }
+
+    if let Some(status) = cli.status.as_ref() {
+        return run_status(status);
+    }
+
+    if let Some(export) = cli.export.as_ref() {
+        return run_export(export);
+    }
+
+    if let Some(import) = cli.import.as_ref() {
+        return run_import(import);
+    }
+
+    if let Some(apply) = cli.apply.as_ref() {
+        return run_apply(apply);
+    }
+
+    if let Some(config) = cli.config.as_ref() {
+        return run_config(config);
+    }
+
+    if cli.doctor_fix {
+        return run_doctor_fix(&cli);
+    }
+
     if cli.doctor {
         return run_doctor(&cli);
     }
@@ -509,6 +936,10 @@ pub fn run(args: impl IntoIterator<Item = String>) -> std::io::Result<()> {
         return run_cleanup_sessions(&cli);
     }
 
+    if cli.gc {
+        return run_gc(&cli);
+    }
+
     if cli.benchmark_scale {
         let options = crate::application::scale_benchmark::ScaleBenchmarkOptions {
             json_output: cli.benchmark_json_output,
@@ -573,12 +1004,15 @@ pub fn run(args: impl IntoIterator<Item = String>) -> std::io::Result<()> {
 
 #[cfg(test)]
 mod tests {
+    use super::agent::AgentCommand;
+    use super::task::TaskCommand;
     use super::{
-        CliArgs, debug_record_path, doctor_exit_code, ensure_event_log_parent_directory,
-        parse_cli_args, resolve_event_log_path,
+        CliArgs, CompletionShell, ConfigArgs, debug_record_path, doctor_exit_code,
+        ensure_event_log_parent_directory, exit_code, parse_cli_args, resolve_event_log_path,
     };
     use crate::application::doctor::{
-        DoctorFinding, DoctorFindingKind, DoctorReport, DoctorSeverity, DoctorSubject,
+        DoctorFinding, DoctorFindingKind, DoctorRepairAction, DoctorReport, DoctorSeverity,
+        DoctorSubject,
     };
     use std::path::PathBuf;
 
@@ -594,6 +1028,7 @@ mod tests {
         assert_eq!(
             parsed,
             CliArgs {
+                profile: None,
                 print_hello: true,
                 event_log_path: Some(PathBuf::from("/tmp/events.jsonl")),
                 debug_record: false,
@@ -608,14 +1043,63 @@ mod tests {
                 benchmark_warn_regression_pct: None,
                 doctor: false,
                 doctor_json_output: false,
+                doctor_fix: false,
+                doctor_fix_yes: false,
+                doctor_fix_only: None,
                 cleanup_sessions: false,
                 cleanup_sessions_apply: false,
                 cleanup_sessions_include_stale: false,
                 cleanup_sessions_include_attached: false,
+                gc: false,
+                gc_apply: false,
+                gc_json_output: false,
+                gc_idle_days: None,
+                task: None,
+                agent: None,
+                watch: None,
+                status: None,
+                export: None,
+                import: None,
+                apply: None,
+                config: None,
+                help: None,
+                version: false,
+                completions: None,
             }
         );
     }
 
+    #[test]
+    fn cli_parser_reads_profile_before_command() {
+        let parsed = parse_cli_args(vec![
+            "--profile".to_string(),
+            "work".to_string(),
+            "status".to_string(),
+        ])
+        .expect("profile flag should parse");
+        assert_eq!(parsed.profile.as_deref(), Some("work"));
+        assert!(parsed.status.is_some());
+
+        let error = parse_cli_args(vec!["--profile".to_string()])
+            .expect_err("missing profile name should fail");
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn cli_parser_reads_config_migrate_dry_run() {
+        let parsed = parse_cli_args(vec![
+            "config".to_string(),
+            "migrate".to_string(),
+            "--dry-run".to_string(),
+        ])
+        .expect("config migrate should parse");
+        assert_eq!(parsed.config, Some(ConfigArgs { dry_run: true }));
+
+        let error = parse_cli_args(vec!["config".to_string(), "upgrade".to_string()])
+            .expect_err("unknown config action should fail");
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
     #[test]
     fn cli_parser_requires_event_log_path() {
         let error = parse_cli_args(vec!["--event-log".to_string()])
@@ -630,6 +1114,7 @@ mod tests {
         assert_eq!(
             parsed,
             CliArgs {
+                profile: None,
                 print_hello: false,
                 event_log_path: None,
                 debug_record: true,
@@ -644,10 +1129,28 @@ mod tests {
                 benchmark_warn_regression_pct: None,
                 doctor: false,
                 doctor_json_output: false,
+                doctor_fix: false,
+                doctor_fix_yes: false,
+                doctor_fix_only: None,
                 cleanup_sessions: false,
                 cleanup_sessions_apply: false,
                 cleanup_sessions_include_stale: false,
                 cleanup_sessions_include_attached: false,
+                gc: false,
+                gc_apply: false,
+                gc_json_output: false,
+                gc_idle_days: None,
+                task: None,
+                agent: None,
+                watch: None,
+                status: None,
+                export: None,
+                import: None,
+                apply: None,
+                config: None,
+                help: None,
+                version: false,
+                completions: None,
             }
         );
     }
@@ -668,6 +1171,7 @@ mod tests {
         assert_eq!(
             parsed,
             CliArgs {
+                profile: None,
                 print_hello: false,
                 event_log_path: None,
                 debug_record: false,
@@ -682,10 +1186,28 @@ mod tests {
                 benchmark_warn_regression_pct: None,
                 doctor: false,
                 doctor_json_output: false,
+                doctor_fix: false,
+                doctor_fix_yes: false,
+                doctor_fix_only: None,
                 cleanup_sessions: false,
                 cleanup_sessions_apply: false,
                 cleanup_sessions_include_stale: false,
                 cleanup_sessions_include_attached: false,
+                gc: false,
+                gc_apply: false,
+                gc_json_output: false,
+                gc_idle_days: None,
+                task: None,
+                agent: None,
+                watch: None,
+                status: None,
+                export: None,
+                import: None,
+                apply: None,
+                config: None,
+                help: None,
+                version: false,
+                completions: None,
             }
         );
     }
@@ -714,6 +1236,7 @@ mod tests {
         assert_eq!(
             parsed,
             CliArgs {
+                profile: None,
                 print_hello: false,
                 event_log_path: None,
                 debug_record: false,
@@ -728,10 +1251,28 @@ mod tests {
                 benchmark_warn_regression_pct: Some(25),
                 doctor: false,
                 doctor_json_output: false,
+                doctor_fix: false,
+                doctor_fix_yes: false,
+                doctor_fix_only: None,
                 cleanup_sessions: false,
                 cleanup_sessions_apply: false,
                 cleanup_sessions_include_stale: false,
                 cleanup_sessions_include_attached: false,
+                gc: false,
+                gc_apply: false,
+                gc_json_output: false,
+                gc_idle_days: None,
+                task: None,
+                agent: None,
+                watch: None,
+                status: None,
+                export: None,
+                import: None,
+                apply: None,
+                config: None,
+                help: None,
+                version: false,
+                completions: None,
             }
         );
     }
@@ -768,6 +1309,7 @@ mod tests {
         assert_eq!(
             parsed,
             CliArgs {
+                profile: None,
                 print_hello: false,
                 event_log_path: None,
                 debug_record: false,
@@ -782,10 +1324,28 @@ mod tests {
                 benchmark_warn_regression_pct: None,
                 doctor: false,
                 doctor_json_output: false,
+                doctor_fix: false,
+                doctor_fix_yes: false,
+                doctor_fix_only: None,
                 cleanup_sessions: true,
                 cleanup_sessions_apply: true,
                 cleanup_sessions_include_stale: true,
                 cleanup_sessions_include_attached: true,
+                gc: false,
+                gc_apply: false,
+                gc_json_output: false,
+                gc_idle_days: None,
+                task: None,
+                agent: None,
+                watch: None,
+                status: None,
+                export: None,
+                import: None,
+                apply: None,
+                config: None,
+                help: None,
+                version: false,
+                completions: None,
             }
         );
     }
@@ -797,6 +1357,68 @@ mod tests {
         assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
     }
 
+    #[test]
+    fn cli_parser_reads_gc_options() {
+        let parsed = parse_cli_args(vec![
+            "gc".to_string(),
+            "--apply".to_string(),
+            "--idle-days".to_string(),
+            "30".to_string(),
+            "--json".to_string(),
+        ])
+        .expect("gc arguments should parse");
+
+        assert_eq!(
+            parsed,
+            CliArgs {
+                profile: None,
+                print_hello: false,
+                event_log_path: None,
+                debug_record: false,
+                replay_trace_path: None,
+                replay_snapshot_path: None,
+                replay_emit_test_name: None,
+                replay_invariant_only: false,
+                benchmark_scale: false,
+                benchmark_json_output: false,
+                benchmark_baseline_path: None,
+                benchmark_write_baseline_path: None,
+                benchmark_warn_regression_pct: None,
+                doctor: false,
+                doctor_json_output: false,
+                doctor_fix: false,
+                doctor_fix_yes: false,
+                doctor_fix_only: None,
+                cleanup_sessions: false,
+                cleanup_sessions_apply: false,
+                cleanup_sessions_include_stale: false,
+                cleanup_sessions_include_attached: false,
+                gc: true,
+                gc_apply: true,
+                gc_json_output: true,
+                gc_idle_days: Some(30),
+                task: None,
+                agent: None,
+                watch: None,
+                status: None,
+                export: None,
+                import: None,
+                apply: None,
+                config: None,
+                help: None,
+                version: false,
+                completions: None,
+            }
+        );
+    }
+
+    #[test]
+    fn cli_parser_rejects_idle_days_without_gc() {
+        let error = parse_cli_args(vec!["--idle-days".to_string(), "7".to_string()])
+            .expect_err("gc flags without gc command should fail");
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
     #[test]
     fn cli_parser_reads_doctor_options() {
         let parsed = parse_cli_args(vec!["doctor".to_string(), "--json".to_string()])
@@ -805,6 +1427,7 @@ mod tests {
         assert_eq!(
             parsed,
             CliArgs {
+                profile: None,
                 print_hello: false,
                 event_log_path: None,
                 debug_record: false,
@@ -819,14 +1442,61 @@ mod tests {
                 benchmark_warn_regression_pct: None,
                 doctor: true,
                 doctor_json_output: true,
+                doctor_fix: false,
+                doctor_fix_yes: false,
+                doctor_fix_only: None,
                 cleanup_sessions: false,
                 cleanup_sessions_apply: false,
                 cleanup_sessions_include_stale: false,
                 cleanup_sessions_include_attached: false,
+                gc: false,
+                gc_apply: false,
+                gc_json_output: false,
+                gc_idle_days: None,
+                task: None,
+                agent: None,
+                watch: None,
+                status: None,
+                export: None,
+                import: None,
+                apply: None,
+                config: None,
+                help: None,
+                version: false,
+                completions: None,
             }
         );
     }
 
+    #[test]
+    fn cli_parser_reads_doctor_fix_options() {
+        let parsed = parse_cli_args(vec![
+            "doctor".to_string(),
+            "--fix".to_string(),
+            "--yes".to_string(),
+            "--only".to_string(),
+            "write_base_marker".to_string(),
+        ])
+        .expect("doctor fix arguments should parse");
+        assert!(parsed.doctor_fix);
+        assert!(parsed.doctor_fix_yes);
+        assert_eq!(
+            parsed.doctor_fix_only,
+            Some(DoctorRepairAction::WriteBaseMarker)
+        );
+
+        for rejected in [
+            vec!["doctor", "--fix", "--only", "bogus"],
+            vec!["doctor", "--fix", "--only"],
+            vec!["doctor", "--yes"],
+            vec!["doctor", "--fix", "--json"],
+        ] {
+            let error = parse_cli_args(rejected.iter().map(|arg| arg.to_string()))
+                .expect_err("invalid doctor fix arguments should fail");
+            assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+        }
+    }
+
     #[test]
     fn cli_parser_rejects_doctor_combined_with_other_modes() {
         let error = parse_cli_args(vec![
@@ -838,6 +1508,193 @@ mod tests {
         assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
     }
 
+    #[test]
+    fn cli_parser_reads_task_subcommand() {
+        let parsed = parse_cli_args(vec![
+            "task".to_string(),
+            "delete".to_string(),
+            "feature-a".to_string(),
+            "--json".to_string(),
+        ])
+        .expect("task arguments should parse");
+
+        let task = parsed.task.expect("task command should be set");
+        assert!(task.json_output);
+        assert_eq!(
+            task.command,
+            TaskCommand::Delete {
+                task: "feature-a".to_string(),
+                delete_local_branch: false,
+            }
+        );
+    }
+
+    #[test]
+    fn cli_parser_rejects_task_combined_with_other_modes() {
+        let error = parse_cli_args(vec![
+            "doctor".to_string(),
+            "task".to_string(),
+            "list".to_string(),
+        ])
+        .expect_err("task should not combine with doctor");
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn cli_parser_reads_agent_subcommand() {
+        let parsed = parse_cli_args(vec![
+            "agent".to_string(),
+            "send".to_string(),
+            "feature-a".to_string(),
+            "--repo".to_string(),
+            "api".to_string(),
+            "rerun the tests".to_string(),
+        ])
+        .expect("agent arguments should parse");
+
+        assert_eq!(parsed.task, None);
+        assert_eq!(
+            parsed.agent.expect("agent command should be set").command,
+            AgentCommand::Send {
+                task: "feature-a".to_string(),
+                repository: Some("api".to_string()),
+                text: "rerun the tests".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn cli_parser_reads_watch_subcommand() {
+        let parsed = parse_cli_args(vec!["watch".to_string(), "--json".to_string()])
+            .expect("watch arguments should parse");
+        assert!(
+            parsed
+                .watch
+                .expect("watch command should be set")
+                .json_output
+        );
+
+        let error = parse_cli_args(vec!["gc".to_string(), "watch".to_string()])
+            .expect_err("watch should not combine with gc");
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn cli_parser_reads_status_subcommand() {
+        let parsed = parse_cli_args(vec![
+            "status".to_string(),
+            "--format".to_string(),
+            "{waiting} waiting".to_string(),
+        ])
+        .expect("status arguments should parse");
+        assert_eq!(
+            parsed
+                .status
+                .expect("status command should be set")
+                .format
+                .as_deref(),
+            Some("{waiting} waiting")
+        );
+    }
+
+    #[test]
+    fn cli_parser_rejects_unknown_flags_and_commands() {
+        let unknown_flag = parse_cli_args(vec!["--evnet-log".to_string()])
+            .expect_err("unknown root flag should fail");
+        assert_eq!(unknown_flag.kind(), std::io::ErrorKind::InvalidInput);
+
+        let unknown_command =
+            parse_cli_args(vec!["doctr".to_string()]).expect_err("unknown command should fail");
+        assert_eq!(unknown_command.kind(), std::io::ErrorKind::InvalidInput);
+        assert!(
+            unknown_command
+                .to_string()
+                .contains("did you mean `doctor`")
+        );
+
+        let foreign_flag = parse_cli_args(vec!["doctor".to_string(), "--apply".to_string()])
+            .expect_err("gc flag should not be accepted by doctor");
+        assert_eq!(foreign_flag.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn cli_parser_reads_help_version_and_completions() {
+        let root = parse_cli_args(vec!["--help".to_string()]).expect("help should parse");
+        assert!(root.help.expect("root help").contains("Commands:"));
+
+        let gc = parse_cli_args(vec!["gc".to_string(), "--help".to_string()])
+            .expect("gc help should parse");
+        assert!(gc.help.expect("gc help").contains("--idle-days <N>"));
+
+        let task = parse_cli_args(vec!["help".to_string(), "task".to_string()])
+            .expect("help topic should parse");
+        assert!(
+            task.help
+                .expect("task help")
+                .contains("grove task <action>")
+        );
+
+        assert!(
+            parse_cli_args(vec!["--version".to_string()])
+                .expect("version should parse")
+                .version
+        );
+        assert_eq!(
+            parse_cli_args(vec!["completions".to_string(), "fish".to_string()])
+                .expect("completions should parse")
+                .completions,
+            Some(CompletionShell::Fish)
+        );
+        assert!(parse_cli_args(vec!["completions".to_string(), "tcsh".to_string()]).is_err());
+    }
+
+    #[test]
+    fn cli_parser_treats_help_in_a_flag_value_position_as_the_value() {
+        let args = |values: &[&str]| {
+            values
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<String>>()
+        };
+
+        let help = parse_cli_args(args(&["agent", "send", "ws", "--help"]))
+            .expect("agent help should parse");
+        assert!(help.help.is_some());
+        assert!(help.agent.is_none());
+
+        let start = parse_cli_args(args(&["agent", "start", "ws", "--prompt", "--help"]))
+            .expect("prompt value should parse");
+        assert!(start.help.is_none());
+        assert_eq!(
+            start.agent.map(|agent| agent.command),
+            Some(AgentCommand::Start {
+                task: "ws".to_string(),
+                repository: None,
+                agent: None,
+                prompt: Some("--help".to_string()),
+                permission_mode: None,
+            })
+        );
+
+        let status =
+            parse_cli_args(args(&["status", "--format", "-h"])).expect("format value should parse");
+        assert!(status.help.is_none());
+        assert!(status.status.is_some());
+
+        let export = parse_cli_args(args(&["export", "feature-a", "-o", "--help"]))
+            .expect("output value should parse");
+        assert!(export.help.is_none());
+    }
+
+    #[test]
+    fn exit_code_distinguishes_usage_and_missing_targets() {
+        let usage = std::io::Error::new(std::io::ErrorKind::InvalidInput, "bad flag");
+        let missing = std::io::Error::new(std::io::ErrorKind::NotFound, "no task");
+        assert_eq!(exit_code(&usage), 2);
+        assert_eq!(exit_code(&missing), 3);
+        assert_eq!(exit_code(&std::io::Error::other("git failed")), 1);
+    }
+
     #[test]
     fn doctor_exit_code_is_zero_for_clean_report() {
         let report = DoctorReport::from_findings(Vec::new());
@@ -0,0 +1,288 @@
+use serde::Serialize;
+
+use crate::infrastructure::event_log::now_millis;
+use crate::infrastructure::status_snapshot::{
+    StatusSnapshot, load_status_snapshot_from_path, status_snapshot_path,
+};
+
+use super::task::{invalid_input, print_json};
+
+const DEFAULT_STATUS_FORMAT: &str = "{running} running {waiting} waiting {error} errored";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct StatusArgs {
+    pub(crate) format: Option<String>,
+    pub(crate) json_output: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+struct StatusSummary {
+    total: usize,
+    running: usize,
+    waiting: usize,
+    error: usize,
+    done: usize,
+    oldest_waiting: Option<String>,
+    oldest_waiting_since_ms: Option<u64>,
+    updated_at_ms: Option<u64>,
+    stale: bool,
+}
+
+pub(crate) fn parse_status_args(
+    args: &mut impl Iterator<Item = String>,
+) -> std::io::Result<StatusArgs> {
+    let mut format = None;
+    let mut json_output = false;
+    while let Some(argument) = args.next() {
+        match argument.as_str() {
+            "--format" => {
+                let Some(template) = args.next() else {
+                    return Err(invalid_input("--format requires a template"));
+                };
+                format = Some(template);
+            }
+            "--json" => {
+                json_output = true;
+            }
+            flag if flag.starts_with("--") => {
+                return Err(invalid_input(format!("unknown status flag `{flag}`")));
+            }
+            _ => {
+                return Err(invalid_input(format!(
+                    "unexpected argument `{argument}` for status"
+                )));
+            }
+        }
+    }
+    if json_output && format.is_some() {
+        return Err(invalid_input("--format cannot be combined with --json"));
+    }
+    Ok(StatusArgs {
+        format,
+        json_output,
+    })
+}
+
+pub(crate) fn run_status(args: &StatusArgs) -> std::io::Result<()> {
+    let snapshot = match status_snapshot_path() {
+        Some(path) => load_status_snapshot_from_path(&path).map_err(std::io::Error::other)?,
+        None => None,
+    };
+    let now_ms = now_millis();
+    let summary = summarize_snapshot(snapshot.as_ref(), now_ms);
+    if args.json_output {
+        return print_json(&summary);
+    }
+
+    let template = args.format.as_deref().unwrap_or(DEFAULT_STATUS_FORMAT);
+    let line = render_status_template(template, &summary, now_ms).map_err(invalid_input)?;
+    println!("{line}");
+    Ok(())
+}
+
+/// A snapshot its writer stopped refreshing reports nothing running, so a
+/// crashed TUI does not leave a status line stuck on old counts.
+fn summarize_snapshot(snapshot: Option<&StatusSnapshot>, now_ms: u64) -> StatusSummary {
+    let Some(snapshot) = snapshot else {
+        return StatusSummary::default();
+    };
+    if snapshot.is_stale(now_ms) {
+        return StatusSummary {
+            updated_at_ms: Some(snapshot.updated_at_ms),
+            stale: true,
+            ..StatusSummary::default()
+        };
+    }
+
+    let mut summary = StatusSummary {
+        total: snapshot.workspaces.len(),
+        updated_at_ms: Some(snapshot.updated_at_ms),
+        ..StatusSummary::default()
+    };
+    for entry in &snapshot.workspaces {
+        match entry.status.as_str() {
+            "active" | "thinking" => summary.running += 1,
+            "waiting" => {
+                summary.running += 1;
+                summary.waiting += 1;
+                let since = entry.waiting_since_ms.unwrap_or(snapshot.updated_at_ms);
+                if summary
+                    .oldest_waiting_since_ms
+                    .is_none_or(|oldest| since < oldest)
+                {
+                    summary.oldest_waiting_since_ms = Some(since);
+                    summary.oldest_waiting = Some(entry.task.clone());
+                }
+            }
+            "error" => summary.error += 1,
+            "done" => summary.done += 1,
+            _ => {}
+        }
+    }
+    summary
+}
+
+fn render_status_template(
+    template: &str,
+    summary: &StatusSummary,
+    now_ms: u64,
+) -> Result<String, String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        let Some(length) = rest[start..].find('}') else {
+            rest = &rest[start..];
+            break;
+        };
+        let placeholder = &rest[start + 1..start + length];
+        let value = match placeholder {
+            "total" => summary.total.to_string(),
+            "running" => summary.running.to_string(),
+            "waiting" => summary.waiting.to_string(),
+            "error" => summary.error.to_string(),
+            "done" => summary.done.to_string(),
+            "oldest_waiting" => summary.oldest_waiting.clone().unwrap_or_default(),
+            "oldest_waiting_age" => summary
+                .oldest_waiting_since_ms
+                .map(|since| format_age(now_ms.saturating_sub(since)))
+                .unwrap_or_default(),
+            other => return Err(format!("unknown status placeholder `{{{other}}}`")),
+        };
+        rendered.push_str(&value);
+        rest = &rest[start + length + 1..];
+    }
+    rendered.push_str(rest);
+    Ok(rendered)
+}
+
+fn format_age(elapsed_ms: u64) -> String {
+    let seconds = elapsed_ms / 1000;
+    if seconds < 60 {
+        return format!("{seconds}s");
+    }
+    if seconds < 3600 {
+        return format!("{}m", seconds / 60);
+    }
+    if seconds < 86_400 {
+        return format!("{}h", seconds / 3600);
+    }
+    format!("{}d", seconds / 86_400)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        DEFAULT_STATUS_FORMAT, StatusArgs, parse_status_args, render_status_template,
+        summarize_snapshot,
+    };
+    use crate::infrastructure::status_snapshot::{
+        STATUS_SNAPSHOT_STALE_MS, StatusSnapshot, StatusSnapshotEntry,
+    };
+    use std::path::PathBuf;
+
+    fn entry(task: &str, status: &str, waiting_since_ms: Option<u64>) -> StatusSnapshotEntry {
+        StatusSnapshotEntry {
+            task: task.to_string(),
+            repository: "grove".to_string(),
+            path: PathBuf::from(format!("/tasks/{task}/grove")),
+            status: status.to_string(),
+            waiting_since_ms,
+        }
+    }
+
+    fn fixture_snapshot() -> StatusSnapshot {
+        StatusSnapshot {
+            updated_at_ms: 600_000,
+            writer: None,
+            workspaces: vec![
+                entry("alpha", "active", None),
+                entry("beta", "waiting", Some(500_000)),
+                entry("gamma", "waiting", Some(300_000)),
+                entry("delta", "error", None),
+                entry("epsilon", "idle", None),
+            ],
+        }
+    }
+
+    #[test]
+    fn parse_status_args_reads_format_and_rejects_json_combination() {
+        let mut args = vec!["--format".to_string(), "{waiting}".to_string()].into_iter();
+        assert_eq!(
+            parse_status_args(&mut args).expect("format should parse"),
+            StatusArgs {
+                format: Some("{waiting}".to_string()),
+                json_output: false,
+            }
+        );
+
+        let mut missing = vec!["--format".to_string()].into_iter();
+        assert!(parse_status_args(&mut missing).is_err());
+
+        let mut combined = vec![
+            "--json".to_string(),
+            "--format".to_string(),
+            "{total}".to_string(),
+        ]
+        .into_iter();
+        assert!(parse_status_args(&mut combined).is_err());
+    }
+
+    #[test]
+    fn summary_counts_statuses_and_picks_oldest_waiting_task() {
+        let summary = summarize_snapshot(Some(&fixture_snapshot()), 600_000);
+
+        assert_eq!(summary.total, 5);
+        assert_eq!(summary.running, 3);
+        assert_eq!(summary.waiting, 2);
+        assert_eq!(summary.error, 1);
+        assert_eq!(summary.oldest_waiting.as_deref(), Some("gamma"));
+        assert_eq!(summary.oldest_waiting_since_ms, Some(300_000));
+        assert!(!summary.stale);
+    }
+
+    #[test]
+    fn stale_snapshot_reports_no_running_workspaces() {
+        let now_ms = 600_000 + STATUS_SNAPSHOT_STALE_MS + 1;
+        let summary = summarize_snapshot(Some(&fixture_snapshot()), now_ms);
+
+        assert!(summary.stale);
+        assert_eq!(summary.updated_at_ms, Some(600_000));
+        assert_eq!(
+            render_status_template(DEFAULT_STATUS_FORMAT, &summary, now_ms),
+            Ok("0 running 0 waiting 0 errored".to_string())
+        );
+    }
+
+    #[test]
+    fn template_renders_placeholders_and_rejects_unknown_ones() {
+        let summary = summarize_snapshot(Some(&fixture_snapshot()), 600_000);
+
+        assert_eq!(
+            render_status_template(DEFAULT_STATUS_FORMAT, &summary, 900_000),
+            Ok("3 running 2 waiting 1 errored".to_string())
+        );
+        assert_eq!(
+            render_status_template(
+                "#[fg=red]{oldest_waiting} {oldest_waiting_age}",
+                &summary,
+                900_000
+            ),
+            Ok("#[fg=red]gamma 10m".to_string())
+        );
+        assert_eq!(
+            render_status_template("{waiting} {", &summary, 0),
+            Ok("2 {".to_string())
+        );
+        assert!(render_status_template("{bogus}", &summary, 0).is_err());
+    }
+
+    #[test]
+    fn missing_snapshot_renders_zero_counts() {
+        let summary = summarize_snapshot(None, 0);
+        assert_eq!(
+            render_status_template("{running}/{waiting} {oldest_waiting}", &summary, 0),
+            Ok("0/0 ".to_string())
+        );
+    }
+}
@@ -0,0 +1,770 @@
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::application::session_cleanup::list_tmux_sessions;
+use crate::application::task_discovery::bootstrap_task_data_for_root_with_sessions;
+use crate::application::task_lifecycle::{
+    CreateTaskRequest, DeleteTaskRequest, TaskBranchSource, create_task, delete_task,
+    task_lifecycle_error_message,
+};
+use crate::application::workspace_lifecycle::{
+    CommandGitRunner, CommandSetupCommandRunner, CommandSetupScriptRunner, MergeWorkspaceRequest,
+    RuntimeSessionTerminator, UpdateWorkspaceFromBaseRequest, merge_workspace_with_terminator,
+    update_workspace_from_base_with_terminator,
+};
+use crate::domain::{AgentType, Task, WorkspaceStatus, Worktree};
+use crate::infrastructure::config::RepositoryConfig;
+use crate::infrastructure::paths::{refer_to_same_location, tasks_root};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum TaskCommand {
+    List,
+    Create {
+        name: String,
+        repositories: Vec<String>,
+        agent: AgentType,
+    },
+    Delete {
+        task: String,
+        delete_local_branch: bool,
+    },
+    Merge {
+        task: String,
+        repository: Option<String>,
+        cleanup_workspace: bool,
+        cleanup_local_branch: bool,
+    },
+    Update {
+        task: String,
+        repository: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TaskArgs {
+    pub(crate) command: TaskCommand,
+    pub(crate) json_output: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct TaskSummary {
+    name: String,
+    slug: String,
+    root_path: PathBuf,
+    branch: String,
+    worktrees: Vec<WorktreeSummary>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct WorktreeSummary {
+    repository: String,
+    repository_path: PathBuf,
+    path: PathBuf,
+    branch: String,
+    base_branch: Option<String>,
+    agent: &'static str,
+    status: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct TaskChangeReport {
+    task: TaskSummary,
+    warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct WorktreeOutcome {
+    repository: String,
+    branch: String,
+    base_branch: String,
+    ok: bool,
+    error: Option<String>,
+    warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct TaskOperationReport {
+    task: String,
+    action: &'static str,
+    worktrees: Vec<WorktreeOutcome>,
+}
+
+pub(super) fn invalid_input(message: impl Into<String>) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidInput, message.into())
+}
+
+pub(super) fn not_found(message: impl Into<String>) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::NotFound, message.into())
+}
+
+pub(crate) fn parse_task_args(
+    args: &mut impl Iterator<Item = String>,
+) -> std::io::Result<TaskArgs> {
+    let Some(action) = args.next() else {
+        return Err(invalid_input(
+            "task requires an action (`list`, `create`, `delete`, `merge`, `update`)",
+        ));
+    };
+    if !matches!(
+        action.as_str(),
+        "list" | "create" | "delete" | "merge" | "update"
+    ) {
+        return Err(invalid_input(format!("unsupported task action `{action}`")));
+    }
+
+    let mut json_output = false;
+    let mut target = None;
+    let mut repositories = Vec::new();
+    let mut agent = None;
+    let mut delete_local_branch = false;
+    let mut keep_worktree = false;
+    let mut keep_branch = false;
+    while let Some(argument) = args.next() {
+        match argument.as_str() {
+            "--json" => {
+                json_output = true;
+            }
+            "--repo" => {
+                let Some(repository) = args.next() else {
+                    return Err(invalid_input("--repo requires a project name or path"));
+                };
+                repositories.push(repository);
+            }
+            "--agent" => {
+                let Some(value) = args.next() else {
+                    return Err(invalid_input("--agent requires `claude` or `codex`"));
+                };
+                let Some(parsed) = AgentType::from_marker(value.as_str()) else {
+                    return Err(invalid_input(format!("unsupported agent `{value}`")));
+                };
+                agent = Some(parsed);
+            }
+            "--delete-branch" => {
+                delete_local_branch = true;
+            }
+            "--keep-worktree" => {
+                keep_worktree = true;
+            }
+            "--keep-branch" => {
+                keep_branch = true;
+            }
+            flag if flag.starts_with("--") => {
+                return Err(invalid_input(format!("unknown task flag `{flag}`")));
+            }
+            _ => {
+                if target.is_some() {
+                    return Err(invalid_input(format!(
+                        "unexpected argument `{argument}` for task {action}"
+                    )));
+                }
+                target = Some(argument);
+            }
+        }
+    }
+
+    let allows = |flag_set: bool, allowed: bool, flag: &str| {
+        if flag_set && !allowed {
+            return Err(invalid_input(format!(
+                "{flag} is not supported by task {action}"
+            )));
+        }
+        Ok(())
+    };
+    let single_repository = matches!(action.as_str(), "merge" | "update");
+    allows(
+        !repositories.is_empty(),
+        action == "create" || single_repository,
+        "--repo",
+    )?;
+    if single_repository && repositories.len() > 1 {
+        return Err(invalid_input(format!(
+            "task {action} accepts a single --repo"
+        )));
+    }
+    allows(agent.is_some(), action == "create", "--agent")?;
+    allows(delete_local_branch, action == "delete", "--delete-branch")?;
+    allows(keep_worktree, action == "merge", "--keep-worktree")?;
+    allows(keep_branch, action == "merge", "--keep-branch")?;
+
+    if action == "list" {
+        if let Some(target) = target {
+            return Err(invalid_input(format!(
+                "unexpected argument `{target}` for task list"
+            )));
+        }
+        return Ok(TaskArgs {
+            command: TaskCommand::List,
+            json_output,
+        });
+    }
+    let Some(target) = target else {
+        return Err(invalid_input(if action == "create" {
+            "task create requires a task name".to_string()
+        } else {
+            format!("task {action} requires a task name or slug")
+        }));
+    };
+
+    let command = match action.as_str() {
+        "create" => {
+            if repositories.is_empty() {
+                return Err(invalid_input("task create requires at least one --repo"));
+            }
+            TaskCommand::Create {
+                name: target,
+                repositories,
+                agent: agent.unwrap_or(AgentType::Codex),
+            }
+        }
+        "delete" => TaskCommand::Delete {
+            task: target,
+            delete_local_branch,
+        },
+        "merge" => TaskCommand::Merge {
+            task: target,
+            repository: repositories.pop(),
+            cleanup_workspace: !keep_worktree,
+            cleanup_local_branch: !keep_branch,
+        },
+        _ => TaskCommand::Update {
+            task: target,
+            repository: repositories.pop(),
+        },
+    };
+
+    Ok(TaskArgs {
+        command,
+        json_output,
+    })
+}
+
+fn summarize_task(task: &Task) -> TaskSummary {
+    TaskSummary {
+        name: task.name.clone(),
+        slug: task.slug.clone(),
+        root_path: task.root_path.clone(),
+        branch: task.branch.clone(),
+        worktrees: task
+            .worktrees
+            .iter()
+            .map(|worktree| WorktreeSummary {
+                repository: worktree.repository_name.clone(),
+                repository_path: worktree.repository_path.clone(),
+                path: worktree.path.clone(),
+                branch: worktree.branch.clone(),
+                base_branch: worktree.base_branch.clone(),
+                agent: worktree.agent.marker(),
+                status: if worktree.is_main_checkout() {
+                    WorkspaceStatus::Main
+                } else {
+                    worktree.status
+                }
+                .label(),
+            })
+            .collect(),
+    }
+}
+
+pub(super) fn find_task<'a>(tasks: &'a [Task], name: &str) -> std::io::Result<&'a Task> {
+    tasks
+        .iter()
+        .find(|task| task.slug == name)
+        .or_else(|| tasks.iter().find(|task| task.name == name))
+        .ok_or_else(|| not_found(format!("task `{name}` not found")))
+}
+
+fn resolve_repository(
+    projects: &[RepositoryConfig],
+    value: &str,
+) -> std::io::Result<RepositoryConfig> {
+    projects
+        .iter()
+        .find(|project| project.name == value)
+        .or_else(|| {
+            projects
+                .iter()
+                .find(|project| refer_to_same_location(project.path.as_path(), Path::new(value)))
+        })
+        .cloned()
+        .ok_or_else(|| not_found(format!("project `{value}` is not configured")))
+}
+
+pub(super) fn task_worktrees<'a>(
+    task: &'a Task,
+    repository: Option<&str>,
+) -> std::io::Result<Vec<&'a Worktree>> {
+    let Some(repository) = repository else {
+        return Ok(task.worktrees.iter().collect());
+    };
+    let selected = task
+        .worktrees
+        .iter()
+        .filter(|worktree| worktree.repository_name == repository)
+        .collect::<Vec<&Worktree>>();
+    if selected.is_empty() {
+        return Err(not_found(format!(
+            "task `{}` has no worktree for `{repository}`",
+            task.slug
+        )));
+    }
+    Ok(selected)
+}
+
+pub(super) fn workspace_name(task: &Task, worktree: &Worktree) -> String {
+    if task.worktrees.len() == 1 {
+        task.name.clone()
+    } else {
+        worktree.repository_name.clone()
+    }
+}
+
+fn worktree_base_branch(worktree: &Worktree, allow_main: bool) -> Result<String, String> {
+    if worktree.is_main_checkout() {
+        if allow_main {
+            return Ok(worktree.branch.clone());
+        }
+        return Err("cannot merge base workspace".to_string());
+    }
+    let Some(base_branch) = worktree
+        .base_branch
+        .clone()
+        .filter(|value| !value.trim().is_empty())
+    else {
+        return Err("workspace base branch marker is missing".to_string());
+    };
+    if base_branch == worktree.branch {
+        return Err("workspace branch already matches base branch".to_string());
+    }
+    Ok(base_branch)
+}
+
+pub(super) fn load_tasks() -> std::io::Result<Vec<Task>> {
+    let task_root = tasks_root().ok_or_else(|| std::io::Error::other("task root unavailable"))?;
+    let running_sessions = list_tmux_sessions()
+        .map(|sessions| {
+            sessions
+                .into_iter()
+                .map(|session| session.name)
+                .collect::<HashSet<String>>()
+        })
+        .unwrap_or_default();
+    Ok(bootstrap_task_data_for_root_with_sessions(task_root.as_path(), &running_sessions).tasks)
+}
+
+pub(super) fn print_json(value: &impl Serialize) -> std::io::Result<()> {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(value).map_err(std::io::Error::other)?
+    );
+    Ok(())
+}
+
+pub(super) fn print_warnings(warnings: &[String]) {
+    for warning in warnings {
+        eprintln!("warning {warning}");
+    }
+}
+
+fn run_task_list(args: &TaskArgs) -> std::io::Result<()> {
+    let summaries = load_tasks()?
+        .iter()
+        .map(summarize_task)
+        .collect::<Vec<TaskSummary>>();
+    if args.json_output {
+        return print_json(&summaries);
+    }
+
+    if summaries.is_empty() {
+        println!("task list: no tasks");
+        return Ok(());
+    }
+    for task in &summaries {
+        println!(
+            "{} [{}] {}",
+            task.slug,
+            task.branch,
+            task.root_path.display()
+        );
+        for worktree in &task.worktrees {
+            println!(
+                "  - {} branch={} base={} agent={} status={} path={}",
+                worktree.repository,
+                worktree.branch,
+                worktree.base_branch.as_deref().unwrap_or("-"),
+                worktree.agent,
+                worktree.status,
+                worktree.path.display()
+            );
+        }
+    }
+    Ok(())
+}
+
+fn run_task_create(
+    args: &TaskArgs,
+    name: &str,
+    repositories: &[String],
+    agent: AgentType,
+) -> std::io::Result<()> {
+    let loaded_config = crate::infrastructure::config::load().map_err(std::io::Error::other)?;
+    let repositories = repositories
+        .iter()
+        .map(|value| resolve_repository(loaded_config.config.projects.as_slice(), value))
+        .collect::<std::io::Result<Vec<RepositoryConfig>>>()?;
+    let request = CreateTaskRequest {
+        task_name: name.to_string(),
+        repositories,
+        agent,
+        branch_source: TaskBranchSource::BaseBranch,
+    };
+    request
+        .validate()
+        .map_err(|error| invalid_input(task_lifecycle_error_message(&error)))?;
+
+    let result = create_task(
+        &request,
+        &CommandGitRunner,
+        &CommandSetupScriptRunner,
+        &CommandSetupCommandRunner,
+    )
+    .map_err(|error| std::io::Error::other(task_lifecycle_error_message(&error)))?;
+    let report = TaskChangeReport {
+        task: summarize_task(&result.task),
+        warnings: result.warnings,
+    };
+    if args.json_output {
+        return print_json(&report);
+    }
+
+    println!(
+        "created {} at {}",
+        report.task.slug,
+        report.task.root_path.display()
+    );
+    for worktree in &report.task.worktrees {
+        println!(
+            "  - {} branch={} path={}",
+            worktree.repository,
+            worktree.branch,
+            worktree.path.display()
+        );
+    }
+    print_warnings(report.warnings.as_slice());
+    Ok(())
+}
+
+fn run_task_delete(args: &TaskArgs, name: &str, delete_local_branch: bool) -> std::io::Result<()> {
+    let tasks = load_tasks()?;
+    let task = find_task(tasks.as_slice(), name)?.clone();
+    let summary = summarize_task(&task);
+    let (result, warnings) = delete_task(DeleteTaskRequest {
+        task,
+        delete_local_branch,
+        kill_tmux_sessions: true,
+    });
+    result.map_err(std::io::Error::other)?;
+
+    let report = TaskChangeReport {
+        task: summary,
+        warnings,
+    };
+    if args.json_output {
+        return print_json(&report);
+    }
+    println!("deleted {}", report.task.slug);
+    print_warnings(report.warnings.as_slice());
+    Ok(())
+}
+
+fn run_worktree_operation(
+    args: &TaskArgs,
+    name: &str,
+    repository: Option<&str>,
+    action: &'static str,
+    run: impl Fn(&Task, &Worktree) -> Result<(String, Vec<String>), (String, String)>,
+) -> std::io::Result<()> {
+    let tasks = load_tasks()?;
+    let task = find_task(tasks.as_slice(), name)?;
+    let worktrees = task_worktrees(task, repository)?;
+
+    let outcomes = worktrees
+        .into_iter()
+        .map(|worktree| {
+            let (base_branch, ok, error, warnings) = match run(task, worktree) {
+                Ok((base_branch, warnings)) => (base_branch, true, None, warnings),
+                Err((base_branch, error)) => (base_branch, false, Some(error), Vec::new()),
+            };
+            WorktreeOutcome {
+                repository: worktree.repository_name.clone(),
+                branch: worktree.branch.clone(),
+                base_branch,
+                ok,
+                error,
+                warnings,
+            }
+        })
+        .collect::<Vec<WorktreeOutcome>>();
+    let failures = outcomes.iter().filter(|outcome| !outcome.ok).count();
+    let report = TaskOperationReport {
+        task: task.slug.clone(),
+        action,
+        worktrees: outcomes,
+    };
+
+    if args.json_output {
+        print_json(&report)?;
+    } else {
+        for outcome in &report.worktrees {
+            match outcome.error.as_ref() {
+                None => println!(
+                    "{action} {} ({} <- {})",
+                    outcome.repository, outcome.base_branch, outcome.branch
+                ),
+                Some(error) => eprintln!("failed {}: {error}", outcome.repository),
+            }
+            print_warnings(outcome.warnings.as_slice());
+        }
+    }
+
+    if failures == 0 {
+        return Ok(());
+    }
+    Err(std::io::Error::other(format!(
+        "task {action} failed for {failures} worktree(s)"
+    )))
+}
+
+fn run_task_merge(
+    args: &TaskArgs,
+    name: &str,
+    repository: Option<&str>,
+    cleanup_workspace: bool,
+    cleanup_local_branch: bool,
+) -> std::io::Result<()> {
+    run_worktree_operation(args, name, repository, "merged", |task, worktree| {
+        let base_branch =
+            worktree_base_branch(worktree, false).map_err(|error| (String::new(), error))?;
+        let request = MergeWorkspaceRequest {
+            task_slug: Some(task.slug.clone()),
+            project_name: Some(worktree.repository_name.clone()),
+            project_path: Some(worktree.repository_path.clone()),
+            workspace_name: workspace_name(task, worktree),
+            workspace_branch: worktree.branch.clone(),
+            workspace_path: worktree.path.clone(),
+            base_branch: base_branch.clone(),
+            cleanup_workspace,
+            cleanup_local_branch,
+        };
+        let (result, warnings) =
+            merge_workspace_with_terminator(request, &RuntimeSessionTerminator);
+        match result {
+            Ok(()) => Ok((base_branch, warnings)),
+            Err(error) => Err((base_branch, error)),
+        }
+    })
+}
+
+fn run_task_update(args: &TaskArgs, name: &str, repository: Option<&str>) -> std::io::Result<()> {
+    run_worktree_operation(args, name, repository, "updated", |task, worktree| {
+        let base_branch =
+            worktree_base_branch(worktree, true).map_err(|error| (String::new(), error))?;
+        let request = UpdateWorkspaceFromBaseRequest {
+            task_slug: Some(task.slug.clone()),
+            project_name: Some(worktree.repository_name.clone()),
+            project_path: Some(worktree.repository_path.clone()),
+            workspace_name: workspace_name(task, worktree),
+            workspace_branch: worktree.branch.clone(),
+            workspace_path: worktree.path.clone(),
+            base_branch: base_branch.clone(),
+        };
+        let (result, warnings) =
+            update_workspace_from_base_with_terminator(request, &RuntimeSessionTerminator);
+        match result {
+            Ok(()) => Ok((base_branch, warnings)),
+            Err(error) => Err((base_branch, error)),
+        }
+    })
+}
+
+pub(crate) fn run_task(args: &TaskArgs) -> std::io::Result<()> {
+    match &args.command {
+        TaskCommand::List => run_task_list(args),
+        TaskCommand::Create {
+            name,
+            repositories,
+            agent,
+        } => run_task_create(args, name, repositories.as_slice(), *agent),
+        TaskCommand::Delete {
+            task,
+            delete_local_branch,
+        } => run_task_delete(args, task, *delete_local_branch),
+        TaskCommand::Merge {
+            task,
+            repository,
+            cleanup_workspace,
+            cleanup_local_branch,
+        } => run_task_merge(
+            args,
+            task,
+            repository.as_deref(),
+            *cleanup_workspace,
+            *cleanup_local_branch,
+        ),
+        TaskCommand::Update { task, repository } => {
+            run_task_update(args, task, repository.as_deref())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        TaskArgs, TaskCommand, find_task, parse_task_args, summarize_task, task_worktrees,
+        worktree_base_branch,
+    };
+    use crate::domain::{AgentType, Task, WorkspaceStatus, Worktree};
+    use std::path::PathBuf;
+
+    fn parse(args: &[&str]) -> std::io::Result<TaskArgs> {
+        let mut args = args.iter().map(|argument| (*argument).to_string());
+        parse_task_args(&mut args)
+    }
+
+    fn fixture_task() -> Task {
+        let worktree = |repository: &str, base_branch: Option<&str>| {
+            Worktree::try_new(
+                repository.to_string(),
+                PathBuf::from(format!("/repos/{repository}")),
+                PathBuf::from(format!("/tasks/feature-a/{repository}")),
+                "feature-a".to_string(),
+                AgentType::Claude,
+                WorkspaceStatus::Idle,
+            )
+            .expect("worktree should be valid")
+            .with_base_branch(base_branch.map(str::to_string))
+        };
+        Task::try_new(
+            "Feature A".to_string(),
+            "feature-a".to_string(),
+            PathBuf::from("/tasks/feature-a"),
+            "feature-a".to_string(),
+            vec![worktree("api", Some("main")), worktree("web", None)],
+        )
+        .expect("task should be valid")
+    }
+
+    #[test]
+    fn task_parser_reads_each_action() {
+        assert_eq!(
+            parse(&["list", "--json"]).expect("list should parse"),
+            TaskArgs {
+                command: TaskCommand::List,
+                json_output: true,
+            }
+        );
+        assert_eq!(
+            parse(&[
+                "create",
+                "feature-a",
+                "--repo",
+                "api",
+                "--repo",
+                "web",
+                "--agent",
+                "claude",
+            ])
+            .expect("create should parse")
+            .command,
+            TaskCommand::Create {
+                name: "feature-a".to_string(),
+                repositories: vec!["api".to_string(), "web".to_string()],
+                agent: AgentType::Claude,
+            }
+        );
+        assert_eq!(
+            parse(&["delete", "feature-a", "--delete-branch"])
+                .expect("delete should parse")
+                .command,
+            TaskCommand::Delete {
+                task: "feature-a".to_string(),
+                delete_local_branch: true,
+            }
+        );
+        assert_eq!(
+            parse(&["merge", "feature-a", "--repo", "api", "--keep-branch"])
+                .expect("merge should parse")
+                .command,
+            TaskCommand::Merge {
+                task: "feature-a".to_string(),
+                repository: Some("api".to_string()),
+                cleanup_workspace: true,
+                cleanup_local_branch: false,
+            }
+        );
+        assert_eq!(
+            parse(&["update", "feature-a"])
+                .expect("update should parse")
+                .command,
+            TaskCommand::Update {
+                task: "feature-a".to_string(),
+                repository: None,
+            }
+        );
+    }
+
+    #[test]
+    fn task_parser_rejects_invalid_arguments() {
+        for args in [
+            &[][..],
+            &["rename", "feature-a"][..],
+            &["create", "feature-a"][..],
+            &["create", "--repo", "api"][..],
+            &["create", "feature-a", "--repo", "api", "--agent", "gpt"][..],
+            &["delete"][..],
+            &["delete", "feature-a", "--keep-branch"][..],
+            &["merge", "feature-a", "--repo", "api", "--repo", "web"][..],
+            &["update", "feature-a", "extra"][..],
+            &["list", "--force"][..],
+        ] {
+            let error = parse(args).expect_err("arguments should be rejected");
+            assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput, "{args:?}");
+        }
+    }
+
+    #[test]
+    fn task_lookup_and_worktree_selection_report_missing_targets() {
+        let tasks = vec![fixture_task()];
+        assert_eq!(
+            find_task(tasks.as_slice(), "Feature A")
+                .expect("task should match by name")
+                .slug,
+            "feature-a"
+        );
+        assert_eq!(
+            find_task(tasks.as_slice(), "missing")
+                .expect_err("unknown task should fail")
+                .kind(),
+            std::io::ErrorKind::NotFound
+        );
+
+        let task = &tasks[0];
+        assert_eq!(task_worktrees(task, None).expect("all worktrees").len(), 2);
+        assert_eq!(
+            task_worktrees(task, Some("docs"))
+                .expect_err("unknown repository should fail")
+                .kind(),
+            std::io::ErrorKind::NotFound
+        );
+
+        assert_eq!(
+            worktree_base_branch(&task.worktrees[0], false),
+            Ok("main".to_string())
+        );
+        assert!(worktree_base_branch(&task.worktrees[1], false).is_err());
+
+        let summary = summarize_task(task);
+        assert_eq!(summary.worktrees[0].agent, "claude");
+        assert_eq!(summary.worktrees[0].status, "idle");
+    }
+}
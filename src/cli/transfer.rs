@@ -0,0 +1,177 @@
+use std::path::PathBuf;
+
+use crate::application::task_transfer::{export_task, import_task_in_root};
+use crate::application::workspace_lifecycle::{
+    CommandGitRunner, CommandSetupCommandRunner, CommandSetupScriptRunner,
+};
+use crate::infrastructure::paths::tasks_root;
+
+use super::task::{find_task, invalid_input, load_tasks, print_warnings};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ExportArgs {
+    pub(crate) task: String,
+    pub(crate) output_path: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ImportArgs {
+    pub(crate) bundle_path: PathBuf,
+}
+
+pub(crate) fn parse_export_args(
+    args: &mut impl Iterator<Item = String>,
+) -> std::io::Result<ExportArgs> {
+    let mut task = None;
+    let mut output_path = None;
+    while let Some(argument) = args.next() {
+        match argument.as_str() {
+            "-o" | "--output" => {
+                let Some(path) = args.next() else {
+                    return Err(invalid_input("--output requires a file path"));
+                };
+                output_path = Some(PathBuf::from(path));
+            }
+            flag if flag.starts_with('-') => {
+                return Err(invalid_input(format!("unknown export flag `{flag}`")));
+            }
+            _ if task.is_none() => {
+                task = Some(argument);
+            }
+            _ => {
+                return Err(invalid_input(format!(
+                    "unexpected argument `{argument}` for export"
+                )));
+            }
+        }
+    }
+    let Some(task) = task else {
+        return Err(invalid_input("export requires a task name"));
+    };
+    Ok(ExportArgs {
+        output_path: output_path.unwrap_or_else(|| PathBuf::from(format!("{task}.tar"))),
+        task,
+    })
+}
+
+pub(crate) fn parse_import_args(
+    args: &mut impl Iterator<Item = String>,
+) -> std::io::Result<ImportArgs> {
+    let Some(bundle_path) = args.next() else {
+        return Err(invalid_input("import requires a bundle path"));
+    };
+    if bundle_path.starts_with('-') {
+        return Err(invalid_input(format!(
+            "unknown import flag `{bundle_path}`"
+        )));
+    }
+    if let Some(extra) = args.next() {
+        return Err(invalid_input(format!(
+            "unexpected argument `{extra}` for import"
+        )));
+    }
+    Ok(ImportArgs {
+        bundle_path: PathBuf::from(bundle_path),
+    })
+}
+
+pub(crate) fn run_export(args: &ExportArgs) -> std::io::Result<()> {
+    let tasks = load_tasks()?;
+    let task = find_task(tasks.as_slice(), args.task.as_str())?;
+    let result = export_task(task, args.output_path.as_path()).map_err(std::io::Error::other)?;
+    println!(
+        "exported {} to {} ({} worktrees, {} with uncommitted changes, {} untracked files, {} tabs)",
+        task.slug,
+        args.output_path.display(),
+        result.worktrees,
+        result.patches,
+        result.untracked_files,
+        result.tabs
+    );
+    Ok(())
+}
+
+pub(crate) fn run_import(args: &ImportArgs) -> std::io::Result<()> {
+    let loaded_config = crate::infrastructure::config::load().map_err(std::io::Error::other)?;
+    let tasks_root = tasks_root().ok_or_else(|| std::io::Error::other("task root unavailable"))?;
+    let result = import_task_in_root(
+        tasks_root.as_path(),
+        args.bundle_path.as_path(),
+        loaded_config.config.projects.as_slice(),
+        &CommandGitRunner,
+        &CommandSetupScriptRunner,
+        &CommandSetupCommandRunner,
+    )
+    .map_err(std::io::Error::other)?;
+
+    println!(
+        "imported {} at {}",
+        result.task.slug,
+        result.task.root_path.display()
+    );
+    for worktree in &result.task.worktrees {
+        println!(
+            "  - {} branch={} path={}",
+            worktree.repository_name,
+            worktree.branch,
+            worktree.path.display()
+        );
+    }
+    for (repository, tab) in &result.tabs {
+        println!(
+            "  tab {repository}: {} \"{}\"{} (was {}, not reopened)",
+            tab.kind,
+            tab.title,
+            tab.agent
+                .as_deref()
+                .map(|agent| format!(" agent={agent}"))
+                .unwrap_or_default(),
+            tab.session_name
+        );
+    }
+    print_warnings(result.warnings.as_slice());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExportArgs, ImportArgs, parse_export_args, parse_import_args};
+    use std::path::PathBuf;
+
+    fn args(values: &[&str]) -> std::vec::IntoIter<String> {
+        values
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<String>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn export_and_import_args_parse_paths() {
+        assert_eq!(
+            parse_export_args(&mut args(&["feature-a", "-o", "/tmp/a.tar"]))
+                .expect("export should parse"),
+            ExportArgs {
+                task: "feature-a".to_string(),
+                output_path: PathBuf::from("/tmp/a.tar"),
+            }
+        );
+        assert_eq!(
+            parse_export_args(&mut args(&["feature-a"]))
+                .expect("export should default output")
+                .output_path,
+            PathBuf::from("feature-a.tar")
+        );
+        assert!(parse_export_args(&mut args(&["-o", "a.tar"])).is_err());
+        assert!(parse_export_args(&mut args(&["a", "b"])).is_err());
+
+        assert_eq!(
+            parse_import_args(&mut args(&["task.tar"])).expect("import should parse"),
+            ImportArgs {
+                bundle_path: PathBuf::from("task.tar"),
+            }
+        );
+        assert!(parse_import_args(&mut args(&[])).is_err());
+        assert!(parse_import_args(&mut args(&["a.tar", "b.tar"])).is_err());
+    }
+}
@@ -0,0 +1,503 @@
+use serde::Serialize;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::application::agent_runtime::status::{detect_waiting_prompt, read_session_exit_code};
+use crate::application::agent_runtime::{
+    SessionActivity, detect_status_with_session_override, reconcile_with_sessions,
+    session_name_for_workspace_ref, workspace_status_targets_for_polling,
+};
+use crate::application::attention::{AttentionReason, AttentionSignals, classify_attention};
+use crate::domain::{Task, Workspace, WorkspaceStatus};
+use crate::infrastructure::event_log::now_millis;
+use crate::infrastructure::status_snapshot::{
+    STATUS_SNAPSHOT_REFRESH_MS, StatusSnapshotEntry, StatusSnapshotSource, StatusSnapshotWriter,
+    publish_status_snapshot_to_path, status_snapshot_path,
+};
+
+use super::agent::{capture_session_output, live_session_names, task_workspace};
+use super::task::{invalid_input, load_tasks};
+
+const WATCH_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct WatchArgs {
+    pub(crate) json_output: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct WatchObservation {
+    task: String,
+    repository: String,
+    path: PathBuf,
+    session: String,
+    status: WorkspaceStatus,
+    running: bool,
+    exit_code: Option<i32>,
+    attention: Option<AttentionReason>,
+    output_digest: Option<u64>,
+    idle_polls: u32,
+    waiting_since_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct WatchEvent {
+    ts_ms: u64,
+    event: &'static str,
+    task: String,
+    repository: String,
+    path: PathBuf,
+    session: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exit_code: Option<i32>,
+}
+
+impl WatchEvent {
+    fn new(ts_ms: u64, event: &'static str, observation: &WatchObservation) -> Self {
+        Self {
+            ts_ms,
+            event,
+            task: observation.task.clone(),
+            repository: observation.repository.clone(),
+            path: observation.path.clone(),
+            session: observation.session.clone(),
+            previous: None,
+            status: None,
+            reason: None,
+            summary: None,
+            exit_code: None,
+        }
+    }
+}
+
+pub(crate) fn parse_watch_args(
+    args: &mut impl Iterator<Item = String>,
+) -> std::io::Result<WatchArgs> {
+    let mut json_output = false;
+    for argument in args {
+        match argument.as_str() {
+            "--json" => {
+                json_output = true;
+            }
+            flag if flag.starts_with("--") => {
+                return Err(invalid_input(format!("unknown watch flag `{flag}`")));
+            }
+            _ => {
+                return Err(invalid_input(format!(
+                    "unexpected argument `{argument}` for watch"
+                )));
+            }
+        }
+    }
+    Ok(WatchArgs { json_output })
+}
+
+pub(crate) fn run_watch(args: &WatchArgs) -> std::io::Result<()> {
+    let mut previous = observe_workspaces(&load_tasks()?, &HashMap::new());
+    let mut published_snapshot = PublishedSnapshot::default();
+    publish_status_snapshot(&previous, &mut published_snapshot);
+    if !args.json_output {
+        eprintln!(
+            "watching {} workspace(s), press Ctrl+C to stop",
+            previous.len()
+        );
+    }
+
+    loop {
+        std::thread::sleep(WATCH_POLL_INTERVAL);
+        let current = observe_workspaces(&load_tasks()?, &previous);
+        let events = watch_events(&previous, &current, now_millis());
+        match write_watch_events(&events, args.json_output) {
+            Err(error) if error.kind() == std::io::ErrorKind::BrokenPipe => return Ok(()),
+            result => result?,
+        }
+        publish_status_snapshot(&current, &mut published_snapshot);
+        previous = current;
+    }
+}
+
+fn write_watch_events(events: &[WatchEvent], json_output: bool) -> std::io::Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    for event in events {
+        if json_output {
+            let line = serde_json::to_string(event).map_err(std::io::Error::other)?;
+            writeln!(stdout, "{line}")?;
+        } else {
+            writeln!(stdout, "{}", format_watch_event(event))?;
+        }
+    }
+    stdout.flush()
+}
+
+#[derive(Default)]
+struct PublishedSnapshot {
+    entries: Vec<StatusSnapshotEntry>,
+    at_ms: Option<u64>,
+}
+
+fn publish_status_snapshot(
+    observations: &HashMap<PathBuf, WatchObservation>,
+    published: &mut PublishedSnapshot,
+) {
+    let entries = status_snapshot_entries(observations);
+    let now_ms = now_millis();
+    let refresh_due = published
+        .at_ms
+        .is_none_or(|at_ms| now_ms.saturating_sub(at_ms) >= STATUS_SNAPSHOT_REFRESH_MS);
+    if entries == published.entries && !refresh_due {
+        return;
+    }
+    let Some(path) = status_snapshot_path() else {
+        return;
+    };
+    let writer = StatusSnapshotWriter::current(StatusSnapshotSource::Watch);
+    match publish_status_snapshot_to_path(&path, writer, &entries, now_ms) {
+        Ok(true) => {
+            *published = PublishedSnapshot {
+                entries,
+                at_ms: Some(now_ms),
+            };
+        }
+        Ok(false) => {}
+        Err(error) => eprintln!("warning {error}"),
+    }
+}
+
+fn status_snapshot_entries(
+    observations: &HashMap<PathBuf, WatchObservation>,
+) -> Vec<StatusSnapshotEntry> {
+    let mut entries = observations
+        .values()
+        .map(|observation| StatusSnapshotEntry {
+            task: observation.task.clone(),
+            repository: observation.repository.clone(),
+            path: observation.path.clone(),
+            status: observation.status.label().to_string(),
+            waiting_since_ms: observation.waiting_since_ms,
+        })
+        .collect::<Vec<StatusSnapshotEntry>>();
+    entries.sort_by(|left, right| left.path.cmp(&right.path));
+    entries
+}
+
+fn observe_workspaces(
+    tasks: &[Task],
+    previous: &HashMap<PathBuf, WatchObservation>,
+) -> HashMap<PathBuf, WatchObservation> {
+    let workspaces = tasks
+        .iter()
+        .flat_map(|task| {
+            task.worktrees
+                .iter()
+                .map(move |worktree| task_workspace(task, worktree))
+        })
+        .collect::<Vec<Workspace>>();
+    let previously_running = workspaces
+        .iter()
+        .filter(|workspace| {
+            previous
+                .get(&workspace.path)
+                .is_some_and(|observation| observation.running)
+        })
+        .map(|workspace| workspace.name.clone())
+        .collect::<HashSet<String>>();
+    let live_sessions = live_session_names().unwrap_or_default();
+    let workspaces =
+        reconcile_with_sessions(workspaces, &live_sessions, &previously_running).workspaces;
+    let targets = workspace_status_targets_for_polling(&workspaces, None);
+
+    workspaces
+        .iter()
+        .map(|workspace| {
+            let prior = previous.get(&workspace.path);
+            let target = targets
+                .iter()
+                .find(|target| target.workspace_path == workspace.path);
+            let session = target.map_or_else(
+                || session_name_for_workspace_ref(workspace),
+                |target| target.session_name.clone(),
+            );
+            let running = live_sessions.contains(&session);
+            let output =
+                target.and_then(|target| capture_session_output(&target.session_name).ok());
+            let observation =
+                observe_workspace(workspace, session, running, output.as_deref(), prior);
+            (observation.path.clone(), observation)
+        })
+        .collect()
+}
+
+fn observe_workspace(
+    workspace: &Workspace,
+    session: String,
+    running: bool,
+    output: Option<&str>,
+    prior: Option<&WatchObservation>,
+) -> WatchObservation {
+    let output_digest = output.map(|output| {
+        let mut hasher = DefaultHasher::new();
+        output.hash(&mut hasher);
+        hasher.finish()
+    });
+    let output_changed =
+        output_digest.is_some() && prior.is_none_or(|prior| prior.output_digest != output_digest);
+    let status = match output {
+        Some(output) => detect_status_with_session_override(
+            output,
+            if output_changed {
+                SessionActivity::Active
+            } else {
+                SessionActivity::Idle
+            },
+            workspace.is_main,
+            true,
+            workspace.supported_agent,
+            workspace.agent,
+            workspace.path.as_path(),
+            session.as_str(),
+        ),
+        None => workspace.status,
+    };
+    let idle_polls = if output_changed {
+        0
+    } else {
+        prior.map_or(0, |prior| prior.idle_polls.saturating_add(1))
+    };
+    let waiting_prompt = output.and_then(detect_waiting_prompt);
+
+    WatchObservation {
+        task: workspace.task_slug.clone().unwrap_or_default(),
+        repository: workspace.project_name.clone().unwrap_or_default(),
+        path: workspace.path.clone(),
+        exit_code: read_session_exit_code(session.as_str()),
+        session,
+        status,
+        running,
+        attention: classify_attention(
+            workspace,
+            AttentionSignals {
+                status,
+                waiting_prompt: waiting_prompt.as_deref(),
+                idle_polls,
+                agent_running: running,
+            },
+        ),
+        output_digest,
+        idle_polls,
+        waiting_since_ms: (status == WorkspaceStatus::Waiting).then(|| {
+            prior
+                .and_then(|prior| prior.waiting_since_ms)
+                .unwrap_or_else(now_millis)
+        }),
+    }
+}
+
+fn watch_events(
+    previous: &HashMap<PathBuf, WatchObservation>,
+    current: &HashMap<PathBuf, WatchObservation>,
+    ts_ms: u64,
+) -> Vec<WatchEvent> {
+    let mut paths = current.keys().collect::<Vec<&PathBuf>>();
+    paths.sort();
+
+    let mut events = Vec::new();
+    for path in paths {
+        let observation = &current[path];
+        let prior = previous.get(path);
+        let prior_running = prior.is_some_and(|prior| prior.running);
+        let prior_exit_code = prior.and_then(|prior| prior.exit_code);
+
+        let restarted = prior_exit_code.is_some() && observation.exit_code.is_none();
+        if observation.running && (!prior_running || restarted) {
+            events.push(WatchEvent::new(ts_ms, "session_started", observation));
+        }
+
+        if prior.is_none_or(|prior| prior.status != observation.status) {
+            events.push(WatchEvent {
+                previous: prior.map(|prior| prior.status.label()),
+                status: Some(observation.status.label()),
+                ..WatchEvent::new(ts_ms, "status_changed", observation)
+            });
+        }
+
+        let newly_exited = prior_exit_code.is_none() && observation.exit_code.is_some();
+        let vanished = prior_running && !observation.running && prior_exit_code.is_none();
+        if prior.is_some() && (newly_exited || vanished) {
+            events.push(WatchEvent {
+                exit_code: observation.exit_code,
+                ..WatchEvent::new(ts_ms, "session_exited", observation)
+            });
+        }
+
+        if let Some(reason) = observation.attention
+            && prior.and_then(|prior| prior.attention) != Some(reason)
+        {
+            events.push(WatchEvent {
+                reason: Some(reason.label()),
+                summary: Some(reason.summary().to_string()),
+                ..WatchEvent::new(ts_ms, "attention", observation)
+            });
+        }
+    }
+    events
+}
+
+fn format_watch_event(event: &WatchEvent) -> String {
+    let workspace = format!("{}/{}", event.task, event.repository);
+    match event.event {
+        "status_changed" => format!(
+            "{workspace} status {} -> {}",
+            event.previous.unwrap_or("none"),
+            event.status.unwrap_or("unknown")
+        ),
+        "session_started" => format!("{workspace} session started {}", event.session),
+        "session_exited" => match event.exit_code {
+            Some(code) => format!("{workspace} session exited (code {code})"),
+            None => format!("{workspace} session exited"),
+        },
+        "attention" => format!(
+            "{workspace} attention {}: {}",
+            event.reason.unwrap_or("unknown"),
+            event.summary.as_deref().unwrap_or_default()
+        ),
+        other => format!("{workspace} {other}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WatchEvent, WatchObservation, format_watch_event, parse_watch_args, watch_events};
+    use crate::application::attention::AttentionReason;
+    use crate::domain::WorkspaceStatus;
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn observation(status: WorkspaceStatus, running: bool) -> WatchObservation {
+        WatchObservation {
+            task: "feature-a".to_string(),
+            repository: "grove".to_string(),
+            path: PathBuf::from("/tasks/feature-a/grove"),
+            session: "grove-wt-feature-a".to_string(),
+            status,
+            running,
+            exit_code: None,
+            attention: None,
+            output_digest: None,
+            idle_polls: 0,
+            waiting_since_ms: None,
+        }
+    }
+
+    fn snapshot(observation: WatchObservation) -> HashMap<PathBuf, WatchObservation> {
+        HashMap::from([(observation.path.clone(), observation)])
+    }
+
+    fn event_names(events: &[WatchEvent]) -> Vec<&'static str> {
+        events.iter().map(|event| event.event).collect()
+    }
+
+    #[test]
+    fn parse_watch_args_accepts_json_and_rejects_extras() {
+        let mut args = vec!["--json".to_string()].into_iter();
+        assert!(
+            parse_watch_args(&mut args)
+                .expect("json flag should parse")
+                .json_output
+        );
+
+        let mut unknown = vec!["--fast".to_string()].into_iter();
+        let error = parse_watch_args(&mut unknown).expect_err("unknown flag should fail");
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+
+        let mut positional = vec!["feature-a".to_string()].into_iter();
+        assert!(parse_watch_args(&mut positional).is_err());
+    }
+
+    #[test]
+    fn unchanged_observations_emit_nothing() {
+        let state = snapshot(observation(WorkspaceStatus::Active, true));
+        assert!(watch_events(&state, &state, 1).is_empty());
+    }
+
+    #[test]
+    fn session_start_emits_started_and_status_change() {
+        let previous = snapshot(observation(WorkspaceStatus::Idle, false));
+        let current = snapshot(observation(WorkspaceStatus::Active, true));
+
+        let events = watch_events(&previous, &current, 7);
+
+        assert_eq!(
+            event_names(&events),
+            vec!["session_started", "status_changed"]
+        );
+        assert_eq!(events[1].previous, Some("idle"));
+        assert_eq!(events[1].status, Some("active"));
+        assert_eq!(events[1].ts_ms, 7);
+    }
+
+    #[test]
+    fn exit_code_file_emits_session_exit_with_code_and_attention() {
+        let previous = snapshot(observation(WorkspaceStatus::Active, true));
+        let mut finished = observation(WorkspaceStatus::Error, true);
+        finished.exit_code = Some(2);
+        let current = snapshot(finished.clone());
+
+        let events = watch_events(&previous, &current, 1);
+        assert_eq!(
+            event_names(&events),
+            vec!["status_changed", "session_exited"]
+        );
+        assert_eq!(events[1].exit_code, Some(2));
+
+        let mut gone = observation(WorkspaceStatus::Idle, false);
+        gone.exit_code = Some(2);
+        let events = watch_events(&current, &snapshot(gone), 2);
+        assert_eq!(event_names(&events), vec!["status_changed"]);
+    }
+
+    #[test]
+    fn new_attention_reason_is_reported_once() {
+        let previous = snapshot(observation(WorkspaceStatus::Active, true));
+        let mut waiting = observation(WorkspaceStatus::Waiting, true);
+        waiting.attention = Some(AttentionReason::PermissionWall);
+        let current = snapshot(waiting);
+
+        let events = watch_events(&previous, &current, 1);
+        assert_eq!(event_names(&events), vec!["status_changed", "attention"]);
+        assert_eq!(events[1].reason, Some("permission_wall"));
+        assert_eq!(
+            format_watch_event(&events[1]),
+            "feature-a/grove attention permission_wall: permission wall"
+        );
+        assert!(watch_events(&current, &current, 2).is_empty());
+    }
+
+    #[test]
+    fn json_events_omit_empty_fields() {
+        let previous = snapshot(observation(WorkspaceStatus::Active, true));
+        let current = snapshot(observation(WorkspaceStatus::Idle, false));
+
+        let events = watch_events(&previous, &current, 5);
+        assert_eq!(
+            event_names(&events),
+            vec!["status_changed", "session_exited"]
+        );
+        let line = serde_json::to_string(&events[1]).expect("event should serialize");
+        assert_eq!(
+            line,
+            "{\"ts_ms\":5,\"event\":\"session_exited\",\"task\":\"feature-a\",\"repository\":\"grove\",\"path\":\"/tasks/feature-a/grove\",\"session\":\"grove-wt-feature-a\"}"
+        );
+    }
+}
@@ -38,6 +38,23 @@ impl PermissionMode {
         matches!(self, Self::Unsafe)
     }
 
+    /// Whichever of the two modes grants the agent fewer permissions.
+    pub const fn more_restrictive(self, other: Self) -> Self {
+        if self.permission_rank() <= other.permission_rank() {
+            self
+        } else {
+            other
+        }
+    }
+
+    const fn permission_rank(self) -> u8 {
+        match self {
+            Self::Default => 0,
+            Self::Auto => 1,
+            Self::Unsafe => 2,
+        }
+    }
+
     pub const fn label(self) -> &'static str {
         match self {
             Self::Default => "default",
@@ -149,6 +166,21 @@ impl WorkspaceStatus {
     pub const fn is_running(self) -> bool {
         matches!(self, Self::Active | Self::Thinking | Self::Waiting)
     }
+
+    /// Stable lowercase name used in manifests, snapshots, and JSON output.
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Main => "main",
+            Self::Idle => "idle",
+            Self::Active => "active",
+            Self::Thinking => "thinking",
+            Self::Waiting => "waiting",
+            Self::Done => "done",
+            Self::Error => "error",
+            Self::Unknown => "unknown",
+            Self::Unsupported => "unsupported",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -205,6 +237,8 @@ pub struct Task {
     pub root_path: PathBuf,
     pub branch: String,
     pub worktrees: Vec<Worktree>,
+    pub tags: Vec<String>,
+    pub notes: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -406,8 +440,20 @@ impl Task {
             root_path,
             branch,
             worktrees,
+            tags: Vec::new(),
+            notes: String::new(),
         })
     }
+
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    pub fn with_notes(mut self, notes: String) -> Self {
+        self.notes = notes;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -1,13 +1,15 @@
+use std::collections::BTreeMap;
 use std::ffi::OsString;
 use std::fs;
 use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 
+use super::migrations::{SchemaDocument, migrate_on_load};
+use super::themes::CustomThemes;
 use crate::domain::PermissionMode;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
-#[serde(rename_all = "kebab-case")]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum ThemeName {
     Monokai,
     CatppuccinLatte,
@@ -18,10 +20,23 @@ pub enum ThemeName {
     RosePine,
     RosePineMoon,
     RosePineDawn,
+    /// A theme loaded from `themes/<key>.toml` next to `config.toml`.
+    Custom(String),
 }
 
 impl ThemeName {
-    pub const fn config_key(self) -> &'static str {
+    pub const BUILT_IN: [ThemeName; 8] = [
+        Self::Monokai,
+        Self::CatppuccinLatte,
+        Self::CatppuccinFrappe,
+        Self::CatppuccinMacchiato,
+        Self::CatppuccinMocha,
+        Self::RosePine,
+        Self::RosePineMoon,
+        Self::RosePineDawn,
+    ];
+
+    pub fn config_key(&self) -> &str {
         match self {
             Self::Monokai => "monokai",
             Self::CatppuccinLatte => "catppuccin-latte",
@@ -31,8 +46,40 @@ impl ThemeName {
             Self::RosePine => "rose-pine",
             Self::RosePineMoon => "rose-pine-moon",
             Self::RosePineDawn => "rose-pine-dawn",
+            Self::Custom(key) => key,
         }
     }
+
+    pub fn from_config_key(key: &str) -> Self {
+        Self::BUILT_IN
+            .into_iter()
+            .find(|theme| theme.config_key() == key)
+            .unwrap_or_else(|| Self::Custom(key.to_string()))
+    }
+}
+
+impl Serialize for ThemeName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.config_key())
+    }
+}
+
+/// Unknown names parse as `Custom`; loading a config from a path rejects the
+/// ones that match no theme in the themes directory next to it.
+impl<'de> Deserialize<'de> for ThemeName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let key = String::deserialize(deserializer)?;
+        if key.trim().is_empty() {
+            return Err(serde::de::Error::custom("theme name must not be empty"));
+        }
+        Ok(Self::from_config_key(key.trim()))
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -50,7 +97,48 @@ pub struct GroveConfig {
     #[serde(default)]
     pub hidden_base_project_paths: Vec<PathBuf>,
     #[serde(default)]
+    pub sidebar: SidebarViewConfig,
+    #[serde(default)]
     pub launch_permission_mode: PermissionMode,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub keys: BTreeMap<String, KeyBindingConfig>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, ProfileConfig>,
+    #[serde(skip)]
+    pub custom_themes: CustomThemes,
+}
+
+/// One `[keys]` entry: a chord or sequence such as `"g d"`, or a list of them.
+/// An empty list unbinds the command.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KeyBindingConfig {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+impl KeyBindingConfig {
+    pub fn sequences(&self) -> Vec<&str> {
+        match self {
+            Self::Single(sequence) => vec![sequence.as_str()],
+            Self::Multiple(sequences) => sequences.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+/// One `[profiles.<name>]` table. Unset fields fall back to paths and a tmux
+/// prefix derived from the profile name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projects_file: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tasks_root: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tmux_prefix: Option<String>,
+    #[serde(default)]
+    pub agent_env: AgentEnvDefaults,
 }
 
 const fn default_sidebar_width_pct() -> u16 {
@@ -66,7 +154,11 @@ impl Default for GroveConfig {
             task_order: Vec::new(),
             attention_acks: Vec::new(),
             hidden_base_project_paths: Vec::new(),
+            sidebar: SidebarViewConfig::default(),
             launch_permission_mode: PermissionMode::Default,
+            keys: BTreeMap::new(),
+            profiles: BTreeMap::new(),
+            custom_themes: CustomThemes::default(),
         }
     }
 }
@@ -75,12 +167,80 @@ impl GroveConfig {
     pub fn global_settings(&self) -> GlobalSettings {
         GlobalSettings {
             sidebar_width_pct: self.sidebar_width_pct,
-            theme: self.theme,
+            theme: self.theme.clone(),
             launch_permission_mode: self.launch_permission_mode,
+            keys: self.keys.clone(),
+            profiles: self.profiles.clone(),
+            custom_themes: self.custom_themes.clone(),
+        }
+    }
+}
+
+/// How the sidebar groups workspaces under headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SidebarGroupMode {
+    /// One header per task, the layout without grouping.
+    #[default]
+    None,
+    Project,
+    Status,
+}
+
+impl SidebarGroupMode {
+    pub const fn next(self) -> Self {
+        match self {
+            Self::None => Self::Project,
+            Self::Project => Self::Status,
+            Self::Status => Self::None,
+        }
+    }
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Project => "project",
+            Self::Status => "status",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SidebarSortMode {
+    /// Follows `task_order`.
+    #[default]
+    Manual,
+    LastActivity,
+    StatusUrgency,
+}
+
+impl SidebarSortMode {
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Manual => Self::LastActivity,
+            Self::LastActivity => Self::StatusUrgency,
+            Self::StatusUrgency => Self::Manual,
+        }
+    }
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Manual => "manual",
+            Self::LastActivity => "last activity",
+            Self::StatusUrgency => "urgency",
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct SidebarViewConfig {
+    #[serde(default)]
+    pub group: SidebarGroupMode,
+    #[serde(default)]
+    pub sort: SidebarSortMode,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WorkspaceAttentionAckConfig {
     pub workspace_path: PathBuf,
@@ -105,6 +265,12 @@ pub struct ProjectDefaults {
     pub workspace_init_command: String,
     #[serde(default)]
     pub agent_env: AgentEnvDefaults,
+    #[serde(default)]
+    pub fetch_interval_secs: Option<u64>,
+    /// Lets the repository's `.grove.toml` run its `workspace_init_command`
+    /// and `[[tabs]]` commands. Off until the user trusts the project.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub trust_shared_commands: bool,
 }
 
 pub type RepositoryDefaults = ProjectDefaults;
@@ -131,6 +297,14 @@ pub struct GlobalSettings {
     pub theme: ThemeName,
     #[serde(default)]
     pub launch_permission_mode: PermissionMode,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub keys: BTreeMap<String, KeyBindingConfig>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, ProfileConfig>,
+    /// Themes from the themes directory next to the file, filled in by
+    /// `load_global_from_path`.
+    #[serde(skip)]
+    pub custom_themes: CustomThemes,
 }
 
 impl Default for GlobalSettings {
@@ -139,6 +313,9 @@ impl Default for GlobalSettings {
             sidebar_width_pct: default_sidebar_width_pct(),
             theme: ThemeName::default(),
             launch_permission_mode: PermissionMode::Default,
+            keys: BTreeMap::new(),
+            profiles: BTreeMap::new(),
+            custom_themes: CustomThemes::default(),
         }
     }
 }
@@ -153,6 +330,8 @@ pub struct ProjectsState {
     pub attention_acks: Vec<WorkspaceAttentionAckConfig>,
     #[serde(default)]
     pub hidden_base_project_paths: Vec<PathBuf>,
+    #[serde(default)]
+    pub sidebar: SidebarViewConfig,
 }
 
 fn config_directory() -> Option<PathBuf> {
@@ -172,6 +351,9 @@ pub fn projects_path() -> Option<PathBuf> {
 }
 
 pub fn projects_path_for(config_path: &Path) -> PathBuf {
+    if let Some(path) = super::profile::projects_path_override(config_path) {
+        return path;
+    }
     let file_name = config_path.file_name().map_or_else(
         || OsString::from("projects.toml"),
         |name| {
@@ -203,21 +385,35 @@ pub fn load_from_path(path: &Path) -> Result<GroveConfig, String> {
         task_order: projects.task_order,
         attention_acks: projects.attention_acks,
         hidden_base_project_paths: projects.hidden_base_project_paths,
+        sidebar: projects.sidebar,
         launch_permission_mode: settings.launch_permission_mode,
+        keys: settings.keys,
+        profiles: settings.profiles,
+        custom_themes: settings.custom_themes,
     })
 }
 
 pub fn load_global_from_path(path: &Path) -> Result<GlobalSettings, String> {
+    let custom_themes = CustomThemes::load_for_config(path);
     let raw = match fs::read_to_string(path) {
         Ok(raw) => raw,
         Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
-            return Ok(GlobalSettings::default());
+            return Ok(GlobalSettings {
+                custom_themes,
+                ..GlobalSettings::default()
+            });
         }
         Err(error) => return Err(format!("global config read failed: {error}")),
     };
-
-    toml::from_str::<GlobalSettings>(&raw)
-        .map_err(|error| format!("global config parse failed: {error}"))
+    let raw = migrate_on_load(SchemaDocument::GlobalConfig, path, raw)?;
+
+    let mut settings = toml::from_str::<GlobalSettings>(&raw)
+        .map_err(|error| format!("global config parse failed: {error}"))?;
+    custom_themes
+        .check(&settings.theme)
+        .map_err(|error| format!("global config invalid: {error}"))?;
+    settings.custom_themes = custom_themes;
+    Ok(settings)
 }
 
 pub fn load_projects_from_path(path: &Path) -> Result<ProjectsState, String> {
@@ -228,6 +424,7 @@ pub fn load_projects_from_path(path: &Path) -> Result<ProjectsState, String> {
         }
         Err(error) => return Err(format!("projects config read failed: {error}")),
     };
+    let raw = migrate_on_load(SchemaDocument::ProjectsState, path, raw)?;
 
     toml::from_str::<ProjectsState>(&raw)
         .map_err(|error| format!("projects config parse failed: {error}"))
@@ -241,6 +438,7 @@ pub fn save_global_to_path(path: &Path, settings: &GlobalSettings) -> Result<(),
     fs::create_dir_all(parent)
         .map_err(|error| format!("global config directory create failed: {error}"))?;
     let encoded = toml::to_string_pretty(settings)
+        .map(|encoded| SchemaDocument::GlobalConfig.stamp(encoded))
         .map_err(|error| format!("global config encode failed: {error}"))?;
     fs::write(path, encoded).map_err(|error| format!("global config write failed: {error}"))
 }
@@ -251,6 +449,7 @@ pub fn save_projects_to_path(
     task_order: &[String],
     attention_acks: &[WorkspaceAttentionAckConfig],
     hidden_base_project_paths: &[PathBuf],
+    sidebar: SidebarViewConfig,
 ) -> Result<(), String> {
     let Some(parent) = path.parent() else {
         return Err("projects config path missing parent directory".to_string());
@@ -263,8 +462,10 @@ pub fn save_projects_to_path(
         task_order: task_order.to_vec(),
         attention_acks: attention_acks.to_vec(),
         hidden_base_project_paths: hidden_base_project_paths.to_vec(),
+        sidebar,
     };
     let encoded = toml::to_string_pretty(&projects_state)
+        .map(|encoded| SchemaDocument::ProjectsState.stamp(encoded))
         .map_err(|error| format!("projects config encode failed: {error}"))?;
     fs::write(path, encoded).map_err(|error| format!("projects config write failed: {error}"))
 }
@@ -280,6 +481,7 @@ pub fn save_projects_state_from_config_path(
         &config.task_order,
         &config.attention_acks,
         &config.hidden_base_project_paths,
+        config.sidebar,
     )
 }
 
@@ -291,10 +493,13 @@ pub fn save_to_path(path: &Path, config: &GroveConfig) -> Result<(), String> {
 #[cfg(test)]
 mod tests {
     use super::{
-        AgentEnvDefaults, GlobalSettings, GroveConfig, PermissionMode, ProjectConfig,
-        ProjectDefaults, RepositoryConfig, RepositoryDefaults, ThemeName, load_from_path,
+        AgentEnvDefaults, CustomThemes, GlobalSettings, GroveConfig, KeyBindingConfig, PermissionMode,
+        ProjectConfig, ProjectDefaults, RepositoryConfig, RepositoryDefaults, SidebarGroupMode,
+        SidebarSortMode, SidebarViewConfig, ThemeName, load_from_path, load_global_from_path,
         projects_path_for, save_global_to_path, save_projects_to_path, save_to_path,
     };
+    use crate::infrastructure::themes::themes_directory_for;
+    use std::collections::BTreeMap;
     use std::fs;
     use std::path::{Path, PathBuf};
     use std::time::{SystemTime, UNIX_EPOCH};
@@ -326,7 +531,11 @@ mod tests {
                 task_order: Vec::new(),
                 attention_acks: Vec::new(),
                 hidden_base_project_paths: Vec::new(),
+                sidebar: SidebarViewConfig::default(),
                 launch_permission_mode: PermissionMode::Default,
+                keys: BTreeMap::new(),
+                profiles: BTreeMap::new(),
+                custom_themes: CustomThemes::default(),
             }
         );
     }
@@ -340,6 +549,8 @@ mod tests {
                 base_branch: "main".to_string(),
                 workspace_init_command: "direnv allow".to_string(),
                 agent_env: AgentEnvDefaults::default(),
+                fetch_interval_secs: None,
+                trust_shared_commands: false,
             },
         };
 
@@ -364,12 +575,21 @@ mod tests {
                         claude: vec!["CLAUDE_CONFIG_DIR=~/.claude-work".to_string()],
                         codex: vec!["CODEX_CONFIG_DIR=~/.codex-work".to_string()],
                     },
+                    fetch_interval_secs: Some(120),
+                    trust_shared_commands: true,
                 },
             }],
             task_order: vec!["grove".to_string(), "task-workflow".to_string()],
             attention_acks: Vec::new(),
             hidden_base_project_paths: vec![PathBuf::from("/repos/hidden")],
+            sidebar: SidebarViewConfig {
+                group: SidebarGroupMode::Status,
+                sort: SidebarSortMode::LastActivity,
+            },
             launch_permission_mode: PermissionMode::Unsafe,
+            keys: BTreeMap::new(),
+            profiles: BTreeMap::new(),
+            custom_themes: CustomThemes::default(),
         };
         save_to_path(&path, &config).expect("config should save");
 
@@ -390,6 +610,7 @@ mod tests {
         assert_eq!(loaded.projects, Vec::<ProjectConfig>::new());
         assert_eq!(loaded.task_order, Vec::<String>::new());
         assert_eq!(loaded.hidden_base_project_paths, Vec::<PathBuf>::new());
+        assert_eq!(loaded.sidebar, SidebarViewConfig::default());
 
         cleanup_files(path.as_path());
     }
@@ -437,7 +658,11 @@ mod tests {
             task_order: vec!["grove".to_string()],
             attention_acks: Vec::new(),
             hidden_base_project_paths: vec![PathBuf::from("/repos/hidden")],
+            sidebar: SidebarViewConfig::default(),
             launch_permission_mode: PermissionMode::Default,
+            keys: BTreeMap::new(),
+            profiles: BTreeMap::new(),
+            custom_themes: CustomThemes::default(),
         };
         save_projects_to_path(
             &projects_path,
@@ -445,12 +670,16 @@ mod tests {
             &initial.task_order,
             &initial.attention_acks,
             &initial.hidden_base_project_paths,
+            initial.sidebar,
         )
         .expect("projects should save");
         let updated = GlobalSettings {
             sidebar_width_pct: 48,
             theme: ThemeName::CatppuccinLatte,
             launch_permission_mode: PermissionMode::Unsafe,
+            keys: BTreeMap::new(),
+            profiles: BTreeMap::new(),
+            custom_themes: CustomThemes::default(),
         };
         save_global_to_path(&path, &updated).expect("global settings should save");
 
@@ -465,6 +694,68 @@ mod tests {
         cleanup_files(path.as_path());
     }
 
+    #[test]
+    fn keys_section_accepts_single_and_list_bindings() {
+        let path = unique_temp_path("keys");
+        fs::write(
+            &path,
+            "[keys]\n\"palette:commit_changes\" = \"g c\"\nquit = [\"ctrl+q\", \"Q\"]\nsend_review = []\n",
+        )
+        .expect("fixture should write");
+
+        let loaded = load_global_from_path(&path).expect("global settings should load");
+        assert_eq!(
+            loaded.keys.get("palette:commit_changes"),
+            Some(&KeyBindingConfig::Single("g c".to_string()))
+        );
+        assert_eq!(
+            loaded.keys.get("quit").map(KeyBindingConfig::sequences),
+            Some(vec!["ctrl+q", "Q"])
+        );
+        assert_eq!(
+            loaded
+                .keys
+                .get("send_review")
+                .map(KeyBindingConfig::sequences),
+            Some(Vec::new())
+        );
+
+        save_global_to_path(&path, &loaded).expect("global settings should save");
+        assert_eq!(
+            load_global_from_path(&path).expect("global settings should reload"),
+            loaded
+        );
+
+        cleanup_files(path.as_path());
+    }
+
+    #[test]
+    fn custom_theme_name_round_trips_through_global_settings() {
+        let path = unique_temp_path("custom-theme");
+        fs::write(&path, "theme = \"house-contrast\"\n").expect("fixture should write");
+        let error = load_global_from_path(&path).expect_err("undefined theme should not load");
+        assert!(error.contains("unknown theme `house-contrast`"), "{error}");
+
+        let themes_directory = themes_directory_for(&path);
+        fs::create_dir_all(&themes_directory).expect("themes directory should exist");
+        fs::write(themes_directory.join("house-contrast.toml"), "").expect("theme should write");
+        let loaded = load_global_from_path(&path).expect("global settings should load");
+        assert_eq!(loaded.theme, ThemeName::Custom("house-contrast".to_string()));
+        assert_eq!(loaded.theme.config_key(), "house-contrast");
+        assert!(loaded.custom_themes.get("house-contrast").is_some());
+
+        save_global_to_path(&path, &loaded).expect("global settings should save");
+        let reloaded = load_global_from_path(&path).expect("global settings should reload");
+        assert_eq!(reloaded.theme, loaded.theme);
+        assert_eq!(ThemeName::from_config_key("rose-pine"), ThemeName::RosePine);
+
+        fs::write(&path, "theme = \"rose-pien\"\n").expect("fixture should write");
+        assert!(load_global_from_path(&path).is_err());
+
+        let _ = fs::remove_dir_all(themes_directory);
+        cleanup_files(path.as_path());
+    }
+
     #[test]
     fn save_projects_to_path_does_not_clear_global_settings() {
         let path = unique_temp_path("projects-only-save");
@@ -473,6 +764,9 @@ mod tests {
             sidebar_width_pct: 61,
             theme: ThemeName::CatppuccinFrappe,
             launch_permission_mode: PermissionMode::Unsafe,
+            keys: BTreeMap::new(),
+            profiles: BTreeMap::new(),
+            custom_themes: CustomThemes::default(),
         };
         save_global_to_path(&path, &settings).expect("global settings should save");
         let projects = vec![ProjectConfig {
@@ -481,8 +775,15 @@ mod tests {
             defaults: ProjectDefaults::default(),
         }];
         let task_order = vec!["task-workflow".to_string(), "grove".to_string()];
-        save_projects_to_path(&projects_path, &projects, &task_order, &[], &[])
-            .expect("projects state should save");
+        save_projects_to_path(
+            &projects_path,
+            &projects,
+            &task_order,
+            &[],
+            &[],
+            SidebarViewConfig::default(),
+        )
+        .expect("projects state should save");
 
         let loaded = load_from_path(&path).expect("combined config should load");
         assert_eq!(loaded.sidebar_width_pct, 61);
@@ -506,8 +807,15 @@ mod tests {
         }];
         let task_order = vec!["task-workflow".to_string(), "grove".to_string()];
 
-        save_projects_to_path(&projects_path, &projects, &task_order, &[], &[])
-            .expect("projects state should save");
+        save_projects_to_path(
+            &projects_path,
+            &projects,
+            &task_order,
+            &[],
+            &[],
+            SidebarViewConfig::default(),
+        )
+        .expect("projects state should save");
 
         let loaded = load_from_path(&path).expect("combined config should load");
         assert_eq!(loaded.task_order, task_order);
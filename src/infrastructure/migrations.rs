@@ -0,0 +1,452 @@
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use toml::{Table, Value};
+
+use super::event_log::{Event, now_millis};
+
+pub const SCHEMA_VERSION_KEY: &str = "schema_version";
+pub const MIGRATION_LOG_FILE: &str = "migrations.log";
+const MAX_PENDING_MIGRATION_EVENTS: usize = 32;
+
+static PENDING_MIGRATION_EVENTS: Mutex<Vec<Event>> = Mutex::new(Vec::new());
+
+/// A Grove-owned file with its own `schema_version`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaDocument {
+    GlobalConfig,
+    ProjectsState,
+    TaskManifest,
+}
+
+struct Migration {
+    version: u32,
+    summary: &'static str,
+    /// Rewrites the document in place, returning whether anything changed.
+    apply: fn(&mut Table) -> bool,
+}
+
+const GLOBAL_CONFIG_MIGRATIONS: &[Migration] = &[Migration {
+    version: 1,
+    summary: "replace launch_skip_permissions with launch_permission_mode",
+    apply: migrate_launch_skip_permissions,
+}];
+
+const PROJECTS_STATE_MIGRATIONS: &[Migration] = &[Migration {
+    version: 1,
+    summary: "add schema_version",
+    apply: add_schema_version,
+}];
+
+const TASK_MANIFEST_MIGRATIONS: &[Migration] = &[Migration {
+    version: 1,
+    summary: "fill worktree fields missing from older manifests",
+    apply: fill_manifest_worktree_defaults,
+}];
+
+impl SchemaDocument {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::GlobalConfig => "global config",
+            Self::ProjectsState => "projects config",
+            Self::TaskManifest => "task manifest",
+        }
+    }
+
+    fn migrations(self) -> &'static [Migration] {
+        match self {
+            Self::GlobalConfig => GLOBAL_CONFIG_MIGRATIONS,
+            Self::ProjectsState => PROJECTS_STATE_MIGRATIONS,
+            Self::TaskManifest => TASK_MANIFEST_MIGRATIONS,
+        }
+    }
+
+    pub fn current_version(self) -> u32 {
+        self.migrations()
+            .last()
+            .map_or(0, |migration| migration.version)
+    }
+
+    /// Prefixes freshly encoded TOML with the current `schema_version`.
+    pub fn stamp(self, encoded: String) -> String {
+        format!(
+            "{SCHEMA_VERSION_KEY} = {}\n{encoded}",
+            self.current_version()
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationOutcome {
+    pub document: SchemaDocument,
+    pub from_version: u32,
+    pub to_version: u32,
+    /// Summaries of the migrations that changed the document.
+    pub applied: Vec<&'static str>,
+    pub contents: String,
+}
+
+impl MigrationOutcome {
+    pub fn changed(&self) -> bool {
+        !self.applied.is_empty()
+    }
+
+    pub fn outdated(&self) -> bool {
+        self.from_version < self.to_version
+    }
+}
+
+/// `migrate_document` writes the version itself; reporting a change is what
+/// gets an unversioned file stamped, backed up and logged on load.
+fn add_schema_version(_document: &mut Table) -> bool {
+    true
+}
+
+fn migrate_launch_skip_permissions(document: &mut Table) -> bool {
+    let Some(legacy) = document.remove("launch_skip_permissions") else {
+        return false;
+    };
+    if legacy.as_bool() == Some(true) && !document.contains_key("launch_permission_mode") {
+        document.insert(
+            "launch_permission_mode".to_string(),
+            Value::String("unsafe".to_string()),
+        );
+    }
+    true
+}
+
+fn fill_manifest_worktree_defaults(document: &mut Table) -> bool {
+    let Some(Value::Array(worktrees)) = document.get_mut("worktrees") else {
+        return false;
+    };
+    let mut changed = false;
+    for worktree in worktrees.iter_mut().filter_map(Value::as_table_mut) {
+        for (key, value) in [
+            ("is_orphaned", Value::Boolean(false)),
+            ("supported_agent", Value::Boolean(true)),
+            ("pull_requests", Value::Array(Vec::new())),
+        ] {
+            if !worktree.contains_key(key) {
+                worktree.insert(key.to_string(), value);
+                changed = true;
+            }
+        }
+    }
+    changed
+}
+
+/// Runs every migration newer than the document's `schema_version`. Files
+/// without a version are treated as version 0.
+pub fn migrate_document(document: SchemaDocument, raw: &str) -> Result<MigrationOutcome, String> {
+    let label = document.label();
+    let mut table = raw
+        .parse::<Table>()
+        .map_err(|error| format!("{label} parse failed: {error}"))?;
+    let from_version = match table.get(SCHEMA_VERSION_KEY) {
+        None => 0,
+        Some(value) => value
+            .as_integer()
+            .and_then(|version| u32::try_from(version).ok())
+            .ok_or_else(|| format!("{label} {SCHEMA_VERSION_KEY} must be a whole number"))?,
+    };
+    let to_version = document.current_version();
+    if from_version > to_version {
+        return Err(format!(
+            "{label} {SCHEMA_VERSION_KEY} {from_version} is newer than this grove supports ({to_version})"
+        ));
+    }
+
+    let mut applied = Vec::new();
+    for migration in document
+        .migrations()
+        .iter()
+        .filter(|migration| migration.version > from_version)
+    {
+        if (migration.apply)(&mut table) {
+            applied.push(migration.summary);
+        }
+    }
+    if from_version == to_version {
+        return Ok(MigrationOutcome {
+            document,
+            from_version,
+            to_version,
+            applied,
+            contents: raw.to_string(),
+        });
+    }
+
+    table.insert(
+        SCHEMA_VERSION_KEY.to_string(),
+        Value::Integer(i64::from(to_version)),
+    );
+    let contents = toml::to_string_pretty(&table)
+        .map_err(|error| format!("{label} encode failed: {error}"))?;
+    Ok(MigrationOutcome {
+        document,
+        from_version,
+        to_version,
+        applied,
+        contents,
+    })
+}
+
+fn backup_path(path: &Path, from_version: u32) -> PathBuf {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let candidate = path.with_file_name(format!("{file_name}.v{from_version}.bak"));
+    if !candidate.exists() {
+        return candidate;
+    }
+    path.with_file_name(format!("{file_name}.v{from_version}.{}.bak", now_millis()))
+}
+
+fn append_migration_log(path: &Path, outcome: &MigrationOutcome) -> Result<(), String> {
+    let log_path = path.with_file_name(MIGRATION_LOG_FILE);
+    let mut log = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&log_path)
+        .map_err(|error| format!("migration log open failed: {error}"))?;
+    let summaries = if outcome.applied.is_empty() {
+        vec!["add schema_version"]
+    } else {
+        outcome.applied.clone()
+    };
+    for summary in summaries {
+        writeln!(
+            log,
+            "{} {} v{} -> v{}: {summary}",
+            now_millis(),
+            path.display(),
+            outcome.from_version,
+            outcome.to_version
+        )
+        .map_err(|error| format!("migration log write failed: {error}"))?;
+    }
+    Ok(())
+}
+
+/// Backs up `path`, writes the migrated contents and logs the migrations to
+/// `migrations.log` in the same directory. Returns the backup path.
+pub fn write_migrated_file(path: &Path, outcome: &MigrationOutcome) -> Result<PathBuf, String> {
+    let backup = backup_path(path, outcome.from_version);
+    fs::copy(path, &backup).map_err(|error| format!("migration backup failed: {error}"))?;
+    fs::write(path, outcome.contents.as_str())
+        .map_err(|error| format!("migrated file write failed: {error}"))?;
+    append_migration_log(path, outcome)?;
+    Ok(backup)
+}
+
+/// Load-time migration. Files are only rewritten when a migration changed
+/// something; when the rewrite fails (e.g. a read-only, declaratively managed
+/// config) the migrated contents are still used for this run. Each rewrite
+/// attempt is queued for `take_migration_events`.
+pub fn migrate_on_load(
+    document: SchemaDocument,
+    path: &Path,
+    raw: String,
+) -> Result<String, String> {
+    let outcome = migrate_document(document, raw.as_str())?;
+    if outcome.changed() {
+        let written = write_migrated_file(path, &outcome);
+        record_migration_event(migration_event(path, &outcome, written));
+    }
+    Ok(outcome.contents)
+}
+
+fn migration_event(
+    path: &Path,
+    outcome: &MigrationOutcome,
+    written: Result<PathBuf, String>,
+) -> Event {
+    let event = match written {
+        Ok(backup) => Event::new("config_migration", "applied").with_data(
+            "backup",
+            serde_json::Value::from(backup.display().to_string()),
+        ),
+        Err(error) => Event::new("config_migration", "write_failed")
+            .with_data("error", serde_json::Value::from(error)),
+    };
+    event
+        .with_data(
+            "document",
+            serde_json::Value::from(outcome.document.label()),
+        )
+        .with_data("path", serde_json::Value::from(path.display().to_string()))
+        .with_data(
+            "from_version",
+            serde_json::Value::from(outcome.from_version),
+        )
+        .with_data("to_version", serde_json::Value::from(outcome.to_version))
+}
+
+/// Keeps the newest load-time migration events until an event log drains them.
+/// Loads happen deep in config code that has no logger of its own.
+fn record_migration_event(event: Event) {
+    let mut pending = PENDING_MIGRATION_EVENTS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if pending.len() >= MAX_PENDING_MIGRATION_EVENTS {
+        pending.remove(0);
+    }
+    pending.push(event);
+}
+
+pub fn take_migration_events() -> Vec<Event> {
+    std::mem::take(
+        &mut *PENDING_MIGRATION_EVENTS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use super::{
+        MIGRATION_LOG_FILE, SchemaDocument, migrate_document, migrate_on_load, migration_event,
+    };
+
+    fn unique_temp_dir(label: &str) -> PathBuf {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_nanos())
+            .unwrap_or(0);
+        let path = std::env::temp_dir().join(format!(
+            "grove-migrations-{label}-{}-{timestamp}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&path).expect("temp dir should exist");
+        path
+    }
+
+    #[test]
+    fn legacy_global_config_migrates_with_backup_and_log() {
+        let directory = unique_temp_dir("global");
+        let path = directory.join("config.toml");
+        let legacy = "sidebar_width_pct = 40\nlaunch_skip_permissions = true\n";
+        fs::write(&path, legacy).expect("config should write");
+
+        let migrated = migrate_on_load(SchemaDocument::GlobalConfig, &path, legacy.to_string())
+            .expect("config should migrate");
+
+        assert!(migrated.contains("launch_permission_mode = \"unsafe\""));
+        assert!(migrated.contains("schema_version = 1"));
+        assert!(!migrated.contains("launch_skip_permissions"));
+        assert_eq!(
+            fs::read_to_string(directory.join("config.toml.v0.bak")).expect("backup should exist"),
+            legacy
+        );
+        assert_eq!(
+            fs::read_to_string(&path).expect("config should read"),
+            migrated
+        );
+        let log = fs::read_to_string(directory.join(MIGRATION_LOG_FILE))
+            .expect("migration log should exist");
+        assert!(log.contains("v0 -> v1: replace launch_skip_permissions"));
+
+        let again = migrate_document(SchemaDocument::GlobalConfig, migrated.as_str())
+            .expect("current config should load");
+        assert!(!again.outdated());
+        assert!(!again.changed());
+
+        let _ = fs::remove_dir_all(directory);
+    }
+
+    #[test]
+    fn migrations_fill_old_manifests_and_reject_newer_versions() {
+        let outcome = migrate_document(
+            SchemaDocument::TaskManifest,
+            r#"
+name = "flohome"
+slug = "flohome"
+root_path = "/tmp/flohome"
+branch = "flohome"
+
+[[worktrees]]
+repository_name = "web"
+repository_path = "/repos/web"
+path = "/tmp/flohome/web"
+branch = "flohome"
+agent = "codex"
+status = "idle"
+"#,
+        )
+        .expect("manifest should migrate");
+        assert_eq!(outcome.applied.len(), 1);
+        assert!(outcome.contents.contains("pull_requests = []"));
+        assert!(outcome.contents.contains("supported_agent = true"));
+
+        let error = migrate_document(SchemaDocument::ProjectsState, "schema_version = 99\n")
+            .expect_err("newer schema should be rejected");
+        assert!(error.contains("newer"));
+    }
+
+    #[test]
+    fn unversioned_projects_config_is_stamped_once_with_backup_and_log() {
+        let directory = unique_temp_dir("projects");
+        let path = directory.join("projects.toml");
+        let legacy = "projects = []\n";
+        fs::write(&path, legacy).expect("projects should write");
+
+        let migrated = migrate_on_load(SchemaDocument::ProjectsState, &path, legacy.to_string())
+            .expect("projects should migrate");
+
+        assert!(migrated.contains("schema_version = 1"));
+        assert_eq!(
+            fs::read_to_string(&path).expect("projects should read"),
+            migrated
+        );
+        assert_eq!(
+            fs::read_to_string(directory.join("projects.toml.v0.bak"))
+                .expect("backup should exist"),
+            legacy
+        );
+        let log = fs::read_to_string(directory.join(MIGRATION_LOG_FILE))
+            .expect("migration log should exist");
+        assert!(log.contains("v0 -> v1: add schema_version"));
+
+        migrate_on_load(SchemaDocument::ProjectsState, &path, migrated.clone())
+            .expect("stamped projects should load");
+        assert_eq!(
+            fs::read_to_string(directory.join(MIGRATION_LOG_FILE))
+                .expect("migration log should exist"),
+            log
+        );
+        assert!(!directory.join("projects.toml.v1.bak").exists());
+
+        let _ = fs::remove_dir_all(directory);
+    }
+
+    #[test]
+    fn failed_migration_writes_are_reported_as_events() {
+        let outcome = migrate_document(
+            SchemaDocument::GlobalConfig,
+            "launch_skip_permissions = true\n",
+        )
+        .expect("config should migrate");
+        let event = migration_event(
+            PathBuf::from("/read-only/config.toml").as_path(),
+            &outcome,
+            Err("migration backup failed: read-only file system".to_string()),
+        );
+
+        assert_eq!(event.event, "config_migration");
+        assert_eq!(event.kind, "write_failed");
+        assert_eq!(
+            event.data["error"],
+            "migration backup failed: read-only file system"
+        );
+        assert_eq!(event.data["document"], "global config");
+        assert_eq!(event.data["from_version"], 0);
+        assert_eq!(event.data["to_version"], 1);
+    }
+}
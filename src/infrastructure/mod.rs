@@ -1,7 +1,12 @@
 pub mod adapters;
 pub mod config;
 pub mod event_log;
+pub mod migrations;
 pub mod paths;
 pub mod process;
 pub mod process_metrics;
+pub mod profile;
+pub mod shared_config;
+pub mod status_snapshot;
 pub mod task_manifest;
+pub mod themes;
@@ -1,5 +1,7 @@
 use std::path::{Path, PathBuf};
 
+use super::profile::{ActiveProfile, active_profile};
+
 pub(crate) fn refer_to_same_location(left: &Path, right: &Path) -> bool {
     match (left.canonicalize().ok(), right.canonicalize().ok()) {
         (Some(left_canonical), Some(right_canonical)) => left_canonical == right_canonical,
@@ -8,7 +10,18 @@ pub(crate) fn refer_to_same_location(left: &Path, right: &Path) -> bool {
 }
 
 pub(crate) fn tasks_root() -> Option<PathBuf> {
-    dirs::home_dir().map(|home| home.join(".grove").join("tasks"))
+    tasks_root_for(active_profile(), dirs::home_dir().as_deref())
+}
+
+/// The tasks root for `profile`, or the default `~/.grove/tasks` without one.
+pub(crate) fn tasks_root_for(
+    profile: Option<&ActiveProfile>,
+    home: Option<&Path>,
+) -> Option<PathBuf> {
+    if let Some(path) = profile.and_then(|profile| profile.tasks_root.clone()) {
+        return Some(path);
+    }
+    home.map(|home| home.join(".grove").join("tasks"))
 }
 
 #[cfg(test)]
@@ -0,0 +1,352 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use super::config::{self, AgentEnvDefaults, GlobalSettings, ProfileConfig};
+
+pub const PROFILE_ENV_VAR: &str = "GROVE_PROFILE";
+pub const DEFAULT_TMUX_PREFIX: &str = "grove";
+
+/// Names that would make a profile's tmux sessions look like another
+/// profile's, e.g. `grove-ws-...`.
+const RESERVED_PROFILE_NAMES: [&str; 3] = ["ws", "wt", "task"];
+
+static ACTIVE_PROFILE: OnceLock<ActiveProfile> = OnceLock::new();
+
+/// A profile resolved against `config.toml`, with every path made absolute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveProfile {
+    pub name: String,
+    pub config_path: PathBuf,
+    pub projects_path: PathBuf,
+    pub tasks_root: Option<PathBuf>,
+    pub tmux_prefix: String,
+    pub agent_env: AgentEnvDefaults,
+}
+
+/// `--profile` wins over `GROVE_PROFILE`. Blank values mean no profile.
+pub fn requested_profile_name(flag: Option<&str>) -> Option<String> {
+    flag.map(ToOwned::to_owned)
+        .or_else(|| std::env::var(PROFILE_ENV_VAR).ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+fn validate_profile_name(name: &str) -> Result<(), String> {
+    if !name.chars().all(|character| {
+        character.is_ascii_lowercase() || character.is_ascii_digit() || character == '-'
+    }) {
+        return Err(format!(
+            "profile `{name}`: name must use lowercase letters, digits and dashes"
+        ));
+    }
+    if RESERVED_PROFILE_NAMES.contains(&name) {
+        return Err(format!("profile `{name}`: name is reserved"));
+    }
+    Ok(())
+}
+
+fn configured_tmux_prefix(name: &str, profile: &ProfileConfig) -> String {
+    profile
+        .tmux_prefix
+        .as_deref()
+        .map(|prefix| prefix.trim().to_string())
+        .unwrap_or_else(|| format!("{DEFAULT_TMUX_PREFIX}-{name}"))
+}
+
+/// Whether sessions under one prefix can be mistaken for the other's, e.g.
+/// `grove-work-ws-ws-a` from `grove-work-ws` looks like `grove-work`'s
+/// workspace `ws-a`.
+fn tmux_prefixes_collide(left: &str, right: &str) -> bool {
+    let shadows = |prefix: &str, other: &str| {
+        RESERVED_PROFILE_NAMES
+            .iter()
+            .any(|kind| format!("{prefix}-").starts_with(format!("{other}-{kind}-").as_str()))
+    };
+    left == right || shadows(left, right) || shadows(right, left)
+}
+
+fn validate_tmux_prefix(name: &str, prefix: &str, settings: &GlobalSettings) -> Result<(), String> {
+    if prefix.is_empty()
+        || !prefix.chars().all(|character| {
+            character.is_ascii_alphanumeric() || character == '-' || character == '_'
+        })
+    {
+        return Err(format!(
+            "profile `{name}`: tmux_prefix must use letters, digits, dashes and underscores"
+        ));
+    }
+    if tmux_prefixes_collide(prefix, DEFAULT_TMUX_PREFIX) {
+        return Err(format!(
+            "profile `{name}`: tmux_prefix `{prefix}` collides with the default profile"
+        ));
+    }
+    let clash = settings
+        .profiles
+        .iter()
+        .filter(|(other, _)| other.as_str() != name)
+        .find(|(other, profile)| {
+            tmux_prefixes_collide(prefix, configured_tmux_prefix(other, profile).as_str())
+        });
+    if let Some((other, _)) = clash {
+        return Err(format!(
+            "profile `{name}`: tmux_prefix `{prefix}` collides with profile `{other}`"
+        ));
+    }
+    Ok(())
+}
+
+fn expand_home(path: &Path, home: Option<&Path>) -> PathBuf {
+    match (path.strip_prefix("~"), home) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// Builds the profile `name` from `settings.profiles`. A name without a
+/// `[profiles.<name>]` table still works and uses the derived defaults.
+pub fn resolve_profile(
+    name: &str,
+    config_path: &Path,
+    settings: &GlobalSettings,
+    home: Option<&Path>,
+) -> Result<ActiveProfile, String> {
+    validate_profile_name(name)?;
+    let profile = settings.profiles.get(name).cloned().unwrap_or_default();
+    let tmux_prefix = configured_tmux_prefix(name, &profile);
+    validate_tmux_prefix(name, tmux_prefix.as_str(), settings)?;
+
+    let projects_path = match profile.projects_file {
+        Some(path) => {
+            let path = expand_home(path.as_path(), home);
+            if path.is_absolute() {
+                path
+            } else {
+                config_path.with_file_name(path)
+            }
+        }
+        None => config_path.with_file_name(format!("projects.{name}.toml")),
+    };
+    let tasks_root = match profile.tasks_root {
+        Some(path) => Some(expand_home(path.as_path(), home)),
+        None => home.map(|home| {
+            home.join(".grove")
+                .join("profiles")
+                .join(name)
+                .join("tasks")
+        }),
+    };
+
+    Ok(ActiveProfile {
+        name: name.to_string(),
+        config_path: config_path.to_path_buf(),
+        projects_path,
+        tasks_root,
+        tmux_prefix,
+        agent_env: profile.agent_env,
+    })
+}
+
+/// Resolves the requested profile, if any, and makes it active for the rest
+/// of the process. Call once at startup, before anything reads config paths.
+pub fn activate_profile(flag: Option<&str>) -> Result<Option<&'static ActiveProfile>, String> {
+    let Some(name) = requested_profile_name(flag) else {
+        return Ok(None);
+    };
+    let config_path =
+        config::config_path().ok_or_else(|| "cannot resolve config path".to_string())?;
+    let settings = config::load_global_from_path(&config_path)?;
+    let profile = resolve_profile(
+        name.as_str(),
+        &config_path,
+        &settings,
+        dirs::home_dir().as_deref(),
+    )?;
+    let _ = ACTIVE_PROFILE.set(profile);
+    Ok(ACTIVE_PROFILE.get())
+}
+
+pub fn active_profile() -> Option<&'static ActiveProfile> {
+    ACTIVE_PROFILE.get()
+}
+
+pub fn active_profile_name() -> Option<&'static str> {
+    active_profile().map(|profile| profile.name.as_str())
+}
+
+/// The root every Grove tmux session name starts with.
+pub fn tmux_session_root() -> &'static str {
+    active_profile().map_or(DEFAULT_TMUX_PREFIX, |profile| profile.tmux_prefix.as_str())
+}
+
+pub(crate) fn projects_path_override(config_path: &Path) -> Option<PathBuf> {
+    active_profile()
+        .filter(|profile| profile.config_path == config_path)
+        .map(|profile| profile.projects_path.clone())
+}
+
+fn agent_env_segments(entries: &[String]) -> Vec<String> {
+    entries
+        .iter()
+        .flat_map(|entry| entry.split([';', '\n']))
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+        .map(ToOwned::to_owned)
+        .collect()
+}
+
+fn agent_env_key(segment: &str) -> &str {
+    segment
+        .split_once('=')
+        .map_or(segment, |(key, _)| key)
+        .trim()
+}
+
+/// Layers profile env entries over a project's, replacing entries that set
+/// the same variable.
+pub fn overlay_agent_env(base: &[String], overrides: &[String]) -> Vec<String> {
+    let overrides = agent_env_segments(overrides);
+    if overrides.is_empty() {
+        return base.to_vec();
+    }
+    let overridden = overrides
+        .iter()
+        .map(|segment| agent_env_key(segment).to_string())
+        .collect::<HashSet<String>>();
+    agent_env_segments(base)
+        .into_iter()
+        .filter(|segment| !overridden.contains(agent_env_key(segment)))
+        .chain(overrides)
+        .collect()
+}
+
+/// Applies the active profile's `agent_env` to a project's defaults.
+pub(crate) fn apply_profile_agent_env(agent_env: &mut AgentEnvDefaults) {
+    let Some(profile) = active_profile() else {
+        return;
+    };
+    agent_env.claude = overlay_agent_env(&agent_env.claude, &profile.agent_env.claude);
+    agent_env.codex = overlay_agent_env(&agent_env.codex, &profile.agent_env.codex);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::path::{Path, PathBuf};
+
+    use super::{overlay_agent_env, resolve_profile};
+    use crate::infrastructure::config::{AgentEnvDefaults, GlobalSettings, ProfileConfig};
+
+    #[test]
+    fn profiles_resolve_configured_and_derived_settings() {
+        let config_path = Path::new("/cfg/grove/config.toml");
+        let home = Path::new("/home/dev");
+        let settings = GlobalSettings {
+            profiles: BTreeMap::from([(
+                "work".to_string(),
+                ProfileConfig {
+                    projects_file: Some(PathBuf::from("work-projects.toml")),
+                    tasks_root: Some(PathBuf::from("~/client/tasks")),
+                    tmux_prefix: Some("gw".to_string()),
+                    agent_env: AgentEnvDefaults {
+                        claude: vec!["CLAUDE_CONFIG_DIR=~/.claude-client".to_string()],
+                        codex: Vec::new(),
+                    },
+                },
+            )]),
+            ..GlobalSettings::default()
+        };
+
+        let work = resolve_profile("work", config_path, &settings, Some(home))
+            .expect("work profile should resolve");
+        assert_eq!(
+            work.projects_path,
+            PathBuf::from("/cfg/grove/work-projects.toml")
+        );
+        assert_eq!(
+            work.tasks_root,
+            Some(PathBuf::from("/home/dev/client/tasks"))
+        );
+        assert_eq!(work.tmux_prefix, "gw");
+
+        let oss = resolve_profile("oss", config_path, &settings, Some(home))
+            .expect("unconfigured profile should resolve");
+        assert_eq!(
+            oss.projects_path,
+            PathBuf::from("/cfg/grove/projects.oss.toml")
+        );
+        assert_eq!(
+            oss.tasks_root,
+            Some(PathBuf::from("/home/dev/.grove/profiles/oss/tasks"))
+        );
+        assert_eq!(oss.tmux_prefix, "grove-oss");
+
+        assert!(resolve_profile("Work", config_path, &settings, Some(home)).is_err());
+        assert!(resolve_profile("ws", config_path, &settings, Some(home)).is_err());
+        let clashing = GlobalSettings {
+            profiles: BTreeMap::from([(
+                "clash".to_string(),
+                ProfileConfig {
+                    tmux_prefix: Some("grove".to_string()),
+                    ..ProfileConfig::default()
+                },
+            )]),
+            ..GlobalSettings::default()
+        };
+        assert!(resolve_profile("clash", config_path, &clashing, Some(home)).is_err());
+    }
+
+    #[test]
+    fn profiles_whose_sessions_look_like_another_profiles_are_rejected() {
+        let config_path = Path::new("/cfg/grove/config.toml");
+        let settings = GlobalSettings {
+            profiles: BTreeMap::from([
+                ("work".to_string(), ProfileConfig::default()),
+                ("work-ws".to_string(), ProfileConfig::default()),
+            ]),
+            ..GlobalSettings::default()
+        };
+
+        let error = resolve_profile("work-ws", config_path, &settings, None)
+            .expect_err("grove-work-ws-ws-* sessions would look like work's");
+        assert!(error.contains("collides with profile `work`"), "{error}");
+        let error = resolve_profile("work", config_path, &settings, None)
+            .expect_err("work's sessions would swallow work-ws's");
+        assert!(error.contains("collides with profile `work-ws`"), "{error}");
+
+        let renamed = GlobalSettings {
+            profiles: BTreeMap::from([
+                ("work".to_string(), ProfileConfig::default()),
+                (
+                    "work-ws".to_string(),
+                    ProfileConfig {
+                        tmux_prefix: Some("gws".to_string()),
+                        ..ProfileConfig::default()
+                    },
+                ),
+            ]),
+            ..GlobalSettings::default()
+        };
+        assert!(resolve_profile("work", config_path, &renamed, None).is_ok());
+        assert!(resolve_profile("work-ws", config_path, &renamed, None).is_ok());
+    }
+
+    #[test]
+    fn profile_agent_env_replaces_matching_project_entries() {
+        let base = vec![
+            "CLAUDE_CONFIG_DIR=~/.claude; EDITOR=vim".to_string(),
+            "TERM=xterm".to_string(),
+        ];
+        let overrides = vec!["CLAUDE_CONFIG_DIR=~/.claude-oss".to_string()];
+
+        assert_eq!(
+            overlay_agent_env(&base, &overrides),
+            vec![
+                "EDITOR=vim".to_string(),
+                "TERM=xterm".to_string(),
+                "CLAUDE_CONFIG_DIR=~/.claude-oss".to_string(),
+            ]
+        );
+        assert_eq!(overlay_agent_env(&base, &[]), base);
+    }
+}
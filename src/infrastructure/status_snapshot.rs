@@ -0,0 +1,264 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::profile::active_profile_name;
+
+/// Writers rewrite the snapshot at least this often, even when nothing
+/// changed, so readers can tell a live writer from one that has exited.
+pub const STATUS_SNAPSHOT_REFRESH_MS: u64 = 10_000;
+
+/// Snapshots older than this are treated as abandoned by their writer.
+pub const STATUS_SNAPSHOT_STALE_MS: u64 = 3 * STATUS_SNAPSHOT_REFRESH_MS;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusSnapshotSource {
+    Tui,
+    Watch,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusSnapshotWriter {
+    pub source: StatusSnapshotSource,
+    pub pid: u32,
+}
+
+impl StatusSnapshotWriter {
+    pub fn current(source: StatusSnapshotSource) -> Self {
+        Self {
+            source,
+            pid: std::process::id(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusSnapshot {
+    pub updated_at_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub writer: Option<StatusSnapshotWriter>,
+    #[serde(default)]
+    pub workspaces: Vec<StatusSnapshotEntry>,
+}
+
+impl StatusSnapshot {
+    pub fn is_stale(&self, now_ms: u64) -> bool {
+        now_ms.saturating_sub(self.updated_at_ms) > STATUS_SNAPSHOT_STALE_MS
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusSnapshotEntry {
+    pub task: String,
+    pub repository: String,
+    pub path: PathBuf,
+    pub status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub waiting_since_ms: Option<u64>,
+}
+
+pub fn status_snapshot_path() -> Option<PathBuf> {
+    status_snapshot_path_for(active_profile_name(), dirs::home_dir().as_deref())
+}
+
+/// Each profile publishes its own snapshot, so a TUI in one profile never
+/// overwrites what another profile's `grove status` reads.
+pub fn status_snapshot_path_for(profile: Option<&str>, home: Option<&Path>) -> Option<PathBuf> {
+    let grove_dir = home?.join(".grove");
+    Some(match profile {
+        Some(name) => grove_dir.join("profiles").join(name).join("status.json"),
+        None => grove_dir.join("status.json"),
+    })
+}
+
+/// The TUI and `grove watch` share one snapshot per profile. A fresh
+/// snapshot from another writer is left alone unless the TUI is replacing
+/// one published by `grove watch`; stale snapshots are always taken over.
+pub fn status_snapshot_writable_by(
+    existing: Option<&StatusSnapshot>,
+    writer: StatusSnapshotWriter,
+    now_ms: u64,
+) -> bool {
+    let Some(existing) = existing else {
+        return true;
+    };
+    if existing.is_stale(now_ms) {
+        return true;
+    }
+    match existing.writer {
+        None => true,
+        Some(owner) if owner.pid == writer.pid => true,
+        Some(owner) => {
+            writer.source == StatusSnapshotSource::Tui && owner.source != StatusSnapshotSource::Tui
+        }
+    }
+}
+
+pub fn load_status_snapshot_from_path(path: &Path) -> Result<Option<StatusSnapshot>, String> {
+    let raw = match fs::read_to_string(path) {
+        Ok(raw) => raw,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(format!("status snapshot read failed: {error}")),
+    };
+
+    serde_json::from_str::<StatusSnapshot>(&raw)
+        .map(Some)
+        .map_err(|error| format!("status snapshot parse failed: {error}"))
+}
+
+/// Publishes `workspaces` unless another live writer owns the snapshot.
+/// Returns whether the snapshot was written.
+pub fn publish_status_snapshot_to_path(
+    path: &Path,
+    writer: StatusSnapshotWriter,
+    workspaces: &[StatusSnapshotEntry],
+    now_ms: u64,
+) -> Result<bool, String> {
+    let existing = load_status_snapshot_from_path(path).ok().flatten();
+    if !status_snapshot_writable_by(existing.as_ref(), writer, now_ms) {
+        return Ok(false);
+    }
+    let snapshot = StatusSnapshot {
+        updated_at_ms: now_ms,
+        writer: Some(writer),
+        workspaces: workspaces.to_vec(),
+    };
+    save_status_snapshot_to_path(path, &snapshot).map(|()| true)
+}
+
+/// Writes through a sibling temp file so readers polling from a status line
+/// never observe a partially written snapshot.
+pub fn save_status_snapshot_to_path(path: &Path, snapshot: &StatusSnapshot) -> Result<(), String> {
+    let Some(parent) = path.parent() else {
+        return Err("status snapshot path missing parent directory".to_string());
+    };
+
+    fs::create_dir_all(parent)
+        .map_err(|error| format!("status snapshot directory create failed: {error}"))?;
+    let encoded = serde_json::to_string(snapshot)
+        .map_err(|error| format!("status snapshot encode failed: {error}"))?;
+    let temp_path = path.with_extension(format!("json.{}.tmp", std::process::id()));
+    fs::write(&temp_path, encoded)
+        .map_err(|error| format!("status snapshot write failed: {error}"))?;
+    fs::rename(&temp_path, path).map_err(|error| {
+        let _ = fs::remove_file(&temp_path);
+        format!("status snapshot rename failed: {error}")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        STATUS_SNAPSHOT_STALE_MS, StatusSnapshot, StatusSnapshotEntry, StatusSnapshotSource,
+        StatusSnapshotWriter, load_status_snapshot_from_path, save_status_snapshot_to_path,
+        status_snapshot_path_for, status_snapshot_writable_by,
+    };
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn unique_temp_path(label: &str) -> PathBuf {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock should be monotonic")
+            .as_nanos();
+        let pid = std::process::id();
+        std::env::temp_dir()
+            .join(format!("grove-status-{label}-{pid}-{timestamp}"))
+            .join("status.json")
+    }
+
+    #[test]
+    fn snapshot_path_is_separate_per_profile() {
+        let home = Path::new("/home/dev");
+        assert_eq!(
+            status_snapshot_path_for(None, Some(home)),
+            Some(PathBuf::from("/home/dev/.grove/status.json"))
+        );
+        assert_eq!(
+            status_snapshot_path_for(Some("work"), Some(home)),
+            Some(PathBuf::from("/home/dev/.grove/profiles/work/status.json"))
+        );
+        assert_eq!(status_snapshot_path_for(Some("work"), None), None);
+    }
+
+    #[test]
+    fn missing_snapshot_loads_as_none() {
+        let path = unique_temp_path("missing");
+        assert_eq!(load_status_snapshot_from_path(&path), Ok(None));
+    }
+
+    #[test]
+    fn snapshot_round_trips_through_disk() {
+        let path = unique_temp_path("round-trip");
+        let snapshot = StatusSnapshot {
+            updated_at_ms: 42,
+            writer: Some(StatusSnapshotWriter {
+                source: StatusSnapshotSource::Watch,
+                pid: 7,
+            }),
+            workspaces: vec![StatusSnapshotEntry {
+                task: "feature-a".to_string(),
+                repository: "grove".to_string(),
+                path: PathBuf::from("/tasks/feature-a/grove"),
+                status: "waiting".to_string(),
+                waiting_since_ms: Some(40),
+            }],
+        };
+
+        save_status_snapshot_to_path(&path, &snapshot).expect("snapshot should save");
+        assert_eq!(load_status_snapshot_from_path(&path), Ok(Some(snapshot)));
+
+        if let Some(parent) = path.parent() {
+            let _ = fs::remove_dir_all(parent);
+        }
+    }
+
+    #[test]
+    fn fresh_snapshots_from_other_writers_are_left_alone() {
+        let tui = StatusSnapshotWriter {
+            source: StatusSnapshotSource::Tui,
+            pid: 10,
+        };
+        let watch = StatusSnapshotWriter {
+            source: StatusSnapshotSource::Watch,
+            pid: 20,
+        };
+        let written_by = |writer| StatusSnapshot {
+            updated_at_ms: 1_000,
+            writer: Some(writer),
+            workspaces: Vec::new(),
+        };
+
+        assert!(status_snapshot_writable_by(None, watch, 1_000));
+        assert!(status_snapshot_writable_by(
+            Some(&written_by(watch)),
+            watch,
+            1_000
+        ));
+        assert!(!status_snapshot_writable_by(
+            Some(&written_by(tui)),
+            watch,
+            1_000
+        ));
+        assert!(status_snapshot_writable_by(
+            Some(&written_by(watch)),
+            tui,
+            1_000
+        ));
+        assert!(!status_snapshot_writable_by(
+            Some(&written_by(StatusSnapshotWriter { pid: 11, ..tui })),
+            tui,
+            1_000
+        ));
+
+        let stale_at = 1_000 + STATUS_SNAPSHOT_STALE_MS + 1;
+        assert!(written_by(tui).is_stale(stale_at));
+        assert!(status_snapshot_writable_by(
+            Some(&written_by(tui)),
+            watch,
+            stale_at
+        ));
+    }
+}
@@ -1,12 +1,21 @@
+use std::fs;
+use std::path::Path;
+
 use crate::domain::{AgentType, PullRequest, PullRequestStatus, Task, WorkspaceStatus, Worktree};
 use serde::{Deserialize, Serialize};
 
+use super::migrations::{SchemaDocument, migrate_document, migrate_on_load};
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 struct TaskManifest {
     name: String,
     slug: String,
     root_path: String,
     branch: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    notes: String,
     worktrees: Vec<TaskManifestWorktree>,
 }
 
@@ -38,6 +47,8 @@ pub fn encode_task_manifest(task: &Task) -> Result<String, String> {
         slug: task.slug.clone(),
         root_path: task.root_path.to_string_lossy().into_owned(),
         branch: task.branch.clone(),
+        tags: task.tags.clone(),
+        notes: task.notes.clone(),
         worktrees: task
             .worktrees
             .iter()
@@ -49,7 +60,7 @@ pub fn encode_task_manifest(task: &Task) -> Result<String, String> {
                 base_branch: worktree.base_branch.clone(),
                 last_activity_unix_secs: worktree.last_activity_unix_secs,
                 agent: worktree.agent.marker().to_string(),
-                status: workspace_status_name(worktree.status).to_string(),
+                status: worktree.status.label().to_string(),
                 is_orphaned: worktree.is_orphaned,
                 supported_agent: worktree.supported_agent,
                 pull_requests: worktree
@@ -66,11 +77,24 @@ pub fn encode_task_manifest(task: &Task) -> Result<String, String> {
     };
 
     toml::to_string_pretty(&manifest)
+        .map(|encoded| SchemaDocument::TaskManifest.stamp(encoded))
         .map_err(|error| format!("task manifest encode failed: {error}"))
 }
 
+/// Reads the manifest at `path`, rewriting older schema versions on disk
+/// with a backup and a `migrations.log` entry before decoding it.
+pub fn load_task_manifest(path: &Path) -> Result<Task, String> {
+    let raw =
+        fs::read_to_string(path).map_err(|error| format!("task manifest read failed: {error}"))?;
+    let raw = migrate_on_load(SchemaDocument::TaskManifest, path, raw)?;
+    decode_task_manifest(raw.as_str())
+}
+
+/// Decodes a manifest, migrating older schema versions in memory. Manifests
+/// on disk go through `load_task_manifest` so the migration is kept.
 pub fn decode_task_manifest(raw: &str) -> Result<Task, String> {
-    let manifest = toml::from_str::<TaskManifest>(raw)
+    let migrated = migrate_document(SchemaDocument::TaskManifest, raw)?;
+    let manifest = toml::from_str::<TaskManifest>(migrated.contents.as_str())
         .map_err(|error| format!("task manifest parse failed: {error}"))?;
     let worktrees = manifest
         .worktrees
@@ -85,6 +109,7 @@ pub fn decode_task_manifest(raw: &str) -> Result<Task, String> {
         manifest.branch,
         worktrees,
     )
+    .map(|task| task.with_tags(manifest.tags).with_notes(manifest.notes))
     .map_err(|error| format!("task manifest invalid: {error:?}"))
 }
 
@@ -127,20 +152,6 @@ fn decode_worktree(manifest: TaskManifestWorktree) -> Result<Worktree, String> {
         .with_pull_requests(pull_requests))
 }
 
-fn workspace_status_name(status: WorkspaceStatus) -> &'static str {
-    match status {
-        WorkspaceStatus::Main => "main",
-        WorkspaceStatus::Idle => "idle",
-        WorkspaceStatus::Active => "active",
-        WorkspaceStatus::Thinking => "thinking",
-        WorkspaceStatus::Waiting => "waiting",
-        WorkspaceStatus::Done => "done",
-        WorkspaceStatus::Error => "error",
-        WorkspaceStatus::Unknown => "unknown",
-        WorkspaceStatus::Unsupported => "unsupported",
-    }
-}
-
 fn parse_workspace_status(value: &str) -> Option<WorkspaceStatus> {
     match value {
         "main" => Some(WorkspaceStatus::Main),
@@ -175,8 +186,11 @@ fn parse_pull_request_status(value: &str) -> Option<PullRequestStatus> {
 
 #[cfg(test)]
 mod tests {
-    use super::{decode_task_manifest, encode_task_manifest};
+    use super::{decode_task_manifest, encode_task_manifest, load_task_manifest};
     use crate::domain::{AgentType, Task, WorkspaceStatus, Worktree};
+    use crate::infrastructure::migrations::MIGRATION_LOG_FILE;
+    use crate::test_support::unique_test_dir;
+    use std::fs;
     use std::path::PathBuf;
 
     fn fixture_task() -> Task {
@@ -219,4 +233,66 @@ mod tests {
 
         assert_eq!(decoded, task);
     }
+
+    #[test]
+    fn task_manifest_round_trips_tags_and_notes() {
+        let task = fixture_task()
+            .with_tags(vec!["launch".to_string(), "infra".to_string()])
+            .with_notes("## Context\n\nShip behind the `launch` flag.\n".to_string());
+
+        let encoded = encode_task_manifest(&task).expect("manifest should encode");
+        let decoded = decode_task_manifest(&encoded).expect("manifest should decode");
+
+        assert_eq!(decoded, task);
+    }
+
+    #[test]
+    fn task_manifest_without_tags_or_notes_omits_them() {
+        let encoded = encode_task_manifest(&fixture_task()).expect("manifest should encode");
+
+        assert!(!encoded.contains("tags"));
+        assert!(!encoded.contains("notes"));
+    }
+
+    #[test]
+    fn loading_an_old_manifest_rewrites_it_once_with_a_backup() {
+        let directory = unique_test_dir("task-manifest-migrate");
+        let path = directory.join("task.toml");
+        let legacy = r#"name = "flohome"
+slug = "flohome"
+root_path = "/tmp/flohome"
+branch = "flohome"
+
+[[worktrees]]
+repository_name = "web"
+repository_path = "/repos/web"
+path = "/tmp/flohome/web"
+branch = "flohome"
+agent = "codex"
+status = "idle"
+"#;
+        fs::write(&path, legacy).expect("manifest should write");
+
+        let task = load_task_manifest(&path).expect("old manifest should load");
+
+        assert!(task.worktrees[0].supported_agent);
+        let rewritten = fs::read_to_string(&path).expect("manifest should read");
+        assert!(rewritten.contains("schema_version = 1"));
+        assert!(rewritten.contains("pull_requests = []"));
+        assert_eq!(
+            fs::read_to_string(directory.join("task.toml.v0.bak")).expect("backup should exist"),
+            legacy
+        );
+        let log = fs::read_to_string(directory.join(MIGRATION_LOG_FILE))
+            .expect("migration log should exist");
+
+        assert_eq!(load_task_manifest(&path), Ok(task));
+        assert_eq!(
+            fs::read_to_string(directory.join(MIGRATION_LOG_FILE))
+                .expect("migration log should exist"),
+            log
+        );
+
+        let _ = fs::remove_dir_all(directory);
+    }
 }
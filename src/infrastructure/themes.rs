@@ -0,0 +1,357 @@
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Deserializer};
+
+use super::config::ThemeName;
+
+pub const THEMES_DIRECTORY: &str = "themes";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThemeColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl ThemeColor {
+    fn parse(value: &str) -> Option<Self> {
+        let hex = value.trim().strip_prefix('#')?;
+        if hex.len() != 6 || !hex.chars().all(|character| character.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).ok();
+        Some(Self {
+            r: channel(0)?,
+            g: channel(2)?,
+            b: channel(4)?,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for ThemeColor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        Self::parse(raw.as_str()).ok_or_else(|| {
+            serde::de::Error::custom(format!("invalid color `{raw}`, expected #rrggbb"))
+        })
+    }
+}
+
+/// UI palette keys. Anything left out keeps the default theme's color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ThemePalette {
+    pub background: Option<ThemeColor>,
+    pub surface: Option<ThemeColor>,
+    pub overlay: Option<ThemeColor>,
+    pub text: Option<ThemeColor>,
+    pub text_muted: Option<ThemeColor>,
+    pub text_subtle: Option<ThemeColor>,
+    pub primary: Option<ThemeColor>,
+    pub secondary: Option<ThemeColor>,
+    pub accent: Option<ThemeColor>,
+    pub info: Option<ThemeColor>,
+    pub success: Option<ThemeColor>,
+    pub warning: Option<ThemeColor>,
+    pub error: Option<ThemeColor>,
+    pub border: Option<ThemeColor>,
+    pub border_focused: Option<ThemeColor>,
+    pub selection_bg: Option<ThemeColor>,
+    pub selection_fg: Option<ThemeColor>,
+    pub scrollbar_track: Option<ThemeColor>,
+    pub scrollbar_thumb: Option<ThemeColor>,
+}
+
+/// tmux colors. Anything left out is derived from the UI palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct TmuxPalette {
+    pub border: Option<ThemeColor>,
+    pub active_border: Option<ThemeColor>,
+    pub status_fg: Option<ThemeColor>,
+    pub status_bg: Option<ThemeColor>,
+    pub message_fg: Option<ThemeColor>,
+    pub message_bg: Option<ThemeColor>,
+    pub mode_fg: Option<ThemeColor>,
+    pub mode_bg: Option<ThemeColor>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CustomThemeFile {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    palette: ThemePalette,
+    #[serde(default)]
+    tmux: TmuxPalette,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomTheme {
+    pub key: String,
+    pub display_name: String,
+    pub palette: ThemePalette,
+    pub tmux: TmuxPalette,
+}
+
+/// The custom themes loaded next to a config file. Config loads keep this
+/// set alongside the settings that name a theme from it.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CustomThemes {
+    pub themes: Vec<CustomTheme>,
+    pub errors: Vec<String>,
+}
+
+impl CustomThemes {
+    pub fn load_for_config(config_path: &Path) -> Self {
+        load_custom_themes(themes_directory_for(config_path).as_path())
+    }
+
+    pub fn get(&self, key: &str) -> Option<&CustomTheme> {
+        self.themes.iter().find(|theme| theme.key == key)
+    }
+
+    pub fn names(&self) -> Vec<ThemeName> {
+        self.themes
+            .iter()
+            .map(|theme| ThemeName::Custom(theme.key.clone()))
+            .collect()
+    }
+
+    /// Built-in themes are always valid; a custom name must match a theme in
+    /// this set.
+    pub fn check(&self, theme: &ThemeName) -> Result<(), String> {
+        match theme {
+            ThemeName::Custom(key) if self.get(key).is_none() => Err(format!(
+                "unknown theme `{key}`, expected a built-in theme or {THEMES_DIRECTORY}/{key}.toml"
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn select(&self, theme: &ThemeName) -> ThemeSelection {
+        let custom = match theme {
+            ThemeName::Custom(key) => self.get(key).cloned(),
+            _ => None,
+        };
+        ThemeSelection {
+            name: theme.clone(),
+            custom,
+        }
+    }
+}
+
+/// A theme name with its custom colors already looked up, so rendering and
+/// tmux styling work from the value alone.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ThemeSelection {
+    pub name: ThemeName,
+    pub custom: Option<CustomTheme>,
+}
+
+impl From<ThemeName> for ThemeSelection {
+    fn from(name: ThemeName) -> Self {
+        Self { name, custom: None }
+    }
+}
+
+pub fn themes_directory_for(config_path: &Path) -> PathBuf {
+    let directory_name = config_path.file_name().map_or_else(
+        || OsString::from(THEMES_DIRECTORY),
+        |name| {
+            if name == "config.toml" {
+                OsString::from(THEMES_DIRECTORY)
+            } else {
+                let mut value = name.to_os_string();
+                value.push(".themes");
+                value
+            }
+        },
+    );
+    config_path.with_file_name(directory_name)
+}
+
+/// Reads every `*.toml` under `directory`, sorted by file name. Broken files
+/// are reported in `errors` and skipped.
+pub fn load_custom_themes(directory: &Path) -> CustomThemes {
+    let mut load = CustomThemes::default();
+    let entries = match fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return load,
+        Err(error) => {
+            load.errors
+                .push(format!("themes directory read failed: {error}"));
+            return load;
+        }
+    };
+    let mut paths = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "toml")
+        })
+        .collect::<Vec<PathBuf>>();
+    paths.sort();
+
+    for path in paths {
+        match load_custom_theme(path.as_path()) {
+            Ok(theme) => load.themes.push(theme),
+            Err(error) => load.errors.push(error),
+        }
+    }
+    load
+}
+
+fn load_custom_theme(path: &Path) -> Result<CustomTheme, String> {
+    let file_name = path.display();
+    let key = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default();
+    if key.is_empty()
+        || !key.chars().all(|character| {
+            character.is_ascii_lowercase() || character.is_ascii_digit() || character == '-'
+        })
+    {
+        return Err(format!(
+            "theme {file_name}: file name must use lowercase letters, digits and dashes"
+        ));
+    }
+    if ThemeName::BUILT_IN
+        .iter()
+        .any(|built_in| built_in.config_key() == key)
+    {
+        return Err(format!(
+            "theme {file_name}: `{key}` shadows a built-in theme"
+        ));
+    }
+    let raw = fs::read_to_string(path)
+        .map_err(|error| format!("theme {file_name}: read failed: {error}"))?;
+    let file = toml::from_str::<CustomThemeFile>(&raw)
+        .map_err(|error| format!("theme {file_name}: {error}"))?;
+    let display_name = file
+        .name
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| key.to_string());
+
+    Ok(CustomTheme {
+        key: key.to_string(),
+        display_name,
+        palette: file.palette,
+        tmux: file.tmux,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CustomThemes, ThemeColor, load_custom_themes};
+    use crate::infrastructure::config::ThemeName;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn unique_temp_dir(label: &str) -> PathBuf {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_nanos())
+            .unwrap_or(0);
+        let path = std::env::temp_dir().join(format!(
+            "grove-themes-{label}-{}-{timestamp}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&path).expect("temp dir should exist");
+        path
+    }
+
+    #[test]
+    fn load_custom_themes_keeps_valid_files_and_reports_broken_ones() {
+        let directory = unique_temp_dir("load");
+        fs::write(
+            directory.join("house.toml"),
+            r##"
+name = "House"
+
+[palette]
+background = "#101010"
+primary = "#ffcc00"
+
+[tmux]
+status_bg = "#202020"
+"##,
+        )
+        .expect("theme should write");
+        fs::write(
+            directory.join("bad-color.toml"),
+            "[palette]\nprimary = \"yellow\"\n",
+        )
+        .expect("theme should write");
+        fs::write(directory.join("monokai.toml"), "").expect("theme should write");
+        fs::write(
+            directory.join("typo.toml"),
+            "[palette]\nbackgroud = \"#000000\"\n",
+        )
+        .expect("theme should write");
+
+        let load = load_custom_themes(directory.as_path());
+
+        assert_eq!(load.themes.len(), 1);
+        let theme = &load.themes[0];
+        assert_eq!(theme.key, "house");
+        assert_eq!(theme.display_name, "House");
+        assert_eq!(
+            theme.palette.primary,
+            Some(ThemeColor {
+                r: 0xff,
+                g: 0xcc,
+                b: 0x00
+            })
+        );
+        assert_eq!(theme.palette.text, None);
+        assert_eq!(
+            theme.tmux.status_bg,
+            Some(ThemeColor {
+                r: 0x20,
+                g: 0x20,
+                b: 0x20
+            })
+        );
+        assert_eq!(load.errors.len(), 3);
+        assert!(load.errors.iter().any(|error| error.contains("#rrggbb")));
+        assert!(load.errors.iter().any(|error| error.contains("built-in")));
+        assert!(load.errors.iter().any(|error| error.contains("backgroud")));
+
+        let _ = fs::remove_dir_all(directory);
+    }
+
+    #[test]
+    fn only_loaded_custom_theme_names_pass_the_check() {
+        let directory = unique_temp_dir("check");
+        fs::write(directory.join("house.toml"), "").expect("theme should write");
+        let themes = load_custom_themes(directory.as_path());
+
+        assert_eq!(themes.check(&ThemeName::RosePine), Ok(()));
+        assert_eq!(themes.check(&ThemeName::Custom("house".to_string())), Ok(()));
+        assert_eq!(
+            themes.check(&ThemeName::Custom("rose-pien".to_string())),
+            Err(
+                "unknown theme `rose-pien`, expected a built-in theme or themes/rose-pien.toml"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            themes.select(&ThemeName::Custom("house".to_string())).custom,
+            themes.get("house").cloned()
+        );
+        assert_eq!(CustomThemes::default().select(&ThemeName::RosePine).custom, None);
+
+        let _ = fs::remove_dir_all(directory);
+    }
+}
@@ -1,3 +1,6 @@
-fn main() -> std::io::Result<()> {
-    grove::cli::run(std::env::args().skip(1))
+fn main() {
+    if let Err(error) = grove::cli::run(std::env::args().skip(1)) {
+        eprintln!("Error: {error}");
+        std::process::exit(grove::cli::exit_code(&error));
+    }
 }
@@ -55,12 +55,14 @@ impl GroveApp {
                 tasks: Vec::new(),
                 discovery_state: TaskDiscoveryState::Empty,
             });
-        Self::from_task_parts_with_clipboard_and_projects(
+        let app = Self::from_task_parts_with_clipboard_and_projects(
             bootstrap,
             projects,
             hidden_base_project_paths,
             dependencies,
-        )
+        );
+        app.log_config_migrations();
+        app
     }
 
     pub(super) fn from_task_parts_with_clipboard_and_projects(
@@ -104,12 +106,20 @@ impl GroveApp {
             event_log,
             debug_record_start_ts,
         } = dependencies;
-        let persisted_config = crate::infrastructure::config::load_from_path(&config_path)
-            .unwrap_or_else(|_| GroveConfig::default());
+        let (persisted_config, config_warning) =
+            match crate::infrastructure::config::load_from_path(&config_path) {
+                Ok(config) => (config, None),
+                Err(error) => (GroveConfig::default(), Some(error)),
+            };
+        let config_reload =
+            ConfigReloadState::capture(&config_path, persisted_config.global_settings());
         let sidebar_width_pct = clamp_sidebar_ratio(persisted_config.sidebar_width_pct);
         let theme_name = persisted_config.theme;
+        let custom_themes = persisted_config.custom_themes;
         let launch_permission_mode = persisted_config.launch_permission_mode;
+        let (keymap, keymap_errors) = Keymap::from_config(&persisted_config.keys);
         let task_order = persisted_config.task_order;
+        let sidebar_view = persisted_config.sidebar;
         let workspace_attention_ack_markers = persisted_config
             .attention_acks
             .into_iter()
@@ -131,12 +141,17 @@ impl GroveApp {
             .unwrap_or_default();
         let mut app = Self {
             repo_name,
+            profile_name: crate::infrastructure::profile::active_profile_name()
+                .map(ToOwned::to_owned),
             projects,
             hidden_base_project_paths: hidden_base_project_paths
                 .into_iter()
                 .collect::<HashSet<PathBuf>>(),
             task_order,
             task_reorder: None,
+            dashboard: None,
+            sidebar_view,
+            sidebar_filter: SidebarFilterState::default(),
             state,
             discovery_state,
             preview_tab: PreviewTab::Agent,
@@ -144,6 +159,7 @@ impl GroveApp {
             last_agent_selection,
             workspace_visit_order: initial_visit_order,
             preview: PreviewState::new(),
+            preview_split: None,
             notifications: NotificationQueue::new(
                 QueueConfig::new()
                     .max_visible(3)
@@ -153,6 +169,8 @@ impl GroveApp {
                     .dedup_window_ms(0),
             ),
             action_mapper: ActionMapper::new(mapper_config),
+            keymap,
+            pending_key_sequence: Vec::new(),
             dialogs: DialogState {
                 active_dialog: None,
                 keybind_help_open: false,
@@ -205,6 +223,7 @@ impl GroveApp {
                 #[cfg(test)]
                 workspace_status_observation_overrides: HashMap::new(),
                 workspace_waiting_prompts: HashMap::new(),
+                workspace_output_tails: HashMap::new(),
                 workspace_idle_polls_since_output: HashMap::new(),
                 next_tick_due_at: None,
                 next_tick_interval_ms: None,
@@ -223,7 +242,12 @@ impl GroveApp {
                 last_diff_stat_poll_at: None,
                 diff_capture_in_flight: false,
                 diff_stat_in_flight: false,
+                last_branch_sync_poll_at: None,
+                branch_sync_in_flight: false,
+                fetch_scheduler: FetchScheduler::new(),
                 preview_stream: PreviewStreamState::default(),
+                last_config_reload_poll_at: None,
+                config_reload,
             },
             workspace_attention: HashMap::new(),
             workspace_attention_ack_markers,
@@ -239,6 +263,7 @@ impl GroveApp {
             sidebar_width_pct,
             panes: panes::GrovePaneModel::canonical(sidebar_width_pct),
             theme_name,
+            custom_themes,
             sidebar_hidden: false,
             mouse_capture_enabled: true,
             launch_permission_mode,
@@ -273,8 +298,12 @@ impl GroveApp {
             sidebar_list_state: RefCell::new(VirtualizedListState::new().with_overscan(0)),
             last_sidebar_mouse_scroll_at: None,
             workspace_diff_stats: HashMap::new(),
+            diff_review: DiffReviewState::default(),
+            workspace_branch_sync: HashMap::new(),
             last_sidebar_mouse_scroll_delta: 0,
             focus_manager: Self::build_main_focus_manager(),
+            control_socket: ControlSocketState::default(),
+            status_snapshot: StatusSnapshotState::default(),
             #[cfg(test)]
             task_root_override: None,
             #[cfg(test)]
@@ -287,6 +316,10 @@ impl GroveApp {
         app.reconcile_workspace_attention_tracking();
         app.refresh_preview_summary();
         app.sync_main_focus_nodes();
+        app.report_keymap_errors(&keymap_errors);
+        if let Some(warning) = config_warning {
+            app.show_warning_toast(warning);
+        }
         app
     }
 
@@ -1,4 +1,3 @@
-use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
@@ -68,6 +67,7 @@ fn ensure_current_repo_project(config: &mut GroveConfig, config_path: &Path) ->
         &config.task_order,
         &config.attention_acks,
         &config.hidden_base_project_paths,
+        config.sidebar,
     )
     .err()
 }
@@ -89,75 +89,3 @@ pub(super) fn load_runtime_config() -> (GroveConfig, PathBuf, Option<String>) {
 
     (config, config_path, error)
 }
-
-pub(super) fn read_workspace_launch_prompt(workspace_path: &Path) -> Option<String> {
-    let raw = fs::read_to_string(workspace_path.join(WORKSPACE_LAUNCH_PROMPT_FILENAME)).ok()?;
-    let trimmed = raw.trim();
-    if trimmed.is_empty() {
-        return None;
-    }
-
-    Some(trimmed.to_string())
-}
-
-pub(super) fn read_workspace_init_command(workspace_path: &Path) -> Option<String> {
-    let raw = fs::read_to_string(workspace_path.join(WORKSPACE_INIT_COMMAND_FILENAME)).ok()?;
-    let trimmed = raw.trim();
-    if trimmed.is_empty() {
-        return None;
-    }
-
-    Some(trimmed.to_string())
-}
-
-pub(super) fn write_workspace_init_command(
-    workspace_path: &Path,
-    init_command: Option<&str>,
-) -> Result<(), String> {
-    let marker_path = workspace_path.join(WORKSPACE_INIT_COMMAND_FILENAME);
-    let Some(parent) = marker_path.parent() else {
-        return Err(format!(
-            "workspace init marker has no parent: {}",
-            marker_path.display()
-        ));
-    };
-    fs::create_dir_all(parent)
-        .map_err(|error| format!("create marker directory failed: {error}"))?;
-    let trimmed = init_command.map(str::trim).unwrap_or_default();
-    if trimmed.is_empty() {
-        if marker_path.exists() {
-            fs::remove_file(&marker_path)
-                .map_err(|error| format!("remove init marker failed: {error}"))?;
-        }
-        return Ok(());
-    }
-
-    fs::write(&marker_path, format!("{trimmed}\n"))
-        .map_err(|error| format!("write marker failed: {error}"))?;
-    Ok(())
-}
-
-pub(super) fn read_workspace_permission_mode(
-    workspace_path: &Path,
-) -> Option<crate::domain::PermissionMode> {
-    let raw = fs::read_to_string(workspace_path.join(WORKSPACE_SKIP_PERMISSIONS_FILENAME)).ok()?;
-    crate::domain::PermissionMode::from_marker(raw.trim())
-}
-
-pub(super) fn write_workspace_permission_mode(
-    workspace_path: &Path,
-    permission_mode: crate::domain::PermissionMode,
-) -> Result<(), String> {
-    let marker_path = workspace_path.join(WORKSPACE_SKIP_PERMISSIONS_FILENAME);
-    let Some(parent) = marker_path.parent() else {
-        return Err(format!(
-            "workspace permission-mode marker has no parent: {}",
-            marker_path.display()
-        ));
-    };
-    fs::create_dir_all(parent)
-        .map_err(|error| format!("create marker directory failed: {error}"))?;
-    let value = format!("{}\n", permission_mode.marker());
-    fs::write(&marker_path, value).map_err(|error| format!("write marker failed: {error}"))?;
-    Ok(())
-}
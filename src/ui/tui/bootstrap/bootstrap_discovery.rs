@@ -1,6 +1,7 @@
 use std::collections::HashSet;
 use std::process::Command;
 
+use crate::application::agent_runtime::{task_session_prefix, task_worktree_session_prefix};
 use crate::application::task_discovery::{
     TaskBootstrapData, TaskDiscoveryState,
     bootstrap_task_data_for_root_with_sessions as discover_task_bootstrap_for_root,
@@ -55,7 +56,10 @@ fn running_task_sessions() -> HashSet<String> {
             .map(|content| {
                 content
                     .lines()
-                    .filter(|name| name.starts_with("grove-task-") || name.starts_with("grove-wt-"))
+                    .filter(|name| {
+                        name.starts_with(task_session_prefix().as_str())
+                            || name.starts_with(task_worktree_session_prefix().as_str())
+                    })
                     .map(ToOwned::to_owned)
                     .collect()
             })
@@ -1,6 +1,6 @@
 use super::*;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub(super) enum UiCommand {
     ToggleFocus,
     ToggleSidebar,
@@ -53,6 +53,29 @@ pub(super) enum UiCommand {
     Quit,
     OpenPerformance,
     OpenWorkspaceJump,
+    CleanupWorktrees,
+    CommitChanges,
+    NextDiffHunk,
+    PreviousDiffHunk,
+    ToggleDiffHunkStaged,
+    DiscardDiffHunk,
+    CycleDiffMode,
+    MoreDiffCommits,
+    FewerDiffCommits,
+    ToggleDiffFileTree,
+    NextDiffFile,
+    PreviousDiffFile,
+    AddReviewComment,
+    SendReview,
+    OpenProjectTabs,
+    TogglePreviewSplit,
+    RotatePreviewSplit,
+    FocusOtherPreviewHalf,
+    ToggleDashboard,
+    FilterSidebar,
+    CycleSidebarGroup,
+    CycleSidebarSort,
+    EditTaskNotes,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -173,7 +196,7 @@ impl KeyModifiersMatch {
 }
 
 impl KeybindingSpec {
-    fn matches(self, key_event: &KeyEvent) -> bool {
+    pub(super) fn matches(self, key_event: &KeyEvent) -> bool {
         self.code.matches(key_event)
             && (matches!(self.code, KeyCodeMatch::CtrlChar(_))
                 || self.modifiers.matches(key_event.modifiers))
@@ -193,7 +216,7 @@ fn control_character_for(character: char) -> Option<char> {
 }
 
 impl UiCommand {
-    pub(super) const ALL: [UiCommand; 51] = [
+    pub(super) const ALL: [UiCommand; 74] = [
         UiCommand::ToggleFocus,
         UiCommand::ToggleSidebar,
         UiCommand::OpenPreview,
@@ -245,6 +268,29 @@ impl UiCommand {
         UiCommand::Quit,
         UiCommand::OpenPerformance,
         UiCommand::OpenWorkspaceJump,
+        UiCommand::CleanupWorktrees,
+        UiCommand::CommitChanges,
+        UiCommand::NextDiffHunk,
+        UiCommand::PreviousDiffHunk,
+        UiCommand::ToggleDiffHunkStaged,
+        UiCommand::DiscardDiffHunk,
+        UiCommand::CycleDiffMode,
+        UiCommand::MoreDiffCommits,
+        UiCommand::FewerDiffCommits,
+        UiCommand::ToggleDiffFileTree,
+        UiCommand::NextDiffFile,
+        UiCommand::PreviousDiffFile,
+        UiCommand::AddReviewComment,
+        UiCommand::SendReview,
+        UiCommand::OpenProjectTabs,
+        UiCommand::TogglePreviewSplit,
+        UiCommand::RotatePreviewSplit,
+        UiCommand::FocusOtherPreviewHalf,
+        UiCommand::ToggleDashboard,
+        UiCommand::FilterSidebar,
+        UiCommand::CycleSidebarGroup,
+        UiCommand::CycleSidebarSort,
+        UiCommand::EditTaskNotes,
     ];
 
     pub(super) fn all() -> &'static [UiCommand] {
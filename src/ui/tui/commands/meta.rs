@@ -1,6 +1,6 @@
 use super::*;
 
-static COMMAND_META: [UiCommandMeta; 51] = [
+static COMMAND_META: [UiCommandMeta; 74] = [
     UiCommandMeta {
         palette: Some(PaletteCommandSpec {
             id: "palette:toggle_focus",
@@ -1399,6 +1399,438 @@ static COMMAND_META: [UiCommandMeta; 51] = [
             modifiers: KeyModifiersMatch::Any,
         }],
     },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:cleanup_worktrees",
+            title: "Cleanup Worktrees",
+            description: "Review merged, idle, and unmanaged task worktrees and remove them",
+            tags: &["cleanup", "gc", "worktrees", "merged", "idle", "stale"],
+            category: "System",
+        }),
+        help_hints: &[HelpHintSpec {
+            context: HelpHintContext::Global,
+            label: "Palette cleanup worktrees",
+            key: "Palette",
+            action: "cleanup worktrees",
+        }],
+        keybindings: &[],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:commit_changes",
+            title: "Commit Changes",
+            description: "Stage files and commit from the Diff tab (c in diff preview)",
+            tags: &["commit", "stage", "unstage", "git", "diff", "message", "c"],
+            category: "Workspace",
+        }),
+        help_hints: &[HelpHintSpec {
+            context: HelpHintContext::PreviewDiff,
+            label: "c commit",
+            key: "c",
+            action: "commit",
+        }],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char('c'),
+            modifiers: KeyModifiersMatch::Any,
+        }],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:next_diff_hunk",
+            title: "Next Diff Hunk",
+            description: "Select the next hunk in the Diff tab (J in diff preview)",
+            tags: &["diff", "hunk", "next", "review", "j"],
+            category: "Preview",
+        }),
+        help_hints: &[HelpHintSpec {
+            context: HelpHintContext::PreviewDiff,
+            label: "J/K next/prev hunk",
+            key: "J/K",
+            action: "next/prev hunk",
+        }],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char('J'),
+            modifiers: KeyModifiersMatch::Any,
+        }],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:previous_diff_hunk",
+            title: "Previous Diff Hunk",
+            description: "Select the previous hunk in the Diff tab (K in diff preview)",
+            tags: &["diff", "hunk", "previous", "review", "k"],
+            category: "Preview",
+        }),
+        help_hints: &[],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char('K'),
+            modifiers: KeyModifiersMatch::Any,
+        }],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:toggle_diff_hunk_staged",
+            title: "Stage/Unstage Hunk",
+            description: "Stage the selected unstaged hunk, or unstage a staged one (Space)",
+            tags: &["diff", "hunk", "stage", "unstage", "git", "apply", "space"],
+            category: "Workspace",
+        }),
+        help_hints: &[HelpHintSpec {
+            context: HelpHintContext::PreviewDiff,
+            label: "Space stage/unstage hunk",
+            key: "Space",
+            action: "stage/unstage hunk",
+        }],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char(' '),
+            modifiers: KeyModifiersMatch::None,
+        }],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:discard_diff_hunk",
+            title: "Discard Hunk",
+            description: "Discard the selected unstaged hunk, keeping a patch in .grove/discarded (X)",
+            tags: &["diff", "hunk", "discard", "revert", "git", "x"],
+            category: "Workspace",
+        }),
+        help_hints: &[HelpHintSpec {
+            context: HelpHintContext::PreviewDiff,
+            label: "X discard hunk",
+            key: "X",
+            action: "discard hunk",
+        }],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char('X'),
+            modifiers: KeyModifiersMatch::Any,
+        }],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:cycle_diff_mode",
+            title: "Cycle Diff Mode",
+            description: "Switch the Diff tab between working tree, branch vs merge-base, and last commits (v)",
+            tags: &["diff", "mode", "branch", "merge-base", "commits", "pr", "v"],
+            category: "Preview",
+        }),
+        help_hints: &[HelpHintSpec {
+            context: HelpHintContext::PreviewDiff,
+            label: "v diff mode",
+            key: "v",
+            action: "diff mode",
+        }],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char('v'),
+            modifiers: KeyModifiersMatch::Any,
+        }],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:more_diff_commits",
+            title: "More Diff Commits",
+            description: "Include one more commit in last-commits diff mode (+)",
+            tags: &["diff", "commits", "more", "history", "+"],
+            category: "Preview",
+        }),
+        help_hints: &[HelpHintSpec {
+            context: HelpHintContext::PreviewDiff,
+            label: "+/- commit count",
+            key: "+/-",
+            action: "commit count",
+        }],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char('+'),
+            modifiers: KeyModifiersMatch::Any,
+        }],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:fewer_diff_commits",
+            title: "Fewer Diff Commits",
+            description: "Include one fewer commit in last-commits diff mode (-)",
+            tags: &["diff", "commits", "fewer", "history", "-"],
+            category: "Preview",
+        }),
+        help_hints: &[],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char('-'),
+            modifiers: KeyModifiersMatch::Any,
+        }],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:toggle_diff_file_tree",
+            title: "Toggle Diff File Tree",
+            description: "Show or hide the changed-file tree in the Diff tab (t)",
+            tags: &["diff", "files", "tree", "sidebar", "t"],
+            category: "Preview",
+        }),
+        help_hints: &[HelpHintSpec {
+            context: HelpHintContext::PreviewDiff,
+            label: "t file tree",
+            key: "t",
+            action: "file tree",
+        }],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char('t'),
+            modifiers: KeyModifiersMatch::Any,
+        }],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:next_diff_file",
+            title: "Next Diff File",
+            description: "Jump to the next changed file in the Diff tab (L)",
+            tags: &["diff", "file", "next", "jump", "l"],
+            category: "Preview",
+        }),
+        help_hints: &[HelpHintSpec {
+            context: HelpHintContext::PreviewDiff,
+            label: "H/L prev/next file",
+            key: "H/L",
+            action: "prev/next file",
+        }],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char('L'),
+            modifiers: KeyModifiersMatch::Any,
+        }],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:previous_diff_file",
+            title: "Previous Diff File",
+            description: "Jump to the previous changed file in the Diff tab (H)",
+            tags: &["diff", "file", "previous", "jump", "h"],
+            category: "Preview",
+        }),
+        help_hints: &[],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char('H'),
+            modifiers: KeyModifiersMatch::Any,
+        }],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:add_review_comment",
+            title: "Add Review Comment",
+            description: "Comment on a line of the selected hunk, stored in .grove/ (C)",
+            tags: &["diff", "review", "comment", "line", "note", "c"],
+            category: "Workspace",
+        }),
+        help_hints: &[HelpHintSpec {
+            context: HelpHintContext::PreviewDiff,
+            label: "C comment",
+            key: "C",
+            action: "comment",
+        }],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char('C'),
+            modifiers: KeyModifiersMatch::Any,
+        }],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:send_review",
+            title: "Send Review to Agent",
+            description: "Send all pending review comments to the workspace agent (>)",
+            tags: &["diff", "review", "comments", "send", "agent", "prompt", ">"],
+            category: "Workspace",
+        }),
+        help_hints: &[HelpHintSpec {
+            context: HelpHintContext::PreviewDiff,
+            label: "> send review",
+            key: ">",
+            action: "send review",
+        }],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char('>'),
+            modifiers: KeyModifiersMatch::Any,
+        }],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:open_project_tabs",
+            title: "Open Project Tabs",
+            description: "Open the shell tabs defined in the repository's .grove.toml",
+            tags: &["tabs", "shell", "project", "grove.toml", "team"],
+            category: "Workspace",
+        }),
+        help_hints: &[],
+        keybindings: &[],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:toggle_preview_split",
+            title: "Toggle Split Preview",
+            description: "Pin the current session and show a second session beside it (W)",
+            tags: &["split", "preview", "pin", "side by side", "W"],
+            category: "Preview",
+        }),
+        help_hints: &[HelpHintSpec {
+            context: HelpHintContext::Global,
+            label: "W split preview",
+            key: "W",
+            action: "split preview",
+        }],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char('W'),
+            modifiers: KeyModifiersMatch::Any,
+        }],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:rotate_preview_split",
+            title: "Rotate Split Preview",
+            description: "Switch the split preview between side by side and stacked (|)",
+            tags: &["split", "preview", "vertical", "horizontal", "rotate", "|"],
+            category: "Preview",
+        }),
+        help_hints: &[HelpHintSpec {
+            context: HelpHintContext::Global,
+            label: "| rotate split",
+            key: "|",
+            action: "rotate split",
+        }],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char('|'),
+            modifiers: KeyModifiersMatch::Any,
+        }],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:focus_other_preview_half",
+            title: "Focus Other Preview Half",
+            description: "Move input and scrolling to the other half of the split preview (w)",
+            tags: &["split", "preview", "focus", "swap", "half", "w"],
+            category: "Preview",
+        }),
+        help_hints: &[HelpHintSpec {
+            context: HelpHintContext::Global,
+            label: "w other half",
+            key: "w",
+            action: "other half",
+        }],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char('w'),
+            modifiers: KeyModifiersMatch::Any,
+        }],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:toggle_dashboard",
+            title: "Toggle Dashboard",
+            description: "Show every running agent session in one grid (V)",
+            tags: &["dashboard", "grid", "agents", "overview", "sessions", "V"],
+            category: "Navigation",
+        }),
+        help_hints: &[HelpHintSpec {
+            context: HelpHintContext::Global,
+            label: "V dashboard",
+            key: "V",
+            action: "dashboard",
+        }],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char('V'),
+            modifiers: KeyModifiersMatch::Any,
+        }],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:filter_sidebar",
+            title: "Filter Workspaces",
+            description: "Filter the sidebar by name, branch, repo, status or PR state (f)",
+            tags: &["filter", "search", "sidebar", "branch", "status", "pr", "f"],
+            category: "Navigation",
+        }),
+        help_hints: &[HelpHintSpec {
+            context: HelpHintContext::Global,
+            label: "f filter",
+            key: "f",
+            action: "filter",
+        }],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char('f'),
+            modifiers: KeyModifiersMatch::Any,
+        }],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:cycle_sidebar_group",
+            title: "Cycle Sidebar Grouping",
+            description: "Group the sidebar by task, project or status (b)",
+            tags: &["group", "sidebar", "project", "repository", "status", "b"],
+            category: "Navigation",
+        }),
+        help_hints: &[HelpHintSpec {
+            context: HelpHintContext::Global,
+            label: "b group",
+            key: "b",
+            action: "group",
+        }],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char('b'),
+            modifiers: KeyModifiersMatch::Any,
+        }],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:cycle_sidebar_sort",
+            title: "Cycle Sidebar Sort",
+            description: "Sort the sidebar manually, by last activity or by status urgency (O)",
+            tags: &["sort", "order", "sidebar", "activity", "urgency", "O"],
+            category: "Navigation",
+        }),
+        help_hints: &[HelpHintSpec {
+            context: HelpHintContext::Global,
+            label: "O sort",
+            key: "O",
+            action: "sort",
+        }],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char('O'),
+            modifiers: KeyModifiersMatch::Any,
+        }],
+    },
+    UiCommandMeta {
+        palette: Some(PaletteCommandSpec {
+            id: "palette:edit_task_notes",
+            title: "Edit Task Notes",
+            description: "Edit the selected task's tags and markdown notes (T)",
+            tags: &["notes", "tags", "labels", "task", "context", "T"],
+            category: "Task",
+        }),
+        help_hints: &[HelpHintSpec {
+            context: HelpHintContext::Workspace,
+            label: "T notes/tags",
+            key: "T",
+            action: "notes/tags",
+        }],
+        keybindings: &[KeybindingSpec {
+            scope: KeybindingScope::NonInteractive,
+            code: KeyCodeMatch::Char('T'),
+            modifiers: KeyModifiersMatch::Any,
+        }],
+    },
 ];
 
 impl UiCommand {
@@ -1455,6 +1887,29 @@ impl UiCommand {
             UiCommand::OpenPerformance => &COMMAND_META[48],
             UiCommand::OpenRepository => &COMMAND_META[49],
             UiCommand::OpenWorkspaceJump => &COMMAND_META[50],
+            UiCommand::CleanupWorktrees => &COMMAND_META[51],
+            UiCommand::CommitChanges => &COMMAND_META[52],
+            UiCommand::NextDiffHunk => &COMMAND_META[53],
+            UiCommand::PreviousDiffHunk => &COMMAND_META[54],
+            UiCommand::ToggleDiffHunkStaged => &COMMAND_META[55],
+            UiCommand::DiscardDiffHunk => &COMMAND_META[56],
+            UiCommand::CycleDiffMode => &COMMAND_META[57],
+            UiCommand::MoreDiffCommits => &COMMAND_META[58],
+            UiCommand::FewerDiffCommits => &COMMAND_META[59],
+            UiCommand::ToggleDiffFileTree => &COMMAND_META[60],
+            UiCommand::NextDiffFile => &COMMAND_META[61],
+            UiCommand::PreviousDiffFile => &COMMAND_META[62],
+            UiCommand::AddReviewComment => &COMMAND_META[63],
+            UiCommand::SendReview => &COMMAND_META[64],
+            UiCommand::OpenProjectTabs => &COMMAND_META[65],
+            UiCommand::TogglePreviewSplit => &COMMAND_META[66],
+            UiCommand::RotatePreviewSplit => &COMMAND_META[67],
+            UiCommand::FocusOtherPreviewHalf => &COMMAND_META[68],
+            UiCommand::ToggleDashboard => &COMMAND_META[69],
+            UiCommand::FilterSidebar => &COMMAND_META[70],
+            UiCommand::CycleSidebarGroup => &COMMAND_META[71],
+            UiCommand::CycleSidebarSort => &COMMAND_META[72],
+            UiCommand::EditTaskNotes => &COMMAND_META[73],
         }
     }
 }
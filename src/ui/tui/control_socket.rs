@@ -0,0 +1,943 @@
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::os::unix::fs::{DirBuilderExt, MetadataExt, PermissionsExt};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, mpsc};
+
+use ftui::runtime::{StopSignal, SubId, Subscription};
+use serde_json::{Map, json};
+
+use super::*;
+use crate::infrastructure::profile::active_profile_name;
+
+const CONTROL_SOCKET_POLL_MS: u64 = 50;
+const CONTROL_SOCKET_WRITE_TIMEOUT_MS: u64 = 250;
+/// Longest request line a client may send before it is disconnected.
+const CONTROL_REQUEST_MAX_BYTES: usize = 64 * 1024;
+const JSONRPC_VERSION: &str = "2.0";
+const JSONRPC_PARSE_ERROR: i64 = -32700;
+const JSONRPC_INVALID_REQUEST: i64 = -32600;
+const JSONRPC_METHOD_NOT_FOUND: i64 = -32601;
+const JSONRPC_INVALID_PARAMS: i64 = -32602;
+const CONTROL_REQUEST_FAILED: i64 = -32000;
+
+#[derive(Default)]
+pub(super) struct ControlSocketState {
+    path: Option<PathBuf>,
+    clients: ControlClients,
+    published_statuses: HashMap<PathBuf, WorkspaceStatus>,
+    published_attention: Vec<String>,
+}
+
+#[derive(Clone, Default)]
+struct ControlClients {
+    inner: Arc<Mutex<ControlClientSet>>,
+}
+
+#[derive(Default)]
+struct ControlClientSet {
+    next_id: u64,
+    writers: HashMap<u64, UnixStream>,
+}
+
+impl ControlClients {
+    fn register(&self, stream: &UnixStream) -> Option<u64> {
+        let writer = stream.try_clone().ok()?;
+        let _ =
+            writer.set_write_timeout(Some(Duration::from_millis(CONTROL_SOCKET_WRITE_TIMEOUT_MS)));
+        let mut set = self.inner.lock().ok()?;
+        set.next_id = set.next_id.saturating_add(1);
+        let client_id = set.next_id;
+        set.writers.insert(client_id, writer);
+        Some(client_id)
+    }
+
+    fn remove(&self, client_id: u64) {
+        if let Ok(mut set) = self.inner.lock() {
+            set.writers.remove(&client_id);
+        }
+    }
+
+    fn clear(&self) {
+        if let Ok(mut set) = self.inner.lock() {
+            set.writers.clear();
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.inner
+            .lock()
+            .map(|set| set.writers.is_empty())
+            .unwrap_or(true)
+    }
+
+    fn send(&self, client_id: u64, line: &str) {
+        let Ok(mut set) = self.inner.lock() else {
+            return;
+        };
+        let failed = set
+            .writers
+            .get_mut(&client_id)
+            .is_some_and(|writer| write_control_line(writer, line).is_err());
+        if failed {
+            set.writers.remove(&client_id);
+        }
+    }
+
+    fn broadcast(&self, line: &str) {
+        let Ok(mut set) = self.inner.lock() else {
+            return;
+        };
+        set.writers
+            .retain(|_, writer| write_control_line(writer, line).is_ok());
+    }
+}
+
+fn write_control_line(writer: &mut UnixStream, line: &str) -> io::Result<()> {
+    writer.write_all(line.as_bytes())?;
+    writer.write_all(b"\n")?;
+    writer.flush()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ControlError {
+    code: i64,
+    message: String,
+}
+
+impl ControlError {
+    fn invalid_params(message: impl Into<String>) -> Self {
+        Self {
+            code: JSONRPC_INVALID_PARAMS,
+            message: message.into(),
+        }
+    }
+
+    fn failed(message: impl Into<String>) -> Self {
+        Self {
+            code: CONTROL_REQUEST_FAILED,
+            message: message.into(),
+        }
+    }
+}
+
+pub(super) fn control_socket_path() -> PathBuf {
+    control_socket_path_in(
+        control_socket_root(),
+        active_profile_name(),
+        std::process::id(),
+    )
+}
+
+fn control_socket_root() -> PathBuf {
+    control_socket_root_in(dirs::runtime_dir(), current_uid())
+}
+
+/// `$XDG_RUNTIME_DIR` is private to the user already. Without it the shared
+/// temp dir is used, so the directory is per-uid and `bind_control_socket`
+/// refuses it unless this user owns it with mode 0700.
+fn control_socket_root_in(runtime_dir: Option<PathBuf>, uid: u32) -> PathBuf {
+    match runtime_dir {
+        Some(runtime_dir) => runtime_dir.join("grove"),
+        None => std::env::temp_dir().join(format!("grove-{uid}")),
+    }
+}
+
+/// Named profiles get their own socket directory, so a client only finds the
+/// TUIs running under the profile it asked for.
+fn control_socket_path_in(socket_root: PathBuf, profile: Option<&str>, pid: u32) -> PathBuf {
+    match profile {
+        Some(name) => socket_root.join(name),
+        None => socket_root,
+    }
+    .join(format!("grove-{pid}.sock"))
+}
+
+fn current_uid() -> u32 {
+    // SAFETY: geteuid has no preconditions and cannot fail.
+    unsafe { libc::geteuid() }
+}
+
+fn control_result_line(id: &Value, result: Value) -> String {
+    json!({ "jsonrpc": JSONRPC_VERSION, "id": id, "result": result }).to_string()
+}
+
+fn control_error_line(id: &Value, code: i64, message: &str) -> String {
+    json!({
+        "jsonrpc": JSONRPC_VERSION,
+        "id": id,
+        "error": { "code": code, "message": message },
+    })
+    .to_string()
+}
+
+fn control_notification_line(method: &str, params: Value) -> String {
+    json!({ "jsonrpc": JSONRPC_VERSION, "method": method, "params": params }).to_string()
+}
+
+fn parse_control_request(client_id: u64, line: &str) -> Result<ControlRequest, String> {
+    let value = serde_json::from_str::<Value>(line).map_err(|error| {
+        control_error_line(
+            &Value::Null,
+            JSONRPC_PARSE_ERROR,
+            format!("parse error: {error}").as_str(),
+        )
+    })?;
+    let Some(object) = value.as_object() else {
+        return Err(control_error_line(
+            &Value::Null,
+            JSONRPC_INVALID_REQUEST,
+            "request must be an object",
+        ));
+    };
+    let id = object.get("id").cloned();
+    let response_id = id.clone().unwrap_or(Value::Null);
+    if object.get("jsonrpc").and_then(Value::as_str) != Some(JSONRPC_VERSION) {
+        return Err(control_error_line(
+            &response_id,
+            JSONRPC_INVALID_REQUEST,
+            "jsonrpc must be \"2.0\"",
+        ));
+    }
+    let Some(method) = object.get("method").and_then(Value::as_str) else {
+        return Err(control_error_line(
+            &response_id,
+            JSONRPC_INVALID_REQUEST,
+            "method must be a string",
+        ));
+    };
+    let params = match object.get("params") {
+        None | Some(Value::Null) => Value::Object(Map::new()),
+        Some(Value::Object(params)) => Value::Object(params.clone()),
+        Some(_) => {
+            return Err(control_error_line(
+                &response_id,
+                JSONRPC_INVALID_PARAMS,
+                "params must be an object",
+            ));
+        }
+    };
+
+    Ok(ControlRequest {
+        client_id,
+        id,
+        method: method.to_string(),
+        params,
+    })
+}
+
+fn param_str<'a>(params: &'a Value, key: &str) -> Option<&'a str> {
+    params
+        .get(key)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+fn required_param<'a>(params: &'a Value, key: &str) -> Result<&'a str, ControlError> {
+    param_str(params, key).ok_or_else(|| ControlError::invalid_params(format!("{key} is required")))
+}
+
+fn parse_control_tab(value: &str) -> Option<WorkspaceTabKind> {
+    match value.to_ascii_lowercase().as_str() {
+        "home" => Some(WorkspaceTabKind::Home),
+        "agent" => Some(WorkspaceTabKind::Agent),
+        "shell" => Some(WorkspaceTabKind::Shell),
+        "git" => Some(WorkspaceTabKind::Git),
+        "diff" => Some(WorkspaceTabKind::Diff),
+        _ => None,
+    }
+}
+
+impl GroveApp {
+    pub(super) fn enable_control_socket(&mut self) -> PathBuf {
+        let path = control_socket_path();
+        self.control_socket.path = Some(path.clone());
+        path
+    }
+
+    pub(super) fn control_socket_subscription(&self) -> Option<Box<dyn Subscription<Msg>>> {
+        let path = self.control_socket.path.clone()?;
+        Some(Box::new(ControlSocketSubscription::new(
+            path,
+            self.control_socket.clients.clone(),
+        )))
+    }
+
+    pub(super) fn handle_control_request(&mut self, request: ControlRequest) {
+        let result = self.dispatch_control_request(request.method.as_str(), &request.params);
+        let mut event = LogEvent::new("control_socket", "request_handled")
+            .with_data("method", Value::from(request.method.clone()))
+            .with_data("ok", Value::from(result.is_ok()));
+        if let Err(error) = result.as_ref() {
+            event = event.with_data("error", Value::from(error.message.clone()));
+        }
+        self.telemetry.event_log.log(event);
+
+        let Some(id) = request.id else {
+            return;
+        };
+        let line = match result {
+            Ok(result) => control_result_line(&id, result),
+            Err(error) => control_error_line(&id, error.code, error.message.as_str()),
+        };
+        self.control_socket
+            .clients
+            .send(request.client_id, line.as_str());
+    }
+
+    fn dispatch_control_request(
+        &mut self,
+        method: &str,
+        params: &Value,
+    ) -> Result<Value, ControlError> {
+        match method {
+            "state.get" => return Ok(self.control_state()),
+            "attention.list" => return Ok(Value::Array(self.control_attention_items())),
+            "commands.list" => return Ok(self.control_commands()),
+            "command.run" | "workspace.select" | "tab.open" | "agent.start"
+            | "workspaces.refresh" => {}
+            _ => {
+                return Err(ControlError {
+                    code: JSONRPC_METHOD_NOT_FOUND,
+                    message: format!("unknown method `{method}`"),
+                });
+            }
+        }
+
+        if self.modal_open() {
+            return Err(ControlError::failed("a dialog is open"));
+        }
+        match method {
+            "command.run" => self.control_run_command(params),
+            "workspace.select" => {
+                let workspace_path = self
+                    .control_target_workspace(params)?
+                    .ok_or_else(|| ControlError::invalid_params("path or task is required"))?;
+                self.select_workspace_by_path(workspace_path.as_path());
+                Ok(self.control_selection())
+            }
+            "tab.open" => self.control_open_tab(params),
+            "agent.start" => self.control_start_agent(params),
+            _ => {
+                self.request_manual_workspace_refresh();
+                Ok(json!({ "refreshing": self.dialogs.refresh_in_flight }))
+            }
+        }
+    }
+
+    fn control_target_workspace(&self, params: &Value) -> Result<Option<PathBuf>, ControlError> {
+        if let Some(path) = param_str(params, "path") {
+            let path = PathBuf::from(path);
+            return self
+                .state
+                .workspaces
+                .iter()
+                .find(|workspace| workspace.path == path)
+                .map(|workspace| Some(workspace.path.clone()))
+                .ok_or_else(|| {
+                    ControlError::failed(format!("workspace `{}` not found", path.display()))
+                });
+        }
+
+        let Some(task) = param_str(params, "task") else {
+            return Ok(None);
+        };
+        let Some(task_slug) = self
+            .state
+            .tasks
+            .iter()
+            .find(|candidate| candidate.slug == task)
+            .or_else(|| {
+                self.state
+                    .tasks
+                    .iter()
+                    .find(|candidate| candidate.name == task)
+            })
+            .map(|candidate| candidate.slug.clone())
+        else {
+            return Err(ControlError::failed(format!("task `{task}` not found")));
+        };
+        let repository = param_str(params, "repository");
+        self.state
+            .workspaces
+            .iter()
+            .filter(|workspace| workspace.task_slug.as_deref() == Some(task_slug.as_str()))
+            .find(|workspace| {
+                repository
+                    .is_none_or(|repository| workspace.project_name.as_deref() == Some(repository))
+            })
+            .map(|workspace| Some(workspace.path.clone()))
+            .ok_or_else(|| {
+                ControlError::failed(format!(
+                    "task `{task}` has no repository `{}`",
+                    repository.unwrap_or_default()
+                ))
+            })
+    }
+
+    fn control_select_target(&mut self, params: &Value) -> Result<(), ControlError> {
+        if let Some(workspace_path) = self.control_target_workspace(params)? {
+            self.select_workspace_by_path(workspace_path.as_path());
+        }
+        if self.state.selected_workspace().is_none() {
+            return Err(ControlError::failed("no workspace selected"));
+        }
+        Ok(())
+    }
+
+    fn control_run_command(&mut self, params: &Value) -> Result<Value, ControlError> {
+        let id = required_param(params, "command")?;
+        let command = UiCommand::from_palette_id(id)
+            .or_else(|| UiCommand::from_palette_id(format!("palette:{id}").as_str()))
+            .ok_or_else(|| ControlError::invalid_params(format!("unknown command `{id}`")))?;
+        if !self.palette_command_enabled(command) {
+            return Err(ControlError::failed(format!(
+                "command `{id}` is unavailable"
+            )));
+        }
+        self.execute_ui_command(command);
+        Ok(self.control_selection())
+    }
+
+    fn control_open_tab(&mut self, params: &Value) -> Result<Value, ControlError> {
+        let tab = required_param(params, "tab")?;
+        let kind = parse_control_tab(tab)
+            .ok_or_else(|| ControlError::invalid_params(format!("unknown tab `{tab}`")))?;
+        self.control_select_target(params)?;
+        let _ = self.focus_main_pane(FOCUS_ID_PREVIEW);
+
+        match kind {
+            WorkspaceTabKind::Git => self.open_or_focus_git_tab(),
+            WorkspaceTabKind::Diff => self.open_or_focus_diff_tab(),
+            WorkspaceTabKind::Home | WorkspaceTabKind::Agent | WorkspaceTabKind::Shell => {
+                let existing = self.selected_workspace_tabs_state().and_then(|tabs| {
+                    tabs.tabs
+                        .iter()
+                        .rev()
+                        .find(|tab| tab.kind == kind)
+                        .map(|tab| tab.id)
+                });
+                match existing {
+                    Some(tab_id) => {
+                        let _ = self.select_tab_by_id_for_selected_workspace(tab_id);
+                    }
+                    None if kind == WorkspaceTabKind::Shell => self.open_new_shell_tab(),
+                    None => {
+                        return Err(ControlError::failed(format!(
+                            "no {} tab, use agent.start",
+                            kind.label().to_ascii_lowercase()
+                        )));
+                    }
+                }
+            }
+        }
+        Ok(self.control_selection())
+    }
+
+    fn control_start_agent(&mut self, params: &Value) -> Result<Value, ControlError> {
+        if self.dialogs.start_in_flight || self.dialogs.restart_in_flight {
+            return Err(ControlError::failed("agent lifecycle already in progress"));
+        }
+        let agent = param_str(params, "agent")
+            .map(|value| {
+                AgentType::from_marker(value.to_ascii_lowercase().as_str())
+                    .ok_or_else(|| ControlError::invalid_params(format!("unknown agent `{value}`")))
+            })
+            .transpose()?;
+        let permission_mode = param_str(params, "permission")
+            .map(|value| {
+                PermissionMode::from_marker(value).ok_or_else(|| {
+                    ControlError::invalid_params(format!("unknown permission mode `{value}`"))
+                })
+            })
+            .transpose()?;
+        self.control_select_target(params)?;
+        let Some(workspace) = self.state.selected_workspace().cloned() else {
+            return Err(ControlError::failed("no workspace selected"));
+        };
+        if !workspace.supported_agent {
+            return Err(ControlError::failed("workspace does not support agents"));
+        }
+
+        let agent = agent.unwrap_or_else(|| {
+            self.last_agent_selection
+                .get(workspace.path.as_path())
+                .copied()
+                .unwrap_or(workspace.agent)
+        });
+        let options = StartOptions {
+            name: param_str(params, "name").map(str::to_string),
+            prompt: param_str(params, "prompt")
+                .map(str::to_string)
+                .or_else(|| read_workspace_launch_prompt(&workspace.path)),
+            init_command: self.workspace_init_command_for_workspace(&workspace),
+            permission_mode: permission_mode
+                .unwrap_or_else(|| self.workspace_permission_mode_for_workspace(&workspace)),
+        };
+        self.launch_new_agent_tab(agent, options)
+            .map_err(ControlError::failed)?;
+        let _ = self.focus_main_pane(FOCUS_ID_PREVIEW);
+        Ok(self.control_selection())
+    }
+
+    fn control_selection(&self) -> Value {
+        let Some(workspace) = self.state.selected_workspace() else {
+            return Value::Null;
+        };
+        json!({
+            "path": workspace.path,
+            "workspace": workspace.name,
+            "task": workspace.task_slug,
+            "repository": workspace.project_name,
+            "tab": self.preview_tab.label().to_ascii_lowercase(),
+        })
+    }
+
+    fn control_attention_items(&self) -> Vec<Value> {
+        self.attention_items
+            .iter()
+            .map(|item| {
+                json!({
+                    "path": item.workspace_path,
+                    "task": item.task_slug,
+                    "reason": item.reason.label(),
+                    "summary": item.summary,
+                    "first_seen_at_ms": item.first_seen_at_ms,
+                    "last_seen_at_ms": item.last_seen_at_ms,
+                })
+            })
+            .collect()
+    }
+
+    fn control_state(&self) -> Value {
+        let workspaces = self
+            .state
+            .workspaces
+            .iter()
+            .map(|workspace| {
+                json!({
+                    "path": workspace.path,
+                    "workspace": workspace.name,
+                    "task": workspace.task_slug,
+                    "repository": workspace.project_name,
+                    "branch": workspace.branch,
+                    "agent": workspace.agent.marker(),
+                    "status": workspace.status.label(),
+                    "is_main": workspace.is_main,
+                })
+            })
+            .collect::<Vec<Value>>();
+        let mode = if self.session.interactive.is_some() {
+            "interactive"
+        } else {
+            self.state.mode.name()
+        };
+        json!({
+            "mode": mode,
+            "selected": self.control_selection(),
+            "workspaces": workspaces,
+            "attention": self.control_attention_items(),
+        })
+    }
+
+    fn control_commands(&self) -> Value {
+        Value::Array(
+            UiCommand::all()
+                .iter()
+                .filter(|command| self.palette_command_enabled(**command))
+                .filter_map(|command| command.palette_spec())
+                .map(|spec| {
+                    json!({
+                        "command": spec.id.trim_start_matches("palette:"),
+                        "title": spec.title,
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    pub(super) fn publish_control_notifications(&mut self) {
+        if self.control_socket.path.is_none() {
+            return;
+        }
+
+        let attention = self
+            .attention_items
+            .iter()
+            .map(|item| item.fingerprint.clone())
+            .collect::<Vec<String>>();
+        if !self.control_socket.clients.is_empty() {
+            for workspace in &self.state.workspaces {
+                let previous = self
+                    .control_socket
+                    .published_statuses
+                    .get(workspace.path.as_path())
+                    .copied();
+                if previous == Some(workspace.status) {
+                    continue;
+                }
+                let line = control_notification_line(
+                    "workspace.status_changed",
+                    json!({
+                        "path": workspace.path,
+                        "workspace": workspace.name,
+                        "task": workspace.task_slug,
+                        "previous": previous.map(WorkspaceStatus::label),
+                        "status": workspace.status.label(),
+                    }),
+                );
+                self.control_socket.clients.broadcast(line.as_str());
+            }
+            if attention != self.control_socket.published_attention {
+                let line = control_notification_line(
+                    "attention.changed",
+                    json!({ "items": self.control_attention_items() }),
+                );
+                self.control_socket.clients.broadcast(line.as_str());
+            }
+        }
+
+        self.control_socket.published_statuses = self
+            .state
+            .workspaces
+            .iter()
+            .map(|workspace| (workspace.path.clone(), workspace.status))
+            .collect();
+        self.control_socket.published_attention = attention;
+    }
+}
+
+#[cfg(test)]
+impl GroveApp {
+    pub(super) fn attach_control_client(&mut self) -> (u64, UnixStream) {
+        let (server, client) = UnixStream::pair().expect("socket pair should open");
+        let client_id = self
+            .control_socket
+            .clients
+            .register(&server)
+            .expect("client should register");
+        self.control_socket.path = Some(control_socket_path_in(
+            control_socket_root_in(None, current_uid()),
+            None,
+            0,
+        ));
+        (client_id, client)
+    }
+}
+
+struct ControlSocketSubscription {
+    path: PathBuf,
+    clients: ControlClients,
+    id: SubId,
+}
+
+impl ControlSocketSubscription {
+    fn new(path: PathBuf, clients: ControlClients) -> Self {
+        let mut hasher = DefaultHasher::new();
+        "control_socket".hash(&mut hasher);
+        path.hash(&mut hasher);
+        Self {
+            path,
+            clients,
+            id: hasher.finish(),
+        }
+    }
+}
+
+impl Subscription<Msg> for ControlSocketSubscription {
+    fn id(&self) -> SubId {
+        self.id
+    }
+
+    fn run(&self, sender: mpsc::Sender<Msg>, stop: StopSignal) {
+        let Ok(listener) =
+            bind_control_socket(control_socket_root().as_path(), self.path.as_path())
+        else {
+            return;
+        };
+        let shutdown = AtomicBool::new(false);
+        std::thread::scope(|scope| {
+            while !stop.is_stopped() {
+                match listener.accept() {
+                    Ok((stream, _)) => {
+                        let Some(client_id) = self.clients.register(&stream) else {
+                            continue;
+                        };
+                        let sender = sender.clone();
+                        let clients = &self.clients;
+                        let shutdown = &shutdown;
+                        scope.spawn(move || {
+                            serve_control_client(client_id, stream, clients, &sender, shutdown);
+                        });
+                    }
+                    Err(_) => {
+                        std::thread::sleep(Duration::from_millis(CONTROL_SOCKET_POLL_MS));
+                    }
+                }
+            }
+            shutdown.store(true, Ordering::Relaxed);
+        });
+        self.clients.clear();
+        let _ = fs::remove_file(self.path.as_path());
+    }
+}
+
+fn bind_control_socket(socket_root: &Path, path: &Path) -> io::Result<UnixListener> {
+    let Some(socket_dir) = path.parent() else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "control socket path has no parent",
+        ));
+    };
+    fs::DirBuilder::new()
+        .recursive(true)
+        .mode(0o700)
+        .create(socket_dir)?;
+    for dir in socket_dir
+        .ancestors()
+        .take_while(|dir| dir.starts_with(socket_root))
+    {
+        ensure_private_dir(dir, current_uid())?;
+    }
+    if fs::symlink_metadata(path).is_ok() {
+        fs::remove_file(path)?;
+    }
+
+    // SAFETY: umask only swaps the process file-creation mask; the previous
+    // mask is restored right after bind.
+    let previous_umask = unsafe { libc::umask(0o077) };
+    let bound = UnixListener::bind(path);
+    unsafe { libc::umask(previous_umask) };
+    let listener = bound?;
+    fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+    listener.set_nonblocking(true)?;
+    Ok(listener)
+}
+
+/// Rejects socket directories that are symlinks, belong to another user, or
+/// are reachable by group or others.
+fn ensure_private_dir(dir: &Path, uid: u32) -> io::Result<()> {
+    let metadata = fs::symlink_metadata(dir)?;
+    if !metadata.is_dir() {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("{} is not a directory", dir.display()),
+        ));
+    }
+    if metadata.uid() != uid {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("{} is owned by another user", dir.display()),
+        ));
+    }
+    if metadata.mode() & 0o077 != 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("{} must have mode 0700", dir.display()),
+        ));
+    }
+    Ok(())
+}
+
+fn serve_control_client(
+    client_id: u64,
+    stream: UnixStream,
+    clients: &ControlClients,
+    sender: &mpsc::Sender<Msg>,
+    shutdown: &AtomicBool,
+) {
+    if stream.set_nonblocking(false).is_err()
+        || stream
+            .set_read_timeout(Some(Duration::from_millis(CONTROL_SOCKET_POLL_MS)))
+            .is_err()
+    {
+        clients.remove(client_id);
+        return;
+    }
+
+    let mut reader = BufReader::new(stream);
+    let mut line = Vec::new();
+    while !shutdown.load(Ordering::Relaxed) {
+        // Reads stop at the cap, so a client that never sends a newline
+        // cannot grow the buffer without bound.
+        let remaining = CONTROL_REQUEST_MAX_BYTES.saturating_sub(line.len()) as u64;
+        match (&mut reader).take(remaining).read_until(b'\n', &mut line) {
+            Ok(0) if remaining == 0 => {
+                clients.send(
+                    client_id,
+                    control_error_line(
+                        &Value::Null,
+                        JSONRPC_INVALID_REQUEST,
+                        "request line too long",
+                    )
+                    .as_str(),
+                );
+                break;
+            }
+            Ok(0) => break,
+            Ok(_) => {
+                if !line.ends_with(b"\n") {
+                    continue;
+                }
+                let text = String::from_utf8_lossy(&line).trim().to_string();
+                line.clear();
+                if text.is_empty() {
+                    continue;
+                }
+                match parse_control_request(client_id, text.as_str()) {
+                    Ok(request) => {
+                        if sender.send(Msg::ControlRequest(request)).is_err() {
+                            break;
+                        }
+                    }
+                    Err(response) => clients.send(client_id, response.as_str()),
+                }
+            }
+            Err(error)
+                if matches!(
+                    error.kind(),
+                    io::ErrorKind::WouldBlock
+                        | io::ErrorKind::TimedOut
+                        | io::ErrorKind::Interrupted
+                ) => {}
+            Err(_) => break,
+        }
+    }
+    clients.remove(client_id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn control_socket_path_uses_runtime_dir_and_pid() {
+        let runtime_root = control_socket_root_in(Some(PathBuf::from("/run/user/1000")), 1000);
+        assert_eq!(
+            control_socket_path_in(runtime_root.clone(), None, 42),
+            PathBuf::from("/run/user/1000/grove/grove-42.sock")
+        );
+        assert_eq!(
+            control_socket_path_in(runtime_root, Some("work"), 42),
+            PathBuf::from("/run/user/1000/grove/work/grove-42.sock")
+        );
+        assert_eq!(
+            control_socket_path_in(control_socket_root_in(None, 1000), None, 7),
+            std::env::temp_dir().join("grove-1000").join("grove-7.sock")
+        );
+    }
+
+    #[test]
+    fn bind_control_socket_refuses_shared_directories() {
+        let root =
+            std::env::temp_dir().join(format!("grove-control-socket-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let path = root.join("work").join("grove-1.sock");
+
+        let listener = bind_control_socket(root.as_path(), path.as_path())
+            .expect("private socket dir should bind");
+        drop(listener);
+        for dir in [root.as_path(), root.join("work").as_path()] {
+            let mode = fs::metadata(dir).expect("dir should exist").mode();
+            assert_eq!(mode & 0o777, 0o700);
+        }
+        let mode = fs::symlink_metadata(&path)
+            .expect("socket should exist")
+            .mode();
+        assert_eq!(mode & 0o077, 0);
+
+        fs::set_permissions(&root, fs::Permissions::from_mode(0o755))
+            .expect("permissions should update");
+        let error = bind_control_socket(root.as_path(), path.as_path())
+            .expect_err("group-readable socket root should be refused");
+        assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+        assert!(ensure_private_dir(root.join("work").as_path(), current_uid() + 1).is_err());
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn serve_control_client_drops_oversized_request_lines() {
+        let (server, mut client) = UnixStream::pair().expect("socket pair should open");
+        let clients = ControlClients::default();
+        let client_id = clients.register(&server).expect("client should register");
+        let (sender, _receiver) = mpsc::channel();
+        let writer = std::thread::spawn(move || {
+            let _ = client.write_all(&vec![b'a'; CONTROL_REQUEST_MAX_BYTES + 1024]);
+            client
+        });
+
+        serve_control_client(
+            client_id,
+            server,
+            &clients,
+            &sender,
+            &AtomicBool::new(false),
+        );
+
+        let client = writer.join().expect("writer should finish");
+        client
+            .set_read_timeout(Some(Duration::from_millis(500)))
+            .expect("timeout should set");
+        let mut response = String::new();
+        BufReader::new(client)
+            .read_line(&mut response)
+            .expect("error response should arrive");
+        assert!(response.contains("request line too long"));
+    }
+
+    #[test]
+    fn parse_control_request_reads_method_params_and_id() {
+        let request = parse_control_request(
+            3,
+            r#"{"jsonrpc":"2.0","id":9,"method":"tab.open","params":{"tab":"git"}}"#,
+        )
+        .expect("request should parse");
+
+        assert_eq!(request.client_id, 3);
+        assert_eq!(request.id, Some(Value::from(9)));
+        assert_eq!(request.method, "tab.open");
+        assert_eq!(param_str(&request.params, "tab"), Some("git"));
+    }
+
+    #[test]
+    fn parse_control_request_treats_missing_id_as_notification() {
+        let request = parse_control_request(1, r#"{"jsonrpc":"2.0","method":"state.get"}"#)
+            .expect("notification should parse");
+
+        assert_eq!(request.id, None);
+        assert_eq!(request.params, Value::Object(Map::new()));
+    }
+
+    #[test]
+    fn parse_control_request_rejects_malformed_requests() {
+        let parse_error = parse_control_request(1, "{not json").expect_err("should fail");
+        let invalid = parse_control_request(1, r#"{"id":1,"method":"state.get"}"#)
+            .expect_err("missing jsonrpc should fail");
+        let bad_params =
+            parse_control_request(1, r#"{"jsonrpc":"2.0","id":2,"method":"x","params":[1]}"#)
+                .expect_err("array params should fail");
+
+        let code = |line: &str| {
+            serde_json::from_str::<Value>(line).expect("error should be json")["error"]["code"]
+                .as_i64()
+        };
+        assert_eq!(code(parse_error.as_str()), Some(JSONRPC_PARSE_ERROR));
+        assert_eq!(code(invalid.as_str()), Some(JSONRPC_INVALID_REQUEST));
+        assert_eq!(code(bad_params.as_str()), Some(JSONRPC_INVALID_PARAMS));
+    }
+
+    #[test]
+    fn control_clients_drop_writers_that_disconnect() {
+        let clients = ControlClients::default();
+        let (server, client) = UnixStream::pair().expect("socket pair should open");
+        let client_id = clients.register(&server).expect("client should register");
+        drop(client);
+
+        clients.send(client_id, "{}");
+        clients.send(client_id, "{}");
+
+        assert!(clients.is_empty());
+    }
+}
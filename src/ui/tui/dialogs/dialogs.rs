@@ -329,6 +329,100 @@ fn session_cleanup_dialog_initial_focus_id() -> u64 {
     FOCUS_ID_SESSION_CLEANUP_INCLUDE_STALE
 }
 
+fn gc_dialog_focus_ids() -> [u64; 4] {
+    [
+        FOCUS_ID_GC_CANDIDATES,
+        FOCUS_ID_GC_IDLE_DAYS,
+        FOCUS_ID_GC_APPLY_BUTTON,
+        FOCUS_ID_GC_CANCEL_BUTTON,
+    ]
+}
+
+fn gc_dialog_focus_field(focus_id: Option<u64>) -> Option<GcDialogField> {
+    match focus_id {
+        Some(FOCUS_ID_GC_CANDIDATES) => Some(GcDialogField::Candidates),
+        Some(FOCUS_ID_GC_IDLE_DAYS) => Some(GcDialogField::IdleDays),
+        Some(FOCUS_ID_GC_APPLY_BUTTON) => Some(GcDialogField::ApplyButton),
+        Some(FOCUS_ID_GC_CANCEL_BUTTON) => Some(GcDialogField::CancelButton),
+        _ => None,
+    }
+}
+
+fn gc_dialog_initial_focus_id() -> u64 {
+    FOCUS_ID_GC_CANDIDATES
+}
+
+fn commit_dialog_focus_ids() -> [u64; 5] {
+    [
+        FOCUS_ID_COMMIT_FILES,
+        FOCUS_ID_COMMIT_MESSAGE,
+        FOCUS_ID_COMMIT_DRAFT,
+        FOCUS_ID_COMMIT_COMMIT_BUTTON,
+        FOCUS_ID_COMMIT_CANCEL_BUTTON,
+    ]
+}
+
+fn commit_dialog_focus_field(focus_id: Option<u64>) -> Option<CommitDialogField> {
+    match focus_id {
+        Some(FOCUS_ID_COMMIT_FILES) => Some(CommitDialogField::Files),
+        Some(FOCUS_ID_COMMIT_MESSAGE) => Some(CommitDialogField::Message),
+        Some(FOCUS_ID_COMMIT_DRAFT) => Some(CommitDialogField::Draft),
+        Some(FOCUS_ID_COMMIT_COMMIT_BUTTON) => Some(CommitDialogField::CommitButton),
+        Some(FOCUS_ID_COMMIT_CANCEL_BUTTON) => Some(CommitDialogField::CancelButton),
+        _ => None,
+    }
+}
+
+fn commit_dialog_initial_focus_id() -> u64 {
+    FOCUS_ID_COMMIT_FILES
+}
+
+fn review_comment_dialog_focus_ids() -> [u64; 4] {
+    [
+        FOCUS_ID_REVIEW_COMMENT_LINE,
+        FOCUS_ID_REVIEW_COMMENT_BODY,
+        FOCUS_ID_REVIEW_COMMENT_ADD_BUTTON,
+        FOCUS_ID_REVIEW_COMMENT_CANCEL_BUTTON,
+    ]
+}
+
+fn review_comment_dialog_focus_field(focus_id: Option<u64>) -> Option<ReviewCommentDialogField> {
+    match focus_id {
+        Some(FOCUS_ID_REVIEW_COMMENT_LINE) => Some(ReviewCommentDialogField::Line),
+        Some(FOCUS_ID_REVIEW_COMMENT_BODY) => Some(ReviewCommentDialogField::Body),
+        Some(FOCUS_ID_REVIEW_COMMENT_ADD_BUTTON) => Some(ReviewCommentDialogField::AddButton),
+        Some(FOCUS_ID_REVIEW_COMMENT_CANCEL_BUTTON) => Some(ReviewCommentDialogField::CancelButton),
+        _ => None,
+    }
+}
+
+fn review_comment_dialog_initial_focus_id() -> u64 {
+    FOCUS_ID_REVIEW_COMMENT_BODY
+}
+
+fn task_notes_dialog_focus_ids() -> [u64; 4] {
+    [
+        FOCUS_ID_TASK_NOTES_TAGS,
+        FOCUS_ID_TASK_NOTES_NOTES,
+        FOCUS_ID_TASK_NOTES_SAVE_BUTTON,
+        FOCUS_ID_TASK_NOTES_CANCEL_BUTTON,
+    ]
+}
+
+fn task_notes_dialog_focus_field(focus_id: Option<u64>) -> Option<TaskNotesDialogField> {
+    match focus_id {
+        Some(FOCUS_ID_TASK_NOTES_TAGS) => Some(TaskNotesDialogField::Tags),
+        Some(FOCUS_ID_TASK_NOTES_NOTES) => Some(TaskNotesDialogField::Notes),
+        Some(FOCUS_ID_TASK_NOTES_SAVE_BUTTON) => Some(TaskNotesDialogField::SaveButton),
+        Some(FOCUS_ID_TASK_NOTES_CANCEL_BUTTON) => Some(TaskNotesDialogField::CancelButton),
+        _ => None,
+    }
+}
+
+fn task_notes_dialog_initial_focus_id() -> u64 {
+    FOCUS_ID_TASK_NOTES_NOTES
+}
+
 fn delete_dialog_focus_ids() -> [u64; 4] {
     [
         FOCUS_ID_DELETE_LOCAL_BRANCH,
@@ -864,6 +958,28 @@ impl GroveApp {
             .and_then(|_| session_cleanup_dialog_focus_field(self.focus_manager.current()))
     }
 
+    pub(super) fn current_gc_dialog_focus_field(&self) -> Option<GcDialogField> {
+        self.gc_dialog()
+            .and_then(|_| gc_dialog_focus_field(self.focus_manager.current()))
+    }
+
+    pub(super) fn current_commit_dialog_focus_field(&self) -> Option<CommitDialogField> {
+        self.commit_dialog()
+            .and_then(|_| commit_dialog_focus_field(self.focus_manager.current()))
+    }
+
+    pub(super) fn current_review_comment_dialog_focus_field(
+        &self,
+    ) -> Option<ReviewCommentDialogField> {
+        self.review_comment_dialog()
+            .and_then(|_| review_comment_dialog_focus_field(self.focus_manager.current()))
+    }
+
+    pub(super) fn current_task_notes_dialog_focus_field(&self) -> Option<TaskNotesDialogField> {
+        self.task_notes_dialog()
+            .and_then(|_| task_notes_dialog_focus_field(self.focus_manager.current()))
+    }
+
     pub(super) fn current_delete_dialog_focus_field(&self) -> Option<DeleteDialogField> {
         self.delete_dialog()
             .and_then(|_| delete_dialog_focus_field(self.focus_manager.current()))
@@ -1004,6 +1120,38 @@ impl GroveApp {
                     session_cleanup_dialog_initial_focus_id(),
                 );
             }
+            ActiveDialog::Gc(_) => {
+                let members = gc_dialog_focus_ids();
+                self.activate_focus_trap(
+                    FOCUS_GROUP_GC_DIALOG,
+                    &members,
+                    gc_dialog_initial_focus_id(),
+                );
+            }
+            ActiveDialog::Commit(_) => {
+                let members = commit_dialog_focus_ids();
+                self.activate_focus_trap(
+                    FOCUS_GROUP_COMMIT_DIALOG,
+                    &members,
+                    commit_dialog_initial_focus_id(),
+                );
+            }
+            ActiveDialog::ReviewComment(_) => {
+                let members = review_comment_dialog_focus_ids();
+                self.activate_focus_trap(
+                    FOCUS_GROUP_REVIEW_COMMENT_DIALOG,
+                    &members,
+                    review_comment_dialog_initial_focus_id(),
+                );
+            }
+            ActiveDialog::TaskNotes(_) => {
+                let members = task_notes_dialog_focus_ids();
+                self.activate_focus_trap(
+                    FOCUS_GROUP_TASK_NOTES_DIALOG,
+                    &members,
+                    task_notes_dialog_initial_focus_id(),
+                );
+            }
             ActiveDialog::Delete(dialog) => {
                 let members = delete_dialog_focus_ids();
                 self.activate_focus_trap(
@@ -1097,6 +1245,22 @@ impl GroveApp {
                 let members = session_cleanup_dialog_focus_ids();
                 self.deactivate_focus_trap(&members);
             }
+            ActiveDialog::Gc(_) => {
+                let members = gc_dialog_focus_ids();
+                self.deactivate_focus_trap(&members);
+            }
+            ActiveDialog::Commit(_) => {
+                let members = commit_dialog_focus_ids();
+                self.deactivate_focus_trap(&members);
+            }
+            ActiveDialog::ReviewComment(_) => {
+                let members = review_comment_dialog_focus_ids();
+                self.deactivate_focus_trap(&members);
+            }
+            ActiveDialog::TaskNotes(_) => {
+                let members = task_notes_dialog_focus_ids();
+                self.deactivate_focus_trap(&members);
+            }
             ActiveDialog::Delete(_) => {
                 let members = delete_dialog_focus_ids();
                 self.deactivate_focus_trap(&members);
@@ -1189,6 +1353,10 @@ impl GroveApp {
             Some(ActiveDialog::Stop(_)) => Some("stop"),
             Some(ActiveDialog::Confirm(_)) => Some("confirm"),
             Some(ActiveDialog::SessionCleanup(_)) => Some("session_cleanup"),
+            Some(ActiveDialog::Gc(_)) => Some("gc"),
+            Some(ActiveDialog::Commit(_)) => Some("commit"),
+            Some(ActiveDialog::ReviewComment(_)) => Some("review_comment"),
+            Some(ActiveDialog::TaskNotes(_)) => Some("task_notes"),
             Some(ActiveDialog::Delete(_)) => Some("delete"),
             Some(ActiveDialog::Merge(_)) => Some("merge"),
             Some(ActiveDialog::UpdateFromBase(_)) => Some("update_from_base"),
@@ -1227,6 +1395,28 @@ impl GroveApp {
         SessionCleanup,
         SessionCleanupDialogState
     );
+    active_dialog_accessors!(gc_dialog, gc_dialog_mut, set_gc_dialog, Gc, GcDialogState);
+    active_dialog_accessors!(
+        commit_dialog,
+        commit_dialog_mut,
+        set_commit_dialog,
+        Commit,
+        CommitDialogState
+    );
+    active_dialog_accessors!(
+        review_comment_dialog,
+        review_comment_dialog_mut,
+        set_review_comment_dialog,
+        ReviewComment,
+        ReviewCommentDialogState
+    );
+    active_dialog_accessors!(
+        task_notes_dialog,
+        task_notes_dialog_mut,
+        set_task_notes_dialog,
+        TaskNotes,
+        TaskNotesDialogState
+    );
     active_dialog_accessors!(
         delete_dialog,
         delete_dialog_mut,
@@ -0,0 +1,340 @@
+use super::*;
+
+impl GroveApp {
+    pub(super) fn open_commit_dialog(&mut self) {
+        if self.modal_open() {
+            return;
+        }
+        let Some(workspace) = self.state.selected_workspace() else {
+            self.show_info_toast("no workspace selected");
+            return;
+        };
+
+        let workspace_path = workspace.path.clone();
+        self.set_commit_dialog(CommitDialogState::new(
+            workspace.name.clone(),
+            workspace_path.clone(),
+        ));
+        self.log_dialog_event("commit", "dialog_opened");
+        self.queue_commit_files_refresh(workspace_path, None);
+    }
+
+    /// Runs the optional index change and then re-reads the file list off the
+    /// UI thread, so a slow `git status` never stalls input.
+    fn queue_commit_files_refresh(
+        &mut self,
+        workspace_path: PathBuf,
+        index_change: Option<Box<dyn FnOnce(&Path) -> Result<(), String> + Send>>,
+    ) {
+        if let Some(dialog) = self.commit_dialog_mut() {
+            dialog.busy = true;
+        }
+        let refresh = move || {
+            let index_changed = index_change.is_some();
+            let action_error =
+                index_change.and_then(|change| change(workspace_path.as_path()).err());
+            let files = list_changed_files(workspace_path.as_path());
+            CommitFilesCompletion {
+                workspace_path,
+                index_changed,
+                action_error,
+                files,
+            }
+        };
+        if !self.tmux_input.supports_background_launch() {
+            self.handle_commit_files_completed(refresh());
+            return;
+        }
+        self.queue_cmd(Cmd::task(move || Msg::CommitFilesCompleted(refresh())));
+    }
+
+    pub(super) fn handle_commit_files_completed(&mut self, completion: CommitFilesCompletion) {
+        let Some(dialog) = self
+            .commit_dialog_mut()
+            .filter(|dialog| dialog.workspace_path == completion.workspace_path)
+        else {
+            return;
+        };
+        dialog.busy = false;
+        dialog.last_error = completion.action_error;
+        match completion.files {
+            Ok(files) => dialog.replace_files(files),
+            Err(error) => dialog.last_error = Some(format!("commit status failed: {error}")),
+        }
+        if completion.index_changed {
+            self.poll_diff_for_selected_workspace();
+        }
+    }
+
+    fn commit_dialog_idle_workspace_path(&self) -> Option<PathBuf> {
+        self.commit_dialog()
+            .filter(|dialog| !dialog.busy)
+            .map(|dialog| dialog.workspace_path.clone())
+    }
+
+    fn toggle_commit_dialog_cursor_file(&mut self) {
+        let Some(workspace_path) = self.commit_dialog_idle_workspace_path() else {
+            return;
+        };
+        let Some(file) = self
+            .commit_dialog()
+            .and_then(|dialog| dialog.cursor_file().cloned())
+        else {
+            return;
+        };
+
+        let section = file.section;
+        let path = file.path;
+        self.queue_commit_files_refresh(
+            workspace_path,
+            Some(Box::new(move |worktree_path: &Path| match section {
+                ChangeSection::Staged => unstage_file(worktree_path, path.as_str()),
+                ChangeSection::Unstaged => stage_file(worktree_path, path.as_str()),
+            })),
+        );
+    }
+
+    fn stage_all_in_commit_dialog(&mut self) {
+        let Some(workspace_path) = self.commit_dialog_idle_workspace_path() else {
+            return;
+        };
+        self.queue_commit_files_refresh(workspace_path, Some(Box::new(stage_all)));
+    }
+
+    fn confirm_commit_dialog(&mut self) {
+        let Some(dialog) = self.commit_dialog().cloned() else {
+            return;
+        };
+        if dialog.busy {
+            self.show_info_toast("git is still running");
+            return;
+        }
+        if dialog.staged_count() == 0 {
+            self.show_info_toast("nothing staged");
+            return;
+        }
+        if dialog.message.trim().is_empty() {
+            self.show_info_toast("commit message is empty");
+            return;
+        }
+
+        self.log_dialog_event_with_fields(
+            "commit",
+            "dialog_confirmed",
+            [
+                (
+                    "workspace".to_string(),
+                    Value::from(dialog.workspace_name.clone()),
+                ),
+                (
+                    "staged_files".to_string(),
+                    Value::from(usize_to_u64(dialog.staged_count())),
+                ),
+            ],
+        );
+
+        if let Some(state) = self.commit_dialog_mut() {
+            state.busy = true;
+        }
+        let workspace_name = dialog.workspace_name;
+        let workspace_path = dialog.workspace_path;
+        let message = dialog.message;
+        let commit = move || CommitCompletion {
+            result: commit_staged(workspace_path.as_path(), message.as_str()),
+            workspace_name,
+            workspace_path,
+        };
+        if !self.tmux_input.supports_background_launch() {
+            self.handle_commit_completed(commit());
+            return;
+        }
+        self.queue_cmd(Cmd::task(move || Msg::CommitCompleted(commit())));
+    }
+
+    pub(super) fn handle_commit_completed(&mut self, completion: CommitCompletion) {
+        let dialog_open = self
+            .commit_dialog()
+            .is_some_and(|dialog| dialog.workspace_path == completion.workspace_path);
+        match completion.result {
+            Ok(commit) => {
+                if dialog_open {
+                    self.close_active_dialog();
+                }
+                self.show_success_toast(format!(
+                    "committed {commit} in '{}'",
+                    completion.workspace_name
+                ));
+                self.polling.last_diff_stat_poll_at = None;
+                self.polling.last_branch_sync_poll_at = None;
+                self.poll_diff_for_selected_workspace();
+            }
+            Err(error) => {
+                if dialog_open && let Some(state) = self.commit_dialog_mut() {
+                    state.busy = false;
+                    state.last_error = Some(error.clone());
+                }
+                self.show_error_toast(format!("commit failed: {error}"));
+            }
+        }
+    }
+
+    fn request_commit_message_draft(&mut self) {
+        let Some(workspace_path) = self
+            .commit_dialog()
+            .map(|dialog| dialog.workspace_path.clone())
+        else {
+            return;
+        };
+        let summary = match staged_diff_summary(workspace_path.as_path()) {
+            Ok(summary) if summary.is_empty() => {
+                self.show_info_toast("stage files before asking for a draft");
+                return;
+            }
+            Ok(summary) => summary,
+            Err(error) => {
+                self.show_error_toast(format!("staged summary failed: {error}"));
+                return;
+            }
+        };
+        let Some(session_name) =
+            self.workspace_running_agent_session_for_status_poll(workspace_path.as_path(), None)
+        else {
+            self.show_info_toast("no running agent in this workspace");
+            return;
+        };
+
+        if let Err(error) = self.tmux_input.paste_and_submit(
+            session_name.as_str(),
+            commit_draft_prompt(&summary).as_str(),
+        ) {
+            let message = error.to_string();
+            self.log_tmux_error(message.clone());
+            self.show_error_toast(format!("draft request failed: {message}"));
+            return;
+        }
+
+        self.log_dialog_event("commit", "draft_requested");
+        self.close_active_dialog();
+        let _ = self.select_tab_for_session_in_selected_workspace(
+            workspace_path.as_path(),
+            session_name.as_str(),
+        );
+        self.show_info_toast("asked the agent to draft a commit message");
+    }
+
+    pub(super) fn handle_commit_dialog_key(&mut self, key_event: KeyEvent) {
+        self.sync_active_dialog_focus_field();
+        let no_modifiers = key_event.modifiers.is_empty();
+        let ctrl_n = key_event.modifiers == Modifiers::CTRL
+            && matches!(key_event.code, KeyCode::Char('n') | KeyCode::Char('N'));
+        let ctrl_p = key_event.modifiers == Modifiers::CTRL
+            && matches!(key_event.code, KeyCode::Char('p') | KeyCode::Char('P'));
+        let ctrl_s = key_event.modifiers == Modifiers::CTRL
+            && matches!(key_event.code, KeyCode::Char('s') | KeyCode::Char('S'));
+        if key_event.code == KeyCode::Escape {
+            self.log_dialog_event("commit", "dialog_cancelled");
+            self.close_active_dialog();
+            return;
+        }
+        if ctrl_s {
+            self.confirm_commit_dialog();
+            return;
+        }
+
+        let Some(focused_field) = self.current_commit_dialog_focus_field() else {
+            return;
+        };
+        match key_event.code {
+            KeyCode::Tab => {
+                self.focus_next_dialog_field();
+                return;
+            }
+            KeyCode::BackTab => {
+                self.focus_prev_dialog_field();
+                return;
+            }
+            KeyCode::Char(_) if ctrl_n => {
+                self.focus_next_dialog_field();
+                return;
+            }
+            KeyCode::Char(_) if ctrl_p => {
+                self.focus_prev_dialog_field();
+                return;
+            }
+            _ => {}
+        }
+
+        if focused_field == CommitDialogField::Message {
+            if !Self::allows_text_input_modifiers(key_event.modifiers) {
+                return;
+            }
+            if let Some(dialog) = self.commit_dialog_mut() {
+                match key_event.code {
+                    KeyCode::Enter => dialog.message.push('\n'),
+                    KeyCode::Backspace => {
+                        dialog.message.pop();
+                    }
+                    KeyCode::Char(character) if !character.is_control() => {
+                        dialog.message.push(character);
+                    }
+                    _ => {}
+                }
+            }
+            return;
+        }
+
+        match key_event.code {
+            KeyCode::Char('q') if no_modifiers => {
+                self.log_dialog_event("commit", "dialog_cancelled");
+                self.close_active_dialog();
+            }
+            KeyCode::Enter | KeyCode::Char(' ') => match focused_field {
+                CommitDialogField::Files => self.toggle_commit_dialog_cursor_file(),
+                CommitDialogField::Draft => self.request_commit_message_draft(),
+                CommitDialogField::CommitButton => self.confirm_commit_dialog(),
+                CommitDialogField::CancelButton => {
+                    self.log_dialog_event("commit", "dialog_cancelled");
+                    self.close_active_dialog();
+                }
+                CommitDialogField::Message => {}
+            },
+            KeyCode::Up | KeyCode::Char('k')
+                if no_modifiers && focused_field == CommitDialogField::Files =>
+            {
+                if let Some(dialog) = self.commit_dialog_mut() {
+                    dialog.move_cursor(-1);
+                }
+            }
+            KeyCode::Down | KeyCode::Char('j')
+                if no_modifiers && focused_field == CommitDialogField::Files =>
+            {
+                if let Some(dialog) = self.commit_dialog_mut() {
+                    dialog.move_cursor(1);
+                }
+            }
+            KeyCode::Char('a') if no_modifiers && focused_field == CommitDialogField::Files => {
+                self.stage_all_in_commit_dialog();
+            }
+            KeyCode::Up | KeyCode::Char('k') if no_modifiers => {
+                self.focus_prev_dialog_field();
+            }
+            KeyCode::Down | KeyCode::Char('j') if no_modifiers => {
+                self.focus_next_dialog_field();
+            }
+            KeyCode::Char('h') | KeyCode::Char('l')
+                if no_modifiers
+                    && matches!(
+                        focused_field,
+                        CommitDialogField::CommitButton | CommitDialogField::CancelButton
+                    ) =>
+            {
+                self.focus_dialog_field(if focused_field == CommitDialogField::CommitButton {
+                    FOCUS_ID_COMMIT_CANCEL_BUTTON
+                } else {
+                    FOCUS_ID_COMMIT_COMMIT_BUTTON
+                });
+            }
+            _ => {}
+        }
+    }
+}
@@ -28,6 +28,8 @@ impl GroveApp {
     fn confirm_dialog_target(action: &ConfirmDialogAction) -> &'static str {
         match action {
             ConfirmDialogAction::CloseActiveTab { .. } => "close_active_tab",
+            ConfirmDialogAction::DiscardDiffHunk { .. } => "discard_diff_hunk",
+            ConfirmDialogAction::TrustSharedCommands { .. } => "trust_shared_commands",
             ConfirmDialogAction::QuitApp => "quit_app",
         }
     }
@@ -51,6 +53,16 @@ impl GroveApp {
             } => {
                 self.force_close_active_tab_and_session(&workspace_path, tab_id, &session_name);
             }
+            ConfirmDialogAction::DiscardDiffHunk {
+                workspace_path,
+                path,
+                header,
+            } => {
+                self.discard_diff_hunk(workspace_path, path, header);
+            }
+            ConfirmDialogAction::TrustSharedCommands { project_path, .. } => {
+                self.trust_project_shared_commands(&project_path);
+            }
             ConfirmDialogAction::QuitApp => {
                 self.queue_cmd(Cmd::Quit);
             }
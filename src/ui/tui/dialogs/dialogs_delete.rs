@@ -53,10 +53,7 @@ impl GroveApp {
         self.dialogs.delete_in_flight = true;
         self.dialogs.delete_in_flight_workspace = Some(workspace_path.clone());
         self.queue_cmd(Cmd::task(move || {
-            let (result, warnings) = match request {
-                QueuedDeleteRequest::Task(request) => delete_task(request),
-                QueuedDeleteRequest::Worktree(request) => delete_workspace(request),
-            };
+            let (result, warnings) = request.run();
             Msg::DeleteWorkspaceCompleted(DeleteWorkspaceCompletion {
                 workspace_name,
                 workspace_path,
@@ -401,30 +398,31 @@ impl GroveApp {
                     }
                 }
             };
+        self.start_delete_workspace(QueuedDeleteWorkspace {
+            request,
+            workspace_name,
+            workspace_path,
+            requested_workspace_paths,
+            deleted_task,
+            removed_base_task,
+        });
+    }
+
+    pub(super) fn start_delete_workspace(&mut self, queued_delete: QueuedDeleteWorkspace) {
         if !self.tmux_input.supports_background_launch() {
-            let (result, warnings) = match request.clone() {
-                QueuedDeleteRequest::Task(request) => delete_task(request),
-                QueuedDeleteRequest::Worktree(request) => delete_workspace(request),
-            };
+            let (result, warnings) = queued_delete.request.run();
             self.apply_delete_workspace_completion(DeleteWorkspaceCompletion {
-                workspace_name,
-                workspace_path,
-                requested_workspace_paths,
-                deleted_task,
-                removed_base_task,
+                workspace_name: queued_delete.workspace_name,
+                workspace_path: queued_delete.workspace_path,
+                requested_workspace_paths: queued_delete.requested_workspace_paths,
+                deleted_task: queued_delete.deleted_task,
+                removed_base_task: queued_delete.removed_base_task,
                 result,
                 warnings,
             });
             return;
         }
 
-        self.queue_or_start_delete_workspace(QueuedDeleteWorkspace {
-            request,
-            workspace_name,
-            workspace_path,
-            requested_workspace_paths,
-            deleted_task,
-            removed_base_task,
-        });
+        self.queue_or_start_delete_workspace(queued_delete);
     }
 }
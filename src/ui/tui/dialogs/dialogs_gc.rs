@@ -0,0 +1,296 @@
+use super::*;
+
+const GC_IDLE_DAY_CHOICES: [Option<u64>; 5] = [Some(7), Some(14), Some(30), Some(60), None];
+
+fn next_gc_idle_days(current: Option<u64>) -> Option<u64> {
+    let index = GC_IDLE_DAY_CHOICES
+        .iter()
+        .position(|choice| *choice == current)
+        .unwrap_or(0);
+    GC_IDLE_DAY_CHOICES[(index + 1) % GC_IDLE_DAY_CHOICES.len()]
+}
+
+impl GroveApp {
+    fn queue_gc_plan(&mut self, options: GcOptions) {
+        let Some(tasks_root) = self.resolved_tasks_root() else {
+            self.handle_gc_plan_completed(GcPlanCompletion {
+                options,
+                result: Err("task root unavailable".to_string()),
+            });
+            return;
+        };
+        let tasks = self.state.tasks.clone();
+        let repository_paths = self
+            .projects
+            .iter()
+            .map(|project| project.path.clone())
+            .collect::<Vec<PathBuf>>();
+        let plan = move || GcPlanCompletion {
+            options,
+            result: Ok(plan_gc_for_tasks(
+                tasks_root.as_path(),
+                &tasks,
+                repository_paths.as_slice(),
+                options,
+            )),
+        };
+        if !self.tmux_input.supports_background_launch() {
+            self.handle_gc_plan_completed(plan());
+            return;
+        }
+        self.queue_cmd(Cmd::task(move || Msg::GcPlanCompleted(plan())));
+    }
+
+    pub(super) fn open_gc_dialog(&mut self) {
+        if self.modal_open() {
+            return;
+        }
+
+        let options = GcOptions::default();
+        self.set_gc_dialog(GcDialogState::new(options));
+        self.log_dialog_event("gc", "dialog_opened");
+        self.queue_gc_plan(options);
+    }
+
+    fn refresh_gc_dialog_plan_with_options(&mut self, options: GcOptions) {
+        let Some(dialog) = self.gc_dialog_mut() else {
+            return;
+        };
+        dialog.options = options;
+        dialog.scanning = true;
+        self.queue_gc_plan(options);
+    }
+
+    pub(super) fn handle_gc_plan_completed(&mut self, completion: GcPlanCompletion) {
+        let mut error_toast = None;
+        let Some(dialog) = self.gc_dialog_mut() else {
+            return;
+        };
+        // A newer scan was started after the idle threshold changed.
+        if dialog.options != completion.options {
+            return;
+        }
+        match completion.result {
+            Ok(plan) => {
+                dialog.replace_plan(plan);
+                dialog.last_error = None;
+            }
+            Err(error) => {
+                dialog.scanning = false;
+                dialog.last_error = Some(error.clone());
+                error_toast = Some(error);
+            }
+        }
+
+        if let Some(error) = error_toast {
+            self.show_error_toast(format!("worktree gc scan failed: {error}"));
+        }
+    }
+
+    fn confirm_gc_dialog(&mut self) {
+        let Some(dialog) = self.gc_dialog().cloned() else {
+            return;
+        };
+        if dialog.scanning {
+            self.show_info_toast("worktree gc scan still running");
+            return;
+        }
+        let plan = dialog.selected_plan();
+        if plan.candidates.is_empty() {
+            self.show_info_toast("no worktrees selected");
+            return;
+        }
+
+        self.log_dialog_event_with_fields(
+            "gc",
+            "dialog_confirmed",
+            [
+                (
+                    "candidates".to_string(),
+                    Value::from(usize_to_u64(plan.candidates.len())),
+                ),
+                (
+                    "idle_days".to_string(),
+                    dialog.options.idle_days.map_or(Value::Null, Value::from),
+                ),
+            ],
+        );
+        self.close_active_dialog();
+
+        // Removals share the delete queue so they run off the UI thread and
+        // never overlap a delete started from the delete dialog.
+        let removal_count = plan.candidates.len();
+        for entry in plan.candidates {
+            let queued_delete = match entry.delete_task_request() {
+                Some(request) => QueuedDeleteWorkspace {
+                    workspace_name: request.task.name.clone(),
+                    workspace_path: request.task.root_path.clone(),
+                    requested_workspace_paths: request
+                        .task
+                        .worktrees
+                        .iter()
+                        .map(|worktree| worktree.path.clone())
+                        .collect(),
+                    request: QueuedDeleteRequest::Task(request),
+                    deleted_task: true,
+                    removed_base_task: false,
+                },
+                None => {
+                    let GcTarget::UnmanagedWorktree { repository_path } = entry.target else {
+                        continue;
+                    };
+                    QueuedDeleteWorkspace {
+                        request: QueuedDeleteRequest::UnmanagedWorktree {
+                            repository_path,
+                            worktree_path: entry.path.clone(),
+                        },
+                        workspace_name: entry.name,
+                        workspace_path: entry.path.clone(),
+                        requested_workspace_paths: vec![entry.path],
+                        deleted_task: false,
+                        removed_base_task: false,
+                    }
+                }
+            };
+            self.start_delete_workspace(queued_delete);
+        }
+        if self.dialogs.delete_in_flight {
+            self.show_info_toast(format!("removing {removal_count} worktree(s)"));
+        }
+    }
+
+    pub(super) fn handle_gc_dialog_key(&mut self, key_event: KeyEvent) {
+        self.sync_active_dialog_focus_field();
+        let no_modifiers = key_event.modifiers.is_empty();
+        match key_event.code {
+            KeyCode::Escape => {
+                self.log_dialog_event("gc", "dialog_cancelled");
+                self.close_active_dialog();
+                return;
+            }
+            KeyCode::Char('q') if no_modifiers => {
+                self.log_dialog_event("gc", "dialog_cancelled");
+                self.close_active_dialog();
+                return;
+            }
+            KeyCode::Char('D') if no_modifiers => {
+                self.confirm_gc_dialog();
+                return;
+            }
+            _ => {}
+        }
+
+        let mut refresh_options = None;
+        let mut confirm_gc = false;
+        let mut cancel_dialog = false;
+        let Some(focused_field) = self.current_gc_dialog_focus_field() else {
+            return;
+        };
+        let ctrl_n = key_event.modifiers == Modifiers::CTRL
+            && matches!(key_event.code, KeyCode::Char('n') | KeyCode::Char('N'));
+        let ctrl_p = key_event.modifiers == Modifiers::CTRL
+            && matches!(key_event.code, KeyCode::Char('p') | KeyCode::Char('P'));
+
+        match key_event.code {
+            KeyCode::Enter => match focused_field {
+                GcDialogField::Candidates => {
+                    if let Some(dialog) = self.gc_dialog_mut() {
+                        dialog.toggle_cursor();
+                    }
+                }
+                GcDialogField::IdleDays => {
+                    if let Some(dialog) = self.gc_dialog_mut() {
+                        let mut options = dialog.options;
+                        options.idle_days = next_gc_idle_days(options.idle_days);
+                        refresh_options = Some(options);
+                    }
+                }
+                GcDialogField::ApplyButton => {
+                    confirm_gc = true;
+                }
+                GcDialogField::CancelButton => {
+                    cancel_dialog = true;
+                }
+            },
+            KeyCode::Tab => {
+                self.focus_next_dialog_field();
+            }
+            KeyCode::BackTab => {
+                self.focus_prev_dialog_field();
+            }
+            KeyCode::Char(_) if ctrl_n => {
+                self.focus_next_dialog_field();
+            }
+            KeyCode::Char(_) if ctrl_p => {
+                self.focus_prev_dialog_field();
+            }
+            KeyCode::Up | KeyCode::Char('k')
+                if no_modifiers && focused_field == GcDialogField::Candidates =>
+            {
+                if let Some(dialog) = self.gc_dialog_mut() {
+                    dialog.move_cursor(-1);
+                }
+            }
+            KeyCode::Down | KeyCode::Char('j')
+                if no_modifiers && focused_field == GcDialogField::Candidates =>
+            {
+                if let Some(dialog) = self.gc_dialog_mut() {
+                    dialog.move_cursor(1);
+                }
+            }
+            KeyCode::Up | KeyCode::Char('k') if no_modifiers => {
+                self.focus_prev_dialog_field();
+            }
+            KeyCode::Down | KeyCode::Char('j') if no_modifiers => {
+                self.focus_next_dialog_field();
+            }
+            KeyCode::Char(' ') if no_modifiers => match focused_field {
+                GcDialogField::Candidates => {
+                    if let Some(dialog) = self.gc_dialog_mut() {
+                        dialog.toggle_cursor();
+                    }
+                }
+                GcDialogField::IdleDays => {
+                    if let Some(dialog) = self.gc_dialog_mut() {
+                        let mut options = dialog.options;
+                        options.idle_days = next_gc_idle_days(options.idle_days);
+                        refresh_options = Some(options);
+                    }
+                }
+                GcDialogField::ApplyButton | GcDialogField::CancelButton => {}
+            },
+            KeyCode::Char('a') if no_modifiers && focused_field == GcDialogField::Candidates => {
+                if let Some(dialog) = self.gc_dialog_mut() {
+                    let select_all = dialog.selected.iter().any(|selected| !selected);
+                    dialog.selected.fill(select_all);
+                }
+            }
+            KeyCode::Char(character) if no_modifiers => {
+                if (focused_field == GcDialogField::ApplyButton
+                    || focused_field == GcDialogField::CancelButton)
+                    && (character == 'h' || character == 'l')
+                {
+                    self.focus_dialog_field(if focused_field == GcDialogField::ApplyButton {
+                        FOCUS_ID_GC_CANCEL_BUTTON
+                    } else {
+                        FOCUS_ID_GC_APPLY_BUTTON
+                    });
+                }
+            }
+            _ => {}
+        }
+
+        if let Some(options) = refresh_options {
+            self.refresh_gc_dialog_plan_with_options(options);
+        }
+
+        if cancel_dialog {
+            self.log_dialog_event("gc", "dialog_cancelled");
+            self.close_active_dialog();
+            return;
+        }
+        if confirm_gc {
+            self.confirm_gc_dialog();
+        }
+    }
+}
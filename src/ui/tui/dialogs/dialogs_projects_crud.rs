@@ -18,6 +18,7 @@ impl GroveApp {
         task_order: &[String],
         attention_acks: &[WorkspaceAttentionAckConfig],
         hidden_base_project_paths: &[PathBuf],
+        sidebar: SidebarViewConfig,
     ) -> Result<(), String> {
         let projects_path = crate::infrastructure::config::projects_path_for(config_path);
         crate::infrastructure::config::save_projects_to_path(
@@ -26,6 +27,7 @@ impl GroveApp {
             task_order,
             attention_acks,
             hidden_base_project_paths,
+            sidebar,
         )
     }
 
@@ -97,6 +99,7 @@ impl GroveApp {
                 &self.task_order,
                 &self.workspace_attention_acks_for_config(),
                 &updated_hidden_base_project_paths,
+                self.sidebar_view,
             );
             self.apply_delete_project_completion(DeleteProjectCompletion {
                 project_name: project.name,
@@ -111,6 +114,7 @@ impl GroveApp {
         let config_path = self.config_path.clone();
         let task_order = self.task_order.clone();
         let attention_acks = self.workspace_attention_acks_for_config();
+        let sidebar_view = self.sidebar_view;
         self.dialogs.project_delete_in_flight = true;
         self.queue_cmd(Cmd::task(move || {
             let result = Self::save_projects_config_to_path(
@@ -119,6 +123,7 @@ impl GroveApp {
                 &task_order,
                 &attention_acks,
                 &updated_hidden_base_project_paths,
+                sidebar_view,
             );
             Msg::DeleteProjectCompleted(DeleteProjectCompletion {
                 project_name: project.name,
@@ -14,6 +14,11 @@ impl GroveApp {
         let workspace_init_command = project.defaults.workspace_init_command.clone();
         let claude_env = format_agent_env_vars(&project.defaults.agent_env.claude);
         let codex_env = format_agent_env_vars(&project.defaults.agent_env.codex);
+        let trusted = project.defaults.trust_shared_commands;
+        let (shared, shared_error) = match load_shared_project_config(project.path.as_path()) {
+            Ok(shared) => (shared, None),
+            Err(error) => (SharedProjectConfig::default(), Some(error)),
+        };
 
         if let Some(project_dialog) = self.project_dialog_mut() {
             let mut defaults_dialog = ProjectDefaultsDialogState {
@@ -22,6 +27,9 @@ impl GroveApp {
                 workspace_init_command_input: TextInput::new().with_value(workspace_init_command),
                 claude_env_input: TextInput::new().with_value(claude_env),
                 codex_env_input: TextInput::new().with_value(codex_env),
+                shared,
+                shared_error,
+                trusted,
             };
             defaults_dialog.sync_focus(Some(ProjectDefaultsDialogField::BaseBranch));
             project_dialog.defaults_dialog = Some(defaults_dialog);
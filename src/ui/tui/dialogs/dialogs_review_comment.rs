@@ -0,0 +1,168 @@
+use super::*;
+
+impl GroveApp {
+    pub(super) fn open_review_comment_dialog(&mut self) {
+        if self.modal_open() {
+            return;
+        }
+        let Some(hunk) = self.selected_diff_hunk().cloned() else {
+            self.show_info_toast("no hunk selected");
+            return;
+        };
+        let Some(workspace_path) = self.diff_review.workspace_path.clone() else {
+            return;
+        };
+
+        self.set_review_comment_dialog(ReviewCommentDialogState {
+            workspace_path,
+            path: hunk.path.clone(),
+            line: hunk
+                .first_changed_line
+                .map(|line| line.to_string())
+                .unwrap_or_default(),
+            body: String::new(),
+        });
+        self.log_dialog_event_with_fields(
+            "review_comment",
+            "dialog_opened",
+            [("path".to_string(), Value::from(hunk.path))],
+        );
+    }
+
+    fn confirm_review_comment_dialog(&mut self) {
+        let Some(dialog) = self.review_comment_dialog().cloned() else {
+            return;
+        };
+        let Ok(line) = dialog.line.trim().parse::<usize>() else {
+            self.show_info_toast("line must be a number");
+            return;
+        };
+
+        let comments_path =
+            self.review_comments_path_for_workspace(dialog.workspace_path.as_path());
+        match add_review_comment(
+            comments_path.as_path(),
+            dialog.workspace_path.as_path(),
+            dialog.path.as_str(),
+            line,
+            dialog.body.as_str(),
+        ) {
+            Ok(comment) => {
+                self.log_dialog_event_with_fields(
+                    "review_comment",
+                    "dialog_confirmed",
+                    [
+                        ("path".to_string(), Value::from(comment.path.clone())),
+                        ("line".to_string(), Value::from(usize_to_u64(comment.line))),
+                    ],
+                );
+                self.close_active_dialog();
+                self.show_success_toast(format!(
+                    "comment added on {}:{}",
+                    comment.path, comment.line
+                ));
+                self.refresh_diff_capture();
+            }
+            Err(error) => self.show_error_toast(format!("comment failed: {error}")),
+        }
+    }
+
+    pub(super) fn handle_review_comment_dialog_key(&mut self, key_event: KeyEvent) {
+        self.sync_active_dialog_focus_field();
+        let Some(focused_field) = self.current_review_comment_dialog_focus_field() else {
+            return;
+        };
+        let ctrl_n = key_event.modifiers == Modifiers::CTRL
+            && matches!(key_event.code, KeyCode::Char('n') | KeyCode::Char('N'));
+        let ctrl_p = key_event.modifiers == Modifiers::CTRL
+            && matches!(key_event.code, KeyCode::Char('p') | KeyCode::Char('P'));
+        let ctrl_s = key_event.modifiers == Modifiers::CTRL
+            && matches!(key_event.code, KeyCode::Char('s') | KeyCode::Char('S'));
+
+        enum PostAction {
+            None,
+            Add,
+            Cancel,
+        }
+
+        let text_field = matches!(
+            focused_field,
+            ReviewCommentDialogField::Line | ReviewCommentDialogField::Body
+        );
+        if text_field && Self::allows_text_input_modifiers(key_event.modifiers) {
+            match key_event.code {
+                KeyCode::Backspace => {
+                    if let Some(dialog) = self.review_comment_dialog_mut() {
+                        if focused_field == ReviewCommentDialogField::Line {
+                            dialog.line.pop();
+                        } else {
+                            dialog.body.pop();
+                        }
+                    }
+                    return;
+                }
+                KeyCode::Char(character) if !character.is_control() => {
+                    if let Some(dialog) = self.review_comment_dialog_mut() {
+                        if focused_field == ReviewCommentDialogField::Body {
+                            dialog.body.push(character);
+                        } else if character.is_ascii_digit() {
+                            dialog.line.push(character);
+                        }
+                    }
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        let mut post_action = PostAction::None;
+        match key_event.code {
+            KeyCode::Escape => {
+                post_action = PostAction::Cancel;
+            }
+            KeyCode::Char(_) if ctrl_s => {
+                post_action = PostAction::Add;
+            }
+            KeyCode::Tab | KeyCode::Down => {
+                self.focus_next_dialog_field();
+            }
+            KeyCode::BackTab | KeyCode::Up => {
+                self.focus_prev_dialog_field();
+            }
+            KeyCode::Char(_) if ctrl_n => {
+                self.focus_next_dialog_field();
+            }
+            KeyCode::Char(_) if ctrl_p => {
+                self.focus_prev_dialog_field();
+            }
+            KeyCode::Left | KeyCode::Right | KeyCode::Char('h') | KeyCode::Char('l')
+                if !text_field =>
+            {
+                self.focus_dialog_field(if focused_field == ReviewCommentDialogField::AddButton {
+                    FOCUS_ID_REVIEW_COMMENT_CANCEL_BUTTON
+                } else {
+                    FOCUS_ID_REVIEW_COMMENT_ADD_BUTTON
+                });
+            }
+            KeyCode::Enter => match focused_field {
+                ReviewCommentDialogField::Line => {
+                    self.focus_next_dialog_field();
+                }
+                ReviewCommentDialogField::Body | ReviewCommentDialogField::AddButton => {
+                    post_action = PostAction::Add;
+                }
+                ReviewCommentDialogField::CancelButton => post_action = PostAction::Cancel,
+            },
+            _ => {}
+        }
+
+        match post_action {
+            PostAction::None => {}
+            PostAction::Add => self.confirm_review_comment_dialog(),
+            PostAction::Cancel => {
+                self.log_dialog_event("review_comment", "dialog_cancelled");
+                self.close_active_dialog();
+            }
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use super::*;
+use crate::application::task_lifecycle::update_task_metadata;
+#[cfg(test)]
+use crate::application::task_lifecycle::update_task_metadata_in_root;
+
+/// Tags are separated by commas or whitespace, a leading `#` is dropped.
+fn parse_task_tags(raw: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in raw.split(|character: char| character == ',' || character.is_whitespace()) {
+        let tag = tag.trim().trim_start_matches('#');
+        if !tag.is_empty() && !tags.iter().any(|existing| existing == tag) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags
+}
+
+impl GroveApp {
+    pub(super) fn open_task_notes_dialog(&mut self) {
+        if self.modal_open() {
+            return;
+        }
+        let Some(task) = self.state.selected_task().cloned() else {
+            self.show_info_toast("no task selected");
+            return;
+        };
+
+        self.set_task_notes_dialog(TaskNotesDialogState {
+            task_slug: task.slug.clone(),
+            task_name: task.name.clone(),
+            tags: task.tags.join(", "),
+            notes: task.notes.clone(),
+        });
+        self.log_dialog_event_with_fields(
+            "task_notes",
+            "dialog_opened",
+            [
+                ("task".to_string(), Value::from(task.name)),
+                (
+                    "tag_count".to_string(),
+                    Value::from(usize_to_u64(task.tags.len())),
+                ),
+            ],
+        );
+    }
+
+    fn write_task_metadata(&self, task: &Task) -> Result<(), String> {
+        #[cfg(test)]
+        {
+            if let Some(tasks_root) = self.task_root_override.as_deref() {
+                return update_task_metadata_in_root(tasks_root, task)
+                    .map_err(|error| task_lifecycle_error_message(&error));
+            }
+        }
+
+        update_task_metadata(task).map_err(|error| task_lifecycle_error_message(&error))
+    }
+
+    fn confirm_task_notes_dialog(&mut self) {
+        let Some(dialog) = self.task_notes_dialog().cloned() else {
+            return;
+        };
+        let Some(task_index) = self
+            .state
+            .tasks
+            .iter()
+            .position(|task| task.slug == dialog.task_slug)
+        else {
+            self.close_active_dialog();
+            self.show_error_toast(format!("task '{}' no longer exists", dialog.task_name));
+            return;
+        };
+
+        let task = self.state.tasks[task_index]
+            .clone()
+            .with_tags(parse_task_tags(dialog.tags.as_str()))
+            .with_notes(dialog.notes.trim_end().to_string());
+        if let Err(error) = self.write_task_metadata(&task) {
+            self.show_error_toast(format!("notes save failed: {error}"));
+            return;
+        }
+
+        self.log_dialog_event_with_fields(
+            "task_notes",
+            "dialog_confirmed",
+            [
+                ("task".to_string(), Value::from(task.name.clone())),
+                (
+                    "tag_count".to_string(),
+                    Value::from(usize_to_u64(task.tags.len())),
+                ),
+                (
+                    "notes_len".to_string(),
+                    Value::from(usize_to_u64(task.notes.len())),
+                ),
+            ],
+        );
+        self.state.tasks[task_index] = task;
+        self.close_active_dialog();
+        self.show_success_toast(format!("notes saved for {}", dialog.task_name));
+    }
+
+    pub(super) fn handle_task_notes_dialog_key(&mut self, key_event: KeyEvent) {
+        self.sync_active_dialog_focus_field();
+        let Some(focused_field) = self.current_task_notes_dialog_focus_field() else {
+            return;
+        };
+        let ctrl_n = key_event.modifiers == Modifiers::CTRL
+            && matches!(key_event.code, KeyCode::Char('n') | KeyCode::Char('N'));
+        let ctrl_p = key_event.modifiers == Modifiers::CTRL
+            && matches!(key_event.code, KeyCode::Char('p') | KeyCode::Char('P'));
+        let ctrl_s = key_event.modifiers == Modifiers::CTRL
+            && matches!(key_event.code, KeyCode::Char('s') | KeyCode::Char('S'));
+
+        enum PostAction {
+            None,
+            Save,
+            Cancel,
+        }
+
+        let text_field = matches!(
+            focused_field,
+            TaskNotesDialogField::Tags | TaskNotesDialogField::Notes
+        );
+        if text_field && Self::allows_text_input_modifiers(key_event.modifiers) {
+            match key_event.code {
+                KeyCode::Enter if focused_field == TaskNotesDialogField::Notes => {
+                    if let Some(dialog) = self.task_notes_dialog_mut() {
+                        dialog.notes.push('\n');
+                    }
+                    return;
+                }
+                KeyCode::Backspace => {
+                    if let Some(dialog) = self.task_notes_dialog_mut() {
+                        if focused_field == TaskNotesDialogField::Tags {
+                            dialog.tags.pop();
+                        } else {
+                            dialog.notes.pop();
+                        }
+                    }
+                    return;
+                }
+                KeyCode::Char(character) if !character.is_control() => {
+                    if let Some(dialog) = self.task_notes_dialog_mut() {
+                        if focused_field == TaskNotesDialogField::Tags {
+                            dialog.tags.push(character);
+                        } else {
+                            dialog.notes.push(character);
+                        }
+                    }
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        let mut post_action = PostAction::None;
+        match key_event.code {
+            KeyCode::Escape => {
+                post_action = PostAction::Cancel;
+            }
+            KeyCode::Char(_) if ctrl_s => {
+                post_action = PostAction::Save;
+            }
+            KeyCode::Tab => {
+                self.focus_next_dialog_field();
+            }
+            KeyCode::BackTab => {
+                self.focus_prev_dialog_field();
+            }
+            KeyCode::Down if focused_field != TaskNotesDialogField::Notes => {
+                self.focus_next_dialog_field();
+            }
+            KeyCode::Up if focused_field != TaskNotesDialogField::Notes => {
+                self.focus_prev_dialog_field();
+            }
+            KeyCode::Char(_) if ctrl_n => {
+                self.focus_next_dialog_field();
+            }
+            KeyCode::Char(_) if ctrl_p => {
+                self.focus_prev_dialog_field();
+            }
+            KeyCode::Left | KeyCode::Right | KeyCode::Char('h') | KeyCode::Char('l')
+                if !text_field =>
+            {
+                self.focus_dialog_field(if focused_field == TaskNotesDialogField::SaveButton {
+                    FOCUS_ID_TASK_NOTES_CANCEL_BUTTON
+                } else {
+                    FOCUS_ID_TASK_NOTES_SAVE_BUTTON
+                });
+            }
+            KeyCode::Enter => match focused_field {
+                TaskNotesDialogField::Tags | TaskNotesDialogField::Notes => {
+                    self.focus_next_dialog_field();
+                }
+                TaskNotesDialogField::SaveButton => post_action = PostAction::Save,
+                TaskNotesDialogField::CancelButton => post_action = PostAction::Cancel,
+            },
+            _ => {}
+        }
+
+        match post_action {
+            PostAction::None => {}
+            PostAction::Save => self.confirm_task_notes_dialog(),
+            PostAction::Cancel => {
+                self.log_dialog_event("task_notes", "dialog_cancelled");
+                self.close_active_dialog();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_task_tags;
+
+    #[test]
+    fn parse_task_tags_splits_trims_and_dedupes() {
+        assert_eq!(
+            parse_task_tags(" launch, #infra  launch,,ops "),
+            vec!["launch".to_string(), "infra".to_string(), "ops".to_string()]
+        );
+        assert!(parse_task_tags(" , ").is_empty());
+    }
+}
@@ -104,6 +104,17 @@ pub(super) enum ConfirmDialogAction {
         tab_id: u64,
         session_name: String,
     },
+    DiscardDiffHunk {
+        workspace_path: PathBuf,
+        path: String,
+        header: String,
+    },
+    TrustSharedCommands {
+        project_path: PathBuf,
+        project_name: String,
+        /// Commands and agent env entries `.grove.toml` is waiting to apply.
+        entries: Vec<String>,
+    },
     QuitApp,
 }
 
@@ -119,6 +130,127 @@ pub(super) struct SessionCleanupDialogState {
     pub(super) last_error: Option<String>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct GcDialogState {
+    pub(super) options: GcOptions,
+    pub(super) plan: GcPlan,
+    pub(super) selected: Vec<bool>,
+    pub(super) cursor: usize,
+    pub(super) last_error: Option<String>,
+    /// Set while a plan for `options` is computed in the background.
+    pub(super) scanning: bool,
+}
+
+impl GcDialogState {
+    pub(super) fn new(options: GcOptions) -> Self {
+        Self {
+            options,
+            plan: GcPlan {
+                candidates: Vec::new(),
+                skipped_running: Vec::new(),
+                skipped_dirty: Vec::new(),
+            },
+            selected: Vec::new(),
+            cursor: 0,
+            last_error: None,
+            scanning: true,
+        }
+    }
+
+    pub(super) fn replace_plan(&mut self, plan: GcPlan) {
+        self.selected = vec![true; plan.candidates.len()];
+        self.cursor = self.cursor.min(plan.candidates.len().saturating_sub(1));
+        self.plan = plan;
+        self.scanning = false;
+    }
+
+    pub(super) fn selected_count(&self) -> usize {
+        self.selected.iter().filter(|selected| **selected).count()
+    }
+
+    pub(super) fn toggle_cursor(&mut self) {
+        if let Some(selected) = self.selected.get_mut(self.cursor) {
+            *selected = !*selected;
+        }
+    }
+
+    pub(super) fn move_cursor(&mut self, delta: isize) {
+        let len = self.plan.candidates.len();
+        if len == 0 {
+            return;
+        }
+        self.cursor = self.cursor.saturating_add_signed(delta).min(len - 1);
+    }
+
+    pub(super) fn selected_plan(&self) -> GcPlan {
+        GcPlan {
+            candidates: self
+                .plan
+                .candidates
+                .iter()
+                .zip(self.selected.iter())
+                .filter(|(_, selected)| **selected)
+                .map(|(entry, _)| entry.clone())
+                .collect(),
+            skipped_running: Vec::new(),
+            skipped_dirty: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct CommitDialogState {
+    pub(super) workspace_name: String,
+    pub(super) workspace_path: PathBuf,
+    pub(super) files: Vec<ChangedFile>,
+    pub(super) cursor: usize,
+    pub(super) message: String,
+    pub(super) last_error: Option<String>,
+    pub(super) busy: bool,
+}
+
+impl CommitDialogState {
+    pub(super) fn new(workspace_name: String, workspace_path: PathBuf) -> Self {
+        Self {
+            workspace_name,
+            workspace_path,
+            files: Vec::new(),
+            cursor: 0,
+            message: String::new(),
+            last_error: None,
+            busy: true,
+        }
+    }
+
+    pub(super) fn replace_files(&mut self, files: Vec<ChangedFile>) {
+        let cursor_path = self.cursor_file().map(|file| file.path.clone());
+        self.cursor = cursor_path
+            .and_then(|path| files.iter().position(|file| file.path == path))
+            .unwrap_or(self.cursor)
+            .min(files.len().saturating_sub(1));
+        self.files = files;
+    }
+
+    pub(super) fn cursor_file(&self) -> Option<&ChangedFile> {
+        self.files.get(self.cursor)
+    }
+
+    pub(super) fn staged_count(&self) -> usize {
+        self.files
+            .iter()
+            .filter(|file| file.section == ChangeSection::Staged)
+            .count()
+    }
+
+    pub(super) fn move_cursor(&mut self, delta: isize) {
+        let len = self.files.len();
+        if len == 0 {
+            return;
+        }
+        self.cursor = self.cursor.saturating_add_signed(delta).min(len - 1);
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(super) struct DeleteDialogState {
     pub(super) task: Task,
@@ -210,6 +342,55 @@ pub(super) enum SessionCleanupDialogField {
     CancelButton,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum GcDialogField {
+    Candidates,
+    IdleDays,
+    ApplyButton,
+    CancelButton,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct ReviewCommentDialogState {
+    pub(super) workspace_path: PathBuf,
+    pub(super) path: String,
+    pub(super) line: String,
+    pub(super) body: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ReviewCommentDialogField {
+    Line,
+    Body,
+    AddButton,
+    CancelButton,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct TaskNotesDialogState {
+    pub(super) task_slug: String,
+    pub(super) task_name: String,
+    pub(super) tags: String,
+    pub(super) notes: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum TaskNotesDialogField {
+    Tags,
+    Notes,
+    SaveButton,
+    CancelButton,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum CommitDialogField {
+    Files,
+    Message,
+    Draft,
+    CommitButton,
+    CancelButton,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(super) enum MergeDialogField {
     CleanupWorkspace,
@@ -537,9 +718,61 @@ pub(super) struct ProjectDefaultsDialogState {
     pub(super) workspace_init_command_input: TextInput,
     pub(super) claude_env_input: TextInput,
     pub(super) codex_env_input: TextInput,
+    pub(super) shared: SharedProjectConfig,
+    pub(super) shared_error: Option<String>,
+    pub(super) trusted: bool,
 }
 
 impl ProjectDefaultsDialogState {
+    /// Which layer the effective value of a field comes from, with the
+    /// `.grove.toml` value when an empty user field inherits it.
+    pub(super) fn field_source(
+        &self,
+        field: ProjectDefaultsDialogField,
+    ) -> (ConfigSource, Option<String>) {
+        let (user_value, shared_value, needs_trust) = match field {
+            ProjectDefaultsDialogField::BaseBranch => (
+                self.base_branch_input.value(),
+                self.shared.base_branch.clone(),
+                false,
+            ),
+            ProjectDefaultsDialogField::WorkspaceInitCommand => (
+                self.workspace_init_command_input.value(),
+                self.shared.workspace_init_command.clone(),
+                true,
+            ),
+            ProjectDefaultsDialogField::ClaudeEnv => (
+                self.claude_env_input.value(),
+                self.shared
+                    .agent_env
+                    .claude
+                    .as_deref()
+                    .map(format_agent_env_vars),
+                true,
+            ),
+            ProjectDefaultsDialogField::CodexEnv => (
+                self.codex_env_input.value(),
+                self.shared
+                    .agent_env
+                    .codex
+                    .as_deref()
+                    .map(format_agent_env_vars),
+                true,
+            ),
+            ProjectDefaultsDialogField::SaveButton | ProjectDefaultsDialogField::CancelButton => {
+                return (ConfigSource::Default, None);
+            }
+        };
+        if !user_value.trim().is_empty() {
+            return (ConfigSource::User, None);
+        }
+        match shared_value.filter(|value| !value.trim().is_empty()) {
+            Some(value) if needs_trust && !self.trusted => (ConfigSource::Untrusted, Some(value)),
+            Some(value) => (ConfigSource::Shared, Some(value)),
+            None => (ConfigSource::Default, None),
+        }
+    }
+
     pub(super) fn sync_focus(&mut self, focused_field: Option<ProjectDefaultsDialogField>) {
         self.base_branch_input
             .set_focused(focused_field == Some(ProjectDefaultsDialogField::BaseBranch));
@@ -581,6 +814,7 @@ pub(super) enum ProjectDefaultsDialogField {
 pub(super) struct SettingsDialogState {
     pub(super) initial_theme: ThemeName,
     pub(super) theme: ThemeName,
+    pub(super) theme_errors: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -44,17 +44,16 @@ impl HelpCatalogEntry {
     }
 }
 
-fn format_help_hint(hint: &HelpHintSpec) -> String {
-    format!("{} {}", hint.key, hint.action)
-}
-
 impl GroveApp {
+    fn format_help_hint(&self, command: UiCommand, hint: &HelpHintSpec) -> String {
+        match self.keymap.help_key(command) {
+            Some(key) => format!("{key} {}", hint.action),
+            None => format!("{} {}", hint.key, hint.action),
+        }
+    }
+
     fn command_help_labels(&self, context: HelpHintContext) -> Vec<String> {
-        UiCommand::help_hints_for(context)
-            .iter()
-            .filter_map(|command| command.help_hint(context))
-            .map(format_help_hint)
-            .collect()
+        self.command_help_labels_for(context, UiCommand::help_hints_for(context).as_slice())
     }
 
     fn command_help_labels_for(
@@ -64,8 +63,11 @@ impl GroveApp {
     ) -> Vec<String> {
         commands
             .iter()
-            .filter_map(|command| command.help_hint(context))
-            .map(format_help_hint)
+            .filter_map(|command| {
+                command
+                    .help_hint(context)
+                    .map(|hint| self.format_help_hint(*command, hint))
+            })
             .collect()
     }
 
@@ -126,6 +128,47 @@ impl GroveApp {
                 )
                 .join(", "),
             ),
+            HelpCatalogEntry::new(
+                HelpSection::Global,
+                "Split preview",
+                self.command_help_labels_for(
+                    HelpHintContext::Global,
+                    &[
+                        UiCommand::TogglePreviewSplit,
+                        UiCommand::RotatePreviewSplit,
+                        UiCommand::FocusOtherPreviewHalf,
+                    ],
+                )
+                .join(", "),
+            ),
+            HelpCatalogEntry::new(
+                HelpSection::Global,
+                "Dashboard",
+                format!(
+                    "{}, h/j/k/l or arrows move, Tab/S-Tab cycle, Enter attach, Esc close",
+                    self.command_help_labels_for(
+                        HelpHintContext::Global,
+                        &[UiCommand::ToggleDashboard],
+                    )
+                    .join(", ")
+                ),
+            ),
+            HelpCatalogEntry::new(
+                HelpSection::Global,
+                "Sidebar view",
+                format!(
+                    "{}, filter terms take name:/branch:/repo:/status:/pr:/tag: prefixes, Enter keep, Esc clear",
+                    self.command_help_labels_for(
+                        HelpHintContext::Global,
+                        &[
+                            UiCommand::FilterSidebar,
+                            UiCommand::CycleSidebarGroup,
+                            UiCommand::CycleSidebarSort,
+                        ],
+                    )
+                    .join(", ")
+                ),
+            ),
             HelpCatalogEntry::new(
                 HelpSection::Workspace,
                 "Task/worktree",
@@ -202,6 +245,11 @@ impl GroveApp {
                 "Update",
                 "Tab/S-Tab or C-n/C-p fields, h/l buttons, Enter/u update worktree, Esc",
             ),
+            HelpCatalogEntry::new(
+                HelpSection::Modals,
+                "Commit",
+                "Tab/S-Tab/C-n/C-p fields, j/k move, Space stage/unstage file, a stage all, Enter newline in message, Draft asks the agent, Ctrl+S commit, Esc cancel",
+            ),
             HelpCatalogEntry::new(
                 HelpSection::Modals,
                 "Projects",
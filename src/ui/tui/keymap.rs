@@ -0,0 +1,417 @@
+use super::*;
+use crate::infrastructure::config::KeyBindingConfig;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct KeySequence {
+    pub(super) chords: Vec<KeybindingSpec>,
+    pub(super) label: String,
+}
+
+impl KeySequence {
+    /// True when one sequence is a prefix of the other, so a keypress could
+    /// be claimed by both.
+    fn overlaps(&self, other: &Self) -> bool {
+        self.chords
+            .iter()
+            .zip(other.chords.iter())
+            .all(|(left, right)| left.overlaps(right))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum KeySequenceMatch {
+    None,
+    Pending,
+    Complete(UiCommand),
+}
+
+/// Effective keybindings: the built-in table with `[keys]` overrides applied.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(super) struct Keymap {
+    overrides: HashMap<UiCommand, Vec<KeySequence>>,
+}
+
+impl KeybindingSpec {
+    fn overlaps(&self, other: &Self) -> bool {
+        if self.code != other.code {
+            return false;
+        }
+        matches!(self.code, KeyCodeMatch::CtrlChar(_))
+            || self.modifiers == other.modifiers
+            || matches!(self.modifiers, KeyModifiersMatch::Any)
+            || matches!(other.modifiers, KeyModifiersMatch::Any)
+    }
+}
+
+fn parse_key_code(token: &str) -> Option<KeyCodeMatch> {
+    let mut characters = token.chars();
+    if let (Some(character), None) = (characters.next(), characters.next()) {
+        return Some(KeyCodeMatch::Char(character));
+    }
+    let code = match token.to_ascii_lowercase().as_str() {
+        "enter" | "return" => KeyCodeMatch::Enter,
+        "tab" => KeyCodeMatch::Tab,
+        "esc" | "escape" => KeyCodeMatch::Escape,
+        "up" => KeyCodeMatch::Up,
+        "down" => KeyCodeMatch::Down,
+        "left" => KeyCodeMatch::Left,
+        "right" => KeyCodeMatch::Right,
+        "pageup" | "pgup" => KeyCodeMatch::PageUp,
+        "pagedown" | "pgdn" => KeyCodeMatch::PageDown,
+        "end" => KeyCodeMatch::End,
+        "space" => KeyCodeMatch::Char(' '),
+        _ => return None,
+    };
+    Some(code)
+}
+
+fn strip_modifier<'a>(token: &'a str, modifier: &str) -> Option<&'a str> {
+    let prefix = token.get(..modifier.len())?;
+    let rest = token.get(modifier.len()..)?;
+    (prefix.eq_ignore_ascii_case(modifier) && !rest.is_empty()).then_some(rest)
+}
+
+/// Plain keys always bind outside interactive mode. Modified chords for a
+/// command with global navigation bindings also work while attached.
+fn parse_key_chord(token: &str, global: bool) -> Result<KeybindingSpec, String> {
+    let modified_scope = if global {
+        KeybindingScope::GlobalNavigation
+    } else {
+        KeybindingScope::NonInteractive
+    };
+    if let Some(rest) = strip_modifier(token, "ctrl+") {
+        let mut characters = rest.chars();
+        return match (characters.next(), characters.next()) {
+            (Some(character), None) if character.is_ascii_alphabetic() => Ok(KeybindingSpec {
+                scope: modified_scope,
+                code: KeyCodeMatch::CtrlChar(character.to_ascii_lowercase()),
+                modifiers: KeyModifiersMatch::None,
+            }),
+            _ => Err(format!("`{token}`: ctrl+ needs a single letter")),
+        };
+    }
+    if let Some(rest) = strip_modifier(token, "alt+") {
+        let code = parse_key_code(rest).ok_or_else(|| format!("`{token}`: unknown key"))?;
+        return Ok(KeybindingSpec {
+            scope: modified_scope,
+            code,
+            modifiers: KeyModifiersMatch::Contains(Modifiers::ALT),
+        });
+    }
+    let code = parse_key_code(token).ok_or_else(|| format!("`{token}`: unknown key"))?;
+    Ok(KeybindingSpec {
+        scope: KeybindingScope::NonInteractive,
+        code,
+        modifiers: KeyModifiersMatch::Any,
+    })
+}
+
+fn parse_key_sequence(raw: &str, global: bool) -> Result<KeySequence, String> {
+    let tokens = raw.split_whitespace().collect::<Vec<&str>>();
+    if tokens.is_empty() {
+        return Err("empty key sequence".to_string());
+    }
+    let global = global && tokens.len() == 1;
+    let chords = tokens
+        .iter()
+        .map(|token| parse_key_chord(token, global))
+        .collect::<Result<Vec<KeybindingSpec>, String>>()?;
+    Ok(KeySequence {
+        chords,
+        label: tokens.join(" "),
+    })
+}
+
+fn command_for_key_id(id: &str) -> Option<UiCommand> {
+    if id.starts_with("palette:") {
+        return UiCommand::from_palette_id(id);
+    }
+    UiCommand::from_palette_id(format!("palette:{id}").as_str())
+}
+
+impl Keymap {
+    /// Builds the keymap from `[keys]`. Invalid or conflicting entries are
+    /// dropped, leaving that command on its built-in keys, and reported.
+    pub(super) fn from_config(keys: &BTreeMap<String, KeyBindingConfig>) -> (Self, Vec<String>) {
+        let mut errors = Vec::new();
+        let mut overrides = HashMap::new();
+        let mut ids = HashMap::new();
+        for (id, binding) in keys {
+            let Some(command) = command_for_key_id(id.trim()) else {
+                errors.push(format!("keys.{id}: unknown command"));
+                continue;
+            };
+            if let Some(previous) = ids.insert(command, id.clone()) {
+                errors.push(format!("keys.{id}: duplicates keys.{previous}"));
+                continue;
+            }
+            let global = command
+                .keybindings()
+                .iter()
+                .any(|binding| binding.scope == KeybindingScope::GlobalNavigation);
+            match binding
+                .sequences()
+                .into_iter()
+                .map(|raw| parse_key_sequence(raw, global))
+                .collect::<Result<Vec<KeySequence>, String>>()
+            {
+                Ok(sequences) => {
+                    overrides.insert(command, sequences);
+                }
+                Err(error) => errors.push(format!("keys.{id}: {error}")),
+            }
+        }
+
+        let mut keymap = Self { overrides };
+        while let Some((command, other, label)) = keymap.first_conflict() {
+            keymap.overrides.remove(&command);
+            let id = ids.get(&command).cloned().unwrap_or_default();
+            let other_name = other
+                .palette_spec()
+                .map_or("another command", |spec| spec.title);
+            errors.push(format!(
+                "keys.{id}: `{label}` conflicts with {other_name}, keeping defaults"
+            ));
+        }
+        (keymap, errors)
+    }
+
+    fn first_conflict(&self) -> Option<(UiCommand, UiCommand, String)> {
+        let mut user_commands = self.overrides.keys().copied().collect::<Vec<UiCommand>>();
+        user_commands.sort_by_key(|command| {
+            UiCommand::all()
+                .iter()
+                .position(|candidate| candidate == command)
+        });
+        for command in user_commands {
+            for sequence in self.sequences(command) {
+                for other in UiCommand::all() {
+                    if *other == command {
+                        continue;
+                    }
+                    if self
+                        .sequences(*other)
+                        .iter()
+                        .any(|other_sequence| sequence.overlaps(other_sequence))
+                    {
+                        return Some((command, *other, sequence.label.clone()));
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    pub(super) fn is_overridden(&self, command: UiCommand) -> bool {
+        self.overrides.contains_key(&command)
+    }
+
+    pub(super) fn sequences(&self, command: UiCommand) -> Vec<KeySequence> {
+        if let Some(sequences) = self.overrides.get(&command) {
+            return sequences.clone();
+        }
+        command
+            .keybindings()
+            .iter()
+            .map(|binding| KeySequence {
+                chords: vec![*binding],
+                label: String::new(),
+            })
+            .collect()
+    }
+
+    pub(super) fn matches_key(
+        &self,
+        command: UiCommand,
+        key_event: &KeyEvent,
+        scope: KeybindingScope,
+    ) -> bool {
+        let Some(sequences) = self.overrides.get(&command) else {
+            return command.matches_keybinding(key_event, scope);
+        };
+        sequences.iter().any(|sequence| {
+            sequence.chords.len() == 1
+                && sequence.chords[0].scope == scope
+                && sequence.chords[0].matches(key_event)
+        })
+    }
+
+    /// Matches keys pressed so far against the multi-key sequences.
+    pub(super) fn match_sequence(&self, pressed: &[KeyEvent]) -> KeySequenceMatch {
+        let mut pending = false;
+        for (command, sequences) in &self.overrides {
+            for sequence in sequences {
+                if sequence.chords.len() < 2 || pressed.len() > sequence.chords.len() {
+                    continue;
+                }
+                if !sequence
+                    .chords
+                    .iter()
+                    .zip(pressed.iter())
+                    .all(|(chord, key_event)| chord.matches(key_event))
+                {
+                    continue;
+                }
+                if pressed.len() == sequence.chords.len() {
+                    return KeySequenceMatch::Complete(*command);
+                }
+                pending = true;
+            }
+        }
+        if pending {
+            KeySequenceMatch::Pending
+        } else {
+            KeySequenceMatch::None
+        }
+    }
+
+    /// The key label shown in help for an overridden command.
+    pub(super) fn help_key(&self, command: UiCommand) -> Option<String> {
+        let sequences = self.overrides.get(&command)?;
+        if sequences.is_empty() {
+            return Some("unbound".to_string());
+        }
+        Some(
+            sequences
+                .iter()
+                .map(|sequence| sequence.label.as_str())
+                .collect::<Vec<&str>>()
+                .join("/"),
+        )
+    }
+}
+
+impl GroveApp {
+    pub(super) fn report_keymap_errors(&mut self, errors: &[String]) {
+        let Some(first) = errors.first() else {
+            return;
+        };
+        let message = if errors.len() == 1 {
+            first.clone()
+        } else {
+            format!("{first} (+{} more)", errors.len() - 1)
+        };
+        self.show_warning_toast(message);
+    }
+
+    pub(super) fn apply_keys_config(&mut self, keys: &BTreeMap<String, KeyBindingConfig>) {
+        let (keymap, errors) = Keymap::from_config(keys);
+        self.keymap = keymap;
+        self.pending_key_sequence.clear();
+        self.report_keymap_errors(&errors);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KeySequenceMatch, Keymap};
+    use crate::infrastructure::config::KeyBindingConfig;
+    use crate::ui::tui::{KeybindingScope, UiCommand};
+    use ftui::core::event::{KeyCode, KeyEvent, KeyEventKind, Modifiers};
+    use std::collections::BTreeMap;
+
+    fn press(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code).with_kind(KeyEventKind::Press)
+    }
+
+    #[test]
+    fn overrides_replace_defaults_and_support_sequences() {
+        let keys = BTreeMap::from([
+            (
+                "palette:commit_changes".to_string(),
+                KeyBindingConfig::Single("z c".to_string()),
+            ),
+            (
+                "quit".to_string(),
+                KeyBindingConfig::Multiple(vec!["ctrl+q".to_string()]),
+            ),
+        ]);
+
+        let (keymap, errors) = Keymap::from_config(&keys);
+
+        assert!(errors.is_empty(), "{errors:?}");
+        assert!(!keymap.matches_key(
+            UiCommand::Quit,
+            &press(KeyCode::Char('q')),
+            KeybindingScope::NonInteractive
+        ));
+        assert!(keymap.matches_key(
+            UiCommand::Quit,
+            &press(KeyCode::Char('q')).with_modifiers(Modifiers::CTRL),
+            KeybindingScope::NonInteractive
+        ));
+        assert_eq!(
+            keymap.match_sequence(&[press(KeyCode::Char('z'))]),
+            KeySequenceMatch::Pending
+        );
+        assert_eq!(
+            keymap.match_sequence(&[press(KeyCode::Char('z')), press(KeyCode::Char('c'))]),
+            KeySequenceMatch::Complete(UiCommand::CommitChanges)
+        );
+        assert_eq!(
+            keymap.match_sequence(&[press(KeyCode::Char('x'))]),
+            KeySequenceMatch::None
+        );
+        assert_eq!(
+            keymap.help_key(UiCommand::CommitChanges).as_deref(),
+            Some("z c")
+        );
+    }
+
+    #[test]
+    fn conflicting_and_invalid_entries_are_reported_and_dropped() {
+        let keys = BTreeMap::from([
+            (
+                "open_help".to_string(),
+                KeyBindingConfig::Single("?".to_string()),
+            ),
+            (
+                "commit_changes".to_string(),
+                KeyBindingConfig::Single("g d".to_string()),
+            ),
+            (
+                "toggle_sidebar".to_string(),
+                KeyBindingConfig::Single("ctrl+".to_string()),
+            ),
+            (
+                "does_not_exist".to_string(),
+                KeyBindingConfig::Single("z".to_string()),
+            ),
+            (
+                "quit".to_string(),
+                KeyBindingConfig::Single("Q".to_string()),
+            ),
+            (
+                "refresh_workspaces".to_string(),
+                KeyBindingConfig::Single("Q".to_string()),
+            ),
+        ]);
+
+        let (keymap, errors) = Keymap::from_config(&keys);
+
+        assert!(
+            errors
+                .iter()
+                .any(|error| error.contains("keys.does_not_exist"))
+        );
+        assert!(
+            errors
+                .iter()
+                .any(|error| error.contains("keys.toggle_sidebar"))
+        );
+        assert!(errors.iter().any(|error| error.contains("conflicts with")));
+        assert!(!keymap.is_overridden(UiCommand::ToggleSidebar));
+        assert!(keymap.is_overridden(UiCommand::OpenHelp));
+        assert!(
+            errors
+                .iter()
+                .any(|error| error.contains("keys.commit_changes: `g d` conflicts"))
+        );
+        assert!(!keymap.is_overridden(UiCommand::CommitChanges));
+        assert!(
+            !(keymap.is_overridden(UiCommand::Quit)
+                && keymap.is_overridden(UiCommand::RefreshWorkspaces))
+        );
+    }
+}
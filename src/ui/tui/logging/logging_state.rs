@@ -44,6 +44,8 @@ impl GroveApp {
             HitRegion::WorkspaceList => "workspace_list",
             HitRegion::WorkspacePullRequest => "workspace_pull_request",
             HitRegion::Preview => "preview",
+            HitRegion::PinnedPreview => "pinned_preview",
+            HitRegion::DashboardTile => "dashboard_tile",
             HitRegion::Divider => "divider",
             HitRegion::StatusLine => "status_line",
             HitRegion::Header => "header",
@@ -82,6 +84,7 @@ impl GroveApp {
             Msg::Resize { .. } => "resize",
             Msg::PreviewPollCompleted(_) => "preview_poll_completed",
             Msg::PreviewStreamEvent(_) => "preview_stream_event",
+            Msg::PinnedPreviewCaptured(_) => "pinned_preview_captured",
             Msg::LazygitLaunchCompleted(_) => "lazygit_launch_completed",
             Msg::WorkspaceShellLaunchCompleted(_) => "workspace_shell_launch_completed",
             Msg::RefreshWorkspacesCompleted(_) => "refresh_workspaces_completed",
@@ -97,6 +100,14 @@ impl GroveApp {
             Msg::InteractiveSendCompleted(_) => "interactive_send_completed",
             Msg::DiffCaptureCompleted(_) => "diff_capture_completed",
             Msg::DiffStatCompleted(_) => "diff_stat_completed",
+            Msg::BranchSyncCompleted(_) => "branch_sync_completed",
+            Msg::ProjectFetchCompleted(_) => "project_fetch_completed",
+            Msg::GcPlanCompleted(_) => "gc_plan_completed",
+            Msg::CommitFilesCompleted(_) => "commit_files_completed",
+            Msg::CommitCompleted(_) => "commit_completed",
+            Msg::DiffHunkStageCompleted(_) => "diff_hunk_stage_completed",
+            Msg::DiffHunkDiscardCompleted(_) => "diff_hunk_discard_completed",
+            Msg::ControlRequest(_) => "control_request",
             Msg::Noop => "noop",
         }
     }
@@ -217,6 +228,13 @@ impl GroveApp {
         );
     }
 
+    /// Forwards the config migrations recorded by earlier loads.
+    pub(super) fn log_config_migrations(&self) {
+        for event in crate::infrastructure::migrations::take_migration_events() {
+            self.telemetry.event_log.log(event);
+        }
+    }
+
     pub(super) fn execute_tmux_command(&mut self, command: &[String]) -> std::io::Result<()> {
         let started_at = Instant::now();
         let command_text = command.join(" ");
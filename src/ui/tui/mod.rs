@@ -19,8 +19,11 @@ mod commands_hints;
 mod commands_meta;
 #[path = "commands/palette.rs"]
 mod commands_palette;
+mod control_socket;
 #[path = "dialogs/dialogs.rs"]
 mod dialogs;
+#[path = "dialogs/dialogs_commit.rs"]
+mod dialogs_commit;
 #[path = "dialogs/dialogs_confirm.rs"]
 mod dialogs_confirm;
 #[path = "dialogs/dialogs_create_key.rs"]
@@ -31,6 +34,8 @@ mod dialogs_create_setup;
 mod dialogs_delete;
 #[path = "dialogs/dialogs_edit.rs"]
 mod dialogs_edit;
+#[path = "dialogs/dialogs_gc.rs"]
+mod dialogs_gc;
 #[path = "dialogs/dialogs_launch.rs"]
 mod dialogs_launch;
 #[path = "dialogs/dialogs_merge.rs"]
@@ -51,6 +56,8 @@ mod dialogs_projects_state;
 mod dialogs_pull_upstream;
 #[path = "dialogs/dialogs_rename_tab.rs"]
 mod dialogs_rename_tab;
+#[path = "dialogs/dialogs_review_comment.rs"]
+mod dialogs_review_comment;
 #[path = "dialogs/dialogs_session_cleanup.rs"]
 mod dialogs_session_cleanup;
 #[path = "dialogs/dialogs_settings.rs"]
@@ -59,10 +66,14 @@ mod dialogs_settings;
 mod dialogs_state;
 #[path = "dialogs/dialogs_stop.rs"]
 mod dialogs_stop;
+#[path = "dialogs/dialogs_task_notes.rs"]
+mod dialogs_task_notes;
 #[path = "dialogs/dialogs_update_from_base.rs"]
 mod dialogs_update_from_base;
 #[path = "help_catalog.rs"]
 mod help_catalog;
+#[path = "keymap.rs"]
+mod keymap;
 #[path = "logging/logging_frame.rs"]
 mod logging_frame;
 #[path = "logging/logging_input.rs"]
@@ -72,6 +83,7 @@ mod logging_state;
 mod msg;
 mod runner;
 mod selection;
+mod status_snapshot;
 pub use runner::{run_with_debug_record, run_with_event_log};
 mod replay;
 pub use replay::{ReplayOptions, emit_replay_fixture, replay_debug_record};
@@ -107,12 +119,22 @@ mod update_lifecycle_workspace_completion;
 mod update_lifecycle_workspace_refresh;
 #[path = "update/update_navigation_commands.rs"]
 mod update_navigation_commands;
+#[path = "update/update_navigation_dashboard.rs"]
+mod update_navigation_dashboard;
+#[path = "update/update_navigation_diff.rs"]
+mod update_navigation_diff;
 #[path = "update/update_navigation_palette.rs"]
 mod update_navigation_palette;
 #[path = "update/update_navigation_preview.rs"]
 mod update_navigation_preview;
+#[path = "update/update_navigation_preview_split.rs"]
+mod update_navigation_preview_split;
+#[path = "update/update_navigation_sidebar.rs"]
+mod update_navigation_sidebar;
 #[path = "update/update_navigation_tabs.rs"]
 mod update_navigation_tabs;
+#[path = "update/update_polling_branch_sync.rs"]
+mod update_polling_branch_sync;
 #[path = "update/update_polling_capture_cursor.rs"]
 mod update_polling_capture_cursor;
 #[path = "update/update_polling_capture_diff.rs"]
@@ -125,6 +147,8 @@ mod update_polling_capture_live;
 mod update_polling_capture_task;
 #[path = "update/update_polling_capture_workspace.rs"]
 mod update_polling_capture_workspace;
+#[path = "update/update_polling_config_reload.rs"]
+mod update_polling_config_reload;
 #[path = "update/update_polling_state.rs"]
 mod update_polling_state;
 #[path = "update/prelude.rs"]
@@ -141,14 +165,20 @@ mod view_chrome_header;
 mod view_chrome_shared;
 #[path = "view/view_chrome_sidebar.rs"]
 mod view_chrome_sidebar;
+#[path = "view/view_dashboard.rs"]
+mod view_dashboard;
 #[path = "view/view_layout.rs"]
 mod view_layout;
+#[path = "view/view_overlays_commit.rs"]
+mod view_overlays_commit;
 #[path = "view/view_overlays_confirm.rs"]
 mod view_overlays_confirm;
 #[path = "view/view_overlays_create.rs"]
 mod view_overlays_create;
 #[path = "view/view_overlays_edit.rs"]
 mod view_overlays_edit;
+#[path = "view/view_overlays_gc.rs"]
+mod view_overlays_gc;
 #[path = "view/view_overlays_help.rs"]
 mod view_overlays_help;
 #[path = "view/view_overlays_performance.rs"]
@@ -159,10 +189,14 @@ mod view_overlays_projects;
 mod view_overlays_pull_upstream;
 #[path = "view/view_overlays_rename_tab.rs"]
 mod view_overlays_rename_tab;
+#[path = "view/view_overlays_review_comment.rs"]
+mod view_overlays_review_comment;
 #[path = "view/view_overlays_session_cleanup.rs"]
 mod view_overlays_session_cleanup;
 #[path = "view/view_overlays_settings.rs"]
 mod view_overlays_settings;
+#[path = "view/view_overlays_task_notes.rs"]
+mod view_overlays_task_notes;
 #[path = "view/view_overlays_workspace_delete.rs"]
 mod view_overlays_workspace_delete;
 #[path = "view/view_overlays_workspace_launch.rs"]
@@ -179,8 +213,12 @@ mod view_prelude;
 mod view_preview;
 #[path = "view/view_preview_content.rs"]
 mod view_preview_content;
+#[path = "view/view_preview_diff.rs"]
+mod view_preview_diff;
 #[path = "view/view_preview_shell.rs"]
 mod view_preview_shell;
+#[path = "view/view_preview_split.rs"]
+mod view_preview_split;
 #[path = "view/view_selection_interaction.rs"]
 mod view_selection_interaction;
 #[path = "view/view_selection_logging.rs"]
@@ -227,44 +265,56 @@ mod tests {
         assert_row_bg, assert_row_fg, find_cell_with_char, find_row_containing, row_text,
     };
     use self::support::logging::{RecordedEvents, RecordingEventLogger};
+    use super::update_navigation_sidebar::SidebarSectionHeader;
     use super::{
-        AppDependencies, AttentionItem, AttentionReason, ClipboardAccess, CommandTmuxInput,
+        AppDependencies, AttentionItem, AttentionReason, BranchSyncCompletion, ClipboardAccess,
+        CommandTmuxInput, CommitDialogState, CommitFilesCompletion, ControlRequest,
         CreateDialogField, CreateDialogMode, CreateDialogState, CreateDialogTab,
         CreateWorkspaceCompletion, CreateWorkspaceRequest, CreateWorkspaceResult, CursorCapture,
-        DeleteDialogField, DeleteProjectCompletion, DeleteWorkspaceCompletion, EditDialogField,
+        DeleteDialogField, DeleteProjectCompletion, DeleteWorkspaceCompletion,
+        DiffCaptureCompletion, DiffHunkStageCompletion, EditDialogField, FOCUS_ID_COMMIT_DRAFT,
         FOCUS_ID_CONFIRM_CANCEL_BUTTON, FOCUS_ID_CONFIRM_CONFIRM_BUTTON, FOCUS_ID_PREVIEW,
         FOCUS_ID_PROJECT_ADD_CANCEL_BUTTON, FOCUS_ID_PROJECT_ADD_NAME_INPUT,
         FOCUS_ID_PROJECT_ADD_PATH_INPUT, FOCUS_ID_PROJECT_DEFAULTS_BASE_BRANCH_INPUT,
         FOCUS_ID_PROJECT_DEFAULTS_CANCEL_BUTTON, FOCUS_ID_PROJECT_DEFAULTS_CODEX_ENV_INPUT,
         FOCUS_ID_PROJECT_DEFAULTS_INIT_COMMAND_INPUT, FOCUS_ID_PROJECT_DIALOG_FILTER_INPUT,
-        FOCUS_ID_WORKSPACE_LIST, GroveApp, HIT_ID_CREATE_DIALOG_TAB, HIT_ID_HEADER, HIT_ID_PREVIEW,
-        HIT_ID_PROJECT_ADD_RESULTS_LIST, HIT_ID_PROJECT_DIALOG_LIST, HIT_ID_STATUS,
-        HIT_ID_WORKSPACE_LIST, HIT_ID_WORKSPACE_PR_LINK, HIT_ID_WORKSPACE_ROW, HelpHintContext,
-        LaunchDialogState, LaunchDialogTarget, LazygitLaunchCompletion, LivePreviewCapture,
-        MergeDialogField, MergeWorkspaceCompletion, Msg, PREVIEW_METADATA_ROWS, PaletteMode,
-        PendingResizeVerification, PreviewPollCompletion, PreviewSessionGeometry,
-        PreviewStreamConnected, PreviewStreamDisconnected, PreviewStreamEvent, PreviewStreamOutput,
-        PreviewStreamSource, PreviewTab, ProjectAddDialogField, ProjectDefaultsDialogField,
+        FOCUS_ID_WORKSPACE_LIST, GcDialogState, GcPlanCompletion, GroveApp,
+        HIT_ID_CREATE_DIALOG_TAB, HIT_ID_HEADER, HIT_ID_PREVIEW, HIT_ID_PROJECT_ADD_RESULTS_LIST,
+        HIT_ID_PROJECT_DIALOG_LIST, HIT_ID_STATUS, HIT_ID_WORKSPACE_LIST, HIT_ID_WORKSPACE_PR_LINK,
+        HIT_ID_WORKSPACE_ROW, HelpHintContext, LaunchDialogState, LaunchDialogTarget,
+        LazygitLaunchCompletion, LivePreviewCapture, MergeDialogField, MergeWorkspaceCompletion,
+        Msg, PREVIEW_METADATA_ROWS, PaletteMode, PendingResizeVerification, PreviewPollCompletion,
+        PreviewSessionGeometry, PreviewStreamConnected, PreviewStreamDisconnected,
+        PreviewStreamEvent, PreviewStreamOutput, PreviewStreamSource, PreviewTab,
+        ProjectAddDialogField, ProjectDefaultsDialogField, ProjectFetchCompletion,
         PullUpstreamDialogField, RefreshWorkspacesCompletion, SettingsDialogField,
-        StartAgentCompletion, StartAgentConfigState, StopAgentCompletion, StopDialogField,
-        TextSelectionPoint, TmuxInput, UiCommand, UpdateFromBaseDialogField, WorkspaceAttention,
-        WorkspaceShellLaunchCompletion, WorkspaceStatusCapture, WorkspaceTab, WorkspaceTabKind,
-        WorkspaceTabRuntimeState, decode_create_dialog_tab_hit_data, decode_workspace_pr_hit_data,
-        packed, parse_cursor_metadata, ui_theme, ui_theme_for, usize_to_u64,
+        SidebarSelectable, StartAgentCompletion, StartAgentConfigState, StopAgentCompletion,
+        StopDialogField, TextSelectionPoint, TmuxInput, UiCommand, UpdateFromBaseDialogField,
+        WorkspaceAttention, WorkspaceShellLaunchCompletion, WorkspaceStatusCapture, WorkspaceTab,
+        WorkspaceTabKind, WorkspaceTabRuntimeState, decode_create_dialog_tab_hit_data,
+        decode_workspace_pr_hit_data, packed, parse_cursor_metadata, ui_theme, ui_theme_for,
+        usize_to_u64,
     };
     use crate::application::agent_runtime::status::WorkspaceStatusObservation;
     use crate::application::agent_runtime::workspace_status_targets_for_polling_with_live_preview;
+    use crate::application::branch_sync::{BranchDivergence, WorktreeSyncStatus};
+    use crate::application::gc::{GcEntry, GcOptions, GcPlan, GcReason, GcTarget};
     use crate::application::interactive::InteractiveState;
     use crate::application::task_lifecycle::{
         CreateTaskRequest, CreateTaskResult, TaskBranchSource,
     };
+    use crate::application::worktree_changes::{ChangeSection, ChangedFile};
     use crate::domain::{
         AgentType, PermissionMode, PullRequest, PullRequestStatus, Task, Workspace,
         WorkspaceStatus, Worktree,
     };
     use crate::infrastructure::adapters::DiscoveryState;
-    use crate::infrastructure::config::{ProjectConfig, ProjectDefaults, ThemeName};
+    use crate::infrastructure::config::{
+        KeyBindingConfig, ProjectConfig, ProjectDefaults, SidebarGroupMode, SidebarSortMode,
+        SidebarViewConfig, ThemeName,
+    };
     use crate::infrastructure::event_log::{Event as LoggedEvent, NullEventLogger};
+    use crate::infrastructure::status_snapshot::load_status_snapshot_from_path;
     use crate::ui::state::{Action, UiMode, reduce};
     use ftui::core::event::{
         Event, KeyCode, KeyEvent, KeyEventKind, Modifiers, MouseButton, MouseEvent, MouseEventKind,
@@ -277,9 +327,12 @@ mod tests {
     use ftui::widgets::toast::ToastStyle;
     use ftui::{Cmd, Frame, GraphemePool, PackedRgba};
     use proptest::prelude::*;
-    use serde_json::Value;
+    use serde_json::{Value, json};
     use std::cell::RefCell;
+    use std::collections::BTreeMap;
     use std::fs;
+    use std::io::{BufRead, BufReader};
+    use std::os::unix::net::UnixStream;
     use std::path::PathBuf;
     use std::rc::Rc;
     use std::sync::{Arc, Mutex};
@@ -998,6 +1051,10 @@ mod tests {
         app.polling.last_diff_stat_poll_at = Some(Instant::now());
     }
 
+    fn suppress_branch_sync_poll(app: &mut GroveApp) {
+        app.polling.last_branch_sync_poll_at = Some(Instant::now());
+    }
+
     fn seed_running_agent_tabs_for_running_workspaces(app: &mut GroveApp) {
         let workspaces = app.state.workspaces.clone();
         for workspace in workspaces {
@@ -1500,6 +1557,23 @@ mod tests {
             );
         }
 
+        #[test]
+        fn header_shows_active_profile() {
+            let mut app = fixture_app();
+            app.profile_name = Some("work".to_string());
+
+            let (width, height) = (80, 24);
+            let mut pool = GraphemePool::new();
+            let mut frame = Frame::new(width, height, &mut pool);
+            ftui::Model::view(&app, &mut frame);
+
+            let header_text = row_text(&frame, 0, 0, width);
+            assert!(
+                header_text.contains("[profile: work]"),
+                "header should show the active profile, got: {header_text}"
+            );
+        }
+
         #[test]
         fn header_shows_jump_badge_when_workspace_jump_is_open() {
             let mut app = fixture_app();
@@ -3737,6 +3811,138 @@ mod tests {
         assert!(!matches!(cmd, Cmd::Quit));
     }
 
+    fn control_request(client_id: u64, method: &str, params: Value) -> Msg {
+        Msg::ControlRequest(ControlRequest {
+            client_id,
+            id: Some(Value::from(1)),
+            method: method.to_string(),
+            params,
+        })
+    }
+
+    fn read_control_messages(client: &UnixStream) -> Vec<Value> {
+        client
+            .set_nonblocking(true)
+            .expect("client should switch to nonblocking");
+        BufReader::new(client)
+            .lines()
+            .map_while(Result::ok)
+            .map(|line| serde_json::from_str(line.as_str()).expect("line should be json"))
+            .collect()
+    }
+
+    fn control_response(messages: &[Value]) -> Value {
+        messages
+            .iter()
+            .find(|message| message.get("id").is_some())
+            .cloned()
+            .expect("response should be sent")
+    }
+
+    #[test]
+    fn control_state_request_reports_workspaces_and_attention() {
+        let (mut app, _commands, _captures, _cursor_captures) =
+            fixture_app_with_tmux(WorkspaceStatus::Active, Vec::new());
+        app.clear_startup_attention_focus_pending();
+        seed_feature_finished_attention(&mut app);
+        let (client_id, client) = app.attach_control_client();
+
+        let _ = ftui::Model::update(&mut app, control_request(client_id, "state.get", json!({})));
+
+        let response = control_response(&read_control_messages(&client));
+        let result = &response["result"];
+        assert_eq!(result["workspaces"][1]["status"], "done");
+        assert_eq!(result["workspaces"][1]["task"], "feature-a");
+        assert_eq!(result["attention"][0]["reason"], "finished");
+        assert_eq!(
+            result["attention"][0]["path"],
+            feature_workspace_path().display().to_string()
+        );
+    }
+
+    #[test]
+    fn control_workspace_select_request_selects_task_workspace() {
+        let mut app = fixture_app();
+        let (client_id, client) = app.attach_control_client();
+
+        let _ = ftui::Model::update(
+            &mut app,
+            control_request(
+                client_id,
+                "workspace.select",
+                json!({ "task": "feature-a" }),
+            ),
+        );
+
+        assert_eq!(app.state.selected_index, 1);
+        let response = control_response(&read_control_messages(&client));
+        assert_eq!(response["result"]["task"], "feature-a");
+    }
+
+    #[test]
+    fn control_request_reports_unknown_methods_and_targets() {
+        let mut app = fixture_app();
+        let (client_id, client) = app.attach_control_client();
+
+        let _ = ftui::Model::update(&mut app, control_request(client_id, "bogus", json!({})));
+        let unknown_method = control_response(&read_control_messages(&client));
+        let _ = ftui::Model::update(
+            &mut app,
+            control_request(client_id, "workspace.select", json!({ "task": "missing" })),
+        );
+        let unknown_task = control_response(&read_control_messages(&client));
+
+        assert_eq!(unknown_method["error"]["code"], -32601);
+        assert_eq!(unknown_task["error"]["message"], "task `missing` not found");
+        assert_eq!(app.state.selected_index, 0);
+    }
+
+    #[test]
+    fn control_socket_pushes_workspace_status_changes() {
+        let mut app = fixture_app();
+        let (_client_id, client) = app.attach_control_client();
+        app.publish_control_notifications();
+        let _ = read_control_messages(&client);
+        app.state.workspaces[1].status = WorkspaceStatus::Waiting;
+
+        let _ = ftui::Model::update(&mut app, Msg::Noop);
+
+        let messages = read_control_messages(&client);
+        let changes = messages
+            .iter()
+            .filter(|message| message["method"] == "workspace.status_changed")
+            .collect::<Vec<&Value>>();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0]["params"]["previous"], "idle");
+        assert_eq!(changes[0]["params"]["status"], "waiting");
+    }
+
+    #[test]
+    fn status_snapshot_records_waiting_workspaces_for_status_line() {
+        let mut app = fixture_app();
+        let path = std::env::temp_dir()
+            .join(format!("grove-status-snapshot-{}", std::process::id()))
+            .join("status.json");
+        app.enable_status_snapshot_at(path.clone());
+        app.state.workspaces[1].status = WorkspaceStatus::Waiting;
+
+        let _ = ftui::Model::update(&mut app, Msg::Noop);
+
+        let snapshot = load_status_snapshot_from_path(path.as_path())
+            .expect("snapshot should load")
+            .expect("snapshot should exist");
+        let waiting = snapshot
+            .workspaces
+            .iter()
+            .find(|entry| entry.path == app.state.workspaces[1].path)
+            .expect("workspace should be recorded");
+        assert_eq!(waiting.status, "waiting");
+        assert!(waiting.waiting_since_ms.is_some());
+        if let Some(parent) = path.parent() {
+            let _ = fs::remove_dir_all(parent);
+        }
+    }
+
     #[test]
     fn focus_attention_inbox_command_selects_first_attention_item() {
         let (mut app, _commands, _captures, _cursor_captures) =
@@ -3962,6 +4168,97 @@ mod tests {
         );
     }
 
+    fn fixture_branch_sync_completion(ahead: usize, behind: usize) -> BranchSyncCompletion {
+        BranchSyncCompletion {
+            statuses: vec![(
+                feature_workspace_path(),
+                WorktreeSyncStatus {
+                    base_ref: Some("refs/remotes/origin/main".to_string()),
+                    base: Some(BranchDivergence { ahead, behind }),
+                    upstream: Some(BranchDivergence {
+                        ahead: 1,
+                        behind: 0,
+                    }),
+                },
+            )],
+        }
+    }
+
+    #[test]
+    fn branch_sync_far_behind_base_raises_attention_item() {
+        let mut app = fixture_app();
+        app.clear_startup_attention_focus_pending();
+
+        app.handle_branch_sync_completed(fixture_branch_sync_completion(2, 75));
+        assert!(app.attention_items.is_empty());
+
+        app.handle_branch_sync_completed(fixture_branch_sync_completion(2, 75));
+        assert_eq!(app.attention_items.len(), 1);
+        assert_eq!(
+            app.attention_items.first().map(|item| item.reason),
+            Some(AttentionReason::FallingBehind)
+        );
+        assert_eq!(
+            app.attention_items
+                .first()
+                .map(|item| item.summary.as_str()),
+            Some("75 commits behind main")
+        );
+    }
+
+    #[test]
+    fn branch_sync_slightly_behind_base_does_not_raise_attention() {
+        let mut app = fixture_app();
+        app.clear_startup_attention_focus_pending();
+
+        app.handle_branch_sync_completed(fixture_branch_sync_completion(2, 3));
+        app.handle_branch_sync_completed(fixture_branch_sync_completion(2, 3));
+
+        assert!(app.attention_items.is_empty());
+        assert!(!app.polling.branch_sync_in_flight);
+    }
+
+    #[test]
+    fn sidebar_renders_ahead_behind_indicators() {
+        let mut app = fixture_app();
+        app.handle_branch_sync_completed(fixture_branch_sync_completion(3, 12));
+
+        let layout = app.panes.test_rects(120, 24);
+        let x_start = layout.sidebar.x.saturating_add(1);
+        let x_end = layout.sidebar.right().saturating_sub(1);
+
+        with_rendered_frame(&app, 120, 24, |frame| {
+            let Some(feature_row) = find_workspace_row(frame, 1, x_start, x_end) else {
+                panic!("feature workspace row should be rendered");
+            };
+            let row = row_text(frame, feature_row, x_start, x_end);
+            assert!(
+                row.contains("↑3 ↓12 ⇡1"),
+                "feature row should render ahead/behind, got: {row}"
+            );
+        });
+    }
+
+    #[test]
+    fn successful_project_fetch_requests_branch_sync_refresh() {
+        let mut app = fixture_app();
+        app.polling.last_branch_sync_poll_at = Some(Instant::now());
+
+        app.handle_project_fetch_completed(ProjectFetchCompletion {
+            project_path: PathBuf::from("/repos/grove"),
+            duration_ms: 12,
+            result: Err("Could not resolve host: github.com".to_string()),
+        });
+        assert!(app.polling.last_branch_sync_poll_at.is_some());
+
+        app.handle_project_fetch_completed(ProjectFetchCompletion {
+            project_path: PathBuf::from("/repos/grove"),
+            duration_ms: 12,
+            result: Ok(true),
+        });
+        assert!(app.polling.last_branch_sync_poll_at.is_none());
+    }
+
     #[test]
     fn startup_selects_attention_inbox_when_first_item_becomes_visible() {
         let mut app = fixture_app();
@@ -5487,6 +5784,29 @@ mod tests {
         assert!(app.dialogs.keybind_help_open);
     }
 
+    #[test]
+    fn keys_config_remaps_help_to_a_key_sequence() {
+        let mut app = fixture_app();
+        app.apply_keys_config(&BTreeMap::from([(
+            "open_help".to_string(),
+            KeyBindingConfig::Single("z h".to_string()),
+        )]));
+
+        let _ = app.handle_key(KeyEvent::new(KeyCode::Char('?')).with_kind(KeyEventKind::Press));
+        assert!(!app.dialogs.keybind_help_open);
+
+        let _ = app.handle_key(KeyEvent::new(KeyCode::Char('z')).with_kind(KeyEventKind::Press));
+        assert!(!app.dialogs.keybind_help_open);
+        let _ = app.handle_key(KeyEvent::new(KeyCode::Char('h')).with_kind(KeyEventKind::Press));
+        assert!(app.dialogs.keybind_help_open);
+
+        assert!(
+            app.help_catalog_entries()
+                .iter()
+                .any(|entry| entry.action.contains("z h help"))
+        );
+    }
+
     #[test]
     fn ctrl_b_toggles_sidebar_visibility_and_backslash_is_noop() {
         let mut app = fixture_app();
@@ -5518,6 +5838,210 @@ mod tests {
         assert!(!app.sidebar_hidden);
     }
 
+    #[test]
+    fn split_preview_pins_session_and_swaps_halves_on_focus() {
+        let (mut app, _commands, _captures, _cursor_captures) =
+            fixture_app_with_tmux(WorkspaceStatus::Active, Vec::new());
+        app.viewport_width = 120;
+        app.viewport_height = 40;
+        select_workspace(&mut app, 1);
+
+        let _ = app.handle_key(KeyEvent::new(KeyCode::Char('W')).with_kind(KeyEventKind::Press));
+        let pinned_session = app
+            .preview_split
+            .as_ref()
+            .and_then(|split| split.pinned.session_name.clone());
+        assert_eq!(pinned_session, Some(feature_workspace_session()));
+
+        app.handle_pinned_preview_captured(LivePreviewCapture {
+            session: feature_workspace_session(),
+            scrollback_lines: super::LIVE_PREVIEW_SCROLLBACK_LINES,
+            include_escape_sequences: true,
+            capture_ms: 1,
+            total_ms: 1,
+            result: Ok("pinned agent output".to_string()),
+        });
+        select_workspace(&mut app, 0);
+
+        let (_, _, preview_rect) = app.effective_workspace_rects();
+        let live_rect = app.live_preview_rect();
+        assert_eq!(live_rect.x, preview_rect.x);
+        assert!(live_rect.width < preview_rect.width);
+        with_rendered_frame(&app, 120, 40, |frame| {
+            let pinned_row = (0..frame.height()).find(|y| {
+                row_text(frame, *y, live_rect.right(), 120).contains("pinned agent output")
+            });
+            assert!(
+                pinned_row.is_some(),
+                "pinned output should render in the second half"
+            );
+        });
+
+        let _ = app.handle_key(KeyEvent::new(KeyCode::Char('w')).with_kind(KeyEventKind::Press));
+        assert_eq!(app.state.selected_index, 1);
+        let split = app.preview_split.as_ref().expect("split should stay open");
+        assert_eq!(
+            split.focused_half,
+            crate::ui::tui::panes::PreviewHalf::Second
+        );
+        assert_eq!(split.pinned.workspace_path, app.state.workspaces[0].path);
+        assert!(app.live_preview_rect().x > preview_rect.x);
+
+        let _ = app.handle_key(KeyEvent::new(KeyCode::Char('W')).with_kind(KeyEventKind::Press));
+        assert!(app.preview_split.is_none());
+    }
+
+    #[test]
+    fn dashboard_tiles_running_agents_and_enter_attaches_to_tile() {
+        let (mut app, _commands, _captures, _cursor_captures) =
+            fixture_app_with_tmux(WorkspaceStatus::Active, Vec::new());
+        app.viewport_width = 120;
+        app.viewport_height = 40;
+        seed_running_agent_tabs_for_running_workspaces(&mut app);
+        let feature_path = app.state.workspaces[1].path.clone();
+        app.record_workspace_output_tail(
+            feature_path.as_path(),
+            "cargo test\nrunning 12 tests\n\n",
+        );
+        app.polling.workspace_waiting_prompts.insert(
+            feature_path.clone(),
+            "Allow edit to src/lib.rs?".to_string(),
+        );
+
+        let _ = app.handle_key(KeyEvent::new(KeyCode::Char('V')).with_kind(KeyEventKind::Press));
+        assert!(app.dashboard_active());
+        let tiles = app.dashboard_tiles();
+        assert_eq!(tiles.len(), 1);
+        assert_eq!(tiles[0].workspace_path, feature_path);
+
+        with_rendered_frame(&app, 120, 40, |frame| {
+            let rows = (0..frame.height())
+                .map(|y| row_text(frame, y, 0, 120))
+                .collect::<Vec<String>>();
+            assert!(rows.iter().any(|row| row.contains("running 12 tests")));
+            assert!(
+                rows.iter()
+                    .any(|row| row.contains("waiting: Allow edit to src/lib.rs?"))
+            );
+        });
+
+        let _ = app.handle_key(KeyEvent::new(KeyCode::Enter).with_kind(KeyEventKind::Press));
+        assert!(!app.dashboard_active());
+        assert_eq!(app.state.selected_index, 1);
+        assert!(app.session.interactive.is_some());
+    }
+
+    #[test]
+    fn sidebar_filter_hides_workspaces_and_view_modes_persist() {
+        let mut app = fixture_app();
+        select_workspace(&mut app, 0);
+
+        let _ = app.handle_key(KeyEvent::new(KeyCode::Char('f')).with_kind(KeyEventKind::Press));
+        assert!(app.sidebar_filter_editing());
+        for character in "branch:feat".chars() {
+            let _ = app
+                .handle_key(KeyEvent::new(KeyCode::Char(character)).with_kind(KeyEventKind::Press));
+        }
+        let visible_workspaces = app
+            .sidebar_selectable_row_map()
+            .into_iter()
+            .flatten()
+            .filter(|target| matches!(target, SidebarSelectable::Workspace(_)))
+            .collect::<Vec<SidebarSelectable>>();
+        assert_eq!(visible_workspaces, vec![SidebarSelectable::Workspace(1)]);
+        assert_eq!(app.state.selected_index, 1);
+
+        let _ = app.handle_key(KeyEvent::new(KeyCode::Enter).with_kind(KeyEventKind::Press));
+        assert!(!app.sidebar_filter_editing());
+        with_rendered_frame(&app, 120, 24, |frame| {
+            assert!(
+                (0..frame.height())
+                    .any(|y| row_text(frame, y, 0, 120).contains("filter: branch:feat"))
+            );
+        });
+        let _ = app.handle_key(KeyEvent::new(KeyCode::Escape).with_kind(KeyEventKind::Press));
+        assert!(!app.sidebar_filter_active());
+
+        let _ = app.handle_key(KeyEvent::new(KeyCode::Char('O')).with_kind(KeyEventKind::Press));
+        let _ = app.handle_key(KeyEvent::new(KeyCode::Char('b')).with_kind(KeyEventKind::Press));
+        let _ = app.handle_key(KeyEvent::new(KeyCode::Char('b')).with_kind(KeyEventKind::Press));
+        assert_eq!(
+            app.sidebar_sections()
+                .into_iter()
+                .map(|section| section.header)
+                .collect::<Vec<_>>(),
+            vec![
+                SidebarSectionHeader::Status(WorkspaceStatus::Idle),
+                SidebarSectionHeader::Status(WorkspaceStatus::Main),
+            ]
+        );
+
+        let loaded =
+            crate::infrastructure::config::load_from_path(&app.config_path).expect("config loads");
+        assert_eq!(
+            loaded.sidebar,
+            SidebarViewConfig {
+                group: SidebarGroupMode::Status,
+                sort: SidebarSortMode::LastActivity,
+            }
+        );
+    }
+
+    #[test]
+    fn task_notes_dialog_saves_tags_and_notes_to_manifest() {
+        let mut app = fixture_app();
+        let tasks_root = unique_temp_workspace_dir("task-notes");
+        app.task_root_override = Some(tasks_root.clone());
+        select_workspace(&mut app, 1);
+
+        let _ = app.handle_key(KeyEvent::new(KeyCode::Char('T')).with_kind(KeyEventKind::Press));
+        assert!(app.task_notes_dialog().is_some());
+        for character in "Ship behind the flag".chars() {
+            let _ = app
+                .handle_key(KeyEvent::new(KeyCode::Char(character)).with_kind(KeyEventKind::Press));
+        }
+        let _ = app.handle_key(KeyEvent::new(KeyCode::Enter).with_kind(KeyEventKind::Press));
+        let _ = app.handle_key(KeyEvent::new(KeyCode::Char('x')).with_kind(KeyEventKind::Press));
+        let _ = app.handle_key(KeyEvent::new(KeyCode::BackTab).with_kind(KeyEventKind::Press));
+        for character in "launch, #infra".chars() {
+            let _ = app
+                .handle_key(KeyEvent::new(KeyCode::Char(character)).with_kind(KeyEventKind::Press));
+        }
+        let _ = app.handle_key(
+            KeyEvent::new(KeyCode::Char('s'))
+                .with_modifiers(Modifiers::CTRL)
+                .with_kind(KeyEventKind::Press),
+        );
+
+        assert!(app.task_notes_dialog().is_none());
+        let task = &app.state.tasks[1];
+        assert_eq!(task.tags, vec!["launch".to_string(), "infra".to_string()]);
+        assert_eq!(task.notes, "Ship behind the flag\nx");
+        let manifest = fs::read_to_string(tasks_root.join("feature-a/.grove/task.toml"))
+            .expect("task manifest should be written");
+        let decoded = crate::infrastructure::task_manifest::decode_task_manifest(&manifest)
+            .expect("task manifest should decode");
+        assert_eq!(decoded.tags, task.tags);
+        assert_eq!(decoded.notes, task.notes);
+
+        with_rendered_frame(&app, 120, 24, |frame| {
+            assert!(
+                (0..frame.height())
+                    .any(|y| row_text(frame, y, 0, 120).contains("feature-a [1] #launch #infra"))
+            );
+        });
+        app.sidebar_filter.query = "tag:infra".to_string();
+        assert_eq!(
+            app.sidebar_sections()
+                .into_iter()
+                .map(|section| section.header)
+                .collect::<Vec<_>>(),
+            vec![SidebarSectionHeader::Task(1)]
+        );
+
+        let _ = fs::remove_dir_all(tasks_root);
+    }
+
     #[test]
     fn ctrl_b_control_character_toggles_sidebar_visibility() {
         let mut app = fixture_app();
@@ -7004,6 +7528,49 @@ mod tests {
         assert!(list_ids.iter().any(|id| id == move_right_id));
     }
 
+    #[test]
+    fn open_project_tabs_launches_shared_config_tabs_once() {
+        let mut app = fixture_app();
+        let project_root = unique_temp_workspace_dir("shared-tabs");
+        fs::write(
+            project_root.join(".grove.toml"),
+            "[[tabs]]\ntitle = \"Tests\"\ncommand = \"cargo test\"\n",
+        )
+        .expect("shared config should write");
+        app.projects[0].path = project_root.clone();
+        select_workspace(&mut app, 1);
+        app.state.workspaces[1].project_path = Some(project_root.clone());
+
+        app.open_project_tabs();
+        match app.confirm_dialog().map(|dialog| &dialog.action) {
+            Some(crate::ui::tui::ConfirmDialogAction::TrustSharedCommands { entries, .. }) => {
+                assert_eq!(entries, &vec!["cargo test".to_string()]);
+            }
+            _ => panic!("untrusted project tabs should ask for trust first"),
+        }
+        assert!(!app.projects[0].defaults.trust_shared_commands);
+        ftui::Model::update(
+            &mut app,
+            Msg::Key(KeyEvent::new(KeyCode::Char('y')).with_kind(KeyEventKind::Press)),
+        );
+        assert!(app.projects[0].defaults.trust_shared_commands);
+        app.open_project_tabs();
+
+        let shell_titles = app
+            .selected_workspace_tabs_state()
+            .map(|tabs| {
+                tabs.tabs
+                    .iter()
+                    .filter(|tab| tab.kind == WorkspaceTabKind::Shell)
+                    .map(|tab| tab.title.clone())
+                    .collect::<Vec<String>>()
+            })
+            .unwrap_or_default();
+        assert_eq!(shell_titles, vec!["Tests".to_string()]);
+
+        let _ = fs::remove_dir_all(project_root);
+    }
+
     #[test]
     fn command_palette_lists_start_parent_agent_on_task_home() {
         let mut app = fixture_task_app();
@@ -7264,12 +7831,12 @@ mod tests {
                 .iter()
                 .filter(|command| command.meta().palette.is_some())
                 .count(),
-            47
+            69
         );
-        assert_eq!(UiCommand::help_hints_for(HelpHintContext::Global).len(), 16);
+        assert_eq!(UiCommand::help_hints_for(HelpHintContext::Global).len(), 24);
         assert_eq!(
             UiCommand::help_hints_for(HelpHintContext::Workspace).len(),
-            18
+            19
         );
         assert_eq!(UiCommand::help_hints_for(HelpHintContext::List).len(), 2);
         assert_eq!(
@@ -7286,7 +7853,7 @@ mod tests {
         );
         assert_eq!(
             UiCommand::help_hints_for(HelpHintContext::PreviewDiff).len(),
-            10
+            20
         );
     }
 
@@ -7471,12 +8038,74 @@ mod tests {
         let _ = app.handle_key(KeyEvent::new(KeyCode::Right).with_kind(KeyEventKind::Press));
 
         assert_eq!(
-            app.settings_dialog().map(|dialog| dialog.theme),
+            app.settings_dialog().map(|dialog| dialog.theme.clone()),
             Some(ThemeName::RosePine)
         );
         assert_eq!(app.theme_name, ThemeName::RosePine);
     }
 
+    #[test]
+    fn config_reload_applies_changes_and_keeps_previous_on_parse_error() {
+        let mut app = fixture_app();
+        assert_eq!(app.theme_name, ThemeName::CatppuccinMocha);
+
+        fs::write(
+            &app.config_path,
+            "theme = \"rose-pine\"\nsidebar_width_pct = 40\n",
+        )
+        .expect("config should write");
+        app.reload_config_if_changed();
+
+        assert_eq!(app.theme_name, ThemeName::RosePine);
+        assert_eq!(app.sidebar_width_pct, 40);
+        assert!(
+            app.status_bar_line()
+                .contains("config reloaded: theme, sidebar width")
+        );
+
+        fs::write(&app.config_path, "theme = [\n").expect("config should write");
+        app.polling.config_reload.config_modified_at = None;
+        app.reload_config_if_changed();
+
+        assert_eq!(app.theme_name, ThemeName::RosePine);
+        assert_eq!(app.sidebar_width_pct, 40);
+        assert!(
+            app.status_bar_line()
+                .contains("config reload failed, keeping previous")
+        );
+        let _ = fs::remove_file(&app.config_path);
+    }
+
+    #[test]
+    fn config_reload_applies_projects_when_config_fails_to_parse() {
+        let mut app = fixture_app();
+        let mut projects = app.projects.clone();
+        assert!(!projects.is_empty());
+        projects[0].name = "renamed".to_string();
+        let projects_path = crate::infrastructure::config::projects_path_for(&app.config_path);
+
+        fs::write(&app.config_path, "theme = [\n").expect("config should write");
+        crate::infrastructure::config::save_projects_to_path(
+            projects_path.as_path(),
+            &projects,
+            &[],
+            &[],
+            &[],
+            Default::default(),
+        )
+        .expect("projects should write");
+        app.polling.config_reload.config_modified_at = None;
+        app.polling.config_reload.projects_modified_at = None;
+        app.reload_config_if_changed();
+
+        assert_eq!(app.projects, projects);
+        assert!(app.polling.config_reload.config_modified_at.is_some());
+        assert!(app.polling.config_reload.projects_modified_at.is_some());
+        assert!(app.status_bar_line().contains("config reloaded: projects"));
+        let _ = fs::remove_file(&app.config_path);
+        let _ = fs::remove_file(projects_path);
+    }
+
     #[test]
     fn settings_dialog_cancel_restores_theme_after_preview() {
         let mut app = fixture_app();
@@ -7561,16 +8190,22 @@ mod tests {
 
         with_rendered_frame(&monokai_app, 80, 24, |frame| {
             let header_bg = frame.buffer.get(0, 0).expect("header cell should exist").bg;
-            assert_eq!(header_bg, packed(ui_theme_for(ThemeName::Monokai).overlay));
+            assert_eq!(
+                header_bg,
+                packed(ui_theme_for(&ThemeName::Monokai.into()).overlay)
+            );
         });
 
         with_rendered_frame(&latte_app, 80, 24, |frame| {
             let header_bg = frame.buffer.get(0, 0).expect("header cell should exist").bg;
             assert_eq!(
                 header_bg,
-                packed(ui_theme_for(ThemeName::CatppuccinLatte).overlay)
+                packed(ui_theme_for(&ThemeName::CatppuccinLatte.into()).overlay)
+            );
+            assert_ne!(
+                header_bg,
+                packed(ui_theme_for(&ThemeName::Monokai.into()).overlay)
             );
-            assert_ne!(header_bg, packed(ui_theme_for(ThemeName::Monokai).overlay));
         });
     }
 
@@ -7597,11 +8232,11 @@ mod tests {
                 .expect("preview content cell should exist");
             assert_eq!(
                 cell.bg,
-                packed(ui_theme_for(ThemeName::CatppuccinLatte).background)
+                packed(ui_theme_for(&ThemeName::CatppuccinLatte.into()).background)
             );
             assert_eq!(
                 cell.fg,
-                packed(ui_theme_for(ThemeName::CatppuccinLatte).text)
+                packed(ui_theme_for(&ThemeName::CatppuccinLatte.into()).text)
             );
         });
     }
@@ -14646,9 +15281,7 @@ second row\n",
                     } => {
                         assert_eq!(session_name, feature_workspace_session().as_str());
                     }
-                    crate::ui::tui::ConfirmDialogAction::QuitApp => {
-                        panic!("expected close-tab confirm action")
-                    }
+                    _ => panic!("expected close-tab confirm action"),
                 }
                 assert!(!commands.borrow().iter().any(|command| {
                     command
@@ -15720,6 +16353,7 @@ second row\n",
                 );
                 select_workspace(&mut app, 1);
                 suppress_diff_stat_poll(&mut app);
+                suppress_branch_sync_poll(&mut app);
                 force_tick_due(&mut app);
 
                 let cmd = ftui::Model::update(&mut app, Msg::Tick);
@@ -15744,6 +16378,7 @@ second row\n",
                 );
                 select_workspace(&mut app, 0);
                 suppress_diff_stat_poll(&mut app);
+                suppress_branch_sync_poll(&mut app);
                 force_tick_due(&mut app);
 
                 let cmd = ftui::Model::update(&mut app, Msg::Tick);
@@ -20791,6 +21426,478 @@ second row\n",
 
                 assert!(app.create_dialog().is_none());
             }
+
+            fn press_key(app: &mut GroveApp, code: KeyCode) {
+                ftui::Model::update(
+                    app,
+                    Msg::Key(KeyEvent::new(code).with_kind(KeyEventKind::Press)),
+                );
+            }
+
+            #[test]
+            fn commit_key_in_diff_tab_stages_file_and_commits_message() {
+                let mut app = fixture_app();
+                let repo = init_git_repo("commit-dialog", "main");
+                fs::write(repo.join("notes.txt"), "draft\n").expect("write should succeed");
+                select_workspace(&mut app, 1);
+                app.state.workspaces[1].path = repo.clone();
+                app.state.mode = UiMode::Preview;
+                let _ = app.focus_manager.focus(FOCUS_ID_PREVIEW);
+                app.open_or_focus_diff_tab();
+                assert_eq!(app.preview_tab, PreviewTab::Diff);
+
+                press_key(&mut app, KeyCode::Char('c'));
+                let dialog = app
+                    .commit_dialog()
+                    .expect("c in diff tab should open the commit dialog");
+                assert_eq!(dialog.files.len(), 1);
+                assert_eq!(dialog.files[0].path, "notes.txt");
+                assert_eq!(dialog.staged_count(), 0);
+
+                press_key(&mut app, KeyCode::Char(' '));
+                assert_eq!(
+                    app.commit_dialog().map(|dialog| dialog.staged_count()),
+                    Some(1)
+                );
+
+                press_key(&mut app, KeyCode::Tab);
+                for character in "Add notes".chars() {
+                    press_key(&mut app, KeyCode::Char(character));
+                }
+                press_key(&mut app, KeyCode::Enter);
+                press_key(&mut app, KeyCode::Enter);
+                for character in "Keep a draft around.".chars() {
+                    press_key(&mut app, KeyCode::Char(character));
+                }
+                assert_eq!(
+                    app.commit_dialog().map(|dialog| dialog.message.clone()),
+                    Some("Add notes\n\nKeep a draft around.".to_string())
+                );
+
+                ftui::Model::update(
+                    &mut app,
+                    Msg::Key(
+                        KeyEvent::new(KeyCode::Char('s'))
+                            .with_modifiers(Modifiers::CTRL)
+                            .with_kind(KeyEventKind::Press),
+                    ),
+                );
+
+                assert!(app.commit_dialog().is_none());
+                let log_output = std::process::Command::new("git")
+                    .current_dir(&repo)
+                    .args(["log", "-1", "--format=%B"])
+                    .output()
+                    .expect("git log should run");
+                assert_eq!(
+                    String::from_utf8_lossy(&log_output.stdout).trim(),
+                    "Add notes\n\nKeep a draft around."
+                );
+            }
+
+            #[test]
+            fn diff_tab_hunk_keys_stage_and_discard_individual_hunks() {
+                let mut app = fixture_app();
+                let repo = init_git_repo("diff-hunks", "main");
+                let git = |args: &[&str]| {
+                    let output = std::process::Command::new("git")
+                        .current_dir(&repo)
+                        .args(args)
+                        .output()
+                        .expect("git should run");
+                    assert!(output.status.success(), "git {} failed", args.join(" "));
+                    String::from_utf8_lossy(&output.stdout).to_string()
+                };
+                let original = (1..=30)
+                    .map(|index| format!("line {index}\n"))
+                    .collect::<String>();
+                fs::write(repo.join("lines.txt"), &original).expect("write should succeed");
+                git(&["add", "lines.txt"]);
+                git(&["commit", "--quiet", "-m", "lines"]);
+                fs::write(
+                    repo.join("lines.txt"),
+                    original
+                        .replace("line 2\n", "line two\n")
+                        .replace("line 25\n", "line twenty-five\n"),
+                )
+                .expect("write should succeed");
+
+                select_workspace(&mut app, 1);
+                app.state.workspaces[1].path = repo.clone();
+                app.state.mode = UiMode::Preview;
+                let _ = app.focus_manager.focus(FOCUS_ID_PREVIEW);
+                app.open_or_focus_diff_tab();
+                let diff = git(&["diff", "--no-color"]);
+                ftui::Model::update(
+                    &mut app,
+                    Msg::DiffCaptureCompleted(DiffCaptureCompletion {
+                        workspace_path: repo.clone(),
+                        mode: DiffMode::WorkingTree,
+                        capture_ms: 0,
+                        result: Ok(format!("1 file changed, +2 -2\n{diff}")),
+                        file_stats: Vec::new(),
+                        review_comments: Vec::new(),
+                    }),
+                );
+                assert_eq!(app.diff_review.hunks.len(), 2);
+                assert_eq!(app.diff_review.selected_hunk, Some(0));
+
+                press_key(&mut app, KeyCode::Char('J'));
+                assert_eq!(app.diff_review.selected_hunk, Some(1));
+                press_key(&mut app, KeyCode::Char('K'));
+                assert_eq!(app.diff_review.selected_hunk, Some(0));
+
+                press_key(&mut app, KeyCode::Char(' '));
+                let staged = git(&["diff", "--cached", "--no-color"]);
+                assert!(staged.contains("+line two"));
+                assert!(!staged.contains("+line twenty-five"));
+
+                press_key(&mut app, KeyCode::Char('J'));
+                press_key(&mut app, KeyCode::Char('X'));
+                assert!(matches!(
+                    app.confirm_dialog().map(|dialog| &dialog.action),
+                    Some(crate::ui::tui::ConfirmDialogAction::DiscardDiffHunk { .. })
+                ));
+                press_key(&mut app, KeyCode::Char('y'));
+
+                let content =
+                    fs::read_to_string(repo.join("lines.txt")).expect("read should succeed");
+                assert!(content.contains("line two\n"));
+                assert!(content.contains("line 25\n"));
+                let saved = fs::read_dir(repo.join(".grove/discarded"))
+                    .expect("discarded hunks dir should exist")
+                    .count();
+                assert_eq!(saved, 1);
+            }
+
+            #[test]
+            fn diff_hunk_completion_clears_running_flag_and_reports_errors() {
+                let mut app = fixture_app();
+                app.diff_review.hunk_action_running = true;
+
+                ftui::Model::update(
+                    &mut app,
+                    Msg::DiffHunkStageCompleted(DiffHunkStageCompletion {
+                        path: "lines.txt".to_string(),
+                        staged: true,
+                        result: Err("patch does not apply".to_string()),
+                    }),
+                );
+
+                assert!(!app.diff_review.hunk_action_running);
+                let toast = app
+                    .notifications
+                    .visible()
+                    .last()
+                    .expect("error toast should be shown");
+                assert!(
+                    toast
+                        .content
+                        .message
+                        .contains("hunk staged failed: patch does not apply")
+                );
+            }
+
+            #[test]
+            fn diff_tab_modes_and_file_tree_jump_between_files() {
+                let mut app = fixture_app();
+                let repo = init_git_repo("diff-modes", "main");
+                let git = |args: &[&str]| {
+                    let output = std::process::Command::new("git")
+                        .current_dir(&repo)
+                        .args(args)
+                        .output()
+                        .expect("git should run");
+                    assert!(output.status.success(), "git {} failed", args.join(" "));
+                    String::from_utf8_lossy(&output.stdout).to_string()
+                };
+                fs::create_dir_all(repo.join("src")).expect("mkdir should succeed");
+                fs::write(repo.join("README.md"), "readme\n").expect("write should succeed");
+                fs::write(repo.join("src/lib.rs"), "fn lib() {}\n").expect("write should succeed");
+                git(&["add", "."]);
+                git(&["commit", "--quiet", "-m", "files"]);
+                fs::write(repo.join("README.md"), "readme\nmore\n").expect("write should succeed");
+                fs::write(repo.join("src/lib.rs"), "fn lib() { 1; }\n")
+                    .expect("write should succeed");
+
+                select_workspace(&mut app, 1);
+                app.state.workspaces[1].path = repo.clone();
+                app.state.mode = UiMode::Preview;
+                let _ = app.focus_manager.focus(FOCUS_ID_PREVIEW);
+                app.open_or_focus_diff_tab();
+
+                press_key(&mut app, KeyCode::Char('v'));
+                assert_eq!(app.diff_review.mode, DiffMode::Branch);
+                press_key(&mut app, KeyCode::Char('+'));
+                assert_eq!(app.diff_review.mode, DiffMode::Branch);
+                press_key(&mut app, KeyCode::Char('v'));
+                assert_eq!(app.diff_review.mode, DiffMode::LastCommits(1));
+                press_key(&mut app, KeyCode::Char('+'));
+                assert_eq!(app.diff_review.mode, DiffMode::LastCommits(2));
+                press_key(&mut app, KeyCode::Char('v'));
+                assert_eq!(app.diff_review.mode, DiffMode::WorkingTree);
+
+                let diff = git(&["diff", "--no-color"]);
+                let completion = |mode| {
+                    Msg::DiffCaptureCompleted(DiffCaptureCompletion {
+                        workspace_path: repo.clone(),
+                        mode,
+                        capture_ms: 0,
+                        result: Ok(format!("2 files changed, +2 -1\n{diff}")),
+                        file_stats: diff_file_stats(&repo, None).expect("numstat should succeed"),
+                        review_comments: Vec::new(),
+                    })
+                };
+                ftui::Model::update(&mut app, completion(DiffMode::Branch));
+                assert!(app.diff_review.file_stats.is_empty());
+                ftui::Model::update(&mut app, completion(DiffMode::WorkingTree));
+                assert_eq!(app.diff_review.file_stats.len(), 2);
+                assert_eq!(app.diff_review.selected_file.as_deref(), Some("README.md"));
+
+                press_key(&mut app, KeyCode::Char('t'));
+                assert!(app.diff_review.file_tree_visible);
+                press_key(&mut app, KeyCode::Char('L'));
+                assert_eq!(app.diff_review.selected_file.as_deref(), Some("src/lib.rs"));
+                assert_eq!(
+                    app.diff_review.selected().map(|hunk| hunk.path.as_str()),
+                    Some("src/lib.rs")
+                );
+                press_key(&mut app, KeyCode::Char('H'));
+                assert_eq!(app.diff_review.selected_file.as_deref(), Some("README.md"));
+            }
+
+            #[test]
+            fn diff_tab_review_comments_are_saved_and_sent_to_agent() {
+                let (mut app, commands, _captures, _cursor_captures) =
+                    fixture_app_with_tmux(WorkspaceStatus::Idle, Vec::new());
+                let repo = init_git_repo("diff-review", "main");
+                let git = |args: &[&str]| {
+                    let output = std::process::Command::new("git")
+                        .current_dir(&repo)
+                        .args(args)
+                        .output()
+                        .expect("git should run");
+                    assert!(output.status.success(), "git {} failed", args.join(" "));
+                    String::from_utf8_lossy(&output.stdout).to_string()
+                };
+                fs::write(repo.join("lines.txt"), "one\ntwo\nthree\n")
+                    .expect("write should succeed");
+                git(&["add", "lines.txt"]);
+                git(&["commit", "--quiet", "-m", "lines"]);
+                fs::write(repo.join("lines.txt"), "one\n2\nthree\n").expect("write should succeed");
+
+                let task_root = unique_temp_workspace_dir("diff-review-task");
+                select_workspace(&mut app, 1);
+                app.state.workspaces[1].path = repo.clone();
+                app.state.tasks[1].root_path = task_root.clone();
+                focus_agent_preview_tab(&mut app);
+                app.state.mode = UiMode::Preview;
+                let _ = app.focus_manager.focus(FOCUS_ID_PREVIEW);
+                app.open_or_focus_diff_tab();
+                let diff = git(&["diff", "--no-color"]);
+                let completion = |review_comments| {
+                    Msg::DiffCaptureCompleted(DiffCaptureCompletion {
+                        workspace_path: repo.clone(),
+                        mode: DiffMode::WorkingTree,
+                        capture_ms: 0,
+                        result: Ok(format!("1 file changed, +1 -1\n{diff}")),
+                        file_stats: Vec::new(),
+                        review_comments,
+                    })
+                };
+                ftui::Model::update(&mut app, completion(Vec::new()));
+
+                press_key(&mut app, KeyCode::Char('C'));
+                assert_eq!(
+                    app.review_comment_dialog()
+                        .map(|dialog| (dialog.path.as_str(), dialog.line.as_str())),
+                    Some(("lines.txt", "2"))
+                );
+                for character in "use a word".chars() {
+                    press_key(&mut app, KeyCode::Char(character));
+                }
+                press_key(&mut app, KeyCode::Enter);
+                assert!(app.review_comment_dialog().is_none());
+                let comments_path = review_comments_path(&task_root, &repo);
+                let saved = fs::read_to_string(&comments_path).expect("comments should be saved");
+                assert!(saved.contains("use a word"));
+                assert!(!repo.join(".grove").exists());
+
+                let mut comments = resolve_review_comments(
+                    &repo,
+                    load_review_comments(&comments_path).expect("comments should load"),
+                );
+                ftui::Model::update(&mut app, completion(comments.clone()));
+                assert_eq!(app.diff_review.comment_lines.len(), 1);
+
+                comments[0].resolved = true;
+                ftui::Model::update(&mut app, completion(comments));
+                assert!(
+                    load_review_comments(&comments_path).expect("comments should load")[0].resolved,
+                    "the completion handler should store the resolution"
+                );
+                fs::write(&comments_path, saved).expect("comments should be restorable");
+
+                press_key(&mut app, KeyCode::Char('>'));
+                assert_eq!(app.preview_tab, PreviewTab::Agent);
+                assert!(commands.borrow().iter().any(|command| {
+                    command.get(1).map(String::as_str) == Some("paste-buffer")
+                        && command.last().is_some_and(|text| {
+                            text.contains("lines.txt:2") && text.contains("use a word")
+                        })
+                }));
+                let commands = commands.borrow();
+                let pasted_session = commands
+                    .iter()
+                    .rev()
+                    .find(|command| command.get(1).map(String::as_str) == Some("paste-buffer"))
+                    .and_then(|command| command.get(3).cloned())
+                    .expect("review should be pasted");
+                assert_eq!(
+                    commands.last(),
+                    Some(&vec![
+                        "tmux".to_string(),
+                        "send-keys".to_string(),
+                        "-t".to_string(),
+                        pasted_session,
+                        "Enter".to_string(),
+                    ]),
+                    "the review prompt should be submitted"
+                );
+            }
+
+            #[test]
+            fn commit_dialog_draft_pastes_staged_summary_into_agent_tab() {
+                let (mut app, commands, _captures, _cursor_captures) =
+                    fixture_app_with_tmux(WorkspaceStatus::Idle, Vec::new());
+                let repo = init_git_repo("commit-dialog-draft", "main");
+                fs::write(repo.join("notes.txt"), "draft\n").expect("write should succeed");
+                select_workspace(&mut app, 1);
+                app.state.workspaces[1].path = repo;
+                focus_agent_preview_tab(&mut app);
+
+                app.open_commit_dialog();
+                press_key(&mut app, KeyCode::Char('a'));
+                app.focus_dialog_field(FOCUS_ID_COMMIT_DRAFT);
+                press_key(&mut app, KeyCode::Enter);
+
+                assert!(app.commit_dialog().is_none());
+                assert_eq!(app.preview_tab, PreviewTab::Agent);
+                let recorded = commands.borrow().clone();
+                assert!(recorded.iter().any(|command| {
+                    command.get(1).map(String::as_str) == Some("paste-buffer")
+                        && command
+                            .last()
+                            .is_some_and(|text| text.contains("notes.txt"))
+                }));
+                assert_eq!(
+                    recorded
+                        .last()
+                        .and_then(|command| command.get(1))
+                        .map(String::as_str),
+                    Some("send-keys"),
+                    "the draft request should be submitted to the agent"
+                );
+            }
+
+            #[test]
+            fn commit_files_completion_only_updates_the_matching_dialog() {
+                let mut app = fixture_app();
+                let workspace_path = PathBuf::from("/tmp/grove-commit-dialog");
+                app.set_commit_dialog(CommitDialogState::new(
+                    "feature-a".to_string(),
+                    workspace_path.clone(),
+                ));
+                let file = ChangedFile {
+                    path: "notes.txt".to_string(),
+                    section: ChangeSection::Unstaged,
+                    status: '?',
+                };
+
+                ftui::Model::update(
+                    &mut app,
+                    Msg::CommitFilesCompleted(CommitFilesCompletion {
+                        workspace_path: PathBuf::from("/tmp/other"),
+                        index_changed: false,
+                        action_error: None,
+                        files: Ok(vec![file.clone()]),
+                    }),
+                );
+                assert!(app.commit_dialog().is_some_and(|dialog| dialog.busy));
+
+                ftui::Model::update(
+                    &mut app,
+                    Msg::CommitFilesCompleted(CommitFilesCompletion {
+                        workspace_path,
+                        index_changed: false,
+                        action_error: Some("stage failed".to_string()),
+                        files: Ok(vec![file.clone()]),
+                    }),
+                );
+                let dialog = app.commit_dialog().expect("dialog should stay open");
+                assert!(!dialog.busy);
+                assert_eq!(dialog.files, vec![file]);
+                assert_eq!(dialog.last_error.as_deref(), Some("stage failed"));
+            }
+        }
+    }
+
+    mod gc_dialog {
+        use super::*;
+
+        #[test]
+        fn gc_plan_completion_fills_dialog_and_removals_use_the_delete_path() {
+            let mut app = fixture_app();
+            let repo = init_git_repo("gc-dialog", "main");
+            let lost_worktree = repo.with_extension("lost");
+            let options = GcOptions::default();
+            app.set_gc_dialog(GcDialogState::new(options));
+            let plan = GcPlan {
+                candidates: vec![GcEntry {
+                    name: "lost".to_string(),
+                    path: lost_worktree.clone(),
+                    reason: GcReason::UnmanagedWorktree,
+                    idle_secs: None,
+                    branches: Vec::new(),
+                    target: GcTarget::UnmanagedWorktree {
+                        repository_path: repo.clone(),
+                    },
+                }],
+                skipped_running: Vec::new(),
+                skipped_dirty: Vec::new(),
+            };
+
+            ftui::Model::update(
+                &mut app,
+                Msg::GcPlanCompleted(GcPlanCompletion {
+                    options: GcOptions { idle_days: None },
+                    result: Ok(plan.clone()),
+                }),
+            );
+            assert!(
+                app.gc_dialog().is_some_and(|dialog| dialog.scanning),
+                "a plan for other options should be ignored"
+            );
+
+            ftui::Model::update(
+                &mut app,
+                Msg::GcPlanCompleted(GcPlanCompletion {
+                    options,
+                    result: Ok(plan),
+                }),
+            );
+            let dialog = app.gc_dialog().expect("gc dialog should stay open");
+            assert!(!dialog.scanning);
+            assert_eq!(dialog.selected_count(), 1);
+
+            press_key(&mut app, KeyCode::Char('D'));
+            assert!(app.gc_dialog().is_none());
+            let toast = app
+                .notifications
+                .visible()
+                .last()
+                .expect("delete completion toast should be shown");
+            assert!(toast.content.message.contains("worktree 'lost' deleted"));
         }
     }
 
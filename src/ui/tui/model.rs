@@ -3,7 +3,7 @@ use std::collections::{HashMap, HashSet, VecDeque, hash_map::DefaultHasher};
 use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
 use std::process::Command;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime};
 
 use ftui::core::event::{
     Event, KeyCode, KeyEvent, KeyEventKind, Modifiers, MouseButton, MouseEvent, MouseEventKind,
@@ -40,9 +40,13 @@ use ftui::{Cmd, Model, PackedRgba, Style};
 use ftui_extras::text_effects::{AnimationClock, ColorGradient, StyledText, TextEffect};
 use serde_json::Value;
 
+use crate::application::agent_runtime::agent_env::{
+    agent_env_pairs, encode_agent_env_vars, format_agent_env_vars,
+};
 use crate::application::agent_runtime::capture::{
     evaluate_capture_change, tmux_capture_error_indicates_missing_session,
 };
+use crate::application::agent_runtime::launch_prompt::expand_launch_prompt;
 use crate::application::agent_runtime::status::WorkspaceStatusObservation;
 use crate::application::agent_runtime::{
     CommandExecutionMode, LivePreviewTarget, OutputDigest, SessionActivity, ShellLaunchRequest,
@@ -61,11 +65,20 @@ use crate::application::agent_runtime::{
     launch_request_for_workspace, shell_launch_request_for_workspace,
     status::detect_waiting_prompt,
 };
+use crate::application::attention::{AttentionReason, AttentionSignals, classify_attention};
+use crate::application::branch_sync::{FetchScheduler, WorktreeSyncStatus};
+use crate::application::gc::{
+    GcEntry, GcOptions, GcPlan, GcReason, GcTarget, plan_gc_for_tasks, remove_unmanaged_worktree,
+};
 use crate::application::interactive::{
     InteractiveAction, InteractiveKey, InteractiveState, encode_paste_payload,
     multiplexer_send_input_command,
 };
 use crate::application::preview::PreviewState;
+use crate::application::review_comments::{
+    ReviewComment, add_review_comment, load_review_comments, resolve_review_comments,
+    review_comments_path, review_prompt, store_review_comment_resolution,
+};
 use crate::application::session_cleanup::{
     SessionCleanupOptions, SessionCleanupPlan, SessionCleanupReason, apply_session_cleanup,
 };
@@ -78,28 +91,41 @@ use crate::application::workspace_lifecycle::{
     CommandGitRunner, CommandSetupCommandRunner, CommandSetupScriptRunner, DeleteWorkspaceRequest,
     MergeWorkspaceRequest, RuntimeSessionTerminator, UpdateWorkspaceFromBaseRequest,
     WorkspaceLifecycleError, delete_workspace, merge_workspace_with_terminator,
+    read_workspace_init_command, read_workspace_launch_prompt, read_workspace_permission_mode,
     update_workspace_from_base_with_terminator, workspace_lifecycle_error_message,
-    write_workspace_base_marker,
+    write_workspace_base_marker, write_workspace_init_command, write_workspace_permission_mode,
+};
+use crate::application::worktree_changes::{
+    ChangeSection, ChangedFile, DiffMode, FileDiffStat, commit_draft_prompt, commit_staged,
+    diff_file_stats, diff_mode_range, discard_hunk, file_tree_rows, hunk_range, list_changed_files,
+    parse_diff_files, stage_all, stage_file, stage_hunk, staged_diff_summary, unstage_file,
+    unstage_hunk,
 };
 use crate::domain::{AgentType, PermissionMode, Task, Workspace, WorkspaceStatus};
 use crate::infrastructure::adapters::DiscoveryState;
 use crate::infrastructure::config::{
-    AgentEnvDefaults, GroveConfig, ProjectConfig, ThemeName, WorkspaceAttentionAckConfig,
+    AgentEnvDefaults, GlobalSettings, GroveConfig, ProjectConfig, SidebarViewConfig, ThemeName,
+    WorkspaceAttentionAckConfig,
 };
 use crate::infrastructure::event_log::{Event as LogEvent, EventLogger, now_millis};
 use crate::infrastructure::paths::refer_to_same_location;
 use crate::infrastructure::process_metrics::{ProcessMetricsSampler, ProcessMetricsSnapshot};
+use crate::infrastructure::shared_config::{
+    ConfigSource, EffectiveProjectConfig, SHARED_CONFIG_FILE, SharedProjectConfig, SharedTab,
+    effective_project_config, load_shared_project_config,
+};
+use crate::infrastructure::themes::{CustomThemes, ThemeSelection};
 use crate::ui::mouse::{clamp_sidebar_ratio, ratio_from_drag};
 use crate::ui::state::{Action, AppState, UiMode};
 use performance::DurationWindow;
 
 #[cfg(test)]
 use bootstrap_config::AppDependencies;
-use bootstrap_config::{
-    project_display_name, read_workspace_init_command, read_workspace_launch_prompt,
-    read_workspace_permission_mode, write_workspace_init_command, write_workspace_permission_mode,
-};
+use bootstrap_config::project_display_name;
 use commands::*;
+use control_socket::ControlSocketState;
+use keymap::{KeySequenceMatch, Keymap};
+use status_snapshot::StatusSnapshotState;
 use dialogs::*;
 use dialogs_state::*;
 use msg::*;
@@ -123,6 +149,26 @@ struct QueuedDeleteWorkspace {
 enum QueuedDeleteRequest {
     Task(DeleteTaskRequest),
     Worktree(DeleteWorkspaceRequest),
+    UnmanagedWorktree {
+        repository_path: PathBuf,
+        worktree_path: PathBuf,
+    },
+}
+
+impl QueuedDeleteRequest {
+    fn run(self) -> (Result<(), String>, Vec<String>) {
+        match self {
+            Self::Task(request) => delete_task(request),
+            Self::Worktree(request) => delete_workspace(request),
+            Self::UnmanagedWorktree {
+                repository_path,
+                worktree_path,
+            } => (
+                remove_unmanaged_worktree(repository_path.as_path(), worktree_path.as_path()),
+                Vec::new(),
+            ),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -136,37 +182,6 @@ enum WorkspaceAttention {
     NeedsAttention,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-enum AttentionReason {
-    BlockedOnQuestion,
-    PermissionWall,
-    SessionEnded,
-    Finished,
-    Stalled,
-}
-
-impl AttentionReason {
-    const fn rank(self) -> u8 {
-        match self {
-            Self::BlockedOnQuestion => 0,
-            Self::PermissionWall => 1,
-            Self::SessionEnded => 2,
-            Self::Finished => 3,
-            Self::Stalled => 4,
-        }
-    }
-
-    const fn summary(self) -> &'static str {
-        match self {
-            Self::BlockedOnQuestion => "blocked on question",
-            Self::PermissionWall => "permission wall",
-            Self::SessionEnded => "session ended unexpectedly",
-            Self::Finished => "finished, awaiting review",
-            Self::Stalled => "stalled, no output",
-        }
-    }
-}
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct AttentionItem {
     fingerprint: String,
@@ -204,6 +219,17 @@ struct TaskReorderState {
     moving_task_slug: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct DashboardState {
+    selected_tile: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct SidebarFilterState {
+    query: String,
+    editing: bool,
+}
+
 impl SessionTracker {
     fn is_ready(&self, session_name: &str) -> bool {
         self.ready.contains(session_name)
@@ -249,6 +275,10 @@ enum ActiveDialog {
     Stop(StopDialogState),
     Confirm(ConfirmDialogState),
     SessionCleanup(SessionCleanupDialogState),
+    Gc(GcDialogState),
+    Commit(CommitDialogState),
+    ReviewComment(ReviewCommentDialogState),
+    TaskNotes(TaskNotesDialogState),
     Delete(DeleteDialogState),
     Merge(MergeDialogState),
     UpdateFromBase(UpdateFromBaseDialogState),
@@ -295,6 +325,7 @@ struct PollingState {
     #[cfg(test)]
     workspace_status_observation_overrides: HashMap<PathBuf, WorkspaceStatusObservation>,
     workspace_waiting_prompts: HashMap<PathBuf, String>,
+    workspace_output_tails: HashMap<PathBuf, Vec<String>>,
     workspace_idle_polls_since_output: HashMap<PathBuf, u8>,
     next_tick_due_at: Option<Instant>,
     next_tick_interval_ms: Option<u64>,
@@ -313,7 +344,21 @@ struct PollingState {
     last_diff_stat_poll_at: Option<Instant>,
     diff_capture_in_flight: bool,
     diff_stat_in_flight: bool,
+    last_branch_sync_poll_at: Option<Instant>,
+    branch_sync_in_flight: bool,
+    fetch_scheduler: FetchScheduler,
     preview_stream: PreviewStreamState,
+    last_config_reload_poll_at: Option<Instant>,
+    config_reload: ConfigReloadState,
+}
+
+/// Modification times and global settings from the last read of
+/// `config.toml` and `projects.toml`, used to detect external edits.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct ConfigReloadState {
+    config_modified_at: Option<SystemTime>,
+    projects_modified_at: Option<SystemTime>,
+    settings: GlobalSettings,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -322,6 +367,74 @@ struct DiffStatBadge {
     deletions: usize,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DiffHunkTarget {
+    section: ChangeSection,
+    path: String,
+    header: String,
+    start_line: usize,
+    end_line: usize,
+    first_changed_line: Option<usize>,
+}
+
+#[derive(Debug, Default)]
+struct DiffReviewState {
+    mode: DiffMode,
+    file_tree_visible: bool,
+    workspace_path: Option<PathBuf>,
+    hunks: Vec<DiffHunkTarget>,
+    selected_hunk: Option<usize>,
+    file_stats: Vec<FileDiffStat>,
+    file_starts: HashMap<String, usize>,
+    selected_file: Option<String>,
+    comments: Vec<ReviewComment>,
+    comment_lines: HashMap<usize, usize>,
+    hunk_action_running: bool,
+}
+
+impl DiffReviewState {
+    fn selected(&self) -> Option<&DiffHunkTarget> {
+        self.selected_hunk.and_then(|index| self.hunks.get(index))
+    }
+
+    fn clear_capture(&mut self) {
+        self.workspace_path = None;
+        self.hunks.clear();
+        self.selected_hunk = None;
+        self.file_stats.clear();
+        self.file_starts.clear();
+        self.selected_file = None;
+        self.comments.clear();
+        self.comment_lines.clear();
+    }
+
+    fn pending_comment_count(&self) -> usize {
+        self.comments
+            .iter()
+            .filter(|comment| !comment.resolved)
+            .count()
+    }
+}
+
+/// Session kept visible in one half of a split preview while the other,
+/// focused half follows the selected workspace and tab.
+struct PinnedPreview {
+    workspace_path: PathBuf,
+    workspace_name: String,
+    tab_id: u64,
+    tab_title: String,
+    session_name: Option<String>,
+    preview: PreviewState,
+    scroll_from_bottom: usize,
+    capture_in_flight: bool,
+}
+
+struct PreviewSplitState {
+    axis: panes::PreviewSplitAxis,
+    focused_half: panes::PreviewHalf,
+    pinned: PinnedPreview,
+}
+
 struct PerformanceState {
     redraw_timing: RefCell<DurationWindow>,
     draw_timing: RefCell<DurationWindow>,
@@ -367,10 +480,14 @@ struct TelemetryState {
 
 struct GroveApp {
     repo_name: String,
+    profile_name: Option<String>,
     projects: Vec<ProjectConfig>,
     hidden_base_project_paths: HashSet<PathBuf>,
     task_order: Vec<String>,
     task_reorder: Option<TaskReorderState>,
+    dashboard: Option<DashboardState>,
+    sidebar_view: SidebarViewConfig,
+    sidebar_filter: SidebarFilterState,
     state: AppState,
     discovery_state: DiscoveryState,
     preview_tab: PreviewTab,
@@ -378,8 +495,11 @@ struct GroveApp {
     last_agent_selection: HashMap<PathBuf, AgentType>,
     workspace_visit_order: Vec<PathBuf>,
     preview: PreviewState,
+    preview_split: Option<PreviewSplitState>,
     notifications: NotificationQueue,
     action_mapper: ActionMapper,
+    keymap: Keymap,
+    pending_key_sequence: Vec<KeyEvent>,
     dialogs: DialogState,
     tmux_input: Box<dyn TmuxInput>,
     config_path: PathBuf,
@@ -400,6 +520,7 @@ struct GroveApp {
     sidebar_width_pct: u16,
     panes: panes::GrovePaneModel,
     theme_name: ThemeName,
+    custom_themes: CustomThemes,
     sidebar_hidden: bool,
     mouse_capture_enabled: bool,
     launch_permission_mode: PermissionMode,
@@ -417,8 +538,12 @@ struct GroveApp {
     sidebar_list_state: RefCell<VirtualizedListState>,
     last_sidebar_mouse_scroll_at: Option<Instant>,
     workspace_diff_stats: HashMap<PathBuf, DiffStatBadge>,
+    diff_review: DiffReviewState,
+    workspace_branch_sync: HashMap<PathBuf, WorktreeSyncStatus>,
     last_sidebar_mouse_scroll_delta: i8,
     focus_manager: FocusManager,
+    control_socket: ControlSocketState,
+    status_snapshot: StatusSnapshotState,
     #[cfg(test)]
     task_root_override: Option<PathBuf>,
     #[cfg(test)]
@@ -441,7 +566,10 @@ impl Model for GroveApp {
     }
 
     fn subscriptions(&self) -> Vec<Box<dyn ftui::runtime::Subscription<Self::Message>>> {
-        self.preview_stream_subscription().into_iter().collect()
+        self.preview_stream_subscription()
+            .into_iter()
+            .chain(self.control_socket_subscription())
+            .collect()
     }
 }
 
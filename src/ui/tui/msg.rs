@@ -14,6 +14,7 @@ pub(super) enum Msg {
     Resize { width: u16, height: u16 },
     PreviewPollCompleted(PreviewPollCompletion),
     PreviewStreamEvent(PreviewStreamEvent),
+    PinnedPreviewCaptured(LivePreviewCapture),
     LazygitLaunchCompleted(LazygitLaunchCompletion),
     WorkspaceShellLaunchCompleted(WorkspaceShellLaunchCompletion),
     RefreshWorkspacesCompleted(RefreshWorkspacesCompletion),
@@ -29,9 +30,25 @@ pub(super) enum Msg {
     InteractiveSendCompleted(InteractiveSendCompletion),
     DiffCaptureCompleted(DiffCaptureCompletion),
     DiffStatCompleted(DiffStatCompletion),
+    BranchSyncCompleted(BranchSyncCompletion),
+    ProjectFetchCompleted(ProjectFetchCompletion),
+    GcPlanCompleted(GcPlanCompletion),
+    CommitFilesCompleted(CommitFilesCompletion),
+    CommitCompleted(CommitCompletion),
+    DiffHunkStageCompleted(DiffHunkStageCompletion),
+    DiffHunkDiscardCompleted(DiffHunkDiscardCompletion),
+    ControlRequest(ControlRequest),
     Noop,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct ControlRequest {
+    pub(super) client_id: u64,
+    pub(super) id: Option<Value>,
+    pub(super) method: String,
+    pub(super) params: Value,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(super) struct DiffStatCompletion {
     pub(super) workspace_path: PathBuf,
@@ -39,6 +56,56 @@ pub(super) struct DiffStatCompletion {
     pub(super) deletions: usize,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct BranchSyncCompletion {
+    pub(super) statuses: Vec<(PathBuf, WorktreeSyncStatus)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct ProjectFetchCompletion {
+    pub(super) project_path: PathBuf,
+    pub(super) duration_ms: u64,
+    pub(super) result: Result<bool, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct GcPlanCompletion {
+    pub(super) options: GcOptions,
+    pub(super) result: Result<GcPlan, String>,
+}
+
+/// Result of a commit-dialog stage or unstage, followed by the refreshed
+/// file list. `index_changed` is false for a plain status refresh.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct CommitFilesCompletion {
+    pub(super) workspace_path: PathBuf,
+    pub(super) index_changed: bool,
+    pub(super) action_error: Option<String>,
+    pub(super) files: Result<Vec<ChangedFile>, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct CommitCompletion {
+    pub(super) workspace_name: String,
+    pub(super) workspace_path: PathBuf,
+    pub(super) result: Result<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct DiffHunkStageCompletion {
+    pub(super) path: String,
+    pub(super) staged: bool,
+    pub(super) result: Result<(), String>,
+}
+
+/// `result` carries the file the discarded hunk was saved to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct DiffHunkDiscardCompletion {
+    pub(super) workspace_path: PathBuf,
+    pub(super) path: String,
+    pub(super) result: Result<PathBuf, String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(super) struct PreviewPollCompletion {
     pub(super) generation: u64,
@@ -218,8 +285,11 @@ pub(super) struct RestartAgentCompletion {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(super) struct DiffCaptureCompletion {
     pub(super) workspace_path: PathBuf,
+    pub(super) mode: DiffMode,
     pub(super) capture_ms: u64,
     pub(super) result: Result<String, String>,
+    pub(super) file_stats: Vec<FileDiffStat>,
+    pub(super) review_comments: Vec<ReviewComment>,
 }
 
 impl From<SessionExecutionResult> for StartAgentCompletion {
@@ -228,6 +228,129 @@ impl GrovePaneModel {
     }
 }
 
+/// Axis used to divide the preview pane when two sessions are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum PreviewSplitAxis {
+    /// Halves side by side, left and right.
+    Vertical,
+    /// Halves stacked, top and bottom.
+    Horizontal,
+}
+
+impl PreviewSplitAxis {
+    pub(super) fn toggled(self) -> Self {
+        match self {
+            Self::Vertical => Self::Horizontal,
+            Self::Horizontal => Self::Vertical,
+        }
+    }
+
+    pub(super) fn label(self) -> &'static str {
+        match self {
+            Self::Vertical => "vertical",
+            Self::Horizontal => "horizontal",
+        }
+    }
+}
+
+/// One half of a split preview pane. `First` is left or top.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum PreviewHalf {
+    First,
+    Second,
+}
+
+impl PreviewHalf {
+    pub(super) fn other(self) -> Self {
+        match self {
+            Self::First => Self::Second,
+            Self::Second => Self::First,
+        }
+    }
+}
+
+/// Divide a preview rect into two halves along `axis`, first half left or top.
+pub(super) fn split_preview_rect(area: Rect, axis: PreviewSplitAxis) -> (Rect, Rect) {
+    match axis {
+        PreviewSplitAxis::Vertical => {
+            let first_width = area.width / 2;
+            (
+                Rect::new(area.x, area.y, first_width, area.height),
+                Rect::new(
+                    area.x.saturating_add(first_width),
+                    area.y,
+                    area.width.saturating_sub(first_width),
+                    area.height,
+                ),
+            )
+        }
+        PreviewSplitAxis::Horizontal => {
+            let first_height = area.height / 2;
+            (
+                Rect::new(area.x, area.y, area.width, first_height),
+                Rect::new(
+                    area.x,
+                    area.y.saturating_add(first_height),
+                    area.width,
+                    area.height.saturating_sub(first_height),
+                ),
+            )
+        }
+    }
+}
+
+const DASHBOARD_MIN_TILE_WIDTH: u16 = 40;
+
+/// Number of dashboard tile columns that fit in `area_width` for `count` tiles.
+pub(super) fn dashboard_grid_columns(area_width: u16, count: usize) -> usize {
+    if count == 0 {
+        return 1;
+    }
+    let mut square_columns = 1usize;
+    while square_columns.saturating_mul(square_columns) < count {
+        square_columns += 1;
+    }
+    let max_columns = usize::from((area_width / DASHBOARD_MIN_TILE_WIDTH).max(1));
+    square_columns.min(max_columns)
+}
+
+/// Tile rects for `count` dashboard tiles laid out row by row over `area`.
+pub(super) fn dashboard_tile_rects(area: Rect, count: usize) -> Vec<Rect> {
+    if count == 0 || area.is_empty() {
+        return Vec::new();
+    }
+    let columns = dashboard_grid_columns(area.width, count);
+    let rows = count.div_ceil(columns);
+    let columns_u16 = u16::try_from(columns).unwrap_or(u16::MAX);
+    let rows_u16 = u16::try_from(rows).unwrap_or(u16::MAX);
+    let tile_width = area.width / columns_u16;
+    let tile_height = area.height / rows_u16;
+    if tile_width == 0 || tile_height == 0 {
+        return Vec::new();
+    }
+
+    (0..count)
+        .map(|index| {
+            let column = u16::try_from(index % columns).unwrap_or(u16::MAX);
+            let row = u16::try_from(index / columns).unwrap_or(u16::MAX);
+            let x = area.x.saturating_add(column.saturating_mul(tile_width));
+            let y = area.y.saturating_add(row.saturating_mul(tile_height));
+            // The last column and row absorb the division remainder.
+            let width = if column + 1 == columns_u16 {
+                area.right().saturating_sub(x)
+            } else {
+                tile_width
+            };
+            let height = if row + 1 == rows_u16 {
+                area.bottom().saturating_sub(y)
+            } else {
+                tile_height
+            };
+            Rect::new(x, y, width, height)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 pub(super) struct PaneRects {
     pub(super) header: Rect,
@@ -4,7 +4,6 @@ use std::time::Duration;
 use super::*;
 use crate::application::agent_runtime::{poll_interval, session_name_for_workspace_ref};
 use crate::domain::Workspace;
-use crate::domain::WorkspaceStatus;
 use crate::infrastructure::process_metrics::ProcessMetricsSnapshot;
 
 #[derive(Debug, Clone)]
@@ -118,20 +117,6 @@ pub(super) fn format_duration(duration: Option<Duration>) -> String {
         .unwrap_or_else(|| "unavailable".to_string())
 }
 
-pub(super) fn workspace_status_label(status: WorkspaceStatus) -> &'static str {
-    match status {
-        WorkspaceStatus::Main => "main",
-        WorkspaceStatus::Idle => "idle",
-        WorkspaceStatus::Active => "active",
-        WorkspaceStatus::Thinking => "thinking",
-        WorkspaceStatus::Waiting => "waiting",
-        WorkspaceStatus::Done => "done",
-        WorkspaceStatus::Error => "error",
-        WorkspaceStatus::Unknown => "unknown",
-        WorkspaceStatus::Unsupported => "unsupported",
-    }
-}
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(super) struct SessionPerformanceRow {
     pub(super) label: String,
@@ -308,7 +293,7 @@ impl GroveApp {
 
             rows.push(SessionPerformanceRow {
                 label: workspace.name.clone(),
-                status: workspace_status_label(workspace.status),
+                status: workspace.status.label(),
                 cadence,
                 role,
                 reason,
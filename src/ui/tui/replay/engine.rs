@@ -129,7 +129,7 @@ pub(crate) fn app_from_bootstrap(snapshot: &ReplayBootstrapSnapshot) -> GroveApp
     app.sidebar_hidden = snapshot.sidebar_hidden;
     app.mouse_capture_enabled = snapshot.mouse_capture_enabled;
     app.launch_permission_mode = snapshot.launch_permission_mode;
-    app.theme_name = snapshot.theme_name;
+    app.theme_name = snapshot.theme_name.clone();
     app.sync_main_focus_nodes();
     let _ = app.focus_main_pane(snapshot.focus.to_focus_id());
     app.telemetry.replay_msg_seq_counter = 0;
@@ -102,6 +102,8 @@ mod tests {
                         pull_requests: Vec::new(),
                     },
                 ],
+                tags: Vec::new(),
+                notes: String::new(),
             }],
             selected_task_index: 0,
             selected_worktree_index: 0,
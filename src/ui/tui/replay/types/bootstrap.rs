@@ -105,7 +105,7 @@ impl ReplayBootstrapSnapshot {
             sidebar_hidden: app.sidebar_hidden,
             mouse_capture_enabled: app.mouse_capture_enabled,
             launch_permission_mode: app.launch_permission_mode,
-            theme_name: app.theme_name,
+            theme_name: app.theme_name.clone(),
         }
     }
 
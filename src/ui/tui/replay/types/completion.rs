@@ -112,6 +112,42 @@ struct ReplayPullUpstreamCompletion {
     propagate_target_count: usize,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+struct ReplayGcPlanCompletion {
+    idle_days: Option<u64>,
+    result: ReplayGcPlanResult,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum ReplayGcPlanResult {
+    Ok {
+        candidates: Vec<ReplayGcEntry>,
+        skipped_running: Vec<ReplayGcEntry>,
+        skipped_dirty: Vec<ReplayGcEntry>,
+    },
+    Err {
+        error: String,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+struct ReplayGcEntry {
+    name: String,
+    path: PathBuf,
+    reason: GcReason,
+    idle_secs: Option<u64>,
+    branches: Vec<String>,
+    target: ReplayGcTarget,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum ReplayGcTarget {
+    Task { task: ReplayTask },
+    UnmanagedWorktree { repository_path: PathBuf },
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 enum ReplayCreateWorkspaceCompletion {
@@ -198,6 +234,10 @@ struct ReplayTask {
     root_path: PathBuf,
     branch: String,
     worktrees: Vec<ReplayWorktree>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    notes: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -485,6 +525,96 @@ impl ReplayDeleteWorkspaceCompletion {
     }
 }
 
+impl ReplayGcPlanCompletion {
+    fn from_completion(completion: &GcPlanCompletion) -> Self {
+        let entries = |entries: &[GcEntry]| {
+            entries
+                .iter()
+                .map(ReplayGcEntry::from_entry)
+                .collect::<Vec<ReplayGcEntry>>()
+        };
+        Self {
+            idle_days: completion.options.idle_days,
+            result: match &completion.result {
+                Ok(plan) => ReplayGcPlanResult::Ok {
+                    candidates: entries(&plan.candidates),
+                    skipped_running: entries(&plan.skipped_running),
+                    skipped_dirty: entries(&plan.skipped_dirty),
+                },
+                Err(error) => ReplayGcPlanResult::Err {
+                    error: error.clone(),
+                },
+            },
+        }
+    }
+
+    fn to_completion(&self) -> GcPlanCompletion {
+        let entries = |entries: &[ReplayGcEntry]| {
+            entries
+                .iter()
+                .map(ReplayGcEntry::to_entry)
+                .collect::<Vec<GcEntry>>()
+        };
+        GcPlanCompletion {
+            options: GcOptions {
+                idle_days: self.idle_days,
+            },
+            result: match &self.result {
+                ReplayGcPlanResult::Ok {
+                    candidates,
+                    skipped_running,
+                    skipped_dirty,
+                } => Ok(GcPlan {
+                    candidates: entries(candidates),
+                    skipped_running: entries(skipped_running),
+                    skipped_dirty: entries(skipped_dirty),
+                }),
+                ReplayGcPlanResult::Err { error } => Err(error.clone()),
+            },
+        }
+    }
+}
+
+impl ReplayGcEntry {
+    fn from_entry(entry: &GcEntry) -> Self {
+        Self {
+            name: entry.name.clone(),
+            path: entry.path.clone(),
+            reason: entry.reason,
+            idle_secs: entry.idle_secs,
+            branches: entry.branches.clone(),
+            target: match &entry.target {
+                GcTarget::Task(task) => ReplayGcTarget::Task {
+                    task: ReplayTask::from_task(task),
+                },
+                GcTarget::UnmanagedWorktree { repository_path } => {
+                    ReplayGcTarget::UnmanagedWorktree {
+                        repository_path: repository_path.clone(),
+                    }
+                }
+            },
+        }
+    }
+
+    fn to_entry(&self) -> GcEntry {
+        GcEntry {
+            name: self.name.clone(),
+            path: self.path.clone(),
+            reason: self.reason,
+            idle_secs: self.idle_secs,
+            branches: self.branches.clone(),
+            target: match &self.target {
+                ReplayGcTarget::Task { task } => GcTarget::Task(task.to_task()),
+                ReplayGcTarget::UnmanagedWorktree { repository_path } => {
+                    GcTarget::UnmanagedWorktree {
+                        repository_path: repository_path.clone(),
+                    }
+                }
+            },
+        }
+    }
+}
+
 impl ReplayMergeWorkspaceCompletion {
     fn from_completion(completion: &MergeWorkspaceCompletion) -> Self {
         Self {
@@ -786,6 +916,8 @@ impl ReplayTask {
                 .iter()
                 .map(ReplayWorktree::from_worktree)
                 .collect(),
+            tags: task.tags.clone(),
+            notes: task.notes.clone(),
         }
     }
 
@@ -801,6 +933,8 @@ impl ReplayTask {
                 .collect(),
         )
         .expect("replay task should decode")
+        .with_tags(self.tags.clone())
+        .with_notes(self.notes.clone())
     }
 }
 
@@ -21,6 +21,9 @@ enum ReplayMsg {
     PreviewStreamEvent {
         event: ReplayPreviewStreamEvent,
     },
+    PinnedPreviewCaptured {
+        capture: ReplayLivePreviewCapture,
+    },
     LazygitLaunchCompleted {
         completion: ReplayLazygitLaunchCompletion,
     },
@@ -62,14 +65,60 @@ enum ReplayMsg {
     },
     DiffCaptureCompleted {
         workspace_path: String,
+        #[serde(default)]
+        mode: DiffMode,
         capture_ms: u64,
         result: Result<String, String>,
+        #[serde(default)]
+        file_stats: Vec<FileDiffStat>,
+        #[serde(default)]
+        review_comments: Vec<ReviewComment>,
     },
     DiffStatCompleted {
         workspace_path: String,
         insertions: usize,
         deletions: usize,
     },
+    BranchSyncCompleted {
+        statuses: Vec<(String, WorktreeSyncStatus)>,
+    },
+    ProjectFetchCompleted {
+        project_path: String,
+        duration_ms: u64,
+        result: Result<bool, String>,
+    },
+    GcPlanCompleted {
+        completion: ReplayGcPlanCompletion,
+    },
+    CommitFilesCompleted {
+        workspace_path: PathBuf,
+        index_changed: bool,
+        action_error: Option<String>,
+        files: Result<Vec<ChangedFile>, String>,
+    },
+    CommitCompleted {
+        workspace_name: String,
+        workspace_path: PathBuf,
+        result: Result<String, String>,
+    },
+    DiffHunkStageCompleted {
+        path: String,
+        staged: bool,
+        result: Result<(), String>,
+    },
+    DiffHunkDiscardCompleted {
+        workspace_path: PathBuf,
+        path: String,
+        result: Result<PathBuf, String>,
+    },
+    ControlRequest {
+        client_id: u64,
+        #[serde(default)]
+        id: Option<Value>,
+        method: String,
+        #[serde(default)]
+        params: Value,
+    },
     Noop,
 }
 
@@ -194,6 +243,7 @@ impl ReplayMsg {
             Self::Resize { .. } => "resize",
             Self::PreviewPollCompleted { .. } => "preview_poll_completed",
             Self::PreviewStreamEvent { .. } => "preview_stream_event",
+            Self::PinnedPreviewCaptured { .. } => "pinned_preview_captured",
             Self::LazygitLaunchCompleted { .. } => "lazygit_launch_completed",
             Self::WorkspaceShellLaunchCompleted { .. } => "workspace_shell_launch_completed",
             Self::RefreshWorkspacesCompleted { .. } => "refresh_workspaces_completed",
@@ -209,6 +259,14 @@ impl ReplayMsg {
             Self::InteractiveSendCompleted { .. } => "interactive_send_completed",
             Self::DiffCaptureCompleted { .. } => "diff_capture_completed",
             Self::DiffStatCompleted { .. } => "diff_stat_completed",
+            Self::BranchSyncCompleted { .. } => "branch_sync_completed",
+            Self::ProjectFetchCompleted { .. } => "project_fetch_completed",
+            Self::GcPlanCompleted { .. } => "gc_plan_completed",
+            Self::CommitFilesCompleted { .. } => "commit_files_completed",
+            Self::CommitCompleted { .. } => "commit_completed",
+            Self::DiffHunkStageCompleted { .. } => "diff_hunk_stage_completed",
+            Self::DiffHunkDiscardCompleted { .. } => "diff_hunk_discard_completed",
+            Self::ControlRequest { .. } => "control_request",
             Self::Noop => "noop",
         }
     }
@@ -235,6 +293,9 @@ impl ReplayMsg {
             Msg::PreviewStreamEvent(event) => Self::PreviewStreamEvent {
                 event: ReplayPreviewStreamEvent::from_event(event),
             },
+            Msg::PinnedPreviewCaptured(capture) => Self::PinnedPreviewCaptured {
+                capture: ReplayLivePreviewCapture::from_capture(capture),
+            },
             Msg::LazygitLaunchCompleted(completion) => Self::LazygitLaunchCompleted {
                 completion: ReplayLazygitLaunchCompletion::from_completion(completion),
             },
@@ -280,14 +341,59 @@ impl ReplayMsg {
             },
             Msg::DiffCaptureCompleted(completion) => Self::DiffCaptureCompleted {
                 workspace_path: completion.workspace_path.to_string_lossy().to_string(),
+                mode: completion.mode,
                 capture_ms: completion.capture_ms,
                 result: completion.result.clone(),
+                file_stats: completion.file_stats.clone(),
+                review_comments: completion.review_comments.clone(),
             },
             Msg::DiffStatCompleted(completion) => Self::DiffStatCompleted {
                 workspace_path: completion.workspace_path.to_string_lossy().to_string(),
                 insertions: completion.insertions,
                 deletions: completion.deletions,
             },
+            Msg::BranchSyncCompleted(completion) => Self::BranchSyncCompleted {
+                statuses: completion
+                    .statuses
+                    .iter()
+                    .map(|(path, status)| (path.to_string_lossy().to_string(), status.clone()))
+                    .collect(),
+            },
+            Msg::ProjectFetchCompleted(completion) => Self::ProjectFetchCompleted {
+                project_path: completion.project_path.to_string_lossy().to_string(),
+                duration_ms: completion.duration_ms,
+                result: completion.result.clone(),
+            },
+            Msg::GcPlanCompleted(completion) => Self::GcPlanCompleted {
+                completion: ReplayGcPlanCompletion::from_completion(completion),
+            },
+            Msg::CommitFilesCompleted(completion) => Self::CommitFilesCompleted {
+                workspace_path: completion.workspace_path.clone(),
+                index_changed: completion.index_changed,
+                action_error: completion.action_error.clone(),
+                files: completion.files.clone(),
+            },
+            Msg::CommitCompleted(completion) => Self::CommitCompleted {
+                workspace_name: completion.workspace_name.clone(),
+                workspace_path: completion.workspace_path.clone(),
+                result: completion.result.clone(),
+            },
+            Msg::DiffHunkStageCompleted(completion) => Self::DiffHunkStageCompleted {
+                path: completion.path.clone(),
+                staged: completion.staged,
+                result: completion.result.clone(),
+            },
+            Msg::DiffHunkDiscardCompleted(completion) => Self::DiffHunkDiscardCompleted {
+                workspace_path: completion.workspace_path.clone(),
+                path: completion.path.clone(),
+                result: completion.result.clone(),
+            },
+            Msg::ControlRequest(request) => Self::ControlRequest {
+                client_id: request.client_id,
+                id: request.id.clone(),
+                method: request.method.clone(),
+                params: request.params.clone(),
+            },
             Msg::Noop => Self::Noop,
         }
     }
@@ -306,6 +412,9 @@ impl ReplayMsg {
                 Msg::PreviewPollCompleted(completion.to_completion())
             }
             Self::PreviewStreamEvent { event } => Msg::PreviewStreamEvent(event.to_event()),
+            Self::PinnedPreviewCaptured { capture } => {
+                Msg::PinnedPreviewCaptured(capture.to_capture())
+            }
             Self::LazygitLaunchCompleted { completion } => {
                 Msg::LazygitLaunchCompleted(completion.to_completion())
             }
@@ -347,12 +456,18 @@ impl ReplayMsg {
             }
             Self::DiffCaptureCompleted {
                 workspace_path,
+                mode,
                 capture_ms,
                 result,
+                file_stats,
+                review_comments,
             } => Msg::DiffCaptureCompleted(DiffCaptureCompletion {
                 workspace_path: PathBuf::from(workspace_path),
+                mode: *mode,
                 capture_ms: *capture_ms,
                 result: result.clone(),
+                file_stats: file_stats.clone(),
+                review_comments: review_comments.clone(),
             }),
             Self::DiffStatCompleted {
                 workspace_path,
@@ -363,6 +478,75 @@ impl ReplayMsg {
                 insertions: *insertions,
                 deletions: *deletions,
             }),
+            Self::BranchSyncCompleted { statuses } => {
+                Msg::BranchSyncCompleted(BranchSyncCompletion {
+                    statuses: statuses
+                        .iter()
+                        .map(|(path, status)| (PathBuf::from(path), status.clone()))
+                        .collect(),
+                })
+            }
+            Self::ProjectFetchCompleted {
+                project_path,
+                duration_ms,
+                result,
+            } => Msg::ProjectFetchCompleted(ProjectFetchCompletion {
+                project_path: PathBuf::from(project_path),
+                duration_ms: *duration_ms,
+                result: result.clone(),
+            }),
+            Self::GcPlanCompleted { completion } => {
+                Msg::GcPlanCompleted(completion.to_completion())
+            }
+            Self::CommitFilesCompleted {
+                workspace_path,
+                index_changed,
+                action_error,
+                files,
+            } => Msg::CommitFilesCompleted(CommitFilesCompletion {
+                workspace_path: workspace_path.clone(),
+                index_changed: *index_changed,
+                action_error: action_error.clone(),
+                files: files.clone(),
+            }),
+            Self::CommitCompleted {
+                workspace_name,
+                workspace_path,
+                result,
+            } => Msg::CommitCompleted(CommitCompletion {
+                workspace_name: workspace_name.clone(),
+                workspace_path: workspace_path.clone(),
+                result: result.clone(),
+            }),
+            Self::DiffHunkStageCompleted {
+                path,
+                staged,
+                result,
+            } => Msg::DiffHunkStageCompleted(DiffHunkStageCompletion {
+                path: path.clone(),
+                staged: *staged,
+                result: result.clone(),
+            }),
+            Self::DiffHunkDiscardCompleted {
+                workspace_path,
+                path,
+                result,
+            } => Msg::DiffHunkDiscardCompleted(DiffHunkDiscardCompletion {
+                workspace_path: workspace_path.clone(),
+                path: path.clone(),
+                result: result.clone(),
+            }),
+            Self::ControlRequest {
+                client_id,
+                id,
+                method,
+                params,
+            } => Msg::ControlRequest(ControlRequest {
+                client_id: *client_id,
+                id: id.clone(),
+                method: method.clone(),
+                params: params.clone(),
+            }),
             Self::Noop => Msg::Noop,
         }
     }
@@ -80,7 +80,7 @@ impl ReplayStateSnapshot {
             active_modal: app.active_dialog_kind().map(str::to_string),
             keybind_help_open: app.dialogs.keybind_help_open,
             command_palette_open: app.dialogs.command_palette.is_visible(),
-            theme_name: app.theme_name,
+            theme_name: app.theme_name.clone(),
         }
     }
 }
@@ -40,11 +40,15 @@ fn run_with_logger(
         );
     }
 
-    let app = GroveApp::new(event_log, debug_record_start_ts);
+    let mut app = GroveApp::new(event_log, debug_record_start_ts);
+    let control_socket_path = app.enable_control_socket();
+    app.enable_status_snapshot();
     app.record_replay_bootstrap();
 
     let config = program_config();
-    Program::with_config(app, config)?.run()
+    let result = Program::with_config(app, config)?.run();
+    let _ = std::fs::remove_file(control_socket_path);
+    result
 }
 
 fn program_config() -> ProgramConfig {
@@ -3,11 +3,9 @@ use std::time::Instant;
 
 use crate::domain::AgentType;
 use crate::infrastructure::config::ThemeName;
+use crate::infrastructure::themes::{CustomThemes, ThemeColor, ThemeSelection};
 use ftui::{Color, PackedRgba, ResolvedTheme, Theme, ThemeBuilder};
 
-pub(super) const WORKSPACE_LAUNCH_PROMPT_FILENAME: &str = ".grove/prompt";
-pub(super) const WORKSPACE_INIT_COMMAND_FILENAME: &str = ".grove/init_command";
-pub(super) const WORKSPACE_SKIP_PERMISSIONS_FILENAME: &str = ".grove/skip_permissions";
 pub(super) const HEADER_HEIGHT: u16 = 1;
 pub(super) const STATUS_HEIGHT: u16 = 1;
 pub(super) const DIVIDER_WIDTH: u16 = 1;
@@ -31,6 +29,10 @@ pub(super) const FOCUS_GROUP_EDIT_DIALOG: u32 = 21;
 pub(super) const FOCUS_GROUP_LAUNCH_DIALOG: u32 = 22;
 pub(super) const FOCUS_GROUP_CREATE_DIALOG: u32 = 23;
 pub(super) const FOCUS_GROUP_PROJECT_DEFAULTS_DIALOG: u32 = 24;
+pub(super) const FOCUS_GROUP_GC_DIALOG: u32 = 25;
+pub(super) const FOCUS_GROUP_COMMIT_DIALOG: u32 = 26;
+pub(super) const FOCUS_GROUP_REVIEW_COMMENT_DIALOG: u32 = 27;
+pub(super) const FOCUS_GROUP_TASK_NOTES_DIALOG: u32 = 28;
 pub(super) const FOCUS_ID_CONFIRM_CONFIRM_BUTTON: u64 = 1001;
 pub(super) const FOCUS_ID_CONFIRM_CANCEL_BUTTON: u64 = 1002;
 pub(super) const FOCUS_ID_PROJECT_DIALOG_FILTER_INPUT: u64 = 1101;
@@ -85,6 +87,23 @@ pub(super) const FOCUS_ID_CREATE_PULL_REQUEST_URL: u64 = 2303;
 pub(super) const FOCUS_ID_CREATE_PROJECT: u64 = 2304;
 pub(super) const FOCUS_ID_CREATE_CREATE_BUTTON: u64 = 2305;
 pub(super) const FOCUS_ID_CREATE_CANCEL_BUTTON: u64 = 2306;
+pub(super) const FOCUS_ID_GC_CANDIDATES: u64 = 2501;
+pub(super) const FOCUS_ID_GC_IDLE_DAYS: u64 = 2502;
+pub(super) const FOCUS_ID_GC_APPLY_BUTTON: u64 = 2503;
+pub(super) const FOCUS_ID_GC_CANCEL_BUTTON: u64 = 2504;
+pub(super) const FOCUS_ID_COMMIT_FILES: u64 = 2601;
+pub(super) const FOCUS_ID_COMMIT_MESSAGE: u64 = 2602;
+pub(super) const FOCUS_ID_COMMIT_DRAFT: u64 = 2603;
+pub(super) const FOCUS_ID_COMMIT_COMMIT_BUTTON: u64 = 2604;
+pub(super) const FOCUS_ID_COMMIT_CANCEL_BUTTON: u64 = 2605;
+pub(super) const FOCUS_ID_REVIEW_COMMENT_LINE: u64 = 2701;
+pub(super) const FOCUS_ID_REVIEW_COMMENT_BODY: u64 = 2702;
+pub(super) const FOCUS_ID_REVIEW_COMMENT_ADD_BUTTON: u64 = 2703;
+pub(super) const FOCUS_ID_REVIEW_COMMENT_CANCEL_BUTTON: u64 = 2704;
+pub(super) const FOCUS_ID_TASK_NOTES_TAGS: u64 = 2801;
+pub(super) const FOCUS_ID_TASK_NOTES_NOTES: u64 = 2802;
+pub(super) const FOCUS_ID_TASK_NOTES_SAVE_BUTTON: u64 = 2803;
+pub(super) const FOCUS_ID_TASK_NOTES_CANCEL_BUTTON: u64 = 2804;
 pub(super) const HIT_ID_HEADER: u32 = 1;
 pub(super) const HIT_ID_WORKSPACE_LIST: u32 = 2;
 pub(super) const HIT_ID_PREVIEW: u32 = 3;
@@ -112,6 +131,12 @@ pub(super) const HIT_ID_PROJECT_DIALOG_LIST: u32 = 24;
 pub(super) const HIT_ID_PROJECT_ADD_RESULTS_LIST: u32 = 25;
 pub(super) const HIT_ID_PULL_UPSTREAM_DIALOG: u32 = 26;
 pub(super) const HIT_ID_PERFORMANCE_DIALOG: u32 = 27;
+pub(super) const HIT_ID_GC_DIALOG: u32 = 28;
+pub(super) const HIT_ID_COMMIT_DIALOG: u32 = 29;
+pub(super) const HIT_ID_REVIEW_COMMENT_DIALOG: u32 = 30;
+pub(super) const HIT_ID_PREVIEW_PINNED: u32 = 31;
+pub(super) const HIT_ID_DASHBOARD_TILE: u32 = 32;
+pub(super) const HIT_ID_TASK_NOTES_DIALOG: u32 = 33;
 pub(super) const MAX_PENDING_INPUT_TRACES: usize = 256;
 pub(super) const INTERACTIVE_KEYSTROKE_DEBOUNCE_MS: u64 = 20;
 pub(super) const FAST_ANIMATION_INTERVAL_MS: u64 = 100;
@@ -121,10 +146,10 @@ pub(super) const WORKSPACE_STATUS_POLL_INTERVAL_MS: u64 = 2_000;
 pub(super) const LIVE_PREVIEW_SCROLLBACK_LINES: usize = 600;
 pub(super) const LIVE_PREVIEW_IDLE_SCROLLBACK_LINES: usize = 200;
 pub(super) const LIVE_PREVIEW_FULL_SCROLLBACK_LINES: usize = 0;
+pub(super) const DASHBOARD_TAIL_LINES: usize = 40;
 pub(super) const LAZYGIT_COMMAND: &str = "lazygit";
 pub(super) const WORKING_STATUS_HOLD_MS: u64 = 3_000;
 pub(super) const WORKING_IDLE_POLLS_TO_CLEAR: u8 = 2;
-pub(super) const AGENT_ENV_SEPARATOR: char = ';';
 
 pub(super) fn usize_to_u64(value: usize) -> u64 {
     u64::try_from(value).unwrap_or(u64::MAX)
@@ -163,73 +188,7 @@ pub(super) fn decode_create_dialog_tab_hit_data(
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub(super) struct AgentEnvVar {
-    pub(super) key: String,
-    pub(super) value: String,
-}
-
-pub(super) fn parse_agent_env_vars(raw: &str) -> Result<Vec<AgentEnvVar>, String> {
-    let mut parsed = Vec::new();
-    for segment in raw
-        .split([AGENT_ENV_SEPARATOR, '\n'])
-        .map(str::trim)
-        .filter(|segment| !segment.is_empty())
-    {
-        let Some((raw_key, raw_value)) = segment.split_once('=') else {
-            return Err(format!("'{segment}' must be KEY=VALUE"));
-        };
-        let key = raw_key.trim();
-        if !env_var_key_is_valid(key) {
-            return Err(format!("invalid env key '{key}'"));
-        }
-        let value = raw_value.trim();
-        if value.is_empty() {
-            return Err(format!("env '{key}' cannot have empty value"));
-        }
-        parsed.push(AgentEnvVar {
-            key: key.to_string(),
-            value: value.to_string(),
-        });
-    }
-    Ok(parsed)
-}
-
-pub(super) fn parse_agent_env_vars_from_entries(
-    entries: &[String],
-) -> Result<Vec<AgentEnvVar>, String> {
-    parse_agent_env_vars(entries.join("; ").as_str())
-}
-
-pub(super) fn format_agent_env_vars(entries: &[String]) -> String {
-    entries
-        .iter()
-        .map(|entry| entry.trim())
-        .filter(|entry| !entry.is_empty())
-        .collect::<Vec<&str>>()
-        .join("; ")
-}
-
-pub(super) fn encode_agent_env_vars(raw: &str) -> Result<Vec<String>, String> {
-    parse_agent_env_vars(raw).map(|vars| {
-        vars.into_iter()
-            .map(|entry| format!("{}={}", entry.key, entry.value))
-            .collect()
-    })
-}
-
-fn env_var_key_is_valid(key: &str) -> bool {
-    let mut chars = key.chars();
-    let Some(first) = chars.next() else {
-        return false;
-    };
-    if !(first.is_ascii_alphabetic() || first == '_') {
-        return false;
-    }
-    chars.all(|character| character.is_ascii_alphanumeric() || character == '_')
-}
-
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 struct ThemePreset {
     name: ThemeName,
     display_name: &'static str,
@@ -274,7 +233,7 @@ const fn rgb(r: u8, g: u8, b: u8) -> Color {
     Color::rgb(r, g, b)
 }
 
-fn build_theme(theme_name: ThemeName) -> Theme {
+fn build_theme(theme_name: &ThemeName) -> Theme {
     match theme_name {
         ThemeName::Monokai => ThemeBuilder::new()
             .background(rgb(39, 40, 34))
@@ -444,6 +403,7 @@ fn build_theme(theme_name: ThemeName) -> Theme {
             .scrollbar_track(rgb(250, 244, 237))
             .scrollbar_thumb(rgb(223, 218, 217))
             .build(),
+        ThemeName::Custom(_) => build_theme(&ThemeName::default()),
     }
 }
 
@@ -452,46 +412,105 @@ pub(crate) fn packed(color: Color) -> PackedRgba {
     PackedRgba::rgb(rgb.r, rgb.g, rgb.b)
 }
 
-fn theme_preset(theme_name: ThemeName) -> ThemePreset {
+fn theme_preset(theme_name: &ThemeName) -> &'static ThemePreset {
     THEME_PRESETS
         .iter()
-        .copied()
-        .find(|preset| preset.name == theme_name)
-        .unwrap_or(THEME_PRESETS[4])
+        .find(|preset| preset.name == *theme_name)
+        .unwrap_or(&THEME_PRESETS[4])
 }
 
-pub(super) fn theme_display_name(theme_name: ThemeName) -> &'static str {
-    theme_preset(theme_name).display_name
+pub(super) fn theme_display_name(theme_name: &ThemeName, custom_themes: &CustomThemes) -> String {
+    match theme_name {
+        ThemeName::Custom(key) => custom_themes
+            .get(key)
+            .map_or_else(|| key.clone(), |theme| theme.display_name.clone()),
+        _ => theme_preset(theme_name).display_name.to_string(),
+    }
 }
 
-pub(super) fn next_theme_name(theme_name: ThemeName) -> ThemeName {
-    let index = THEME_PRESETS
+fn selectable_theme_names(custom_themes: &CustomThemes) -> Vec<ThemeName> {
+    let mut names = THEME_PRESETS
         .iter()
-        .position(|preset| preset.name == theme_name)
+        .map(|preset| preset.name.clone())
+        .collect::<Vec<ThemeName>>();
+    names.extend(custom_themes.names());
+    names
+}
+
+pub(super) fn next_theme_name(theme_name: &ThemeName, custom_themes: &CustomThemes) -> ThemeName {
+    let names = selectable_theme_names(custom_themes);
+    let index = names
+        .iter()
+        .position(|name| name == theme_name)
         .unwrap_or(0);
-    THEME_PRESETS[(index + 1) % THEME_PRESETS.len()].name
+    names[(index + 1) % names.len()].clone()
 }
 
-pub(super) fn previous_theme_name(theme_name: ThemeName) -> ThemeName {
-    let index = THEME_PRESETS
+pub(super) fn previous_theme_name(
+    theme_name: &ThemeName,
+    custom_themes: &CustomThemes,
+) -> ThemeName {
+    let names = selectable_theme_names(custom_themes);
+    let index = names
         .iter()
-        .position(|preset| preset.name == theme_name)
+        .position(|name| name == theme_name)
         .unwrap_or(0);
-    THEME_PRESETS[(index + THEME_PRESETS.len() - 1) % THEME_PRESETS.len()].name
+    names[(index + names.len() - 1) % names.len()].clone()
+}
+
+fn theme_color(color: ThemeColor) -> Color {
+    rgb(color.r, color.g, color.b)
 }
 
-pub(crate) fn ui_theme_for(theme_name: ThemeName) -> ResolvedTheme {
-    build_theme(theme_name).resolve(Theme::detect_dark_mode())
+/// Resolves a theme, layering a custom theme's colors over the default
+/// palette. A custom name without a loaded theme renders as the default.
+pub(crate) fn ui_theme_for(theme: &ThemeSelection) -> ResolvedTheme {
+    let mut resolved = build_theme(&theme.name).resolve(Theme::detect_dark_mode());
+    let Some(custom) = theme.custom.as_ref() else {
+        return resolved;
+    };
+    let palette = custom.palette;
+    let overrides = [
+        (&mut resolved.background, palette.background),
+        (&mut resolved.surface, palette.surface),
+        (&mut resolved.overlay, palette.overlay),
+        (&mut resolved.text, palette.text),
+        (&mut resolved.text_muted, palette.text_muted),
+        (&mut resolved.text_subtle, palette.text_subtle),
+        (&mut resolved.primary, palette.primary),
+        (&mut resolved.secondary, palette.secondary),
+        (&mut resolved.accent, palette.accent),
+        (&mut resolved.info, palette.info),
+        (&mut resolved.success, palette.success),
+        (&mut resolved.warning, palette.warning),
+        (&mut resolved.error, palette.error),
+        (&mut resolved.border, palette.border),
+        (&mut resolved.border_focused, palette.border_focused),
+        (&mut resolved.selection_bg, palette.selection_bg),
+        (&mut resolved.selection_fg, palette.selection_fg),
+        (&mut resolved.scrollbar_track, palette.scrollbar_track),
+        (&mut resolved.scrollbar_thumb, palette.scrollbar_thumb),
+    ];
+    for (slot, color) in overrides {
+        if let Some(color) = color {
+            *slot = theme_color(color);
+        }
+    }
+    resolved
 }
 
 #[cfg_attr(not(test), allow(dead_code))]
 pub(super) fn ui_theme() -> ResolvedTheme {
-    ui_theme_for(ThemeName::default())
+    ui_theme_for(&ThemeSelection::default())
 }
 
 impl super::GroveApp {
+    pub(super) fn theme_selection(&self) -> ThemeSelection {
+        self.custom_themes.select(&self.theme_name)
+    }
+
     pub(super) fn active_ui_theme(&self) -> ResolvedTheme {
-        ui_theme_for(self.theme_name)
+        ui_theme_for(&self.theme_selection())
     }
 }
 
@@ -500,6 +519,8 @@ pub(super) enum HitRegion {
     WorkspaceList,
     WorkspacePullRequest,
     Preview,
+    PinnedPreview,
+    DashboardTile,
     Divider,
     StatusLine,
     Header,
@@ -0,0 +1,85 @@
+use crate::infrastructure::status_snapshot::{
+    STATUS_SNAPSHOT_REFRESH_MS, StatusSnapshotEntry, StatusSnapshotSource, StatusSnapshotWriter,
+    publish_status_snapshot_to_path, status_snapshot_path,
+};
+
+use super::*;
+
+#[derive(Default)]
+pub(super) struct StatusSnapshotState {
+    path: Option<PathBuf>,
+    published: Vec<StatusSnapshotEntry>,
+    published_at_ms: Option<u64>,
+    waiting_since_ms: HashMap<PathBuf, u64>,
+}
+
+impl GroveApp {
+    pub(super) fn enable_status_snapshot(&mut self) {
+        self.status_snapshot.path = status_snapshot_path();
+    }
+
+    pub(super) fn publish_status_snapshot(&mut self) {
+        let Some(path) = self.status_snapshot.path.clone() else {
+            return;
+        };
+
+        let now_ms = now_millis();
+        let waiting_since_ms = self
+            .state
+            .workspaces
+            .iter()
+            .filter(|workspace| workspace.status == WorkspaceStatus::Waiting)
+            .map(|workspace| {
+                let since = self
+                    .status_snapshot
+                    .waiting_since_ms
+                    .get(workspace.path.as_path())
+                    .copied()
+                    .unwrap_or(now_ms);
+                (workspace.path.clone(), since)
+            })
+            .collect::<HashMap<PathBuf, u64>>();
+        let entries = self
+            .state
+            .workspaces
+            .iter()
+            .map(|workspace| StatusSnapshotEntry {
+                task: workspace.task_slug.clone().unwrap_or_default(),
+                repository: workspace.project_name.clone().unwrap_or_default(),
+                path: workspace.path.clone(),
+                status: workspace.status.label().to_string(),
+                waiting_since_ms: waiting_since_ms.get(workspace.path.as_path()).copied(),
+            })
+            .collect::<Vec<StatusSnapshotEntry>>();
+        self.status_snapshot.waiting_since_ms = waiting_since_ms;
+        let refresh_due = self
+            .status_snapshot
+            .published_at_ms
+            .is_none_or(|at_ms| now_ms.saturating_sub(at_ms) >= STATUS_SNAPSHOT_REFRESH_MS);
+        if entries == self.status_snapshot.published && !refresh_due {
+            return;
+        }
+
+        let writer = StatusSnapshotWriter::current(StatusSnapshotSource::Tui);
+        match publish_status_snapshot_to_path(path.as_path(), writer, &entries, now_ms) {
+            Ok(true) => {
+                self.status_snapshot.published = entries;
+                self.status_snapshot.published_at_ms = Some(now_ms);
+            }
+            Ok(false) => {}
+            Err(error) => {
+                self.telemetry.event_log.log(
+                    LogEvent::new("status_snapshot", "write_failed")
+                        .with_data("error", Value::from(error)),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+impl GroveApp {
+    pub(super) fn enable_status_snapshot_at(&mut self, path: PathBuf) {
+        self.status_snapshot.path = Some(path);
+    }
+}
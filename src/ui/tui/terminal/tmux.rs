@@ -1,5 +1,4 @@
-use std::io::Write;
-
+use crate::application::agent_runtime::{paste_into_session, submit_session_input_command};
 use crate::infrastructure::process::{
     execute_command as execute_process_command, stderr_or_status, stderr_trimmed,
 };
@@ -29,6 +28,11 @@ pub(in crate::ui::tui) trait TmuxInput {
         target_height: u16,
     ) -> std::io::Result<()>;
     fn paste_buffer(&self, target_session: &str, text: &str) -> std::io::Result<()>;
+    /// Pastes `text` and presses Enter so the agent receives it as a prompt.
+    fn paste_and_submit(&self, target_session: &str, text: &str) -> std::io::Result<()> {
+        self.paste_buffer(target_session, text)?;
+        self.execute(&submit_session_input_command(target_session))
+    }
     fn list_sessions_for_cleanup(&self) -> std::io::Result<String> {
         Ok(String::new())
     }
@@ -92,7 +96,7 @@ impl TmuxInput for CommandTmuxInput {
     }
 
     fn paste_buffer(&self, target_session: &str, text: &str) -> std::io::Result<()> {
-        Self::paste_target_session_buffer(target_session, text)
+        paste_into_session(target_session, text)
     }
 
     fn list_sessions_with_tab_metadata(&self) -> std::io::Result<String> {
@@ -336,34 +340,6 @@ impl CommandTmuxInput {
             "tmux resize failed for '{target_session}': resize-window={resize_window_error}; resize-pane={resize_pane_error}{set_manual_suffix}"
         )))
     }
-
-    fn paste_target_session_buffer(target_session: &str, text: &str) -> std::io::Result<()> {
-        let mut load_buffer = std::process::Command::new("tmux");
-        load_buffer.arg("load-buffer").arg("-");
-        load_buffer.stdin(std::process::Stdio::piped());
-        let mut load_child = load_buffer.spawn()?;
-        if let Some(stdin) = load_child.stdin.as_mut() {
-            stdin.write_all(text.as_bytes())?;
-        }
-        let load_status = load_child.wait()?;
-        if !load_status.success() {
-            return Err(std::io::Error::other(format!(
-                "tmux load-buffer failed for '{target_session}': exit status {load_status}"
-            )));
-        }
-
-        let paste_output = std::process::Command::new("tmux")
-            .args(["paste-buffer", "-t", target_session])
-            .output()?;
-        if paste_output.status.success() {
-            return Ok(());
-        }
-
-        Err(std::io::Error::other(format!(
-            "tmux paste-buffer failed: {}",
-            stderr_or_status(&paste_output),
-        )))
-    }
 }
 
 #[cfg(test)]
@@ -67,6 +67,10 @@ impl GroveApp {
                 self.handle_preview_stream_event(event);
                 Cmd::None
             }
+            Msg::PinnedPreviewCaptured(capture) => {
+                self.handle_pinned_preview_captured(capture);
+                Cmd::None
+            }
             Msg::LazygitLaunchCompleted(completion) => {
                 self.handle_lazygit_launch_completed(completion);
                 Cmd::None
@@ -126,12 +130,46 @@ impl GroveApp {
                 self.handle_diff_stat_completed(completion);
                 Cmd::None
             }
+            Msg::BranchSyncCompleted(completion) => {
+                self.handle_branch_sync_completed(completion);
+                Cmd::None
+            }
+            Msg::ProjectFetchCompleted(completion) => {
+                self.handle_project_fetch_completed(completion);
+                Cmd::None
+            }
+            Msg::GcPlanCompleted(completion) => {
+                self.handle_gc_plan_completed(completion);
+                Cmd::None
+            }
+            Msg::CommitFilesCompleted(completion) => {
+                self.handle_commit_files_completed(completion);
+                Cmd::None
+            }
+            Msg::CommitCompleted(completion) => {
+                self.handle_commit_completed(completion);
+                Cmd::None
+            }
+            Msg::DiffHunkStageCompleted(completion) => {
+                self.handle_diff_hunk_stage_completed(completion);
+                Cmd::None
+            }
+            Msg::DiffHunkDiscardCompleted(completion) => {
+                self.handle_diff_hunk_discard_completed(completion);
+                Cmd::None
+            }
+            Msg::ControlRequest(request) => {
+                self.handle_control_request(request);
+                self.schedule_next_tick()
+            }
             Msg::Noop => {
                 self.interactive_preview_reset_pending = false;
                 Cmd::None
             }
         };
         self.sync_preview_stream_target();
+        self.publish_control_notifications();
+        self.publish_status_snapshot();
         self.emit_transition_events(&before);
         self.record_replay_state_after_update(replay_seq);
         self.telemetry.event_log.log(
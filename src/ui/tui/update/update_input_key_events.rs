@@ -38,9 +38,12 @@ impl GroveApp {
         key_event
     }
 
-    fn global_workspace_navigation_command(key_event: &KeyEvent) -> Option<UiCommand> {
+    fn global_workspace_navigation_command(&self, key_event: &KeyEvent) -> Option<UiCommand> {
         for command in UiCommand::all() {
-            if command.matches_keybinding(key_event, KeybindingScope::GlobalNavigation) {
+            if self
+                .keymap
+                .matches_key(*command, key_event, KeybindingScope::GlobalNavigation)
+            {
                 return Some(*command);
             }
         }
@@ -213,7 +216,10 @@ impl GroveApp {
         let can_enter_interactive = self.can_enter_interactive_session();
 
         for command in UiCommand::all() {
-            if !command.matches_keybinding(key_event, KeybindingScope::NonInteractive) {
+            if !self
+                .keymap
+                .matches_key(*command, key_event, KeybindingScope::NonInteractive)
+            {
                 continue;
             }
             if self.non_interactive_command_enabled(
@@ -259,6 +265,22 @@ impl GroveApp {
                 self.workspace_list_focused() && self.state.selected_workspace().is_some()
             }
             UiCommand::OpenDiffTab => in_preview_focus,
+            UiCommand::CommitChanges
+            | UiCommand::NextDiffHunk
+            | UiCommand::PreviousDiffHunk
+            | UiCommand::ToggleDiffHunkStaged
+            | UiCommand::DiscardDiffHunk
+            | UiCommand::CycleDiffMode
+            | UiCommand::ToggleDiffFileTree
+            | UiCommand::NextDiffFile
+            | UiCommand::PreviousDiffFile
+            | UiCommand::AddReviewComment
+            | UiCommand::SendReview => in_preview_focus && self.preview_tab == PreviewTab::Diff,
+            UiCommand::MoreDiffCommits | UiCommand::FewerDiffCommits => {
+                in_preview_focus
+                    && self.preview_tab == PreviewTab::Diff
+                    && matches!(self.diff_review.mode, DiffMode::LastCommits(_))
+            }
             UiCommand::RenameActiveTab
             | UiCommand::StopAgent
             | UiCommand::RestartAgent
@@ -317,12 +339,44 @@ impl GroveApp {
             self.acknowledge_selected_attention_item();
             return false;
         }
+        if let Some(command) = self.advance_key_sequence(key_event) {
+            return command.is_some_and(|command| self.execute_ui_command(command));
+        }
         let Some(command) = self.non_interactive_command_for_key(&key_event) else {
             return false;
         };
         self.execute_ui_command(command)
     }
 
+    /// Feeds a key into the pending multi-key sequence. Returns `None` when
+    /// the key is not part of a sequence and should be handled normally.
+    fn advance_key_sequence(&mut self, key_event: KeyEvent) -> Option<Option<UiCommand>> {
+        self.pending_key_sequence.push(key_event);
+        match self.keymap.match_sequence(&self.pending_key_sequence) {
+            KeySequenceMatch::Complete(command) => {
+                self.pending_key_sequence.clear();
+                let in_preview_focus = self.preview_focused();
+                let enabled = command == UiCommand::OpenCommandPalette
+                    || self.non_interactive_command_enabled(
+                        command,
+                        in_preview_focus,
+                        in_preview_focus && self.active_tab_is_scrollable(),
+                        self.can_enter_interactive_session(),
+                    );
+                Some(enabled.then_some(command))
+            }
+            KeySequenceMatch::Pending => Some(None),
+            KeySequenceMatch::None => {
+                let restart = self.pending_key_sequence.len() > 1;
+                self.pending_key_sequence.clear();
+                if restart {
+                    return self.advance_key_sequence(key_event);
+                }
+                None
+            }
+        }
+    }
+
     fn dispatch_dialog_key(&mut self, key_event: &KeyEvent) -> bool {
         if self.create_dialog().is_some() {
             self.handle_create_dialog_key(*key_event);
@@ -352,6 +406,22 @@ impl GroveApp {
             self.handle_session_cleanup_dialog_key(*key_event);
             return true;
         }
+        if self.gc_dialog().is_some() {
+            self.handle_gc_dialog_key(*key_event);
+            return true;
+        }
+        if self.commit_dialog().is_some() {
+            self.handle_commit_dialog_key(*key_event);
+            return true;
+        }
+        if self.review_comment_dialog().is_some() {
+            self.handle_review_comment_dialog_key(*key_event);
+            return true;
+        }
+        if self.task_notes_dialog().is_some() {
+            self.handle_task_notes_dialog_key(*key_event);
+            return true;
+        }
         if self.delete_dialog().is_some() {
             self.handle_delete_dialog_key(*key_event);
             return true;
@@ -385,9 +455,11 @@ impl GroveApp {
             return (false, Cmd::None);
         }
 
-        if UiCommand::OpenCommandPalette
-            .matches_keybinding(&key_event, KeybindingScope::NonInteractive)
-        {
+        if self.keymap.matches_key(
+            UiCommand::OpenCommandPalette,
+            &key_event,
+            KeybindingScope::NonInteractive,
+        ) {
             return (
                 self.execute_ui_command(UiCommand::OpenCommandPalette),
                 Cmd::None,
@@ -446,8 +518,56 @@ impl GroveApp {
             return (false, Cmd::None);
         }
 
+        if self.sidebar_filter_editing() && !self.modal_open() {
+            self.handle_sidebar_filter_key(key_event);
+            return (false, Cmd::None);
+        }
+        if key_event.code == KeyCode::Escape
+            && self.sidebar_filter_bar_visible()
+            && self.workspace_list_focused()
+            && !self.modal_open()
+        {
+            self.clear_sidebar_filter();
+            return (false, Cmd::None);
+        }
+
+        if self.dashboard_active() {
+            match key_event.code {
+                KeyCode::Escape => self.close_dashboard(),
+                KeyCode::Enter => self.open_selected_dashboard_tile(),
+                KeyCode::Char('h') if key_event.modifiers.is_empty() => {
+                    self.move_dashboard_selection(-1, 0);
+                }
+                KeyCode::Char('l') if key_event.modifiers.is_empty() => {
+                    self.move_dashboard_selection(1, 0);
+                }
+                KeyCode::Char('k') if key_event.modifiers.is_empty() => {
+                    self.move_dashboard_selection(0, -1);
+                }
+                KeyCode::Char('j') if key_event.modifiers.is_empty() => {
+                    self.move_dashboard_selection(0, 1);
+                }
+                KeyCode::Left => self.move_dashboard_selection(-1, 0),
+                KeyCode::Right => self.move_dashboard_selection(1, 0),
+                KeyCode::Up => self.move_dashboard_selection(0, -1),
+                KeyCode::Down => self.move_dashboard_selection(0, 1),
+                KeyCode::Tab => self.cycle_dashboard_selection(1),
+                KeyCode::BackTab => self.cycle_dashboard_selection(-1),
+                _ if self.keymap.matches_key(
+                    UiCommand::ToggleDashboard,
+                    &key_event,
+                    KeybindingScope::NonInteractive,
+                ) =>
+                {
+                    self.close_dashboard();
+                }
+                _ => {}
+            }
+            return (false, Cmd::None);
+        }
+
         if !self.modal_open()
-            && let Some(command) = Self::global_workspace_navigation_command(&key_event)
+            && let Some(command) = self.global_workspace_navigation_command(&key_event)
         {
             if self.session.interactive.is_some()
                 && matches!(
@@ -534,19 +654,27 @@ impl GroveApp {
             .selected_attention_item
             .map(SidebarSelectable::Attention)
             .unwrap_or(SidebarSelectable::Workspace(self.state.selected_index));
-        let Some(current_line) = row_map
-            .iter()
-            .position(|entry| entry.is_some_and(|target| target == current_target))
-        else {
-            return;
-        };
-
         let direction: isize = match action {
             Action::MoveSelectionUp => -1,
             Action::MoveSelectionDown => 1,
             #[cfg(test)]
             Action::EnterPreviewMode | Action::EnterListMode => return,
         };
+        let Some(current_line) = row_map
+            .iter()
+            .position(|entry| entry.is_some_and(|target| target == current_target))
+        else {
+            // The sidebar filter can hide the selection, step onto the nearest end.
+            let fallback = if direction > 0 {
+                row_map.iter().flatten().next()
+            } else {
+                row_map.iter().flatten().next_back()
+            };
+            if let Some(target) = fallback.copied() {
+                self.select_sidebar_target(target);
+            }
+            return;
+        };
         let len = row_map.len();
         let mut candidate = current_line;
         loop {
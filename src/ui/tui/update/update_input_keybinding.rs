@@ -21,6 +21,10 @@ impl GroveApp {
         if let Some(dialog) = self.create_dialog() {
             return !dialog.task_name.is_empty() || !dialog.pr_url.is_empty();
         }
+        if let Some(dialog) = self.commit_dialog() {
+            return self.current_commit_dialog_focus_field() == Some(CommitDialogField::Message)
+                && !dialog.message.is_empty();
+        }
         if let Some(project_dialog) = self.project_dialog() {
             if !project_dialog.filter().is_empty() {
                 return true;
@@ -56,6 +60,10 @@ impl GroveApp {
                     self.cancel_task_reorder();
                     return false;
                 }
+                if self.dashboard_active() {
+                    self.close_dashboard();
+                    return false;
+                }
                 if let Some(kind) = self.active_dialog_kind() {
                     self.log_dialog_event(kind, "dialog_cancelled");
                     if kind == "settings" {
@@ -81,6 +89,12 @@ impl GroveApp {
                     }
                     return false;
                 }
+                if self.current_commit_dialog_focus_field() == Some(CommitDialogField::Message)
+                    && let Some(dialog) = self.commit_dialog_mut()
+                {
+                    dialog.message.clear();
+                    return false;
+                }
                 let create_focus = self.current_create_dialog_focus_field();
                 if let Some(dialog) = self.create_dialog_mut() {
                     match create_focus {
@@ -9,7 +9,7 @@ impl GroveApp {
     const DIVIDER_POINTER_ID: u32 = 1;
 
     pub(super) fn preview_tab_id_at_pointer(&self, x: u16, y: u16) -> Option<u64> {
-        let (_, _, preview_rect) = self.effective_workspace_rects();
+        let preview_rect = self.live_preview_rect();
         if preview_rect.is_empty() {
             return None;
         }
@@ -184,7 +184,8 @@ impl GroveApp {
 
     pub(super) fn sidebar_selection_at_point(&self, x: u16, y: u16) -> Option<SidebarSelectable> {
         let (sidebar_rect, _, _) = self.effective_workspace_rects();
-        let sidebar_inner = Block::new().borders(Borders::ALL).inner(sidebar_rect);
+        let sidebar_inner =
+            self.sidebar_list_area(Block::new().borders(Borders::ALL).inner(sidebar_rect));
         if y < sidebar_inner.y || y >= sidebar_inner.bottom() {
             return None;
         }
@@ -609,6 +610,16 @@ impl GroveApp {
             return;
         }
 
+        if self.dashboard_active() {
+            if matches!(mouse_event.kind, MouseEventKind::Down(MouseButton::Left))
+                && matches!(region, HitRegion::DashboardTile)
+                && let Some(index) = row_data.and_then(|data| usize::try_from(data).ok())
+            {
+                self.open_dashboard_tile(index);
+            }
+            return;
+        }
+
         match mouse_event.kind {
             MouseEventKind::Down(MouseButton::Left) => match region {
                 HitRegion::Divider => {
@@ -672,7 +683,14 @@ impl GroveApp {
                         }
                     }
                 }
-                HitRegion::StatusLine | HitRegion::Header | HitRegion::Outside => {}
+                HitRegion::PinnedPreview => {
+                    self.focus_other_preview_half();
+                    self.clear_preview_selection();
+                }
+                HitRegion::DashboardTile
+                | HitRegion::StatusLine
+                | HitRegion::Header
+                | HitRegion::Outside => {}
             },
             MouseEventKind::Drag(MouseButton::Left) => {
                 if let (Some(target), Some(previous)) = (
@@ -729,6 +747,8 @@ impl GroveApp {
                     if self.preview_scroll_tab_is_focused() {
                         self.scroll_preview(-Self::PREVIEW_MOUSE_SCROLL_LINES);
                     }
+                } else if matches!(region, HitRegion::PinnedPreview) {
+                    self.scroll_pinned_preview(-Self::PREVIEW_MOUSE_SCROLL_LINES);
                 }
             }
             MouseEventKind::ScrollDown => {
@@ -749,6 +769,8 @@ impl GroveApp {
                     if self.preview_scroll_tab_is_focused() {
                         self.scroll_preview(Self::PREVIEW_MOUSE_SCROLL_LINES);
                     }
+                } else if matches!(region, HitRegion::PinnedPreview) {
+                    self.scroll_pinned_preview(Self::PREVIEW_MOUSE_SCROLL_LINES);
                 }
             }
             _ => {}
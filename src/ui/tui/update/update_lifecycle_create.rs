@@ -67,11 +67,11 @@ impl GroveApp {
 
         if dialog.register_as_base {
             let base_branch = {
-                let configured = project.defaults.base_branch.trim();
+                let configured = effective_project_config(&project).defaults.base_branch;
                 if configured.is_empty() {
                     "main".to_string()
                 } else {
-                    configured.to_string()
+                    configured
                 }
             };
             let repo_name = project
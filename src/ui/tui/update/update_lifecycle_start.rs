@@ -32,41 +32,33 @@ impl GroveApp {
         read_workspace_permission_mode(&task.root_path).unwrap_or(self.launch_permission_mode)
     }
 
-    pub(super) fn project_agent_env_for_workspace(
+    pub(super) fn effective_project_config_for_workspace(
         &self,
         workspace: &Workspace,
-    ) -> Result<Vec<(String, String)>, String> {
-        let Some(workspace_project_path) = workspace.project_path.as_ref() else {
-            return Ok(Vec::new());
-        };
-        let Some(project) = self
-            .projects
+    ) -> Option<EffectiveProjectConfig> {
+        let workspace_project_path = workspace.project_path.as_ref()?;
+        self.projects
             .iter()
             .find(|project| refer_to_same_location(&project.path, workspace_project_path))
-        else {
+            .map(effective_project_config)
+    }
+
+    pub(super) fn project_agent_env_for_workspace(
+        &self,
+        workspace: &Workspace,
+    ) -> Result<Vec<(String, String)>, String> {
+        let Some(project) = self.effective_project_config_for_workspace(workspace) else {
             return Ok(Vec::new());
         };
-        let entries = match workspace.agent {
-            AgentType::Claude => &project.defaults.agent_env.claude,
-            AgentType::Codex => &project.defaults.agent_env.codex,
-        };
-        parse_agent_env_vars_from_entries(entries).map(|vars| {
-            vars.into_iter()
-                .map(|entry| (entry.key, entry.value))
-                .collect()
-        })
+        agent_env_pairs(project.agent_env(workspace.agent))
     }
 
     pub(super) fn project_workspace_init_command_for_workspace(
         &self,
         workspace: &Workspace,
     ) -> Option<String> {
-        let workspace_project_path = workspace.project_path.as_ref()?;
-        let project = self
-            .projects
-            .iter()
-            .find(|project| refer_to_same_location(&project.path, workspace_project_path))?;
-        trimmed_nonempty(project.defaults.workspace_init_command.as_str())
+        self.effective_project_config_for_workspace(workspace)?
+            .workspace_init_command()
     }
 
     pub(super) fn workspace_init_command_for_workspace(
@@ -89,8 +81,23 @@ impl GroveApp {
         {
             return permission_mode;
         }
+        self.effective_project_config_for_workspace(workspace)
+            .map_or(self.launch_permission_mode, |project| {
+                project.launch_permission_mode_for(self.launch_permission_mode)
+            })
+    }
 
-        self.launch_permission_mode
+    fn expand_launch_prompt_template(&self, prompt: Option<String>) -> Option<String> {
+        let project = self
+            .state
+            .selected_workspace()
+            .and_then(|workspace| self.effective_project_config_for_workspace(workspace));
+        let notes = self
+            .state
+            .selected_task()
+            .map(|task| task.notes.as_str())
+            .unwrap_or_default();
+        Some(expand_launch_prompt(prompt?, project.as_ref(), notes))
     }
 
     fn start_task_agent_with_options(
@@ -120,7 +127,7 @@ impl GroveApp {
             task_slug: task.slug.clone(),
             task_root: task.root_path.clone(),
             agent,
-            theme_name: self.theme_name,
+            theme: self.theme_selection(),
             prompt,
             workspace_init_command: init_command.or_else(|| self.task_init_command_for_task(&task)),
             permission_mode,
@@ -191,7 +198,7 @@ impl GroveApp {
         let request = launch_request_for_workspace(
             &workspace,
             prompt,
-            self.theme_name,
+            self.theme_selection(),
             workspace_init_command,
             permission_mode,
             agent_env,
@@ -344,7 +351,7 @@ impl GroveApp {
         } = dialog.start_config.parse_start_options();
         let options = StartOptions {
             name,
-            prompt,
+            prompt: self.expand_launch_prompt_template(prompt),
             init_command,
             permission_mode,
         };
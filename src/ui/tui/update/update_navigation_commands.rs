@@ -24,7 +24,7 @@ impl GroveApp {
         self.select_attention_item(0);
     }
 
-    fn select_workspace_by_path(&mut self, workspace_path: &Path) {
+    pub(super) fn select_workspace_by_path(&mut self, workspace_path: &Path) {
         if let Some(workspace_index) = self
             .state
             .workspaces
@@ -297,6 +297,75 @@ impl GroveApp {
             UiCommand::CleanupSessions => {
                 self.open_session_cleanup_dialog();
             }
+            UiCommand::CleanupWorktrees => {
+                self.open_gc_dialog();
+            }
+            UiCommand::CommitChanges => {
+                self.open_commit_dialog();
+            }
+            UiCommand::NextDiffHunk => {
+                self.select_diff_hunk_by(1);
+            }
+            UiCommand::PreviousDiffHunk => {
+                self.select_diff_hunk_by(-1);
+            }
+            UiCommand::ToggleDiffHunkStaged => {
+                self.toggle_selected_diff_hunk_staged();
+            }
+            UiCommand::DiscardDiffHunk => {
+                self.confirm_discard_selected_diff_hunk();
+            }
+            UiCommand::CycleDiffMode => {
+                self.cycle_diff_mode();
+            }
+            UiCommand::MoreDiffCommits => {
+                self.change_diff_commit_count(1);
+            }
+            UiCommand::FewerDiffCommits => {
+                self.change_diff_commit_count(-1);
+            }
+            UiCommand::ToggleDiffFileTree => {
+                self.toggle_diff_file_tree();
+            }
+            UiCommand::NextDiffFile => {
+                self.select_diff_file_by(1);
+            }
+            UiCommand::PreviousDiffFile => {
+                self.select_diff_file_by(-1);
+            }
+            UiCommand::AddReviewComment => {
+                self.open_review_comment_dialog();
+            }
+            UiCommand::SendReview => {
+                self.send_review_to_agent();
+            }
+            UiCommand::OpenProjectTabs => {
+                self.open_project_tabs();
+            }
+            UiCommand::TogglePreviewSplit => {
+                self.toggle_preview_split();
+            }
+            UiCommand::RotatePreviewSplit => {
+                self.rotate_preview_split();
+            }
+            UiCommand::FocusOtherPreviewHalf => {
+                self.focus_other_preview_half();
+            }
+            UiCommand::ToggleDashboard => {
+                self.toggle_dashboard();
+            }
+            UiCommand::FilterSidebar => {
+                self.open_sidebar_filter();
+            }
+            UiCommand::CycleSidebarGroup => {
+                self.cycle_sidebar_group_mode();
+            }
+            UiCommand::CycleSidebarSort => {
+                self.cycle_sidebar_sort_mode();
+            }
+            UiCommand::EditTaskNotes => {
+                self.open_task_notes_dialog();
+            }
             UiCommand::OpenHelp => {
                 self.open_keybind_help();
             }
@@ -0,0 +1,163 @@
+use super::panes::dashboard_grid_columns;
+use super::update_prelude::*;
+
+/// A running agent session shown as one tile of the dashboard grid.
+pub(super) struct DashboardTile {
+    pub(super) workspace_path: PathBuf,
+    pub(super) workspace_name: String,
+    pub(super) agent: AgentType,
+    pub(super) status: WorkspaceStatus,
+    pub(super) session_name: String,
+    pub(super) tab_id: u64,
+}
+
+impl GroveApp {
+    pub(super) fn dashboard_active(&self) -> bool {
+        self.dashboard.is_some()
+    }
+
+    pub(super) fn toggle_dashboard(&mut self) {
+        if self.dashboard_active() {
+            self.close_dashboard();
+        } else {
+            self.open_dashboard();
+        }
+    }
+
+    pub(super) fn open_dashboard(&mut self) {
+        if self.modal_open() || self.task_reorder_active() {
+            return;
+        }
+        let tiles = self.dashboard_tiles();
+        if tiles.is_empty() {
+            self.show_info_toast("no running agent sessions");
+            return;
+        }
+        if self.session.interactive.is_some() {
+            self.exit_interactive_to_preview();
+        }
+        let selected_path = self
+            .state
+            .selected_workspace()
+            .map(|workspace| workspace.path.clone());
+        let selected_tile = tiles
+            .iter()
+            .position(|tile| Some(&tile.workspace_path) == selected_path.as_ref())
+            .unwrap_or(0);
+        self.dashboard = Some(DashboardState { selected_tile });
+        self.show_info_toast("dashboard, h/j/k/l move, Enter attach, Esc close");
+    }
+
+    pub(super) fn close_dashboard(&mut self) {
+        self.dashboard = None;
+    }
+
+    /// Tiles in sidebar order, one per workspace with a running agent tab.
+    pub(super) fn dashboard_tiles(&self) -> Vec<DashboardTile> {
+        self.state
+            .workspaces
+            .iter()
+            .filter_map(|workspace| {
+                let session_name = self.workspace_running_agent_session_for_status_poll(
+                    workspace.path.as_path(),
+                    None,
+                )?;
+                let tab_id = self
+                    .workspace_tabs
+                    .get(workspace.path.as_path())?
+                    .tabs
+                    .iter()
+                    .find(|tab| tab.session_name.as_deref() == Some(session_name.as_str()))?
+                    .id;
+                Some(DashboardTile {
+                    workspace_path: workspace.path.clone(),
+                    workspace_name: Self::workspace_display_name(workspace),
+                    agent: workspace.agent,
+                    status: workspace.status,
+                    session_name,
+                    tab_id,
+                })
+            })
+            .collect()
+    }
+
+    /// Latest output for a tile. The selected live session is not part of the
+    /// background status poll, so its lines come from the preview instead.
+    pub(super) fn dashboard_tile_lines(&self, tile: &DashboardTile) -> &[String] {
+        if self.selected_live_preview_session_if_ready().as_deref()
+            == Some(tile.session_name.as_str())
+        {
+            let lines = self.preview.active_plain_lines();
+            return &lines[lines.len().saturating_sub(DASHBOARD_TAIL_LINES)..];
+        }
+        self.polling
+            .workspace_output_tails
+            .get(tile.workspace_path.as_path())
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    pub(super) fn dashboard_selected_tile(&self, tile_count: usize) -> usize {
+        self.dashboard.map_or(0, |dashboard| {
+            dashboard.selected_tile.min(tile_count.saturating_sub(1))
+        })
+    }
+
+    pub(super) fn move_dashboard_selection(&mut self, column_delta: isize, row_delta: isize) {
+        let tile_count = self.dashboard_tiles().len();
+        if tile_count == 0 {
+            return;
+        }
+        let columns = dashboard_grid_columns(self.dashboard_rect().width, tile_count);
+        let current = self.dashboard_selected_tile(tile_count);
+        let Some(column) = (current % columns)
+            .checked_add_signed(column_delta)
+            .filter(|column| *column < columns)
+        else {
+            return;
+        };
+        let Some(row) = (current / columns).checked_add_signed(row_delta) else {
+            return;
+        };
+        let next = row.saturating_mul(columns).saturating_add(column);
+        if next >= tile_count {
+            return;
+        }
+        if let Some(dashboard) = self.dashboard.as_mut() {
+            dashboard.selected_tile = next;
+        }
+    }
+
+    pub(super) fn cycle_dashboard_selection(&mut self, direction: isize) {
+        let tile_count = self.dashboard_tiles().len();
+        if tile_count == 0 {
+            return;
+        }
+        let current = self.dashboard_selected_tile(tile_count);
+        let next = if direction.is_negative() {
+            current.checked_sub(1).unwrap_or(tile_count - 1)
+        } else {
+            (current + 1) % tile_count
+        };
+        if let Some(dashboard) = self.dashboard.as_mut() {
+            dashboard.selected_tile = next;
+        }
+    }
+
+    pub(super) fn open_selected_dashboard_tile(&mut self) {
+        let tile_count = self.dashboard_tiles().len();
+        self.open_dashboard_tile(self.dashboard_selected_tile(tile_count));
+    }
+
+    /// Leave the dashboard for the tile's agent tab and attach to it.
+    pub(super) fn open_dashboard_tile(&mut self, index: usize) {
+        let Some(tile) = self.dashboard_tiles().into_iter().nth(index) else {
+            return;
+        };
+        self.dashboard = None;
+        self.selected_attention_item = None;
+        self.select_workspace_by_path(tile.workspace_path.as_path());
+        let _ = self.select_tab_by_id_for_selected_workspace(tile.tab_id);
+        self.enter_preview_or_interactive();
+    }
+}
@@ -0,0 +1,343 @@
+use super::update_prelude::*;
+
+impl GroveApp {
+    pub(super) fn review_comments_path_for_workspace(&self, workspace_path: &Path) -> PathBuf {
+        let task_root = self
+            .state
+            .workspaces
+            .iter()
+            .find(|workspace| workspace.path == workspace_path)
+            .and_then(|workspace| workspace.task_slug.as_deref())
+            .and_then(|task_slug| self.state.tasks.iter().find(|task| task.slug == task_slug))
+            .map(|task| task.root_path.as_path())
+            .unwrap_or(workspace_path);
+        review_comments_path(task_root, workspace_path)
+    }
+
+    pub(super) fn diff_review_matches_selection(&self) -> bool {
+        self.preview_tab == PreviewTab::Diff
+            && self.state.selected_workspace().is_some_and(|workspace| {
+                self.diff_review.workspace_path.as_deref() == Some(workspace.path.as_path())
+            })
+    }
+
+    pub(super) fn selected_diff_hunk(&self) -> Option<&DiffHunkTarget> {
+        if !self.diff_review_matches_selection() {
+            return None;
+        }
+        self.diff_review.selected()
+    }
+
+    pub(super) fn select_diff_hunk_by(&mut self, delta: isize) {
+        if self.selected_diff_hunk().is_none() {
+            self.show_info_toast("no hunks in diff");
+            return;
+        }
+        let last = self.diff_review.hunks.len().saturating_sub(1);
+        let current = self.diff_review.selected_hunk.unwrap_or(0);
+        let next = current.saturating_add_signed(delta).min(last);
+        self.diff_review.selected_hunk = Some(next);
+
+        let Some(hunk) = self.diff_review.selected().cloned() else {
+            return;
+        };
+        self.diff_review.selected_file = Some(hunk.path);
+        self.scroll_diff_line_into_view(hunk.start_line);
+    }
+
+    pub(super) fn diff_file_paths(&self) -> Vec<String> {
+        file_tree_rows(&self.diff_review.file_stats)
+            .into_iter()
+            .filter_map(|row| row.path)
+            .collect()
+    }
+
+    pub(super) fn select_diff_file_by(&mut self, delta: isize) {
+        let paths = self.diff_file_paths();
+        if paths.is_empty() {
+            self.show_info_toast("no files in diff");
+            return;
+        }
+        let current = self
+            .diff_review
+            .selected_file
+            .as_ref()
+            .and_then(|selected| paths.iter().position(|path| path == selected));
+        let next = match current {
+            Some(index) => index.saturating_add_signed(delta).min(paths.len() - 1),
+            None if delta < 0 => paths.len() - 1,
+            None => 0,
+        };
+        let path = paths[next].clone();
+        if let Some(index) = self
+            .diff_review
+            .hunks
+            .iter()
+            .position(|hunk| hunk.path == path)
+        {
+            self.diff_review.selected_hunk = Some(index);
+        }
+        if let Some(start_line) = self.diff_review.file_starts.get(&path).copied() {
+            self.scroll_diff_line_into_view(start_line);
+        }
+        self.diff_review.selected_file = Some(path);
+    }
+
+    pub(super) fn toggle_diff_file_tree(&mut self) {
+        self.diff_review.file_tree_visible = !self.diff_review.file_tree_visible;
+    }
+
+    pub(super) fn cycle_diff_mode(&mut self) {
+        self.set_diff_mode(self.diff_review.mode.cycle());
+    }
+
+    pub(super) fn change_diff_commit_count(&mut self, delta: isize) {
+        if !matches!(self.diff_review.mode, DiffMode::LastCommits(_)) {
+            self.show_info_toast("commit count applies to last-commits mode");
+            return;
+        }
+        let mode = self.diff_review.mode.with_commit_delta(delta);
+        if mode != self.diff_review.mode {
+            self.set_diff_mode(mode);
+        }
+    }
+
+    fn set_diff_mode(&mut self, mode: DiffMode) {
+        self.diff_review.mode = mode;
+        self.diff_review.clear_capture();
+        self.telemetry.event_log.log(
+            LogEvent::new("diff_mode", "changed").with_data("mode", Value::from(mode.label())),
+        );
+        self.show_info_toast(format!("diff: {}", mode.label()));
+        self.refresh_diff_capture();
+    }
+
+    fn scroll_diff_line_into_view(&mut self, line: usize) {
+        let viewport_height = self
+            .preview_output_dimensions()
+            .map_or(1, |(_, height)| usize::from(height));
+        let (visible_start, visible_end) = self.preview_visible_range_for_height(viewport_height);
+        if line >= visible_start && line < visible_end {
+            return;
+        }
+        let total_lines = self.preview_line_count();
+        let mut preview_scroll = self.preview_scroll.borrow_mut();
+        preview_scroll.set_external_len(total_lines);
+        let _ = preview_scroll.visible_range(u16::try_from(viewport_height).unwrap_or(u16::MAX));
+        preview_scroll.set_follow(false);
+        preview_scroll.scroll_to(line);
+    }
+
+    pub(super) fn toggle_selected_diff_hunk_staged(&mut self) {
+        if self.diff_review.mode != DiffMode::WorkingTree {
+            self.show_info_toast("hunks can only be staged in working tree mode");
+            return;
+        }
+        if self.diff_review.hunk_action_running {
+            self.show_info_toast("git is still running");
+            return;
+        }
+        let Some(hunk) = self.selected_diff_hunk().cloned() else {
+            self.show_info_toast("no hunk selected");
+            return;
+        };
+        let Some(workspace_path) = self.diff_review.workspace_path.clone() else {
+            return;
+        };
+
+        self.diff_review.hunk_action_running = true;
+        let staged = hunk.section == ChangeSection::Unstaged;
+        let apply = move || {
+            let result = if staged {
+                stage_hunk(
+                    workspace_path.as_path(),
+                    hunk.path.as_str(),
+                    hunk.header.as_str(),
+                )
+            } else {
+                unstage_hunk(
+                    workspace_path.as_path(),
+                    hunk.path.as_str(),
+                    hunk.header.as_str(),
+                )
+            };
+            DiffHunkStageCompletion {
+                path: hunk.path,
+                staged,
+                result,
+            }
+        };
+        if !self.tmux_input.supports_background_launch() {
+            self.handle_diff_hunk_stage_completed(apply());
+            return;
+        }
+        self.queue_cmd(Cmd::task(move || Msg::DiffHunkStageCompleted(apply())));
+    }
+
+    pub(super) fn handle_diff_hunk_stage_completed(&mut self, completion: DiffHunkStageCompletion) {
+        self.diff_review.hunk_action_running = false;
+        let verb = if completion.staged {
+            "staged"
+        } else {
+            "unstaged"
+        };
+        self.telemetry.event_log.log(
+            LogEvent::new("diff_hunk", verb)
+                .with_data("path", Value::from(completion.path.clone()))
+                .with_data("ok", Value::from(completion.result.is_ok())),
+        );
+        match completion.result {
+            Ok(()) => {
+                self.show_success_toast(format!("hunk {verb} in '{}'", completion.path));
+                self.refresh_diff_capture();
+            }
+            Err(error) => self.show_error_toast(format!("hunk {verb} failed: {error}")),
+        }
+    }
+
+    pub(super) fn confirm_discard_selected_diff_hunk(&mut self) {
+        if self.modal_open() {
+            return;
+        }
+        if self.diff_review.mode != DiffMode::WorkingTree {
+            self.show_info_toast("hunks can only be discarded in working tree mode");
+            return;
+        }
+        let Some(hunk) = self.selected_diff_hunk().cloned() else {
+            self.show_info_toast("no hunk selected");
+            return;
+        };
+        if hunk.section == ChangeSection::Staged {
+            self.show_info_toast("unstage the hunk before discarding it");
+            return;
+        }
+        let Some(workspace_path) = self.diff_review.workspace_path.clone() else {
+            return;
+        };
+
+        self.set_confirm_dialog(ConfirmDialogState {
+            action: ConfirmDialogAction::DiscardDiffHunk {
+                workspace_path,
+                path: hunk.path,
+                header: hunk.header,
+            },
+        });
+        self.log_dialog_event_with_fields(
+            "confirm",
+            "dialog_opened",
+            [(
+                "target".to_string(),
+                Value::from("discard_diff_hunk".to_string()),
+            )],
+        );
+    }
+
+    pub(super) fn discard_diff_hunk(
+        &mut self,
+        workspace_path: PathBuf,
+        path: String,
+        header: String,
+    ) {
+        if self.diff_review.hunk_action_running {
+            self.show_info_toast("git is still running");
+            return;
+        }
+
+        self.diff_review.hunk_action_running = true;
+        let discard = move || DiffHunkDiscardCompletion {
+            result: discard_hunk(workspace_path.as_path(), path.as_str(), header.as_str()),
+            workspace_path,
+            path,
+        };
+        if !self.tmux_input.supports_background_launch() {
+            self.handle_diff_hunk_discard_completed(discard());
+            return;
+        }
+        self.queue_cmd(Cmd::task(move || Msg::DiffHunkDiscardCompleted(discard())));
+    }
+
+    pub(super) fn handle_diff_hunk_discard_completed(
+        &mut self,
+        completion: DiffHunkDiscardCompletion,
+    ) {
+        self.diff_review.hunk_action_running = false;
+        match completion.result {
+            Ok(saved_path) => {
+                let saved_label = saved_path
+                    .strip_prefix(completion.workspace_path.as_path())
+                    .unwrap_or(saved_path.as_path())
+                    .display()
+                    .to_string();
+                self.telemetry.event_log.log(
+                    LogEvent::new("diff_hunk", "discarded")
+                        .with_data("path", Value::from(completion.path))
+                        .with_data("saved_path", Value::from(saved_label.clone())),
+                );
+                self.show_success_toast(format!("hunk discarded, saved to {saved_label}"));
+                self.refresh_diff_capture();
+            }
+            Err(error) => self.show_error_toast(format!("hunk discard failed: {error}")),
+        }
+    }
+
+    pub(super) fn send_review_to_agent(&mut self) {
+        let Some(workspace_path) = self
+            .state
+            .selected_workspace()
+            .map(|workspace| workspace.path.clone())
+        else {
+            self.show_info_toast("no workspace selected");
+            return;
+        };
+        let comments_path = self.review_comments_path_for_workspace(workspace_path.as_path());
+        let comments = match load_review_comments(comments_path.as_path())
+            .map(|comments| resolve_review_comments(workspace_path.as_path(), comments))
+            .and_then(|comments| {
+                store_review_comment_resolution(comments_path.as_path(), &comments)
+            }) {
+            Ok(comments) => comments,
+            Err(error) => {
+                self.show_error_toast(format!("review failed: {error}"));
+                return;
+            }
+        };
+        let Some(prompt) = review_prompt(&comments) else {
+            self.show_info_toast("no pending review comments");
+            return;
+        };
+        let Some(session_name) =
+            self.workspace_running_agent_session_for_status_poll(workspace_path.as_path(), None)
+        else {
+            self.show_info_toast("no running agent in this workspace");
+            return;
+        };
+
+        if let Err(error) = self
+            .tmux_input
+            .paste_and_submit(session_name.as_str(), prompt.as_str())
+        {
+            let message = error.to_string();
+            self.log_tmux_error(message.clone());
+            self.show_error_toast(format!("send review failed: {message}"));
+            return;
+        }
+
+        let pending = comments.iter().filter(|comment| !comment.resolved).count();
+        self.telemetry.event_log.log(
+            LogEvent::new("diff_review", "sent")
+                .with_data("comments", Value::from(usize_to_u64(pending))),
+        );
+        let _ = self.select_tab_for_session_in_selected_workspace(
+            workspace_path.as_path(),
+            session_name.as_str(),
+        );
+        let noun = if pending == 1 { "comment" } else { "comments" };
+        self.show_success_toast(format!("sent {pending} review {noun} to agent"));
+    }
+
+    pub(super) fn refresh_diff_capture(&mut self) {
+        self.polling.last_diff_poll_at = None;
+        self.polling.last_diff_stat_poll_at = None;
+        self.poll_diff_for_selected_workspace();
+    }
+}
@@ -114,6 +114,9 @@ impl GroveApp {
                 searchable_terms.push(term.to_string());
             }
         }
+        if let Some(task) = self.workspace_jump_task(workspace) {
+            searchable_terms.extend(task.tags.iter().map(|tag| format!("#{tag}")));
+        }
 
         if !searchable_terms.is_empty() {
             title.push_str(" · ");
@@ -153,11 +156,15 @@ impl GroveApp {
             action_targets.insert(id.clone(), workspace.path.clone());
             let title = self.workspace_jump_visible_title(workspace);
             let description = self.workspace_jump_visible_description(workspace);
+            let tags = self
+                .workspace_jump_task(workspace)
+                .map(|task| task.tags.iter().map(String::as_str).collect::<Vec<&str>>())
+                .unwrap_or_default();
             actions.push(Self::palette_action(
                 id,
                 title,
                 description,
-                &[],
+                tags.as_slice(),
                 "Workspace",
             ));
         }
@@ -236,7 +243,7 @@ impl GroveApp {
         self.open_shared_palette(PaletteMode::WorkspaceJump, actions);
     }
 
-    fn palette_command_enabled(&self, command: UiCommand) -> bool {
+    pub(super) fn palette_command_enabled(&self, command: UiCommand) -> bool {
         if command.palette_spec().is_none() {
             return false;
         }
@@ -254,6 +261,7 @@ impl GroveApp {
             | UiCommand::ToggleMouseCapture
             | UiCommand::ToggleUnsafe
             | UiCommand::CleanupSessions
+            | UiCommand::CleanupWorktrees
             | UiCommand::OpenHelp
             | UiCommand::Quit => true,
             UiCommand::OpenPreview => self.workspace_list_focused(),
@@ -297,9 +305,41 @@ impl GroveApp {
                     && !self.dialogs.restart_in_flight
                     && self.selected_home_tab_targets_task_root()
             }
-            UiCommand::OpenShellTab => self.state.selected_workspace().is_some(),
+            UiCommand::OpenShellTab | UiCommand::OpenProjectTabs => {
+                self.state.selected_workspace().is_some()
+            }
             UiCommand::OpenGitTab => self.state.selected_workspace().is_some(),
+            UiCommand::TogglePreviewSplit => {
+                self.preview_split.is_some() || self.state.selected_workspace().is_some()
+            }
+            UiCommand::RotatePreviewSplit | UiCommand::FocusOtherPreviewHalf => {
+                self.preview_split.is_some()
+            }
+            UiCommand::ToggleDashboard => !self.dashboard_tiles().is_empty(),
+            UiCommand::FilterSidebar
+            | UiCommand::CycleSidebarGroup
+            | UiCommand::CycleSidebarSort => !self.projects.is_empty(),
+            UiCommand::EditTaskNotes => self.state.selected_task().is_some(),
             UiCommand::OpenDiffTab => self.state.selected_workspace().is_some(),
+            UiCommand::CommitChanges => self.state.selected_workspace().is_some(),
+            UiCommand::NextDiffHunk
+            | UiCommand::PreviousDiffHunk
+            | UiCommand::ToggleDiffHunkStaged
+            | UiCommand::DiscardDiffHunk => self.selected_diff_hunk().is_some(),
+            UiCommand::CycleDiffMode | UiCommand::ToggleDiffFileTree => {
+                self.preview_tab == PreviewTab::Diff && self.state.selected_workspace().is_some()
+            }
+            UiCommand::MoreDiffCommits | UiCommand::FewerDiffCommits => {
+                self.preview_tab == PreviewTab::Diff
+                    && matches!(self.diff_review.mode, DiffMode::LastCommits(_))
+            }
+            UiCommand::AddReviewComment => self.selected_diff_hunk().is_some(),
+            UiCommand::SendReview => {
+                self.diff_review_matches_selection() && self.diff_review.pending_comment_count() > 0
+            }
+            UiCommand::NextDiffFile | UiCommand::PreviousDiffFile => {
+                self.preview_tab == PreviewTab::Diff && !self.diff_review.file_stats.is_empty()
+            }
             UiCommand::RenameActiveTab => self
                 .selected_active_tab()
                 .is_some_and(|tab| tab.kind != WorkspaceTabKind::Home),
@@ -9,7 +9,7 @@ struct SessionLaunchCompletionContext {
 
 impl GroveApp {
     pub(super) fn preview_output_dimensions(&self) -> Option<(u16, u16)> {
-        let (_, _, preview_rect) = self.effective_workspace_rects();
+        let preview_rect = self.live_preview_rect();
         if preview_rect.is_empty() {
             return None;
         }
@@ -253,7 +253,7 @@ impl GroveApp {
             workspace,
             session_name.clone(),
             command,
-            self.theme_name,
+            self.theme_selection(),
             workspace_init_command,
             Some(capture_cols),
             Some(capture_rows),
@@ -0,0 +1,207 @@
+use super::panes::{PreviewHalf, PreviewSplitAxis};
+use super::update_prelude::*;
+
+impl GroveApp {
+    pub(super) fn toggle_preview_split(&mut self) {
+        if self.preview_split.take().is_some() {
+            self.show_info_toast("split preview closed");
+            return;
+        }
+        let Some(mut pinned) = self.pinned_preview_from_selection() else {
+            return;
+        };
+        pinned.preview = self.preview.clone();
+        let label = format!("{} · {}", pinned.workspace_name, pinned.tab_title);
+        self.preview_split = Some(PreviewSplitState {
+            axis: PreviewSplitAxis::Vertical,
+            focused_half: PreviewHalf::First,
+            pinned,
+        });
+        self.poll_pinned_preview();
+        self.show_info_toast(format!("pinned '{label}', pick another tab or task"));
+    }
+
+    pub(super) fn rotate_preview_split(&mut self) {
+        let Some(split) = self.preview_split.as_mut() else {
+            return;
+        };
+        split.axis = split.axis.toggled();
+        let label = split.axis.label();
+        self.show_info_toast(format!("split preview {label}"));
+    }
+
+    /// Swap the live and pinned sessions so input and scrolling follow the
+    /// other half. The halves keep their place on screen.
+    pub(super) fn focus_other_preview_half(&mut self) {
+        let Some(target_path) = self
+            .preview_split
+            .as_ref()
+            .map(|split| split.pinned.workspace_path.clone())
+        else {
+            return;
+        };
+        if !self
+            .state
+            .workspaces
+            .iter()
+            .any(|workspace| workspace.path == target_path)
+        {
+            self.preview_split = None;
+            self.show_info_toast("pinned task is gone, split preview closed");
+            return;
+        }
+        let Some(mut next_pinned) = self.pinned_preview_from_selection() else {
+            return;
+        };
+        next_pinned.scroll_from_bottom = self.live_preview_scroll_from_bottom();
+        if self.session.interactive.is_some() {
+            self.exit_interactive_to_preview();
+        }
+        next_pinned.preview = std::mem::take(&mut self.preview);
+        let Some(split) = self.preview_split.as_mut() else {
+            return;
+        };
+        let pinned = std::mem::replace(&mut split.pinned, next_pinned);
+        split.focused_half = split.focused_half.other();
+
+        self.preview = pinned.preview;
+        self.select_workspace_by_path(pinned.workspace_path.as_path());
+        let _ = self.select_tab_by_id_for_selected_workspace(pinned.tab_id);
+        self.jump_preview_to_bottom();
+        if pinned.scroll_from_bottom > 0 {
+            self.scroll_preview(-i32::try_from(pinned.scroll_from_bottom).unwrap_or(i32::MAX));
+        }
+        let _ = self.focus_main_pane(FOCUS_ID_PREVIEW);
+    }
+
+    pub(super) fn scroll_pinned_preview(&mut self, delta: i32) {
+        let height = self.pinned_preview_height();
+        let Some(split) = self.preview_split.as_mut() else {
+            return;
+        };
+        let pinned = &mut split.pinned;
+        let max_scroll = pinned
+            .preview
+            .active_parsed_lines()
+            .len()
+            .saturating_sub(height);
+        let lines = usize::try_from(delta.unsigned_abs()).unwrap_or(usize::MAX);
+        let scrolled = if delta < 0 {
+            pinned.scroll_from_bottom.saturating_add(lines)
+        } else {
+            pinned.scroll_from_bottom.saturating_sub(lines)
+        };
+        pinned.scroll_from_bottom = scrolled.min(max_scroll);
+    }
+
+    pub(super) fn poll_pinned_preview(&mut self) {
+        let Some(pinned) = self.preview_split.as_ref().map(|split| &split.pinned) else {
+            return;
+        };
+        if pinned.capture_in_flight {
+            return;
+        }
+        let Some(session_name) = pinned.session_name.clone() else {
+            return;
+        };
+
+        if !self.tmux_input.supports_background_poll() {
+            let capture_started_at = Instant::now();
+            let result = self
+                .tmux_input
+                .capture_output(&session_name, LIVE_PREVIEW_SCROLLBACK_LINES, true)
+                .map_err(|error| error.to_string());
+            let capture_ms =
+                Self::duration_millis(Instant::now().saturating_duration_since(capture_started_at));
+            self.handle_pinned_preview_captured(LivePreviewCapture {
+                session: session_name,
+                scrollback_lines: LIVE_PREVIEW_SCROLLBACK_LINES,
+                include_escape_sequences: true,
+                capture_ms,
+                total_ms: capture_ms,
+                result,
+            });
+            return;
+        }
+
+        if let Some(split) = self.preview_split.as_mut() {
+            split.pinned.capture_in_flight = true;
+        }
+        self.queue_cmd(Cmd::task(move || {
+            let capture_started_at = Instant::now();
+            let result = CommandTmuxInput::capture_session_output(
+                &session_name,
+                LIVE_PREVIEW_SCROLLBACK_LINES,
+                true,
+            )
+            .map_err(|error| error.to_string());
+            let capture_ms = GroveApp::duration_millis(
+                Instant::now().saturating_duration_since(capture_started_at),
+            );
+            Msg::PinnedPreviewCaptured(LivePreviewCapture {
+                session: session_name,
+                scrollback_lines: LIVE_PREVIEW_SCROLLBACK_LINES,
+                include_escape_sequences: true,
+                capture_ms,
+                total_ms: capture_ms,
+                result,
+            })
+        }));
+    }
+
+    pub(super) fn handle_pinned_preview_captured(&mut self, capture: LivePreviewCapture) {
+        let Some(split) = self.preview_split.as_mut() else {
+            return;
+        };
+        let pinned = &mut split.pinned;
+        if pinned.session_name.as_deref() != Some(capture.session.as_str()) {
+            return;
+        }
+        pinned.capture_in_flight = false;
+        match capture.result {
+            Ok(output) => {
+                let _ = pinned.preview.apply_capture(&output);
+            }
+            Err(error) => {
+                if tmux_capture_error_indicates_missing_session(&error) {
+                    pinned.session_name = None;
+                } else {
+                    self.log_tmux_error(error);
+                }
+            }
+        }
+    }
+
+    fn pinned_preview_from_selection(&self) -> Option<PinnedPreview> {
+        let workspace = self.state.selected_workspace()?;
+        let tab = self.selected_active_tab()?;
+        Some(PinnedPreview {
+            workspace_path: workspace.path.clone(),
+            workspace_name: Self::workspace_display_name(workspace),
+            tab_id: tab.id,
+            tab_title: tab.title.clone(),
+            session_name: self.selected_live_preview_session_if_ready(),
+            preview: PreviewState::new(),
+            scroll_from_bottom: 0,
+            capture_in_flight: false,
+        })
+    }
+
+    fn live_preview_scroll_from_bottom(&self) -> usize {
+        let height = self
+            .preview_output_dimensions()
+            .map_or(1, |(_, height)| usize::from(height));
+        let (_, end) = self.preview_visible_range_for_height(height);
+        self.preview_line_count().saturating_sub(end)
+    }
+
+    fn pinned_preview_height(&self) -> usize {
+        let (_, _, preview_rect) = self.effective_workspace_rects();
+        self.split_preview_area(preview_rect)
+            .1
+            .map_or(1, |rect| {
+                usize::from(Block::new().borders(Borders::ALL).inner(rect).height)
+            })
+            .max(1)
+    }
+}
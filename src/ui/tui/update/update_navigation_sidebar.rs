@@ -0,0 +1,315 @@
+use std::cmp::Ordering;
+
+use super::update_prelude::*;
+use crate::domain::PullRequestStatus;
+use crate::infrastructure::config::{SidebarGroupMode, SidebarSortMode};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum SidebarSectionHeader {
+    Task(usize),
+    Project(String),
+    Status(WorkspaceStatus),
+}
+
+/// One headed group of workspace rows, in display order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct SidebarSection {
+    pub(super) header: SidebarSectionHeader,
+    pub(super) workspace_indices: Vec<usize>,
+}
+
+impl GroveApp {
+    pub(super) fn sidebar_filter_active(&self) -> bool {
+        !self.sidebar_filter.query.trim().is_empty()
+    }
+
+    pub(super) fn sidebar_filter_editing(&self) -> bool {
+        self.sidebar_filter.editing
+    }
+
+    pub(super) fn sidebar_filter_bar_visible(&self) -> bool {
+        self.sidebar_filter.editing || !self.sidebar_filter.query.is_empty()
+    }
+
+    pub(super) fn open_sidebar_filter(&mut self) {
+        if self.modal_open() || self.task_reorder_active() || self.dashboard_active() {
+            return;
+        }
+        if self.session.interactive.is_some() {
+            self.exit_interactive_to_list();
+        }
+        if self.sidebar_hidden {
+            self.sidebar_hidden = false;
+            self.sync_main_focus_nodes();
+        }
+        let _ = self.focus_main_pane(FOCUS_ID_WORKSPACE_LIST);
+        self.sidebar_filter.editing = true;
+    }
+
+    pub(super) fn clear_sidebar_filter(&mut self) {
+        self.sidebar_filter = SidebarFilterState::default();
+        self.ensure_sidebar_selection_visible();
+    }
+
+    pub(super) fn handle_sidebar_filter_key(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            KeyCode::Escape => self.clear_sidebar_filter(),
+            KeyCode::Enter => self.sidebar_filter.editing = false,
+            KeyCode::Up | KeyCode::BackTab => self.move_selection(Action::MoveSelectionUp),
+            KeyCode::Down | KeyCode::Tab => self.move_selection(Action::MoveSelectionDown),
+            KeyCode::Backspace => {
+                self.sidebar_filter.query.pop();
+                self.ensure_sidebar_selection_visible();
+            }
+            KeyCode::Char(character)
+                if !character.is_control()
+                    && Self::allows_text_input_modifiers(key_event.modifiers) =>
+            {
+                self.sidebar_filter.query.push(character);
+                self.ensure_sidebar_selection_visible();
+            }
+            _ => {}
+        }
+    }
+
+    pub(super) fn cycle_sidebar_group_mode(&mut self) {
+        self.sidebar_view.group = self.sidebar_view.group.next();
+        self.save_sidebar_view(format!(
+            "sidebar grouped by {}",
+            self.sidebar_view.group.label()
+        ));
+    }
+
+    pub(super) fn cycle_sidebar_sort_mode(&mut self) {
+        self.sidebar_view.sort = self.sidebar_view.sort.next();
+        self.save_sidebar_view(format!(
+            "sidebar sorted by {}",
+            self.sidebar_view.sort.label()
+        ));
+    }
+
+    fn save_sidebar_view(&mut self, message: String) {
+        if let Err(error) = self.save_projects_config() {
+            self.show_error_toast(format!("sidebar view save failed: {error}"));
+            return;
+        }
+        self.show_info_toast(message);
+    }
+
+    /// Moves the selection onto the first visible workspace when the filter
+    /// hides the current one.
+    pub(super) fn ensure_sidebar_selection_visible(&mut self) {
+        let row_map = self.sidebar_selectable_row_map();
+        let current_target = self
+            .selected_attention_item
+            .map(SidebarSelectable::Attention)
+            .unwrap_or(SidebarSelectable::Workspace(self.state.selected_index));
+        if row_map.contains(&Some(current_target)) {
+            return;
+        }
+        let fallback = row_map
+            .iter()
+            .flatten()
+            .find(|target| matches!(target, SidebarSelectable::Workspace(_)))
+            .or_else(|| row_map.iter().flatten().next())
+            .copied();
+        if let Some(target) = fallback {
+            self.select_sidebar_target(target);
+        }
+    }
+
+    /// Groups and orders the visible workspaces for the sidebar. Task reorder
+    /// mode always shows tasks in their manual order so moves stay visible.
+    pub(super) fn sidebar_sections(&self) -> Vec<SidebarSection> {
+        let (group, sort) = if self.task_reorder_active() {
+            (SidebarGroupMode::None, SidebarSortMode::Manual)
+        } else {
+            (self.sidebar_view.group, self.sidebar_view.sort)
+        };
+        let filter_active = self.sidebar_filter_active();
+
+        let mut task_sections = Vec::new();
+        let mut workspace_index = 0usize;
+        for (task_index, task) in self.state.tasks.iter().enumerate() {
+            let mut workspace_indices = Vec::new();
+            for _worktree in &task.worktrees {
+                if let Some(workspace) = self.state.workspaces.get(workspace_index)
+                    && self.workspace_matches_sidebar_filter(task, workspace)
+                {
+                    workspace_indices.push(workspace_index);
+                }
+                workspace_index = workspace_index.saturating_add(1);
+            }
+            if filter_active && workspace_indices.is_empty() {
+                continue;
+            }
+            task_sections.push(SidebarSection {
+                header: SidebarSectionHeader::Task(task_index),
+                workspace_indices,
+            });
+        }
+
+        let mut sections = match group {
+            SidebarGroupMode::None => task_sections,
+            SidebarGroupMode::Project | SidebarGroupMode::Status => {
+                let mut grouped: Vec<SidebarSection> = Vec::new();
+                for workspace_index in task_sections
+                    .into_iter()
+                    .flat_map(|section| section.workspace_indices)
+                {
+                    let Some(workspace) = self.state.workspaces.get(workspace_index) else {
+                        continue;
+                    };
+                    let header = if group == SidebarGroupMode::Project {
+                        SidebarSectionHeader::Project(
+                            workspace
+                                .project_name
+                                .clone()
+                                .unwrap_or_else(|| "(no project)".to_string()),
+                        )
+                    } else {
+                        SidebarSectionHeader::Status(self.sidebar_effective_status(workspace))
+                    };
+                    match grouped.iter_mut().find(|section| section.header == header) {
+                        Some(section) => section.workspace_indices.push(workspace_index),
+                        None => grouped.push(SidebarSection {
+                            header,
+                            workspace_indices: vec![workspace_index],
+                        }),
+                    }
+                }
+                grouped
+            }
+        };
+
+        for section in &mut sections {
+            section
+                .workspace_indices
+                .sort_by(|left, right| self.compare_sidebar_workspaces(sort, *left, *right));
+        }
+        sections.sort_by(|left, right| {
+            let header_order = match (&left.header, &right.header) {
+                (SidebarSectionHeader::Status(left), SidebarSectionHeader::Status(right)) => {
+                    sidebar_status_urgency(*left).cmp(&sidebar_status_urgency(*right))
+                }
+                _ => Ordering::Equal,
+            };
+            header_order.then_with(|| {
+                match (
+                    left.workspace_indices.first(),
+                    right.workspace_indices.first(),
+                ) {
+                    (Some(left), Some(right)) => {
+                        self.compare_sidebar_workspaces(sort, *left, *right)
+                    }
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                }
+            })
+        });
+        sections
+    }
+
+    fn compare_sidebar_workspaces(
+        &self,
+        sort: SidebarSortMode,
+        left: usize,
+        right: usize,
+    ) -> Ordering {
+        let (Some(left), Some(right)) = (
+            self.state.workspaces.get(left),
+            self.state.workspaces.get(right),
+        ) else {
+            return Ordering::Equal;
+        };
+        match sort {
+            SidebarSortMode::Manual => Ordering::Equal,
+            SidebarSortMode::LastActivity => right
+                .last_activity_unix_secs
+                .cmp(&left.last_activity_unix_secs),
+            SidebarSortMode::StatusUrgency => {
+                sidebar_status_urgency(self.sidebar_effective_status(left)).cmp(
+                    &sidebar_status_urgency(self.sidebar_effective_status(right)),
+                )
+            }
+        }
+    }
+
+    /// Workspaces with pending attention sort and group as waiting.
+    fn sidebar_effective_status(&self, workspace: &Workspace) -> WorkspaceStatus {
+        if self.workspace_attention(workspace.path.as_path()).is_some() {
+            return WorkspaceStatus::Waiting;
+        }
+        workspace.status
+    }
+
+    /// Whitespace separated terms must all match. A `name:`, `branch:`,
+    /// `repo:`, `status:`, `pr:` or `tag:` prefix limits a term to that field.
+    fn workspace_matches_sidebar_filter(&self, task: &Task, workspace: &Workspace) -> bool {
+        let query = self.sidebar_filter.query.to_lowercase();
+        query.split_whitespace().all(|term| {
+            let (field, needle) = match term.split_once(':') {
+                Some((field, needle))
+                    if matches!(field, "name" | "branch" | "repo" | "status" | "pr" | "tag") =>
+                {
+                    (Some(field), needle)
+                }
+                _ => (None, term),
+            };
+            let contains = |value: &str| value.to_lowercase().contains(needle);
+            let name_matches = || contains(task.name.as_str()) || contains(workspace.name.as_str());
+            let branch_matches = || contains(workspace.branch.as_str());
+            let repo_matches = || {
+                workspace
+                    .project_name
+                    .as_deref()
+                    .is_some_and(|project_name| contains(project_name))
+            };
+            let status_matches = || contains(self.sidebar_effective_status(workspace).label());
+            let pr_matches = || {
+                workspace.pull_requests.iter().any(|pull_request| {
+                    contains(pull_request_state_label(pull_request.status))
+                        || contains(format!("#{}", pull_request.number).as_str())
+                })
+            };
+            let tag_matches = || task.tags.iter().any(|tag| contains(tag.as_str()));
+            match field {
+                Some("name") => name_matches(),
+                Some("branch") => branch_matches(),
+                Some("repo") => repo_matches(),
+                Some("status") => status_matches(),
+                Some("pr") => pr_matches(),
+                Some("tag") => tag_matches(),
+                _ => {
+                    name_matches()
+                        || branch_matches()
+                        || repo_matches()
+                        || status_matches()
+                        || pr_matches()
+                        || tag_matches()
+                }
+            }
+        })
+    }
+}
+
+fn sidebar_status_urgency(status: WorkspaceStatus) -> u8 {
+    match status {
+        WorkspaceStatus::Waiting => 0,
+        WorkspaceStatus::Error => 1,
+        WorkspaceStatus::Active | WorkspaceStatus::Thinking => 2,
+        WorkspaceStatus::Done => 3,
+        WorkspaceStatus::Idle => 4,
+        WorkspaceStatus::Unknown | WorkspaceStatus::Unsupported => 5,
+        WorkspaceStatus::Main => 6,
+    }
+}
+
+fn pull_request_state_label(status: PullRequestStatus) -> &'static str {
+    match status {
+        PullRequestStatus::Open => "open",
+        PullRequestStatus::Merged => "merged",
+        PullRequestStatus::Closed => "closed",
+    }
+}
@@ -525,6 +525,20 @@ impl GroveApp {
         true
     }
 
+    pub(super) fn select_tab_for_session_in_selected_workspace(
+        &mut self,
+        workspace_path: &Path,
+        session_name: &str,
+    ) -> bool {
+        let tab_id = self.workspace_tabs.get(workspace_path).and_then(|tabs| {
+            tabs.tabs
+                .iter()
+                .find(|tab| tab.session_name.as_deref() == Some(session_name))
+                .map(|tab| tab.id)
+        });
+        tab_id.is_some_and(|tab_id| self.select_tab_by_id_for_selected_workspace(tab_id))
+    }
+
     fn new_session_name_for_tab(
         workspace: &Workspace,
         kind: WorkspaceTabKind,
@@ -880,20 +894,140 @@ impl GroveApp {
             self.show_info_toast("no workspace selected");
             return;
         };
-        let Some(tabs) = self.workspace_tabs.get_mut(workspace.path.as_path()) else {
+        if self.launch_shell_tab(&workspace, None, String::new()) {
+            self.poll_preview();
+        }
+    }
+
+    pub(super) fn open_project_tabs(&mut self) {
+        self.sync_workspace_tab_maps();
+        let Some(workspace) = self.state.selected_workspace().cloned() else {
+            self.show_info_toast("no workspace selected");
+            return;
+        };
+        let Some(project) = self.effective_project_config_for_workspace(&workspace) else {
+            self.show_info_toast("workspace has no configured project");
+            return;
+        };
+        if let Some(error) = project.shared_error {
+            self.show_error_toast(error);
+            return;
+        }
+        if project.has_untrusted_entries() {
+            self.open_trust_shared_commands_dialog(&workspace, project.untrusted_entries());
+            return;
+        }
+        if project.tabs.is_empty() {
+            self.show_info_toast(format!("no tabs defined in {SHARED_CONFIG_FILE}"));
+            return;
+        }
+
+        let open_titles = self
+            .workspace_tabs
+            .get(workspace.path.as_path())
+            .map(|tabs| {
+                tabs.tabs
+                    .iter()
+                    .map(|tab| tab.title.clone())
+                    .collect::<HashSet<String>>()
+            })
+            .unwrap_or_default();
+        let missing = project
+            .tabs
+            .into_iter()
+            .filter(|tab| !open_titles.contains(tab.title.as_str()))
+            .collect::<Vec<SharedTab>>();
+        if missing.is_empty() {
+            self.show_info_toast("project tabs already open");
+            return;
+        }
+        let mut opened = 0usize;
+        for tab in missing {
+            if !self.launch_shell_tab(&workspace, Some(tab.title), tab.command) {
+                break;
+            }
+            opened = opened.saturating_add(1);
+        }
+        if opened > 0 {
+            self.show_success_toast(format!("opened {opened} project tab(s)"));
+            self.poll_preview();
+        }
+    }
+
+    /// `.grove.toml` commands come from whoever committed to the repository,
+    /// so they only run once the user trusts the project.
+    fn open_trust_shared_commands_dialog(&mut self, workspace: &Workspace, entries: Vec<String>) {
+        let Some(project) = workspace.project_path.as_ref().and_then(|project_path| {
+            self.projects
+                .iter()
+                .find(|project| refer_to_same_location(&project.path, project_path))
+        }) else {
+            return;
+        };
+
+        let project_name = project.name.clone();
+        self.set_confirm_dialog(ConfirmDialogState {
+            action: ConfirmDialogAction::TrustSharedCommands {
+                project_path: project.path.clone(),
+                project_name: project_name.clone(),
+                entries,
+            },
+        });
+        self.log_dialog_event_with_fields(
+            "confirm",
+            "dialog_opened",
+            [
+                (
+                    "target".to_string(),
+                    Value::from("trust_shared_commands".to_string()),
+                ),
+                ("project".to_string(), Value::from(project_name)),
+            ],
+        );
+    }
+
+    pub(super) fn trust_project_shared_commands(&mut self, project_path: &Path) {
+        let Some(project) = self
+            .projects
+            .iter_mut()
+            .find(|project| refer_to_same_location(&project.path, project_path))
+        else {
+            self.show_error_toast("project not found");
             return;
         };
+        project.defaults.trust_shared_commands = true;
+        let project_name = project.name.clone();
+        if let Err(error) = self.save_projects_config() {
+            self.show_error_toast(format!("project trust save failed: {error}"));
+            return;
+        }
+
+        self.show_success_toast(format!(
+            "trusted {SHARED_CONFIG_FILE} commands for '{project_name}'"
+        ));
+        self.open_project_tabs();
+    }
+
+    fn launch_shell_tab(
+        &mut self,
+        workspace: &Workspace,
+        title: Option<String>,
+        command: String,
+    ) -> bool {
+        let Some(tabs) = self.workspace_tabs.get_mut(workspace.path.as_path()) else {
+            return false;
+        };
         let ordinal = tabs.next_tab_ordinal(WorkspaceTabKind::Shell);
         let Some(session_name) =
-            Self::new_session_name_for_tab(&workspace, WorkspaceTabKind::Shell, ordinal)
+            Self::new_session_name_for_tab(workspace, WorkspaceTabKind::Shell, ordinal)
         else {
-            return;
+            return false;
         };
         let tab_id = tabs.insert_tab_adjacent(WorkspaceTab {
             id: 0,
             display_order: 0,
             kind: WorkspaceTabKind::Shell,
-            title: format!("Shell {ordinal}"),
+            title: title.unwrap_or_else(|| format!("Shell {ordinal}")),
             session_name: Some(session_name.clone()),
             agent_type: None,
             state: WorkspaceTabRuntimeState::Starting,
@@ -903,12 +1037,12 @@ impl GroveApp {
             .shell_sessions
             .mark_in_flight(session_name.clone());
         let (capture_cols, capture_rows) = self.capture_dimensions();
-        let workspace_init_command = self.workspace_init_command_for_workspace(&workspace);
+        let workspace_init_command = self.workspace_init_command_for_workspace(workspace);
         let request = shell_launch_request_for_workspace(
-            &workspace,
+            workspace,
             session_name.clone(),
-            String::new(),
-            self.theme_name,
+            command,
+            self.theme_selection(),
             workspace_init_command,
             Some(capture_cols),
             Some(capture_rows),
@@ -926,7 +1060,7 @@ impl GroveApp {
             self.set_tab_state_by_id(&workspace.path, tab_id, WorkspaceTabRuntimeState::Failed);
             self.session.last_tmux_error = Some(error.clone());
             self.show_error_toast("shell tab launch failed");
-            return;
+            return false;
         }
         self.session.shell_sessions.mark_ready(session_name);
         self.set_tab_state_by_id(&workspace.path, tab_id, WorkspaceTabRuntimeState::Running);
@@ -939,7 +1073,7 @@ impl GroveApp {
             self.write_tab_tmux_metadata(workspace.path.as_path(), &tab);
         }
         self.session.last_tmux_error = None;
-        self.poll_preview();
+        true
     }
 
     fn agent_env_for_workspace_agent(
@@ -947,25 +1081,10 @@ impl GroveApp {
         workspace: &Workspace,
         agent: AgentType,
     ) -> Result<Vec<(String, String)>, String> {
-        let Some(workspace_project_path) = workspace.project_path.as_ref() else {
-            return Ok(Vec::new());
-        };
-        let Some(project) = self
-            .projects
-            .iter()
-            .find(|project| refer_to_same_location(&project.path, workspace_project_path))
-        else {
+        let Some(project) = self.effective_project_config_for_workspace(workspace) else {
             return Ok(Vec::new());
         };
-        let entries = match agent {
-            AgentType::Claude => &project.defaults.agent_env.claude,
-            AgentType::Codex => &project.defaults.agent_env.codex,
-        };
-        parse_agent_env_vars_from_entries(entries).map(|vars| {
-            vars.into_iter()
-                .map(|entry| (entry.key, entry.value))
-                .collect()
-        })
+        agent_env_pairs(project.agent_env(agent))
     }
 
     pub(super) fn launch_new_agent_tab(
@@ -1015,7 +1134,7 @@ impl GroveApp {
         let mut request = launch_request_for_workspace(
             &launch_workspace,
             options.prompt,
-            self.theme_name,
+            self.theme_selection(),
             options
                 .init_command
                 .or_else(|| self.workspace_init_command_for_workspace(&workspace)),
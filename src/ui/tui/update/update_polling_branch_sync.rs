@@ -0,0 +1,139 @@
+use super::update_prelude::*;
+use crate::application::branch_sync::{
+    fetch_interval_for, fetch_project_remotes, worktree_sync_status,
+};
+
+const BRANCH_SYNC_POLL_INTERVAL_MS: u64 = 30_000;
+
+impl GroveApp {
+    pub(super) fn maybe_poll_branch_sync(&mut self) {
+        if self.polling.branch_sync_in_flight {
+            return;
+        }
+        let now = Instant::now();
+        if let Some(last) = self.polling.last_branch_sync_poll_at
+            && now.saturating_duration_since(last)
+                < Duration::from_millis(BRANCH_SYNC_POLL_INTERVAL_MS)
+        {
+            return;
+        }
+        self.polling.last_branch_sync_poll_at = Some(now);
+        self.schedule_project_fetches(now);
+
+        let targets = self
+            .state
+            .workspaces
+            .iter()
+            .map(|workspace| {
+                let base_branch = if workspace.is_main {
+                    None
+                } else {
+                    workspace.base_branch.clone()
+                };
+                (workspace.path.clone(), base_branch)
+            })
+            .collect::<Vec<(PathBuf, Option<String>)>>();
+        if targets.is_empty() {
+            return;
+        }
+
+        self.polling.branch_sync_in_flight = true;
+        self.queue_cmd(Cmd::task(move || {
+            let statuses = targets
+                .into_iter()
+                .map(|(workspace_path, base_branch)| {
+                    let status =
+                        worktree_sync_status(workspace_path.as_path(), base_branch.as_deref());
+                    (workspace_path, status)
+                })
+                .collect();
+            Msg::BranchSyncCompleted(BranchSyncCompletion { statuses })
+        }));
+    }
+
+    fn schedule_project_fetches(&mut self, now: Instant) {
+        let projects = self
+            .projects
+            .iter()
+            .filter_map(|project| {
+                fetch_interval_for(&project.defaults)
+                    .map(|interval| (project.path.clone(), interval))
+            })
+            .collect::<Vec<(PathBuf, Duration)>>();
+        for project_path in self
+            .polling
+            .fetch_scheduler
+            .take_due(now, projects.as_slice())
+        {
+            self.queue_cmd(Cmd::task(move || {
+                let started_at = Instant::now();
+                let result = fetch_project_remotes(project_path.as_path());
+                let duration_ms =
+                    Self::duration_millis(Instant::now().saturating_duration_since(started_at));
+                Msg::ProjectFetchCompleted(ProjectFetchCompletion {
+                    project_path,
+                    duration_ms,
+                    result,
+                })
+            }));
+        }
+    }
+
+    pub(super) fn handle_branch_sync_completed(&mut self, completion: BranchSyncCompletion) {
+        self.polling.branch_sync_in_flight = false;
+        self.workspace_branch_sync = completion
+            .statuses
+            .into_iter()
+            .filter(|(workspace_path, _)| {
+                self.state
+                    .workspaces
+                    .iter()
+                    .any(|workspace| &workspace.path == workspace_path)
+            })
+            .collect();
+        self.flush_deferred_attention_refresh();
+    }
+
+    pub(super) fn handle_project_fetch_completed(&mut self, completion: ProjectFetchCompletion) {
+        let succeeded = completion.result.is_ok();
+        self.polling.fetch_scheduler.record_result(
+            completion.project_path.as_path(),
+            Instant::now(),
+            succeeded,
+        );
+        let project_path = completion.project_path.to_string_lossy().to_string();
+        match completion.result {
+            Ok(fetched) => {
+                self.telemetry.event_log.log(
+                    LogEvent::new("branch_sync", "fetch_completed")
+                        .with_data("project_path", Value::from(project_path))
+                        .with_data("fetched", Value::from(fetched))
+                        .with_data("duration_ms", Value::from(completion.duration_ms)),
+                );
+                if fetched && !self.polling.branch_sync_in_flight {
+                    self.polling.last_branch_sync_poll_at = None;
+                }
+            }
+            Err(error) => {
+                let failures = self
+                    .polling
+                    .fetch_scheduler
+                    .consecutive_failures(completion.project_path.as_path());
+                self.telemetry.event_log.log(
+                    LogEvent::new("branch_sync", "fetch_failed")
+                        .with_data("project_path", Value::from(project_path))
+                        .with_data("error", Value::from(error))
+                        .with_data("consecutive_failures", Value::from(failures))
+                        .with_data("duration_ms", Value::from(completion.duration_ms)),
+                );
+            }
+        }
+    }
+
+    pub(super) fn branch_sync_for_workspace(
+        &self,
+        workspace_path: &Path,
+    ) -> Option<&WorktreeSyncStatus> {
+        self.workspace_branch_sync.get(workspace_path)
+    }
+}
@@ -3,6 +3,8 @@ use super::update_prelude::*;
 const DIFF_POLL_INTERVAL_FOCUSED_MS: u64 = 2_000;
 const DIFF_POLL_INTERVAL_UNFOCUSED_MS: u64 = 10_000;
 const DIFF_STAT_POLL_INTERVAL_MS: u64 = 5_000;
+const DIFF_STAGED_MARKER: &str = "── staged ──";
+const DIFF_UNSTAGED_MARKER: &str = "── unstaged ──";
 
 fn format_diff_summary(files_changed: usize, insertions: usize, deletions: usize) -> String {
     let file_word = if files_changed == 1 { "file" } else { "files" };
@@ -38,62 +40,137 @@ fn parse_diff_stat_summary(stat_output: &str) -> (usize, usize, usize) {
     (files, insertions, deletions)
 }
 
-fn run_diff_capture(workspace_path: PathBuf) -> DiffCaptureCompletion {
+/// Locates hunks in the rendered Diff tab lines. Everything before the
+/// staged marker, and everything after the unstaged marker, is unstaged.
+fn diff_capture_hunks(lines: &[String]) -> Vec<DiffHunkTarget> {
+    let mut hunks = Vec::new();
+    let mut section = ChangeSection::Unstaged;
+    let mut segment_start = 0usize;
+    for index in 0..=lines.len() {
+        let next_section = match lines.get(index).map(|line| line.trim()) {
+            Some(DIFF_STAGED_MARKER) => ChangeSection::Staged,
+            Some(DIFF_UNSTAGED_MARKER) => ChangeSection::Unstaged,
+            Some(_) => continue,
+            None => section,
+        };
+        for file in parse_diff_files(&lines[segment_start..index]) {
+            for hunk in &file.hunks {
+                hunks.push(DiffHunkTarget {
+                    section,
+                    path: file.path.clone(),
+                    header: hunk.header().to_string(),
+                    start_line: segment_start + hunk.start_line,
+                    end_line: segment_start + hunk.end_line(),
+                    first_changed_line: hunk.first_changed_line(),
+                });
+            }
+        }
+        section = next_section;
+        segment_start = index + 1;
+    }
+    hunks
+}
+
+fn run_diff_capture(
+    workspace_path: PathBuf,
+    mode: DiffMode,
+    base_branch: Option<String>,
+    review_comments: Vec<ReviewComment>,
+) -> DiffCaptureCompletion {
     let started_at = std::time::Instant::now();
+    let range = match diff_mode_range(workspace_path.as_path(), mode, base_branch.as_deref()) {
+        Ok(range) => range,
+        Err(error) => {
+            return DiffCaptureCompletion {
+                workspace_path,
+                mode,
+                capture_ms: 0,
+                result: Err(error),
+                file_stats: Vec::new(),
+                review_comments,
+            };
+        }
+    };
 
-    let (stat_result, diff_result, staged_result) = std::thread::scope(|s| {
+    let (stat_result, diff_result, staged_result, file_stats) = std::thread::scope(|s| {
         let wp = &workspace_path;
+        let range = range.as_deref();
         let stat = s.spawn(move || {
             std::process::Command::new("git")
-                .args(["diff", "HEAD", "--stat"])
+                .args(["diff", range.unwrap_or("HEAD"), "--stat"])
                 .current_dir(wp)
                 .output()
         });
         let diff = s.spawn(move || {
-            std::process::Command::new("git")
-                .args(["diff", "--color=always"])
-                .current_dir(wp)
-                .output()
+            let mut command = std::process::Command::new("git");
+            command.args(["diff", "--color=always"]);
+            if let Some(range) = range {
+                command.arg(range);
+            }
+            command.current_dir(wp).output()
         });
         let staged = s.spawn(move || {
-            std::process::Command::new("git")
-                .args(["diff", "--cached", "--color=always"])
-                .current_dir(wp)
-                .output()
+            if range.is_some() {
+                return None;
+            }
+            Some(
+                std::process::Command::new("git")
+                    .args(["diff", "--cached", "--color=always"])
+                    .current_dir(wp)
+                    .output(),
+            )
         });
+        let file_stats = s.spawn(move || diff_file_stats(wp, range));
         (
             stat.join().unwrap(),
             diff.join().unwrap(),
             staged.join().unwrap(),
+            file_stats.join().unwrap(),
         )
     });
 
+    let review_comments = resolve_review_comments(&workspace_path, review_comments);
+
     let elapsed = std::time::Instant::now().saturating_duration_since(started_at);
     let capture_ms = elapsed.as_millis() as u64;
 
     let build_output = || -> Result<String, String> {
         let stat_output = stat_result.map_err(|e| format!("git diff --stat failed: {e}"))?;
         let diff_output = diff_result.map_err(|e| format!("git diff failed: {e}"))?;
-        let staged_output = staged_result.map_err(|e| format!("git diff --cached failed: {e}"))?;
+        let staged_output = staged_result
+            .transpose()
+            .map_err(|e| format!("git diff --cached failed: {e}"))?;
+        if let Some(range) = range.as_deref()
+            && !diff_output.status.success()
+        {
+            let stderr = String::from_utf8_lossy(&diff_output.stderr);
+            return Err(format!("git diff {range} failed: {}", stderr.trim()));
+        }
 
         let stat_str = String::from_utf8_lossy(&stat_output.stdout);
         let diff_str = String::from_utf8_lossy(&diff_output.stdout);
-        let staged_str = String::from_utf8_lossy(&staged_output.stdout);
+        let staged_str = staged_output
+            .as_ref()
+            .map(|output| String::from_utf8_lossy(&output.stdout))
+            .unwrap_or_default();
 
         let (files, ins, del) = parse_diff_stat_summary(&stat_str);
         let summary = format_diff_summary(files, ins, del);
 
         let mut output = String::new();
+        if let Some(range) = range.as_deref() {
+            output.push_str(&format!("{} ({range}) · ", mode.label()));
+        }
         output.push_str(&summary);
         output.push('\n');
 
         if !staged_str.is_empty() {
-            output.push_str("\n── staged ──\n\n");
+            output.push_str(&format!("\n{DIFF_STAGED_MARKER}\n\n"));
             output.push_str(&staged_str);
         }
         if !diff_str.is_empty() {
             if !staged_str.is_empty() {
-                output.push_str("\n── unstaged ──\n\n");
+                output.push_str(&format!("\n{DIFF_UNSTAGED_MARKER}\n\n"));
             }
             output.push_str(&diff_str);
         }
@@ -105,8 +182,11 @@ fn run_diff_capture(workspace_path: PathBuf) -> DiffCaptureCompletion {
 
     DiffCaptureCompletion {
         workspace_path,
+        mode,
         capture_ms,
         result: build_output(),
+        file_stats: file_stats.unwrap_or_default(),
+        review_comments,
     }
 }
 
@@ -123,8 +203,18 @@ impl GroveApp {
         };
         self.polling.diff_capture_in_flight = true;
         let workspace_path = workspace.path.clone();
+        let base_branch = workspace.base_branch.clone();
+        let mode = self.diff_review.mode;
+        let review_comments =
+            load_review_comments(&self.review_comments_path_for_workspace(&workspace_path))
+                .unwrap_or_default();
         self.queue_cmd(Cmd::task(move || {
-            Msg::DiffCaptureCompleted(run_diff_capture(workspace_path))
+            Msg::DiffCaptureCompleted(run_diff_capture(
+                workspace_path,
+                mode,
+                base_branch,
+                review_comments,
+            ))
         }));
     }
 
@@ -136,23 +226,34 @@ impl GroveApp {
         let Some(workspace) = self.state.selected_workspace() else {
             return;
         };
-        if workspace.path != completion.workspace_path {
+        if workspace.path != completion.workspace_path || completion.mode != self.diff_review.mode {
             return;
         }
+        self.diff_review.file_stats = completion.file_stats;
+        // Resolution is computed off-thread but stored here, so it cannot
+        // race with comments added from the dialog.
+        let comments_path = self.review_comments_path_for_workspace(&completion.workspace_path);
+        self.diff_review.comments =
+            store_review_comment_resolution(&comments_path, &completion.review_comments)
+                .unwrap_or(completion.review_comments);
         match completion.result {
             Ok(ref output) => {
                 self.preview.apply_capture(output);
+                self.refresh_diff_hunks(completion.workspace_path.as_path());
                 let (_, ins, del) = parse_diff_stat_summary(output);
-                if ins > 0 || del > 0 {
-                    self.workspace_diff_stats.insert(
-                        completion.workspace_path.clone(),
-                        DiffStatBadge {
-                            insertions: ins,
-                            deletions: del,
-                        },
-                    );
-                } else {
-                    self.workspace_diff_stats.remove(&completion.workspace_path);
+                // Branch and commit modes do not describe uncommitted work.
+                if completion.mode == DiffMode::WorkingTree {
+                    if ins > 0 || del > 0 {
+                        self.workspace_diff_stats.insert(
+                            completion.workspace_path.clone(),
+                            DiffStatBadge {
+                                insertions: ins,
+                                deletions: del,
+                            },
+                        );
+                    } else {
+                        self.workspace_diff_stats.remove(&completion.workspace_path);
+                    }
                 }
                 self.telemetry.event_log.log(
                     LogEvent::new("diff_poll", "capture_completed")
@@ -167,6 +268,7 @@ impl GroveApp {
             Err(error) => {
                 self.preview
                     .apply_capture(&format!("(diff capture failed: {error})"));
+                self.refresh_diff_hunks(completion.workspace_path.as_path());
                 self.telemetry.event_log.log(
                     LogEvent::new("diff_poll", "capture_failed")
                         .with_data("error", Value::from(error))
@@ -176,6 +278,62 @@ impl GroveApp {
         }
     }
 
+    fn refresh_diff_hunks(&mut self, workspace_path: &Path) {
+        let hunks = diff_capture_hunks(self.preview.active_plain_lines());
+        let same_workspace = self.diff_review.workspace_path.as_deref() == Some(workspace_path);
+        let previous = same_workspace
+            .then(|| self.diff_review.selected().cloned())
+            .flatten();
+        let previous_index = self.diff_review.selected_hunk.filter(|_| same_workspace);
+        let selected_hunk = previous
+            .and_then(|previous| {
+                hunks.iter().position(|hunk| {
+                    hunk.section == previous.section
+                        && hunk.path == previous.path
+                        && hunk_range(hunk.header.as_str()) == hunk_range(previous.header.as_str())
+                })
+            })
+            .or_else(|| {
+                (!hunks.is_empty()).then(|| {
+                    previous_index
+                        .unwrap_or(0)
+                        .min(hunks.len().saturating_sub(1))
+                })
+            });
+        let mut file_starts = HashMap::new();
+        let mut comment_lines = HashMap::new();
+        for file in parse_diff_files(self.preview.active_plain_lines()) {
+            for hunk in &file.hunks {
+                for (offset, new_line) in hunk.new_line_numbers().into_iter().enumerate() {
+                    let Some(new_line) = new_line else {
+                        continue;
+                    };
+                    if let Some(index) = self.diff_review.comments.iter().position(|comment| {
+                        !comment.resolved && comment.path == file.path && comment.line == new_line
+                    }) {
+                        comment_lines.insert(hunk.start_line + offset, index);
+                    }
+                }
+            }
+            file_starts.entry(file.path).or_insert(file.start_line);
+        }
+        let previous_file = self
+            .diff_review
+            .selected_file
+            .take()
+            .filter(|path| same_workspace && file_starts.contains_key(path));
+        self.diff_review.selected_file = previous_file.or_else(|| {
+            selected_hunk
+                .and_then(|index| hunks.get(index))
+                .map(|hunk| hunk.path.clone())
+        });
+        self.diff_review.workspace_path = Some(workspace_path.to_path_buf());
+        self.diff_review.hunks = hunks;
+        self.diff_review.selected_hunk = selected_hunk;
+        self.diff_review.file_starts = file_starts;
+        self.diff_review.comment_lines = comment_lines;
+    }
+
     pub(super) fn handle_diff_stat_completed(&mut self, completion: DiffStatCompletion) {
         self.polling.diff_stat_in_flight = false;
         if completion.insertions > 0 || completion.deletions > 0 {
@@ -314,6 +472,42 @@ mod tests {
         assert_eq!((files, ins, del), (1, 5, 0));
     }
 
+    #[test]
+    fn diff_capture_hunks_tracks_staged_and_unstaged_sections() {
+        let lines = [
+            "2 files changed, +2 -0",
+            "",
+            DIFF_STAGED_MARKER,
+            "",
+            "diff --git a/a.rs b/a.rs",
+            "--- a/a.rs",
+            "+++ b/a.rs",
+            "@@ -1 +1,2 @@",
+            " a",
+            "+b",
+            "",
+            DIFF_UNSTAGED_MARKER,
+            "",
+            "diff --git a/c.rs b/c.rs",
+            "--- a/c.rs",
+            "+++ b/c.rs",
+            "@@ -3,0 +4 @@ fn c() {",
+            "+d",
+        ]
+        .map(str::to_string);
+
+        let hunks = diff_capture_hunks(&lines);
+        assert_eq!(hunks.len(), 2);
+        assert_eq!(hunks[0].section, ChangeSection::Staged);
+        assert_eq!(hunks[0].path, "a.rs");
+        assert_eq!((hunks[0].start_line, hunks[0].end_line), (7, 10));
+        assert_eq!(hunks[0].first_changed_line, Some(2));
+        assert_eq!(hunks[1].section, ChangeSection::Unstaged);
+        assert_eq!(hunks[1].header, "@@ -3,0 +4 @@ fn c() {");
+        assert_eq!((hunks[1].start_line, hunks[1].end_line), (16, 18));
+        assert_eq!(hunks[1].first_changed_line, Some(4));
+    }
+
     #[test]
     fn parse_diff_stat_summary_deletions_only() {
         let stat = " 2 files changed, 8 deletions(-)";
@@ -80,6 +80,7 @@ impl GroveApp {
             self.polling.last_diff_poll_at = None;
         }
         self.sync_preview_stream_target();
+        self.poll_pinned_preview();
         if !self.tmux_input.supports_background_poll() {
             self.poll_preview_sync();
             return;
@@ -78,6 +78,10 @@ impl GroveApp {
                         changed,
                     )
                     .unwrap_or(next_status);
+                self.record_workspace_output_tail(
+                    workspace_path.as_path(),
+                    cleaned_output.as_str(),
+                );
                 let workspace = &mut self.state.workspaces[workspace_index];
                 workspace.status = next_status;
                 workspace.is_orphaned = false;
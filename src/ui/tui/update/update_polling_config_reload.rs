@@ -0,0 +1,147 @@
+use super::update_prelude::*;
+use crate::infrastructure::config::{
+    load_global_from_path, load_projects_from_path, projects_path_for,
+};
+use crate::ui::mouse::clamp_sidebar_ratio;
+
+const CONFIG_RELOAD_POLL_INTERVAL_MS: u64 = 1_000;
+
+fn modified_at(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+impl ConfigReloadState {
+    pub(super) fn capture(config_path: &Path, settings: GlobalSettings) -> Self {
+        Self {
+            config_modified_at: modified_at(config_path),
+            projects_modified_at: modified_at(projects_path_for(config_path).as_path()),
+            settings,
+        }
+    }
+}
+
+impl GroveApp {
+    pub(super) fn maybe_poll_config_reload(&mut self) {
+        let now = Instant::now();
+        if let Some(last) = self.polling.last_config_reload_poll_at
+            && now.saturating_duration_since(last)
+                < Duration::from_millis(CONFIG_RELOAD_POLL_INTERVAL_MS)
+        {
+            return;
+        }
+        self.polling.last_config_reload_poll_at = Some(now);
+        self.reload_config_if_changed();
+    }
+
+    /// Re-reads `config.toml` and `projects.toml` when either file's mtime
+    /// moved and applies what changed. Each file is handled on its own: one
+    /// that fails to parse keeps its previous values without blocking the
+    /// other, and is retried once its mtime moves again.
+    pub(super) fn reload_config_if_changed(&mut self) {
+        let projects_path = projects_path_for(&self.config_path);
+        let config_modified_at = modified_at(&self.config_path);
+        let projects_modified_at = modified_at(projects_path.as_path());
+        let config_changed = config_modified_at != self.polling.config_reload.config_modified_at;
+        let projects_changed =
+            projects_modified_at != self.polling.config_reload.projects_modified_at;
+        if !config_changed && !projects_changed {
+            return;
+        }
+
+        let mut applied = Vec::new();
+        if config_changed {
+            match load_global_from_path(&self.config_path) {
+                Ok(settings) => applied.extend(self.apply_reloaded_global_settings(settings)),
+                Err(error) => {
+                    self.show_error_toast(format!(
+                        "config reload failed, keeping previous: {error}"
+                    ));
+                }
+            }
+            self.polling.config_reload.config_modified_at = config_modified_at;
+        }
+        if projects_changed {
+            match load_projects_from_path(projects_path.as_path()) {
+                Ok(state) => {
+                    if self.apply_reloaded_projects(state.projects) {
+                        applied.push("projects");
+                    }
+                }
+                Err(error) => {
+                    self.show_error_toast(format!(
+                        "projects reload failed, keeping previous: {error}"
+                    ));
+                }
+            }
+            self.polling.config_reload.projects_modified_at = projects_modified_at;
+        }
+        self.log_config_migrations();
+        if applied.is_empty() {
+            return;
+        }
+
+        self.telemetry.event_log.log(
+            LogEvent::new("config", "reloaded")
+                .with_data("changed", Value::from(applied.join(","))),
+        );
+        self.show_success_toast(format!("config reloaded: {}", applied.join(", ")));
+    }
+
+    fn apply_reloaded_global_settings(&mut self, settings: GlobalSettings) -> Vec<&'static str> {
+        let previous =
+            std::mem::replace(&mut self.polling.config_reload.settings, settings.clone());
+        let mut applied = Vec::new();
+        let previous_selection = self.theme_selection();
+        self.custom_themes = settings.custom_themes.clone();
+        if settings.theme != previous.theme {
+            self.theme_name = settings.theme.clone();
+        }
+        if self.theme_selection() != previous_selection {
+            let theme_name = self.theme_name.clone();
+            if let Err(error) = self.retheme_grove_tmux_sessions(&theme_name) {
+                self.session.last_tmux_error = Some(format!("tmux retheme failed: {error}"));
+            }
+            applied.push("theme");
+        }
+        let ratio = clamp_sidebar_ratio(settings.sidebar_width_pct);
+        if settings.sidebar_width_pct != previous.sidebar_width_pct
+            && ratio != self.sidebar_width_pct
+        {
+            self.sidebar_width_pct = ratio;
+            let _ = self.panes.set_sidebar_ratio_pct(ratio);
+            self.sync_main_focus_nodes();
+            self.sync_interactive_session_geometry();
+            applied.push("sidebar width");
+        }
+        if settings.launch_permission_mode != previous.launch_permission_mode
+            && settings.launch_permission_mode != self.launch_permission_mode
+        {
+            self.launch_permission_mode = settings.launch_permission_mode;
+            applied.push("launch permission mode");
+        }
+        if settings.keys != previous.keys {
+            self.apply_keys_config(&settings.keys);
+            applied.push("keys");
+        }
+        applied
+    }
+
+    fn apply_reloaded_projects(&mut self, projects: Vec<ProjectConfig>) -> bool {
+        if projects == self.projects {
+            return false;
+        }
+        let same_paths = projects.len() == self.projects.len()
+            && projects
+                .iter()
+                .zip(self.projects.iter())
+                .all(|(next, current)| refer_to_same_location(&next.path, &current.path));
+        self.projects = projects;
+        self.refresh_project_dialog_filtered();
+        if !same_paths {
+            self.refresh_workspaces(None);
+        }
+        true
+    }
+}
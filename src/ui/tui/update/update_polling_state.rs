@@ -1,9 +1,9 @@
 use super::update_prelude::*;
 
 impl GroveApp {
-    const STALLED_IDLE_POLLS_TO_ATTENTION: u8 = 6;
     const ATTENTION_PROMOTION_POLLS: u8 = 2;
     const ATTENTION_REMOVAL_POLLS: u8 = 3;
+    pub(super) const BEHIND_BASE_COMMITS_TO_ATTENTION: usize = 50;
 
     fn workspace_has_running_agent_tab(&self, workspace_path: &Path) -> bool {
         self.workspace_tabs.get(workspace_path).is_some_and(|tabs| {
@@ -33,94 +33,88 @@ impl GroveApp {
         latest_assistant_attention_marker(workspace.agent, workspace.path.as_path())
     }
 
-    fn permission_wall_prompt(prompt: &str) -> bool {
-        let lower = prompt.to_ascii_lowercase();
-        ["approve", "allow", "confirm", "do you want"]
-            .iter()
-            .any(|pattern| lower.contains(pattern))
-    }
-
     fn attention_item_for_workspace(
         &self,
         workspace: &Workspace,
         now_ms: u64,
     ) -> Option<AttentionItem> {
-        if workspace.is_orphaned && !workspace.is_main {
-            return Some(AttentionItem {
-                fingerprint: format!("session-ended:{}", workspace.path.display()),
-                reason: AttentionReason::SessionEnded,
-                summary: AttentionReason::SessionEnded.summary().to_string(),
-                workspace_path: workspace.path.clone(),
-                task_slug: workspace.task_slug.clone().unwrap_or_default(),
-                first_seen_at_ms: now_ms,
-                last_seen_at_ms: now_ms,
-            });
-        }
-
-        if workspace.status == WorkspaceStatus::Waiting
-            && let Some(marker) =
-                self.current_attention_marker_for_workspace_path(workspace.path.as_path())
-        {
-            let waiting_prompt = self
-                .polling
-                .workspace_waiting_prompts
-                .get(workspace.path.as_path())
-                .cloned();
-            let reason = waiting_prompt
-                .as_deref()
-                .filter(|prompt| Self::permission_wall_prompt(prompt))
-                .map(|_| AttentionReason::PermissionWall)
-                .unwrap_or(AttentionReason::BlockedOnQuestion);
-            return Some(AttentionItem {
-                fingerprint: format!("{}:{marker}", reason.summary()),
-                reason,
-                summary: reason.summary().to_string(),
-                workspace_path: workspace.path.clone(),
-                task_slug: workspace.task_slug.clone().unwrap_or_default(),
-                first_seen_at_ms: now_ms,
-                last_seen_at_ms: now_ms,
-            });
-        }
-
-        if !workspace.supported_agent
-            || !self.workspace_has_running_agent_tab(workspace.path.as_path())
-        {
-            return None;
-        }
-
-        if workspace.status == WorkspaceStatus::Done {
-            return Some(AttentionItem {
-                fingerprint: format!("finished:{}", workspace.path.display()),
-                reason: AttentionReason::Finished,
-                summary: AttentionReason::Finished.summary().to_string(),
-                workspace_path: workspace.path.clone(),
-                task_slug: workspace.task_slug.clone().unwrap_or_default(),
-                first_seen_at_ms: now_ms,
-                last_seen_at_ms: now_ms,
-            });
-        }
-
+        let workspace_path = workspace.path.as_path();
+        let marker = (workspace.status == WorkspaceStatus::Waiting)
+            .then(|| self.current_attention_marker_for_workspace_path(workspace_path))
+            .flatten();
+        let waiting_prompt = self.polling.workspace_waiting_prompts.get(workspace_path);
         let idle_polls = self
             .polling
             .workspace_idle_polls_since_output
-            .get(workspace.path.as_path())
+            .get(workspace_path)
             .copied()
             .unwrap_or(0);
-        if workspace.status == WorkspaceStatus::Idle
-            && idle_polls >= Self::STALLED_IDLE_POLLS_TO_ATTENTION
-        {
-            return Some(AttentionItem {
-                fingerprint: format!("stalled:{}", workspace.path.display()),
-                reason: AttentionReason::Stalled,
-                summary: AttentionReason::Stalled.summary().to_string(),
-                workspace_path: workspace.path.clone(),
-                task_slug: workspace.task_slug.clone().unwrap_or_default(),
-                first_seen_at_ms: now_ms,
-                last_seen_at_ms: now_ms,
-            });
-        }
+        // A marker is only read for a running agent tab.
+        let agent_running =
+            marker.is_some() || self.workspace_has_running_agent_tab(workspace_path);
+        let Some(reason) = classify_attention(
+            workspace,
+            AttentionSignals {
+                status: workspace.status,
+                waiting_prompt: waiting_prompt.map(String::as_str),
+                idle_polls: u32::from(idle_polls),
+                agent_running,
+            },
+        ) else {
+            return self.falling_behind_attention_item(workspace, now_ms);
+        };
+        let fingerprint = match reason {
+            AttentionReason::BlockedOnQuestion | AttentionReason::PermissionWall => {
+                let Some(marker) = marker else {
+                    return self.falling_behind_attention_item(workspace, now_ms);
+                };
+                format!("{}:{marker}", reason.summary())
+            }
+            AttentionReason::SessionEnded => {
+                format!("session-ended:{}", workspace.path.display())
+            }
+            AttentionReason::Finished => format!("finished:{}", workspace.path.display()),
+            AttentionReason::Stalled => format!("stalled:{}", workspace.path.display()),
+            AttentionReason::FallingBehind => {
+                return self.falling_behind_attention_item(workspace, now_ms);
+            }
+        };
 
-        None
+        Some(AttentionItem {
+            fingerprint,
+            reason,
+            summary: reason.summary().to_string(),
+            workspace_path: workspace.path.clone(),
+            task_slug: workspace.task_slug.clone().unwrap_or_default(),
+            first_seen_at_ms: now_ms,
+            last_seen_at_ms: now_ms,
+        })
+    }
+
+    fn falling_behind_attention_item(
+        &self,
+        workspace: &Workspace,
+        now_ms: u64,
+    ) -> Option<AttentionItem> {
+        if workspace.is_main {
+            return None;
+        }
+        let behind = self
+            .branch_sync_for_workspace(workspace.path.as_path())?
+            .behind_base();
+        if behind < Self::BEHIND_BASE_COMMITS_TO_ATTENTION {
+            return None;
+        }
+        let base_branch = workspace.base_branch.as_deref().unwrap_or("base");
+        Some(AttentionItem {
+            fingerprint: format!("falling-behind:{}", workspace.path.display()),
+            reason: AttentionReason::FallingBehind,
+            summary: format!("{behind} commits behind {base_branch}"),
+            workspace_path: workspace.path.clone(),
+            task_slug: workspace.task_slug.clone().unwrap_or_default(),
+            first_seen_at_ms: now_ms,
+            last_seen_at_ms: now_ms,
+        })
     }
 
     fn current_attention_fingerprint_for_workspace_path(
@@ -182,6 +176,7 @@ impl GroveApp {
             &self.task_order,
             &self.workspace_attention_acks_for_config(),
             &self.hidden_base_project_paths_for_config(),
+            self.sidebar_view,
         )
     }
 
@@ -433,6 +428,22 @@ impl GroveApp {
         self.polling.agent_idle_polls_since_output = 0;
     }
 
+    pub(super) fn record_workspace_output_tail(
+        &mut self,
+        workspace_path: &Path,
+        cleaned_output: &str,
+    ) {
+        let lines = cleaned_output.trim_end().lines().collect::<Vec<&str>>();
+        let tail_start = lines.len().saturating_sub(DASHBOARD_TAIL_LINES);
+        self.polling.workspace_output_tails.insert(
+            workspace_path.to_path_buf(),
+            lines[tail_start..]
+                .iter()
+                .map(|line| line.to_string())
+                .collect(),
+        );
+    }
+
     pub(super) fn record_workspace_poll_state(
         &mut self,
         workspace_path: &Path,
@@ -607,6 +618,7 @@ impl GroveApp {
         self.polling
             .workspace_waiting_prompts
             .remove(workspace_path);
+        self.polling.workspace_output_tails.remove(workspace_path);
         self.polling
             .workspace_idle_polls_since_output
             .remove(workspace_path);
@@ -620,6 +632,7 @@ impl GroveApp {
         #[cfg(test)]
         self.polling.workspace_status_observation_overrides.clear();
         self.polling.workspace_waiting_prompts.clear();
+        self.polling.workspace_output_tails.clear();
         self.polling.workspace_idle_polls_since_output.clear();
         self.attention_observations.clear();
     }
@@ -64,6 +64,8 @@ impl GroveApp {
             }
             self.poll_preview();
             self.maybe_poll_diff();
+            self.maybe_poll_branch_sync();
+            self.maybe_poll_config_reload();
         }
 
         let pending_after = self.pending_input_depth();
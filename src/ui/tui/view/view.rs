@@ -78,9 +78,17 @@ impl GroveApp {
         };
 
         self.render_header(frame, header_rect);
-        self.render_sidebar(frame, sidebar_rect);
-        self.render_divider(frame, divider_rect);
-        self.render_preview_pane(frame, preview_rect);
+        if self.dashboard_active() {
+            self.render_dashboard(frame, Self::dashboard_area(sidebar_rect, preview_rect));
+        } else {
+            self.render_sidebar(frame, sidebar_rect);
+            self.render_divider(frame, divider_rect);
+            let (live_preview_rect, pinned_preview_rect) = self.split_preview_area(preview_rect);
+            self.render_preview_pane(frame, live_preview_rect);
+            if let Some(pinned_preview_rect) = pinned_preview_rect {
+                self.render_pinned_preview(frame, pinned_preview_rect);
+            }
+        }
         self.render_status_line(frame, status_rect);
         self.render_create_dialog_overlay(frame, area);
         self.render_edit_dialog_overlay(frame, area);
@@ -89,6 +97,10 @@ impl GroveApp {
         self.render_stop_dialog_overlay(frame, area);
         self.render_confirm_dialog_overlay(frame, area);
         self.render_session_cleanup_dialog_overlay(frame, area);
+        self.render_gc_dialog_overlay(frame, area);
+        self.render_commit_dialog_overlay(frame, area);
+        self.render_review_comment_dialog_overlay(frame, area);
+        self.render_task_notes_dialog_overlay(frame, area);
         self.render_delete_dialog_overlay(frame, area);
         self.render_merge_dialog_overlay(frame, area);
         self.render_update_from_base_dialog_overlay(frame, area);
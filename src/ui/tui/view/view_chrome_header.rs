@@ -7,6 +7,10 @@ impl GroveApp {
         }
 
         let theme = self.active_ui_theme();
+        let profile_label = self
+            .profile_name
+            .as_deref()
+            .map(|profile| format!("[profile: {profile}]"));
         let base_style = Style::new()
             .bg(packed(theme.overlay))
             .fg(packed(theme.text));
@@ -15,6 +19,9 @@ impl GroveApp {
             .separator("  ")
             .left(StatusItem::text("[Grove]"))
             .left(StatusItem::text(self.repo_name.as_str()));
+        if let Some(profile_label) = profile_label.as_deref() {
+            line = line.left(StatusItem::text(profile_label));
+        }
         if self.dialogs.command_palette.is_visible() {
             line = line.left(StatusItem::text(self.active_palette_badge_label()));
         }
@@ -1,4 +1,6 @@
+use super::update_navigation_sidebar::{SidebarSection, SidebarSectionHeader};
 use super::view_prelude::*;
+use crate::infrastructure::config::{SidebarGroupMode, SidebarSortMode};
 use ftui::widgets::virtualized::{RenderItem, VirtualizedList};
 
 include!("view_chrome_sidebar/model.rs");
@@ -19,6 +19,30 @@ impl GroveApp {
         }
     }
 
+    fn branch_sync_label(status: &WorktreeSyncStatus) -> Option<String> {
+        let mut parts = Vec::new();
+        if let Some(base) = status.base {
+            if base.ahead > 0 {
+                parts.push(format!("↑{}", base.ahead));
+            }
+            if base.behind > 0 {
+                parts.push(format!("↓{}", base.behind));
+            }
+        }
+        if let Some(upstream) = status.upstream.filter(|upstream| !upstream.is_even()) {
+            if upstream.ahead > 0 {
+                parts.push(format!("⇡{}", upstream.ahead));
+            }
+            if upstream.behind > 0 {
+                parts.push(format!("⇣{}", upstream.behind));
+            }
+        }
+        if parts.is_empty() {
+            return None;
+        }
+        Some(parts.join(" "))
+    }
+
     fn selected_sidebar_target(&self) -> SidebarSelectable {
         self.selected_attention_item
             .map(SidebarSelectable::Attention)
@@ -268,6 +292,26 @@ impl GroveApp {
             }
         }
 
+        if let Some(status) = self.branch_sync_for_workspace(workspace.path.as_path())
+            && let Some(sync_label) = Self::branch_sync_label(status)
+        {
+            if !trailing_segments.is_empty() {
+                trailing_segments.push(SidebarSegment {
+                    text: " ".to_string(),
+                    style: secondary_style,
+                });
+            }
+            let sync_style = if status.behind_base() >= Self::BEHIND_BASE_COMMITS_TO_ATTENTION {
+                secondary_style.fg(packed(theme.warning)).bold()
+            } else {
+                secondary_style
+            };
+            trailing_segments.push(SidebarSegment {
+                text: sync_label,
+                style: sync_style,
+            });
+        }
+
         lines.push(SidebarListLine::workspace(
             leading_segments,
             trailing_segments,
@@ -278,28 +322,55 @@ impl GroveApp {
         ));
     }
 
+    fn sidebar_section_header_text(&self, section: &SidebarSection) -> String {
+        let count = section.workspace_indices.len();
+        match &section.header {
+            SidebarSectionHeader::Task(task_index) => {
+                let Some(task) = self.state.tasks.get(*task_index) else {
+                    return String::new();
+                };
+                format!("{} {} [{count}]", self.task_header_marker(task), task.name)
+            }
+            SidebarSectionHeader::Project(project_name) => format!("▾ {project_name} [{count}]"),
+            SidebarSectionHeader::Status(status) => {
+                format!("▾ {} [{count}]", status.label())
+            }
+        }
+    }
+
     fn build_sidebar_lines(&self, theme: ftui::ResolvedTheme) -> (Vec<SidebarListLine>, Option<usize>) {
         let mut lines = Vec::new();
         let mut selected_line = None;
-        let mut workspace_index = 0usize;
         self.push_attention_sidebar_lines(&mut lines, &mut selected_line, theme);
 
-        for (task_index, task) in self.state.tasks.iter().enumerate() {
-            if task_index > 0 && !lines.is_empty() {
+        let sections = self.sidebar_sections();
+        if sections.is_empty() && self.sidebar_filter_active() {
+            lines.push(SidebarListLine::project(vec![SidebarSegment {
+                text: "  no workspaces match the filter".to_string(),
+                style: Style::new().fg(packed(theme.text_subtle)),
+            }]));
+        }
+
+        for (section_index, section) in sections.iter().enumerate() {
+            if section_index > 0 && !lines.is_empty() {
                 lines.push(SidebarListLine::project(Vec::new()));
             }
 
-            lines.push(SidebarListLine::project(vec![SidebarSegment {
-                text: format!(
-                    "{} {} [{}]",
-                    self.task_header_marker(task),
-                    task.name,
-                    task.worktrees.len()
-                ),
+            let mut header_segments = vec![SidebarSegment {
+                text: self.sidebar_section_header_text(section),
                 style: Style::new().fg(packed(theme.border)).bold(),
-            }]));
+            }];
+            if let SidebarSectionHeader::Task(task_index) = &section.header
+                && let Some(task) = self.state.tasks.get(*task_index)
+            {
+                header_segments.extend(task.tags.iter().map(|tag| SidebarSegment {
+                    text: format!(" #{tag}"),
+                    style: Style::new().fg(packed(theme.accent)),
+                }));
+            }
+            lines.push(SidebarListLine::project(header_segments));
 
-            if task.worktrees.is_empty() {
+            if section.workspace_indices.is_empty() {
                 lines.push(SidebarListLine::project(vec![SidebarSegment {
                     text: "  (no worktrees)".to_string(),
                     style: Style::new().fg(packed(theme.text_subtle)),
@@ -307,18 +378,13 @@ impl GroveApp {
                 continue;
             }
 
-            for _worktree in &task.worktrees {
-                if self.state.workspaces.get(workspace_index).is_none() {
-                    workspace_index = workspace_index.saturating_add(1);
-                    continue;
-                }
+            for workspace_index in &section.workspace_indices {
                 self.push_workspace_sidebar_lines(
                     &mut lines,
                     &mut selected_line,
                     theme,
-                    workspace_index,
+                    *workspace_index,
                 );
-                workspace_index = workspace_index.saturating_add(1);
             }
         }
 
@@ -1,11 +1,46 @@
 impl GroveApp {
+    fn sidebar_title(&self) -> String {
+        let mut title = "Workspaces".to_string();
+        if self.sidebar_view.group != SidebarGroupMode::None {
+            title.push_str(format!(" · by {}", self.sidebar_view.group.label()).as_str());
+        }
+        if self.sidebar_view.sort != SidebarSortMode::Manual {
+            title.push_str(format!(" · {}", self.sidebar_view.sort.label()).as_str());
+        }
+        title
+    }
+
+    /// Rows left for the workspace list once the filter bar takes the first one.
+    pub(super) fn sidebar_list_area(&self, inner: Rect) -> Rect {
+        if !self.sidebar_filter_bar_visible() || inner.height < 2 {
+            return inner;
+        }
+        Rect::new(inner.x, inner.y.saturating_add(1), inner.width, inner.height - 1)
+    }
+
+    fn render_sidebar_filter_bar(&self, frame: &mut Frame, inner: Rect, theme: ftui::ResolvedTheme) {
+        let mut spans = vec![
+            FtSpan::styled("filter: ", Style::new().fg(packed(theme.text_subtle))),
+            FtSpan::styled(
+                self.sidebar_filter.query.as_str(),
+                Style::new().fg(packed(theme.text)).bold(),
+            ),
+        ];
+        if self.sidebar_filter_editing() {
+            spans.push(FtSpan::styled("▏", Style::new().fg(packed(theme.primary))));
+        }
+        Paragraph::new(FtText::from_lines(vec![FtLine::from_spans(spans)]))
+            .render(Rect::new(inner.x, inner.y, inner.width, 1), frame);
+    }
+
     pub(super) fn render_sidebar(&self, frame: &mut Frame, area: Rect) {
         if area.is_empty() {
             return;
         }
 
+        let sidebar_title = self.sidebar_title();
         let block = Block::new()
-            .title("Workspaces")
+            .title(sidebar_title.as_str())
             .borders(Borders::ALL)
             .border_style(self.pane_border_style(self.workspace_list_focused() && !self.modal_open()));
         let inner = block.inner(area);
@@ -65,13 +100,18 @@ impl GroveApp {
             return;
         }
 
+        let list_area = self.sidebar_list_area(inner);
+        if list_area != inner {
+            self.render_sidebar_filter_bar(frame, inner, theme);
+        }
+
         let (lines, selected_line) = self.build_sidebar_lines(theme);
         if lines.is_empty() {
             return;
         }
 
         let mut list_state = self.sidebar_list_state.borrow_mut();
-        if selected_line.is_some_and(|line| line <= 1) && inner.height > 1 {
+        if selected_line.is_some_and(|line| line <= 1) && list_area.height > 1 {
             list_state.scroll_to_top();
         }
         list_state.select(selected_line);
@@ -79,7 +119,7 @@ impl GroveApp {
             .fixed_height(1)
             .show_scrollbar(true)
             .highlight_style(Style::new());
-        ftui::widgets::StatefulWidget::render(&list, inner, frame, &mut *list_state);
+        ftui::widgets::StatefulWidget::render(&list, list_area, frame, &mut *list_state);
 
     }
 }
@@ -0,0 +1,124 @@
+use super::panes::dashboard_tile_rects;
+use super::update_navigation_dashboard::DashboardTile;
+use super::view_prelude::*;
+
+impl GroveApp {
+    pub(super) fn render_dashboard(&self, frame: &mut Frame, area: Rect) {
+        if area.is_empty() {
+            return;
+        }
+        let theme = self.active_ui_theme();
+        Paragraph::new("")
+            .style(Style::new().bg(packed(theme.background)))
+            .render(area, frame);
+
+        let tiles = self.dashboard_tiles();
+        if tiles.is_empty() {
+            Paragraph::new("No running agent sessions. Esc to close.")
+                .style(
+                    Style::new()
+                        .fg(packed(theme.text_muted))
+                        .bg(packed(theme.background)),
+                )
+                .render(area, frame);
+            return;
+        }
+
+        let selected_tile = self.dashboard_selected_tile(tiles.len());
+        for (index, (tile, tile_area)) in tiles
+            .iter()
+            .zip(dashboard_tile_rects(area, tiles.len()))
+            .enumerate()
+        {
+            self.render_dashboard_tile(frame, tile_area, tile, index == selected_tile);
+            if let Ok(data) = u64::try_from(index) {
+                let _ = frame.register_hit(
+                    tile_area,
+                    HitId::new(HIT_ID_DASHBOARD_TILE),
+                    FrameHitRegion::Content,
+                    data,
+                );
+            }
+        }
+    }
+
+    fn render_dashboard_tile(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        tile: &DashboardTile,
+        selected: bool,
+    ) {
+        let theme = self.active_ui_theme();
+        let status_color = self.dashboard_status_color(tile);
+        let mut border_style = Style::new().fg(status_color);
+        if selected {
+            border_style = border_style.bold();
+        }
+        let marker = if selected { "▶ " } else { "" };
+        let title = format!("{marker}{} · {}", tile.workspace_name, tile.status.label());
+        let block = Block::new()
+            .title(title.as_str())
+            .borders(Borders::ALL)
+            .border_style(border_style);
+        let inner = block.inner(area);
+        block.render(area, frame);
+        if inner.is_empty() {
+            return;
+        }
+
+        let mut text_lines = Vec::new();
+        if let Some(prompt) = self
+            .polling
+            .workspace_waiting_prompts
+            .get(tile.workspace_path.as_path())
+        {
+            text_lines.push(FtLine::from_spans(vec![FtSpan::styled(
+                format!("waiting: {prompt}"),
+                Style::new().fg(packed(theme.warning)).bold(),
+            )]));
+        }
+        let output_rows = usize::from(inner.height).saturating_sub(text_lines.len());
+        let output_lines = self.dashboard_tile_lines(tile);
+        if output_lines.is_empty() {
+            text_lines.push(FtLine::from_spans(vec![FtSpan::styled(
+                "(waiting for output)",
+                Style::new().fg(packed(theme.text_muted)),
+            )]));
+        } else {
+            text_lines.extend(
+                output_lines[output_lines.len().saturating_sub(output_rows)..]
+                    .iter()
+                    .map(|line| FtLine::raw(line.as_str())),
+            );
+        }
+
+        Paragraph::new(FtText::from_lines(text_lines))
+            .wrap(ftui::text::WrapMode::None)
+            .style(
+                Style::new()
+                    .fg(packed(theme.text))
+                    .bg(packed(theme.background)),
+            )
+            .render(inner, frame);
+    }
+
+    fn dashboard_status_color(&self, tile: &DashboardTile) -> PackedRgba {
+        let theme = self.active_ui_theme();
+        if self
+            .workspace_attention(tile.workspace_path.as_path())
+            .is_some()
+        {
+            return packed(theme.warning);
+        }
+        match tile.status {
+            WorkspaceStatus::Waiting => packed(theme.warning),
+            WorkspaceStatus::Error => packed(theme.error),
+            WorkspaceStatus::Done => packed(theme.success),
+            WorkspaceStatus::Active | WorkspaceStatus::Thinking => {
+                self.workspace_agent_color(tile.agent)
+            }
+            _ => packed(theme.border),
+        }
+    }
+}
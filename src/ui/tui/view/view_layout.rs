@@ -1,4 +1,4 @@
-use super::panes::PaneRole;
+use super::panes::{PaneRole, PreviewHalf, split_preview_rect};
 use super::view_prelude::*;
 
 impl GroveApp {
@@ -58,6 +58,43 @@ impl GroveApp {
         }
     }
 
+    /// Returns (live, pinned) rects for the preview pane. Without a split
+    /// preview, the live rect is the whole pane and there is no pinned rect.
+    pub(super) fn split_preview_area(&self, preview_rect: Rect) -> (Rect, Option<Rect>) {
+        let Some(split) = self.preview_split.as_ref() else {
+            return (preview_rect, None);
+        };
+        let (first, second) = split_preview_rect(preview_rect, split.axis);
+        match split.focused_half {
+            PreviewHalf::First => (first, Some(second)),
+            PreviewHalf::Second => (second, Some(first)),
+        }
+    }
+
+    /// Rect of the preview half that shows the selected workspace's live session.
+    pub(super) fn live_preview_rect(&self) -> Rect {
+        let (_, _, preview_rect) = self.effective_workspace_rects();
+        self.split_preview_area(preview_rect).0
+    }
+
+    /// The dashboard grid covers the sidebar, divider, and preview together.
+    pub(super) fn dashboard_area(sidebar_rect: Rect, preview_rect: Rect) -> Rect {
+        if sidebar_rect.is_empty() {
+            return preview_rect;
+        }
+        Rect::new(
+            sidebar_rect.x,
+            preview_rect.y,
+            preview_rect.right().saturating_sub(sidebar_rect.x),
+            preview_rect.height,
+        )
+    }
+
+    pub(super) fn dashboard_rect(&self) -> Rect {
+        let (sidebar_rect, _, preview_rect) = self.effective_workspace_rects();
+        Self::dashboard_area(sidebar_rect, preview_rect)
+    }
+
     pub(super) fn divider_hit_area(divider: Rect, viewport_width: u16) -> Rect {
         if divider.is_empty() {
             return divider;
@@ -79,6 +116,8 @@ impl GroveApp {
                 HIT_ID_STATUS => HitRegion::StatusLine,
                 HIT_ID_DIVIDER => HitRegion::Divider,
                 HIT_ID_PREVIEW => HitRegion::Preview,
+                HIT_ID_PREVIEW_PINNED => HitRegion::PinnedPreview,
+                HIT_ID_DASHBOARD_TILE => HitRegion::DashboardTile,
                 HIT_ID_WORKSPACE_LIST | HIT_ID_WORKSPACE_ROW => HitRegion::WorkspaceList,
                 HIT_ID_WORKSPACE_PR_LINK => HitRegion::WorkspacePullRequest,
                 HIT_ID_CREATE_DIALOG
@@ -87,13 +126,19 @@ impl GroveApp {
                 | HIT_ID_STOP_DIALOG
                 | HIT_ID_CONFIRM_DIALOG
                 | HIT_ID_SESSION_CLEANUP_DIALOG
+                | HIT_ID_GC_DIALOG
+                | HIT_ID_COMMIT_DIALOG
+                | HIT_ID_REVIEW_COMMENT_DIALOG
+                | HIT_ID_TASK_NOTES_DIALOG
                 | HIT_ID_RENAME_TAB_DIALOG
                 | HIT_ID_KEYBIND_HELP_DIALOG
                 | HIT_ID_PERFORMANCE_DIALOG => HitRegion::Outside,
                 _ => HitRegion::Outside,
             };
-            let row_data = if id.id() == HIT_ID_WORKSPACE_ROW || id.id() == HIT_ID_WORKSPACE_PR_LINK
-            {
+            let row_data = if matches!(
+                id.id(),
+                HIT_ID_WORKSPACE_ROW | HIT_ID_WORKSPACE_PR_LINK | HIT_ID_DASHBOARD_TILE
+            ) {
                 Some(data)
             } else {
                 None
@@ -154,6 +199,20 @@ impl GroveApp {
                 preview_rect.x + DIVIDER_WIDTH
             };
             if x >= adjusted_x && x < preview_rect.right() {
+                let full_preview = Rect::new(
+                    adjusted_x,
+                    preview_rect.y,
+                    preview_rect.right().saturating_sub(adjusted_x),
+                    preview_rect.height,
+                );
+                if let (_, Some(pinned)) = self.split_preview_area(full_preview)
+                    && x >= pinned.x
+                    && x < pinned.right()
+                    && y >= pinned.y
+                    && y < pinned.bottom()
+                {
+                    return (HitRegion::PinnedPreview, None);
+                }
                 return (HitRegion::Preview, None);
             }
         }
@@ -0,0 +1,226 @@
+use super::view_prelude::*;
+
+const COMMIT_MESSAGE_MIN_ROWS: usize = 4;
+const COMMIT_MESSAGE_MAX_ROWS: usize = 8;
+
+impl GroveApp {
+    pub(super) fn render_commit_dialog_overlay(&self, frame: &mut Frame, area: Rect) {
+        let Some(dialog) = self.commit_dialog() else {
+            return;
+        };
+        if area.width < 36 || area.height < 16 {
+            return;
+        }
+
+        let dialog_width = area.width.saturating_sub(8).clamp(64, 110);
+        let dialog_height = area.height.saturating_sub(4).clamp(20, 36);
+        let theme = self.active_ui_theme();
+        let content_width = usize::from(dialog_width.saturating_sub(2));
+        let files_focused = self.dialog_focus_is(FOCUS_ID_COMMIT_FILES);
+        let message_focused = self.dialog_focus_is(FOCUS_ID_COMMIT_MESSAGE);
+        let draft_focused = self.dialog_focus_is(FOCUS_ID_COMMIT_DRAFT);
+        let commit_focused = self.dialog_focus_is(FOCUS_ID_COMMIT_COMMIT_BUTTON);
+        let cancel_focused = self.dialog_focus_is(FOCUS_ID_COMMIT_CANCEL_BUTTON);
+        let fit = |text: &str| {
+            let text = ftui::text::truncate_with_ellipsis(text, content_width, "…");
+            format!(
+                "{text}{}",
+                " ".repeat(content_width.saturating_sub(ftui::text::display_width(text.as_str())))
+            )
+        };
+        let heading = |text: &str, focused: bool| {
+            let marker = if focused { ">" } else { " " };
+            FtLine::from_spans(vec![FtSpan::styled(
+                fit(format!("{marker} {text}").as_str()),
+                Style::new()
+                    .fg(if focused {
+                        packed(theme.warning)
+                    } else {
+                        packed(theme.text_muted)
+                    })
+                    .bold(),
+            )])
+        };
+
+        let staged_count = dialog.staged_count();
+        let mut lines = vec![FtLine::from_spans(vec![FtSpan::styled(
+            fit(format!(
+                "Workspace: {} · Staged: {} · Unstaged: {}{}",
+                dialog.workspace_name,
+                staged_count,
+                dialog.files.len().saturating_sub(staged_count),
+                if dialog.busy {
+                    " · running git..."
+                } else {
+                    ""
+                }
+            )
+            .as_str()),
+            Style::new().fg(packed(theme.border)),
+        )])];
+        if let Some(error) = dialog.last_error.as_ref() {
+            lines.push(FtLine::from_spans(vec![FtSpan::styled(
+                fit(format!("Error: {error}").as_str()),
+                Style::new().fg(packed(theme.error)).bold(),
+            )]));
+        }
+        lines.push(FtLine::raw(""));
+        lines.push(heading("Files", files_focused));
+
+        let max_list_rows = usize::from(dialog_height)
+            .saturating_sub(17 + COMMIT_MESSAGE_MIN_ROWS)
+            .max(3);
+        if dialog.files.is_empty() {
+            lines.push(FtLine::from_spans(vec![FtSpan::styled(
+                fit(if dialog.busy {
+                    "  reading changes..."
+                } else {
+                    "  no changes"
+                }),
+                Style::new().fg(packed(theme.text_subtle)),
+            )]));
+        } else {
+            let first_row = dialog
+                .cursor
+                .saturating_sub(max_list_rows.saturating_sub(1));
+            let mut previous_section = None;
+            for (index, file) in dialog
+                .files
+                .iter()
+                .enumerate()
+                .skip(first_row)
+                .take(max_list_rows)
+            {
+                if previous_section != Some(file.section) {
+                    previous_section = Some(file.section);
+                    let label = match file.section {
+                        ChangeSection::Staged => "  staged",
+                        ChangeSection::Unstaged => "  unstaged",
+                    };
+                    lines.push(FtLine::from_spans(vec![FtSpan::styled(
+                        fit(label),
+                        Style::new().fg(packed(theme.border)).bold(),
+                    )]));
+                }
+                let marker = match file.section {
+                    ChangeSection::Staged => "[x]",
+                    ChangeSection::Unstaged => "[ ]",
+                };
+                let line = format!(
+                    "    {marker} {} {} ({})",
+                    file.status,
+                    file.path,
+                    file.status_label()
+                );
+                let file_color = match file.section {
+                    ChangeSection::Staged => packed(theme.success),
+                    ChangeSection::Unstaged if file.status == 'U' => packed(theme.error),
+                    ChangeSection::Unstaged => packed(theme.warning),
+                };
+                let row_style = if files_focused && index == dialog.cursor {
+                    Style::new()
+                        .fg(file_color)
+                        .bg(packed(theme.selection_bg))
+                        .bold()
+                } else {
+                    Style::new().fg(file_color)
+                };
+                lines.push(FtLine::from_spans(vec![FtSpan::styled(
+                    fit(line.as_str()),
+                    row_style,
+                )]));
+            }
+            if dialog.files.len() > first_row + max_list_rows {
+                lines.push(FtLine::from_spans(vec![FtSpan::styled(
+                    fit(format!(
+                        "  ... +{} more",
+                        dialog.files.len().saturating_sub(first_row + max_list_rows)
+                    )
+                    .as_str()),
+                    Style::new().fg(packed(theme.border)),
+                )]));
+            }
+        }
+
+        lines.push(FtLine::raw(""));
+        lines.push(heading("Message", message_focused));
+        let mut message_lines = if dialog.message.is_empty() {
+            Vec::new()
+        } else {
+            dialog
+                .message
+                .split('\n')
+                .map(str::to_string)
+                .collect::<Vec<String>>()
+        };
+        if message_focused {
+            match message_lines.last_mut() {
+                Some(last) => last.push('▏'),
+                None => message_lines.push("▏".to_string()),
+            }
+        }
+        let message_style = |fg: PackedRgba| Style::new().fg(fg).bg(packed(theme.surface));
+        let mut message_rows = 0usize;
+        if message_lines.is_empty() {
+            lines.push(FtLine::from_spans(vec![FtSpan::styled(
+                fit("  Subject line, blank line, then body"),
+                message_style(packed(theme.border)),
+            )]));
+            message_rows += 1;
+        }
+        let first_message_line = message_lines.len().saturating_sub(COMMIT_MESSAGE_MAX_ROWS);
+        for line in message_lines.iter().skip(first_message_line) {
+            lines.push(FtLine::from_spans(vec![FtSpan::styled(
+                fit(format!("  {line}").as_str()),
+                message_style(packed(theme.text)),
+            )]));
+            message_rows += 1;
+        }
+        for _ in message_rows..COMMIT_MESSAGE_MIN_ROWS {
+            lines.push(FtLine::from_spans(vec![FtSpan::styled(
+                fit(""),
+                message_style(packed(theme.text)),
+            )]));
+        }
+
+        lines.push(FtLine::raw(""));
+        lines.push(modal_focus_badged_row(
+            content_width,
+            theme,
+            "Draft",
+            "ask the agent to draft a message from the staged summary",
+            draft_focused,
+            packed(theme.accent),
+            packed(theme.text),
+        ));
+        lines.push(FtLine::raw(""));
+        lines.push(modal_actions_row(
+            content_width,
+            theme,
+            "Commit",
+            "Cancel",
+            commit_focused,
+            cancel_focused,
+        ));
+        lines.extend(modal_wrapped_hint_rows(
+            content_width,
+            theme,
+            "Tab/C-n next, S-Tab/C-p prev, j/k move, Space stage/unstage, a stage all, Enter newline in message, C-s commit, Esc cancel",
+        ));
+
+        let body = FtText::from_lines(lines);
+        render_modal_dialog(
+            frame,
+            area,
+            body,
+            ModalDialogSpec {
+                dialog_width,
+                dialog_height,
+                title: "Commit Changes",
+                theme,
+                border_color: packed(theme.accent),
+                hit_id: HIT_ID_COMMIT_DIALOG,
+            },
+        );
+    }
+}
@@ -30,6 +30,22 @@ impl GroveApp {
                 format!("Session '{session_name}' is still live in tmux"),
                 packed(theme.warning),
             ),
+            ConfirmDialogAction::DiscardDiffHunk { path, header, .. } => (
+                "Discard Hunk?",
+                format!("Discard {header} in {path}?"),
+                "The hunk is saved under .grove/discarded/ for git apply".to_string(),
+                packed(theme.error),
+            ),
+            ConfirmDialogAction::TrustSharedCommands {
+                project_name,
+                entries,
+                ..
+            } => (
+                "Trust Project Commands?",
+                format!("Let {SHARED_CONFIG_FILE} in '{project_name}' run commands and set env?"),
+                format!("Applies: {}", entries.join("; ")),
+                packed(theme.warning),
+            ),
             ConfirmDialogAction::QuitApp => (
                 "Are you sure?",
                 "Quit Grove now?".to_string(),
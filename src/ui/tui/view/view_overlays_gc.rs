@@ -0,0 +1,182 @@
+use super::view_prelude::*;
+
+fn gc_idle_label(idle_secs: Option<u64>) -> String {
+    let Some(idle_secs) = idle_secs else {
+        return "-".to_string();
+    };
+    if idle_secs < 60 * 60 {
+        return format!("{}m", idle_secs / 60);
+    }
+    if idle_secs < 24 * 60 * 60 {
+        return format!("{}h", idle_secs / (60 * 60));
+    }
+    format!("{}d", idle_secs / (24 * 60 * 60))
+}
+
+fn gc_idle_days_label(idle_days: Option<u64>) -> String {
+    match idle_days {
+        Some(days) => format!("{days} days, include worktrees idle longer than this"),
+        None => "disabled, merged and unmanaged worktrees only".to_string(),
+    }
+}
+
+impl GroveApp {
+    pub(super) fn render_gc_dialog_overlay(&self, frame: &mut Frame, area: Rect) {
+        let Some(dialog) = self.gc_dialog() else {
+            return;
+        };
+        if area.width < 36 || area.height < 14 {
+            return;
+        }
+
+        let dialog_width = area.width.saturating_sub(8).clamp(70, 116);
+        let dialog_height = area.height.saturating_sub(4).clamp(16, 30);
+        let theme = self.active_ui_theme();
+        let content_width = usize::from(dialog_width.saturating_sub(2));
+        let candidates_focused = self.dialog_focus_is(FOCUS_ID_GC_CANDIDATES);
+        let idle_days_focused = self.dialog_focus_is(FOCUS_ID_GC_IDLE_DAYS);
+        let apply_focused = self.dialog_focus_is(FOCUS_ID_GC_APPLY_BUTTON);
+        let cancel_focused = self.dialog_focus_is(FOCUS_ID_GC_CANCEL_BUTTON);
+        let fit = |text: &str| {
+            let text = ftui::text::truncate_with_ellipsis(text, content_width, "…");
+            format!(
+                "{text}{}",
+                " ".repeat(content_width.saturating_sub(ftui::text::display_width(text.as_str())))
+            )
+        };
+
+        let mut lines = vec![FtLine::from_spans(vec![FtSpan::styled(
+            fit(format!(
+                "Candidates: {} · Selected: {} · Skipped running: {} · Skipped dirty: {}",
+                dialog.plan.candidates.len(),
+                dialog.selected_count(),
+                dialog.plan.skipped_running.len(),
+                dialog.plan.skipped_dirty.len()
+            )
+            .as_str()),
+            Style::new().fg(packed(theme.border)),
+        )])];
+        if let Some(error) = dialog.last_error.as_ref() {
+            lines.push(FtLine::from_spans(vec![FtSpan::styled(
+                fit(format!("Error: {error}").as_str()),
+                Style::new().fg(packed(theme.error)).bold(),
+            )]));
+        }
+        lines.push(FtLine::raw(""));
+        lines.push(FtLine::from_spans(vec![FtSpan::styled(
+            fit("Planned worktree cleanup"),
+            Style::new().fg(packed(theme.text_muted)).bold(),
+        )]));
+
+        let max_list_rows = usize::from(dialog_height).saturating_sub(13).max(3);
+        if dialog.plan.candidates.is_empty() {
+            lines.push(FtLine::from_spans(vec![FtSpan::styled(
+                fit(if dialog.scanning {
+                    "  scanning worktrees..."
+                } else {
+                    "  none"
+                }),
+                Style::new().fg(packed(theme.text_subtle)),
+            )]));
+        } else {
+            let first_row = dialog
+                .cursor
+                .saturating_sub(max_list_rows.saturating_sub(1));
+            for (index, entry) in dialog
+                .plan
+                .candidates
+                .iter()
+                .enumerate()
+                .skip(first_row)
+                .take(max_list_rows)
+            {
+                let selected = dialog.selected.get(index).copied().unwrap_or(false);
+                let marker = if selected { "[x]" } else { "[ ]" };
+                let line = format!(
+                    "  {marker} {} [{}] idle={} {}",
+                    entry.name,
+                    entry.reason.label(),
+                    gc_idle_label(entry.idle_secs),
+                    entry.branches.join(",")
+                );
+                let reason_color = match entry.reason {
+                    GcReason::BranchMerged | GcReason::PullRequestMerged => packed(theme.success),
+                    GcReason::Idle => packed(theme.warning),
+                    GcReason::UnmanagedWorktree => packed(theme.error),
+                };
+                let row_style = if candidates_focused && index == dialog.cursor {
+                    Style::new()
+                        .fg(reason_color)
+                        .bg(packed(theme.selection_bg))
+                        .bold()
+                } else {
+                    Style::new().fg(reason_color)
+                };
+                lines.push(FtLine::from_spans(vec![FtSpan::styled(
+                    fit(line.as_str()),
+                    row_style,
+                )]));
+            }
+            if dialog.plan.candidates.len() > first_row + max_list_rows {
+                lines.push(FtLine::from_spans(vec![FtSpan::styled(
+                    fit(format!(
+                        "  ... +{} more",
+                        dialog
+                            .plan
+                            .candidates
+                            .len()
+                            .saturating_sub(first_row + max_list_rows)
+                    )
+                    .as_str()),
+                    Style::new().fg(packed(theme.border)),
+                )]));
+            }
+        }
+
+        lines.push(FtLine::raw(""));
+        lines.push(modal_focus_badged_row(
+            content_width,
+            theme,
+            "IdleDays",
+            gc_idle_days_label(dialog.options.idle_days).as_str(),
+            idle_days_focused,
+            packed(theme.accent),
+            packed(theme.text),
+        ));
+        lines.push(FtLine::raw(""));
+
+        let apply_label = if dialog.selected_count() == 0 {
+            "Apply"
+        } else {
+            "Remove Worktrees"
+        };
+        lines.push(modal_actions_row(
+            content_width,
+            theme,
+            apply_label,
+            "Cancel",
+            apply_focused,
+            cancel_focused,
+        ));
+        lines.extend(modal_wrapped_hint_rows(
+            content_width,
+            theme,
+            "Tab/C-n next, S-Tab/C-p prev, j/k move, Space toggle, a toggle all, Enter or D apply, Esc cancel",
+        ));
+
+        let body = FtText::from_lines(lines);
+        render_modal_dialog(
+            frame,
+            area,
+            body,
+            ModalDialogSpec {
+                dialog_width,
+                dialog_height,
+                title: "Cleanup Worktrees",
+                theme,
+                border_color: packed(theme.warning),
+                hit_id: HIT_ID_GC_DIALOG,
+            },
+        );
+    }
+}
@@ -561,16 +561,28 @@ impl Widget for ProjectDefaultsModalContent<'_> {
             .render(layout.project_path, frame);
 
         let label_style = Style::new().fg(packed(self.theme.text_subtle));
-        Paragraph::new("Base branch")
+        let (base_branch_source, inherited_base_branch) = self
+            .dialog
+            .field_source(ProjectDefaultsDialogField::BaseBranch);
+        let (init_command_source, inherited_init_command) = self
+            .dialog
+            .field_source(ProjectDefaultsDialogField::WorkspaceInitCommand);
+        let (claude_env_source, inherited_claude_env) = self
+            .dialog
+            .field_source(ProjectDefaultsDialogField::ClaudeEnv);
+        let (codex_env_source, inherited_codex_env) = self
+            .dialog
+            .field_source(ProjectDefaultsDialogField::CodexEnv);
+        Paragraph::new(format!("Base branch  ({})", base_branch_source.label()))
             .style(label_style)
             .render(layout.base_branch_label, frame);
-        Paragraph::new("Init command")
+        Paragraph::new(format!("Init command  ({})", init_command_source.label()))
             .style(label_style)
             .render(layout.init_command_label, frame);
-        Paragraph::new("Claude env")
+        Paragraph::new(format!("Claude env  ({})", claude_env_source.label()))
             .style(label_style)
             .render(layout.claude_env_label, frame);
-        Paragraph::new("Codex env")
+        Paragraph::new(format!("Codex env  ({})", codex_env_source.label()))
             .style(label_style)
             .render(layout.codex_env_label, frame);
         let base_branch_input = self
@@ -579,7 +591,10 @@ impl Widget for ProjectDefaultsModalContent<'_> {
             .clone()
             .with_focused(self.focused_id == Some(FOCUS_ID_PROJECT_DEFAULTS_BASE_BRANCH_INPUT))
             .with_style(modal_input_style(self.theme))
-            .with_placeholder("Optional override, empty uses selected branch")
+            .with_placeholder(
+                inherited_base_branch
+                    .unwrap_or_else(|| "Optional override, empty uses selected branch".to_string()),
+            )
             .with_placeholder_style(modal_input_placeholder_style(self.theme))
             .with_cursor_style(modal_input_cursor_style(self.theme))
             .with_selection_style(modal_input_selection_style(self.theme));
@@ -589,7 +604,10 @@ impl Widget for ProjectDefaultsModalContent<'_> {
             .clone()
             .with_focused(self.focused_id == Some(FOCUS_ID_PROJECT_DEFAULTS_INIT_COMMAND_INPUT))
             .with_style(modal_input_style(self.theme))
-            .with_placeholder("Runs once per workspace start")
+            .with_placeholder(
+                inherited_init_command
+                    .unwrap_or_else(|| "Runs once per workspace start".to_string()),
+            )
             .with_placeholder_style(modal_input_placeholder_style(self.theme))
             .with_cursor_style(modal_input_cursor_style(self.theme))
             .with_selection_style(modal_input_selection_style(self.theme));
@@ -599,7 +617,9 @@ impl Widget for ProjectDefaultsModalContent<'_> {
             .clone()
             .with_focused(self.focused_id == Some(FOCUS_ID_PROJECT_DEFAULTS_CLAUDE_ENV_INPUT))
             .with_style(modal_input_style(self.theme))
-            .with_placeholder("KEY=VALUE; KEY2=VALUE")
+            .with_placeholder(
+                inherited_claude_env.unwrap_or_else(|| "KEY=VALUE; KEY2=VALUE".to_string()),
+            )
             .with_placeholder_style(modal_input_placeholder_style(self.theme))
             .with_cursor_style(modal_input_cursor_style(self.theme))
             .with_selection_style(modal_input_selection_style(self.theme));
@@ -609,7 +629,9 @@ impl Widget for ProjectDefaultsModalContent<'_> {
             .clone()
             .with_focused(self.focused_id == Some(FOCUS_ID_PROJECT_DEFAULTS_CODEX_ENV_INPUT))
             .with_style(modal_input_style(self.theme))
-            .with_placeholder("KEY=VALUE; KEY2=VALUE")
+            .with_placeholder(
+                inherited_codex_env.unwrap_or_else(|| "KEY=VALUE; KEY2=VALUE".to_string()),
+            )
             .with_placeholder_style(modal_input_placeholder_style(self.theme))
             .with_cursor_style(modal_input_cursor_style(self.theme))
             .with_selection_style(modal_input_selection_style(self.theme));
@@ -646,9 +668,16 @@ impl Widget for ProjectDefaultsModalContent<'_> {
             _ => {}
         }
 
-        Paragraph::new("Env changes apply on next agent start or restart")
+        match self.dialog.shared_error.as_deref() {
+            Some(error) => Paragraph::new(error.to_string())
+                .style(Style::new().fg(packed(self.theme.error)))
+                .render(layout.note, frame),
+            None => Paragraph::new(format!(
+                "Empty fields inherit {SHARED_CONFIG_FILE}, env changes apply on next agent start"
+            ))
             .style(Style::new().fg(packed(self.theme.border)))
-            .render(layout.note, frame);
+            .render(layout.note, frame),
+        }
         Paragraph::new("")
             .style(Style::new().bg(packed(self.theme.background)))
             .render(layout.actions, frame);
@@ -0,0 +1,86 @@
+use super::view_prelude::*;
+
+impl GroveApp {
+    pub(super) fn render_review_comment_dialog_overlay(&self, frame: &mut Frame, area: Rect) {
+        let Some(dialog) = self.review_comment_dialog() else {
+            return;
+        };
+        if area.width < 24 || area.height < 13 {
+            return;
+        }
+
+        let dialog_width = area.width.saturating_sub(12).min(80);
+        let dialog_height = 14u16;
+        let theme = self.active_ui_theme();
+        let content_width = usize::from(dialog_width.saturating_sub(2));
+        let fit = |text: &str| {
+            let text = ftui::text::truncate_with_ellipsis(text, content_width, "…");
+            format!(
+                "{text}{}",
+                " ".repeat(content_width.saturating_sub(ftui::text::display_width(text.as_str())))
+            )
+        };
+
+        let mut lines = vec![
+            FtLine::from_spans(vec![FtSpan::styled(
+                fit("Comment on a line, then send the review to the agent with >"),
+                Style::new().fg(packed(theme.border)),
+            )]),
+            FtLine::raw(""),
+            modal_static_badged_row(
+                content_width,
+                theme,
+                "File",
+                dialog.path.as_str(),
+                packed(theme.primary),
+                packed(theme.text),
+            ),
+            FtLine::raw(""),
+            modal_labeled_input_row(
+                content_width,
+                theme,
+                "Line",
+                dialog.line.as_str(),
+                "Line number",
+                self.dialog_focus_is(FOCUS_ID_REVIEW_COMMENT_LINE),
+            ),
+            modal_labeled_input_row(
+                content_width,
+                theme,
+                "Comment",
+                dialog.body.as_str(),
+                "What should change here",
+                self.dialog_focus_is(FOCUS_ID_REVIEW_COMMENT_BODY),
+            ),
+            FtLine::raw(""),
+            modal_actions_row(
+                content_width,
+                theme,
+                "Add",
+                "Cancel",
+                self.dialog_focus_is(FOCUS_ID_REVIEW_COMMENT_ADD_BUTTON),
+                self.dialog_focus_is(FOCUS_ID_REVIEW_COMMENT_CANCEL_BUTTON),
+            ),
+        ];
+        lines.extend(modal_wrapped_hint_rows(
+            content_width,
+            theme,
+            "Tab/C-n next, S-Tab/C-p prev, type/backspace edit, Enter or C-s add, Esc cancel",
+        ));
+        let body = FtText::from_lines(lines);
+
+        render_modal_dialog(
+            frame,
+            area,
+            body,
+            ModalDialogSpec {
+                dialog_width,
+                dialog_height,
+                title: "Review Comment",
+                theme,
+                border_color: packed(theme.info),
+                hit_id: HIT_ID_REVIEW_COMMENT_DIALOG,
+            },
+        );
+    }
+}
@@ -18,7 +18,7 @@ impl GroveApp {
         let cancel_focused = self.dialog_focus_is(FOCUS_ID_SETTINGS_CANCEL_BUTTON);
         let theme_value = format!(
             "{} ({})",
-            theme_display_name(dialog.theme),
+            theme_display_name(&dialog.theme, &self.custom_themes),
             dialog.theme.config_key()
         );
         let fit = |text: &str| {
@@ -54,10 +54,27 @@ impl GroveApp {
             cancel_focused,
         ));
         lines.push(FtLine::raw(""));
+        if let Some(error) = dialog.theme_errors.first() {
+            let skipped = if dialog.theme_errors.len() == 1 {
+                format!("Skipped custom theme: {error}")
+            } else {
+                format!(
+                    "Skipped {} custom themes, first: {error}",
+                    dialog.theme_errors.len()
+                )
+            };
+            lines.extend(modal_wrapped_rows(
+                content_width,
+                skipped.as_str(),
+                Style::new()
+                    .fg(packed(theme.warning))
+                    .bg(packed(theme.background)),
+            ));
+        }
         lines.extend(modal_wrapped_hint_rows(
             content_width,
             theme,
-            "Use left/right or space to cycle built-in themes.",
+            "Use left/right or space to cycle themes. Custom themes load from themes/*.toml next to config.toml.",
         ));
         let body = FtText::from_lines(lines);
 
@@ -0,0 +1,130 @@
+use super::view_prelude::*;
+
+const TASK_NOTES_MIN_ROWS: usize = 6;
+const TASK_NOTES_MAX_ROWS: usize = 12;
+
+impl GroveApp {
+    pub(super) fn render_task_notes_dialog_overlay(&self, frame: &mut Frame, area: Rect) {
+        let Some(dialog) = self.task_notes_dialog() else {
+            return;
+        };
+        if area.width < 36 || area.height < 20 {
+            return;
+        }
+
+        let dialog_width = area.width.saturating_sub(8).clamp(48, 96);
+        let theme = self.active_ui_theme();
+        let content_width = usize::from(dialog_width.saturating_sub(2));
+        let notes_focused = self.dialog_focus_is(FOCUS_ID_TASK_NOTES_NOTES);
+        let fit = |text: &str| {
+            let text = ftui::text::truncate_with_ellipsis(text, content_width, "…");
+            format!(
+                "{text}{}",
+                " ".repeat(content_width.saturating_sub(ftui::text::display_width(text.as_str())))
+            )
+        };
+
+        let mut lines = vec![
+            modal_static_badged_row(
+                content_width,
+                theme,
+                "Task",
+                dialog.task_name.as_str(),
+                packed(theme.primary),
+                packed(theme.text),
+            ),
+            FtLine::raw(""),
+            modal_labeled_input_row(
+                content_width,
+                theme,
+                "Tags",
+                dialog.tags.as_str(),
+                "Comma separated, e.g. launch, infra",
+                self.dialog_focus_is(FOCUS_ID_TASK_NOTES_TAGS),
+            ),
+            FtLine::raw(""),
+            FtLine::from_spans(vec![FtSpan::styled(
+                fit(if notes_focused { "> Notes" } else { "  Notes" }),
+                Style::new()
+                    .fg(if notes_focused {
+                        packed(theme.warning)
+                    } else {
+                        packed(theme.text_muted)
+                    })
+                    .bold(),
+            )]),
+        ];
+
+        let mut note_lines = if dialog.notes.is_empty() {
+            Vec::new()
+        } else {
+            dialog
+                .notes
+                .split('\n')
+                .map(str::to_string)
+                .collect::<Vec<String>>()
+        };
+        if notes_focused {
+            match note_lines.last_mut() {
+                Some(last) => last.push('▏'),
+                None => note_lines.push("▏".to_string()),
+            }
+        }
+        let notes_style = |fg: PackedRgba| Style::new().fg(fg).bg(packed(theme.surface));
+        let mut note_rows = 0usize;
+        if note_lines.is_empty() {
+            lines.push(FtLine::from_spans(vec![FtSpan::styled(
+                fit("  Markdown context for this task"),
+                notes_style(packed(theme.border)),
+            )]));
+            note_rows += 1;
+        }
+        let first_note_line = note_lines.len().saturating_sub(TASK_NOTES_MAX_ROWS);
+        for line in note_lines.iter().skip(first_note_line) {
+            lines.push(FtLine::from_spans(vec![FtSpan::styled(
+                fit(format!("  {line}").as_str()),
+                notes_style(packed(theme.text)),
+            )]));
+            note_rows += 1;
+        }
+        for _ in note_rows..TASK_NOTES_MIN_ROWS {
+            lines.push(FtLine::from_spans(vec![FtSpan::styled(
+                fit(""),
+                notes_style(packed(theme.text)),
+            )]));
+        }
+
+        lines.push(FtLine::raw(""));
+        lines.push(modal_actions_row(
+            content_width,
+            theme,
+            "Save",
+            "Cancel",
+            self.dialog_focus_is(FOCUS_ID_TASK_NOTES_SAVE_BUTTON),
+            self.dialog_focus_is(FOCUS_ID_TASK_NOTES_CANCEL_BUTTON),
+        ));
+        lines.extend(modal_wrapped_hint_rows(
+            content_width,
+            theme,
+            "Tab/C-n next, S-Tab/C-p prev, Enter newline in notes, C-s save, Esc cancel. Use {notes} in a launch prompt to include these notes",
+        ));
+        let dialog_height = u16::try_from(lines.len().saturating_add(2))
+            .unwrap_or(u16::MAX)
+            .min(area.height);
+        let body = FtText::from_lines(lines);
+
+        render_modal_dialog(
+            frame,
+            area,
+            body,
+            ModalDialogSpec {
+                dialog_width,
+                dialog_height,
+                title: "Task Notes",
+                theme,
+                border_color: packed(theme.info),
+                hit_id: HIT_ID_TASK_NOTES_DIALOG,
+            },
+        );
+    }
+}
@@ -77,6 +77,9 @@ impl GroveApp {
             &visible_plain_lines,
             visible_start,
         );
+        if self.preview_tab == PreviewTab::Diff && self.diff_review.file_tree_visible {
+            self.render_diff_file_tree(frame, inner);
+        }
         if interactive_input_active {
             let output_x = inner.x;
             let output_y = inner.y.saturating_add(PREVIEW_METADATA_ROWS);
@@ -272,9 +272,30 @@ impl GroveApp {
             }];
         }
 
+        let selected_hunk_lines = self
+            .selected_diff_hunk()
+            .map(|hunk| hunk.start_line..hunk.end_line);
+        let comment_lines = self
+            .diff_review_matches_selection()
+            .then_some(&self.diff_review.comment_lines);
         visible_parsed_lines
             .iter()
-            .map(|line| parsed_preview_line_to_ft_line(line, theme))
+            .enumerate()
+            .map(|(index, line)| {
+                let highlighted = selected_hunk_lines
+                    .as_ref()
+                    .is_some_and(|lines| lines.contains(&(visible_start + index)));
+                if let Some(comment) = comment_lines
+                    .and_then(|lines| lines.get(&(visible_start + index)))
+                    .and_then(|comment_index| self.diff_review.comments.get(*comment_index))
+                {
+                    return commented_preview_line(line, comment.body.as_str(), highlighted, theme);
+                }
+                if highlighted {
+                    return highlighted_preview_line(line, theme);
+                }
+                parsed_preview_line_to_ft_line(line, theme)
+            })
             .collect()
     }
 }
@@ -298,7 +319,7 @@ fn plain_preview_line(line: &str) -> PreviewParsedLine {
     }
 }
 
-fn parsed_preview_line_to_ft_line(
+pub(super) fn parsed_preview_line_to_ft_line(
     line: &PreviewParsedLine,
     theme: ftui::ResolvedTheme,
 ) -> FtLine<'static> {
@@ -316,6 +337,46 @@ fn parsed_preview_line_to_ft_line(
     )
 }
 
+fn highlighted_preview_line(
+    line: &PreviewParsedLine,
+    theme: ftui::ResolvedTheme,
+) -> FtLine<'static> {
+    FtLine::from_spans(line.spans.iter().map(|span| {
+        let style = parsed_preview_style_to_ft_style(&span.style, theme)
+            .unwrap_or_else(Style::new)
+            .bg(packed(theme.selection_bg));
+        FtSpan::styled(span.text.clone(), style)
+    }))
+}
+
+fn commented_preview_line(
+    line: &PreviewParsedLine,
+    comment: &str,
+    highlighted: bool,
+    theme: ftui::ResolvedTheme,
+) -> FtLine<'static> {
+    let mut spans = line
+        .spans
+        .iter()
+        .map(|span| {
+            let style =
+                parsed_preview_style_to_ft_style(&span.style, theme).unwrap_or_else(Style::new);
+            let style = if highlighted {
+                style.bg(packed(theme.selection_bg))
+            } else {
+                style
+            };
+            FtSpan::styled(span.text.clone(), style)
+        })
+        .collect::<Vec<FtSpan<'static>>>();
+    let comment = comment.lines().next().unwrap_or_default();
+    spans.push(FtSpan::styled(
+        format!("  ◆ {comment}"),
+        Style::new().fg(packed(theme.warning)).italic(),
+    ));
+    FtLine::from_spans(spans)
+}
+
 fn parsed_preview_span_to_ft_span(
     span: &PreviewParsedSpan,
     theme: ftui::ResolvedTheme,
@@ -0,0 +1,89 @@
+use super::view_prelude::*;
+
+const DIFF_FILE_TREE_MIN_WIDTH: u16 = 24;
+const DIFF_FILE_TREE_MAX_WIDTH: u16 = 48;
+
+impl GroveApp {
+    pub(super) fn render_diff_file_tree(&self, frame: &mut Frame, inner: Rect) {
+        let top = inner.y.saturating_add(PREVIEW_METADATA_ROWS);
+        let height = inner.bottom().saturating_sub(top);
+        let width = (inner.width / 3).clamp(DIFF_FILE_TREE_MIN_WIDTH, DIFF_FILE_TREE_MAX_WIDTH);
+        if height == 0 || inner.width < width.saturating_mul(2) {
+            return;
+        }
+        let area = Rect::new(inner.right().saturating_sub(width), top, width, height);
+
+        let theme = self.active_ui_theme();
+        let rows = file_tree_rows(&self.diff_review.file_stats);
+        let file_count = rows.iter().filter(|row| row.path.is_some()).count();
+        let title = format!("Files ({file_count})");
+        let block = Block::new()
+            .title(title.as_str())
+            .borders(Borders::LEFT)
+            .border_style(Style::new().fg(packed(theme.border)))
+            .style(Style::new().bg(packed(theme.surface)));
+        let tree_inner = block.inner(area);
+        block.render(area, frame);
+        if tree_inner.is_empty() {
+            return;
+        }
+
+        let visible_rows = usize::from(tree_inner.height);
+        let selected_index = self
+            .diff_review
+            .selected_file
+            .as_ref()
+            .and_then(|selected| {
+                rows.iter()
+                    .position(|row| row.path.as_ref() == Some(selected))
+            });
+        let skip = selected_index.map_or(0, |index| (index + 1).saturating_sub(visible_rows));
+
+        let mut lines = Vec::with_capacity(visible_rows);
+        if rows.is_empty() {
+            lines.push(FtLine::from_spans(vec![FtSpan::styled(
+                "(no changes)",
+                Style::new().fg(packed(theme.text_subtle)),
+            )]));
+        }
+        for (index, row) in rows.iter().enumerate().skip(skip).take(visible_rows) {
+            let row_style = if selected_index == Some(index) {
+                Style::new().bg(packed(theme.selection_bg))
+            } else {
+                Style::new()
+            };
+            let name_style = if row.path.is_some() {
+                row_style.fg(packed(theme.text))
+            } else {
+                row_style.fg(packed(theme.text_muted)).bold()
+            };
+            let insertions = format!(" +{}", row.insertions);
+            let deletions = format!(" -{}", row.deletions);
+            let indent = "  ".repeat(row.depth);
+            let suffix = if row.path.is_some() { "" } else { "/" };
+            let available = usize::from(tree_inner.width)
+                .saturating_sub(text_display_width(insertions.as_str()))
+                .saturating_sub(text_display_width(deletions.as_str()))
+                .saturating_sub(indent.len());
+            let name = ftui::text::truncate_with_ellipsis(
+                format!("{}{suffix}", row.name).as_str(),
+                available,
+                "…",
+            );
+            let padding = available.saturating_sub(text_display_width(name.as_str()));
+
+            lines.push(FtLine::from_spans(vec![
+                FtSpan::styled(indent, row_style),
+                FtSpan::styled(name, name_style),
+                FtSpan::styled(" ".repeat(padding), row_style),
+                FtSpan::styled(insertions, row_style.fg(packed(theme.success))),
+                FtSpan::styled(deletions, row_style.fg(packed(theme.error))),
+            ]));
+        }
+
+        Paragraph::new(FtText::from_lines(lines))
+            .wrap(ftui::text::WrapMode::None)
+            .style(Style::new().bg(packed(theme.surface)))
+            .render(tree_inner, frame);
+    }
+}
@@ -0,0 +1,72 @@
+use super::view_prelude::*;
+use super::view_preview_content::parsed_preview_line_to_ft_line;
+
+impl GroveApp {
+    pub(super) fn render_pinned_preview(&self, frame: &mut Frame, area: Rect) {
+        if area.is_empty() {
+            return;
+        }
+        let Some(split) = self.preview_split.as_ref() else {
+            return;
+        };
+        let pinned = &split.pinned;
+        let theme = self.active_ui_theme();
+        let title = format!("{} · {}", pinned.workspace_name, pinned.tab_title);
+        let block = Block::new()
+            .title(title.as_str())
+            .borders(Borders::ALL)
+            .border_style(self.pane_border_style(false));
+        let inner = block.inner(area);
+        block.render(area, frame);
+        let _ = frame.register_hit_region(area, HitId::new(HIT_ID_PREVIEW_PINNED));
+
+        if inner.is_empty() {
+            return;
+        }
+
+        let muted = Style::new().fg(packed(theme.text_muted));
+        let parsed_lines = pinned.preview.active_parsed_lines();
+        let text_lines = if pinned.session_name.is_none() {
+            vec![FtLine::from_spans(vec![FtSpan::styled(
+                "(no live session)",
+                muted,
+            )])]
+        } else if parsed_lines.is_empty() {
+            vec![FtLine::from_spans(vec![FtSpan::styled(
+                "(waiting for output)",
+                muted,
+            )])]
+        } else {
+            let (start, end) = pinned_visible_range(
+                parsed_lines.len(),
+                usize::from(inner.height),
+                pinned.scroll_from_bottom,
+            );
+            parsed_lines[start..end]
+                .iter()
+                .map(|line| parsed_preview_line_to_ft_line(line, theme))
+                .collect()
+        };
+
+        Paragraph::new(FtText::from_lines(text_lines))
+            .wrap(ftui::text::WrapMode::None)
+            .style(
+                Style::new()
+                    .fg(packed(theme.text))
+                    .bg(packed(theme.background)),
+            )
+            .render(inner, frame);
+    }
+}
+
+/// Visible `(start, end)` line range for a pinned preview scrolled
+/// `scroll_from_bottom` lines up from the newest output.
+fn pinned_visible_range(
+    total_lines: usize,
+    height: usize,
+    scroll_from_bottom: usize,
+) -> (usize, usize) {
+    let max_scroll = total_lines.saturating_sub(height);
+    let end = total_lines.saturating_sub(scroll_from_bottom.min(max_scroll));
+    (end.saturating_sub(height), end)
+}
@@ -35,7 +35,7 @@ impl GroveApp {
     }
 
     pub(super) fn preview_content_viewport(&self) -> Option<PreviewContentViewport> {
-        let (_, _, preview_rect) = self.effective_workspace_rects();
+        let preview_rect = self.live_preview_rect();
         if preview_rect.is_empty() {
             return None;
         }
@@ -10,6 +10,10 @@ impl GroveApp {
             "merge" => "Merge",
             "update_from_base" => "Update",
             "session_cleanup" => "Cleanup",
+            "gc" => "Cleanup",
+            "commit" => "Commit",
+            "review_comment" => "Comment",
+            "task_notes" => "Notes",
             "create" => "Create",
             "edit" => "Edit",
             "rename_tab" => "Rename",
@@ -48,6 +52,12 @@ impl GroveApp {
         if self.task_reorder_active() {
             return "Task Reorder".to_string();
         }
+        if self.dashboard_active() {
+            return "Dashboard".to_string();
+        }
+        if self.sidebar_filter_editing() {
+            return "Filter".to_string();
+        }
 
         if self.preview_focused() {
             format!("Preview: {}", self.preview_tab.label())
@@ -115,6 +125,12 @@ impl GroveApp {
                 if self.task_reorder_active() {
                     return "Status: task reorder".to_string();
                 }
+                if self.dashboard_active() {
+                    return "Status: dashboard".to_string();
+                }
+                if self.sidebar_filter_editing() {
+                    return "Status: filter".to_string();
+                }
 
                 match self.state.mode {
                     UiMode::List => format!("Status: list, perm={}", self.permission_mode_label()),
@@ -169,6 +185,10 @@ impl GroveApp {
         let state_label = self.footer_state_chip_label();
         let state_chip = format!("[{state_label}]");
         let context = self.footer_context_line();
+        let help_key = self
+            .keymap
+            .help_key(UiCommand::OpenHelp)
+            .unwrap_or_else(|| "?".to_string());
         let base_style = Style::new()
             .bg(packed(theme.surface))
             .fg(packed(theme.text));
@@ -178,7 +198,7 @@ impl GroveApp {
             .left(StatusItem::text(state_chip.as_str()))
             .left(StatusItem::text(context.as_str()))
             .right(StatusItem::text("[Keys]"))
-            .right(StatusItem::key_hint("?", "help"))
+            .right(StatusItem::key_hint(help_key.as_str(), "help"))
             .right(StatusItem::key_hint("Ctrl+K", "palette"))
             .render(area, frame);
         let _ = frame.register_hit_region(area, HitId::new(HIT_ID_STATUS));